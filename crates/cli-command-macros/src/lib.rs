@@ -224,6 +224,15 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 		}
 	});
 
+	let args_arms = catalog.entries.iter().map(|entry| {
+		let id = &entry.id;
+		let ty = &entry.ty;
+		let canonical = &entry.canonical;
+		quote! {
+			CommandId::#id => <<#ty as crate::commands::def::CommandDef>::Raw as clap::Args>::augment_args(clap::Command::new(#canonical)),
+		}
+	});
+
 	let run_arms = catalog.entries.iter().map(|entry| {
 		let id = &entry.id;
 		let ty = &entry.ty;
@@ -259,8 +268,7 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 					}
 				}
 
-				let raw: <Cmd as crate::commands::def::CommandDef>::Raw = serde_json::from_value(args)
-					.map_err(|err| crate::error::PwError::Context(format!("INVALID_INPUT: {}", err)))?;
+				let raw: <Cmd as crate::commands::def::CommandDef>::Raw = crate::commands::input::parse_raw(canonical, args)?;
 
 				<Cmd as crate::commands::def::CommandDef>::validate_mode(&raw, exec.mode)?;
 				let resolved = {
@@ -272,6 +280,11 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 					<<Cmd as crate::commands::def::CommandDef>::Raw as crate::commands::def::Resolve>::resolve(raw, &env)?
 				};
 
+				if exec.dry_run {
+					let report = <Cmd as crate::commands::def::CommandDef>::dry_run(&resolved);
+					return Ok(crate::commands::def::ErasedOutcome::dry_run(canonical, report));
+				}
+
 				let outcome = <Cmd as crate::commands::def::CommandDef>::execute(&resolved, exec).await?;
 				outcome.erase(canonical)
 			}
@@ -321,6 +334,13 @@ fn expand_command_graph(catalog: CatalogInput) -> Result<TokenStream> {
 			}
 		}
 
+		/// Clap description of a command's input fields, for generated docs.
+		pub fn command_args(id: CommandId) -> clap::Command {
+			match id {
+				#(#args_arms)*
+			}
+		}
+
 		pub async fn run_command(
 			id: CommandId,
 			args: serde_json::Value,
@@ -2,15 +2,25 @@
 //!
 //! When a command fails, this module captures debug artifacts (screenshot, HTML)
 //! to help diagnose the failure. Artifacts are saved to the specified directory
-//! and reported in the error envelope.
+//! and reported in the error envelope. Timeout failures can additionally carry a
+//! screenshot and the most recent console errors in the error `details`.
 
+use std::collections::VecDeque;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use pw_rs::Page;
+use pw_rs::{ConsoleMessageKind, ConsoleSubscription, Page};
+use serde_json::{Value, json};
 use tracing::{debug, warn};
 
-use crate::output::{Artifact, ArtifactType};
+use crate::output::{Artifact, ArtifactType, CommandError, FailureWithArtifacts};
+
+/// Number of recent console errors attached to a timeout failure.
+pub const TIMEOUT_CONSOLE_ERRORS: usize = 5;
+
+/// Number of leading console errors reported by `--fail-on-console-error`.
+pub const FAIL_CONSOLE_ERRORS: usize = 20;
 
 /// Collected artifacts from a failure scenario
 #[derive(Debug, Default)]
@@ -69,6 +79,123 @@ pub async fn collect_failure_artifacts(page: &Page, artifacts_dir: &Path, comman
 	collected
 }
 
+/// Bounded record of the `console.error` messages logged on a page.
+///
+/// The page is watched until this value is dropped.
+pub struct ConsoleErrorTail {
+	errors: Arc<Mutex<VecDeque<String>>>,
+	_subscription: ConsoleSubscription,
+}
+
+impl ConsoleErrorTail {
+	/// Starts recording the last [`TIMEOUT_CONSOLE_ERRORS`] console errors emitted by `page`.
+	pub fn watch(page: &Page) -> Self {
+		Self::watch_bounded(page, TIMEOUT_CONSOLE_ERRORS, Overflow::DropOldest)
+	}
+
+	/// Starts recording the first `cap` console errors emitted by `page`.
+	///
+	/// Later errors are dropped, since the first one is usually the root cause.
+	pub fn watch_first(page: &Page, cap: usize) -> Self {
+		Self::watch_bounded(page, cap, Overflow::DropNewest)
+	}
+
+	fn watch_bounded(page: &Page, cap: usize, overflow: Overflow) -> Self {
+		let errors = Arc::new(Mutex::new(VecDeque::with_capacity(cap)));
+		let sink = Arc::clone(&errors);
+		let subscription = page.on_console(move |msg| {
+			if msg.kind() != ConsoleMessageKind::Error {
+				return;
+			}
+			if let Ok(mut errors) = sink.lock() {
+				push_bounded(&mut errors, msg.text().to_string(), cap, overflow);
+			}
+		});
+		Self {
+			errors,
+			_subscription: subscription,
+		}
+	}
+
+	/// Returns the recorded errors, oldest first.
+	pub fn recent(&self) -> Vec<String> {
+		self.errors.lock().map(|errors| errors.iter().cloned().collect()).unwrap_or_default()
+	}
+}
+
+/// Which item a full [`ConsoleErrorTail`] gives up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Overflow {
+	/// Evict the oldest item to make room, keeping the most recent ones.
+	DropOldest,
+	/// Discard the incoming item, keeping the first ones.
+	DropNewest,
+}
+
+fn push_bounded(buf: &mut VecDeque<String>, item: String, cap: usize, overflow: Overflow) {
+	if buf.len() == cap {
+		match overflow {
+			Overflow::DropOldest => {
+				buf.pop_front();
+			}
+			Overflow::DropNewest => return,
+		}
+	}
+	buf.push_back(item);
+}
+
+/// Folds a timed-out command's screenshot and `console_errors` into the
+/// error's `details` under `screenshot` and `consoleErrors`.
+///
+/// A screenshot already among `artifacts` (from failure artifact collection)
+/// is reused; otherwise one is captured to `screenshot_path` and added to them.
+/// Screenshot failures are logged; the failure is still returned with the
+/// console errors so the original timeout is what gets reported.
+pub async fn collect_timeout_trace(
+	page: &Page,
+	screenshot_path: &Path,
+	error: CommandError,
+	console_errors: Vec<String>,
+	mut artifacts: Vec<Artifact>,
+) -> FailureWithArtifacts {
+	let existing = artifacts.iter().find(|artifact| artifact.artifact_type == ArtifactType::Screenshot);
+	let screenshot = match existing {
+		Some(artifact) => Some(artifact.path.clone()),
+		None => {
+			let dir_ready = match screenshot_path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+				Some(dir) => std::fs::create_dir_all(dir)
+					.map_err(|e| warn!("Failed to create screenshot directory: {}", e))
+					.is_ok(),
+				None => true,
+			};
+			let captured = if dir_ready { capture_screenshot(page, screenshot_path).await } else { None };
+			captured.map(|artifact| {
+				let path = artifact.path.clone();
+				artifacts.push(artifact);
+				path
+			})
+		}
+	};
+
+	let details = timeout_details(error.details, screenshot.as_deref(), &console_errors);
+	let error = CommandError {
+		details: Some(details),
+		..error
+	};
+
+	FailureWithArtifacts::new(error).with_artifacts(artifacts)
+}
+
+fn timeout_details(details: Option<Value>, screenshot: Option<&Path>, console_errors: &[String]) -> Value {
+	let mut details = match details {
+		Some(Value::Object(map)) => map,
+		_ => Default::default(),
+	};
+	details.insert("screenshot".to_string(), json!(screenshot));
+	details.insert("consoleErrors".to_string(), json!(console_errors));
+	Value::Object(details)
+}
+
 async fn capture_screenshot(page: &Page, path: &Path) -> Option<Artifact> {
 	match page.screenshot_to_file(path, None).await {
 		Ok(bytes) => {
@@ -124,6 +251,47 @@ mod tests {
 		assert!(collected.is_empty());
 	}
 
+	#[test]
+	fn push_bounded_keeps_most_recent_items() {
+		let mut buf = VecDeque::new();
+		for i in 0..7 {
+			push_bounded(&mut buf, format!("error {i}"), TIMEOUT_CONSOLE_ERRORS, Overflow::DropOldest);
+		}
+		assert_eq!(buf.len(), TIMEOUT_CONSOLE_ERRORS);
+		assert_eq!(buf.front().map(String::as_str), Some("error 2"));
+		assert_eq!(buf.back().map(String::as_str), Some("error 6"));
+	}
+
+	#[test]
+	fn push_bounded_can_keep_first_items() {
+		let mut buf = VecDeque::new();
+		for i in 0..FAIL_CONSOLE_ERRORS + 3 {
+			push_bounded(&mut buf, format!("error {i}"), FAIL_CONSOLE_ERRORS, Overflow::DropNewest);
+		}
+		assert_eq!(buf.len(), FAIL_CONSOLE_ERRORS);
+		assert_eq!(buf.front().map(String::as_str), Some("error 0"));
+		assert_eq!(buf.back(), Some(&format!("error {}", FAIL_CONSOLE_ERRORS - 1)));
+	}
+
+	#[test]
+	fn timeout_details_merge_into_existing_object() {
+		let details = timeout_details(
+			Some(json!({ "timeout_ms": 500, "condition": "expression" })),
+			Some(Path::new("/tmp/eval-timeout.png")),
+			&["boom".to_string()],
+		);
+		assert_eq!(details["timeout_ms"], 500);
+		assert_eq!(details["screenshot"], "/tmp/eval-timeout.png");
+		assert_eq!(details["consoleErrors"], json!(["boom"]));
+	}
+
+	#[test]
+	fn timeout_details_without_screenshot() {
+		let details = timeout_details(None, None, &[]);
+		assert!(details["screenshot"].is_null());
+		assert_eq!(details["consoleErrors"], json!([]));
+	}
+
 	#[test]
 	fn collected_artifacts_not_empty_with_items() {
 		let mut collected = CollectedArtifacts::default();
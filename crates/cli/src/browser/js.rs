@@ -25,6 +25,7 @@ pub fn console_capture_injection_js() -> &'static str {
         }"#
 }
 
+/// Returns the unrounded CSS-pixel bounding box of the first match as JSON, or `'null'`.
 pub fn get_element_coords_js(selector: &str) -> String {
 	let escaped = escape_selector(selector);
 	format!(
@@ -33,10 +34,10 @@ pub fn get_element_coords_js(selector: &str) -> String {
                 if (!el) return 'null';
                 const rect = el.getBoundingClientRect();
                 return JSON.stringify({{
-                    x: Math.round(rect.x + rect.width / 2),
-                    y: Math.round(rect.y + rect.height / 2),
-                    width: Math.round(rect.width),
-                    height: Math.round(rect.height),
+                    x: rect.x,
+                    y: rect.y,
+                    width: rect.width,
+                    height: rect.height,
                     text: el.textContent?.trim().substring(0, 100) || null,
                     href: el.getAttribute('href')
                 }});
@@ -44,6 +45,7 @@ pub fn get_element_coords_js(selector: &str) -> String {
 	)
 }
 
+/// Returns the unrounded CSS-pixel bounding boxes of all matches (with `index`) as a JSON array.
 pub fn get_all_element_coords_js(selector: &str) -> String {
 	let escaped = escape_selector(selector);
 	format!(
@@ -53,10 +55,10 @@ pub fn get_all_element_coords_js(selector: &str) -> String {
                     const rect = el.getBoundingClientRect();
                     return {{
                         index,
-                        x: Math.round(rect.x + rect.width / 2),
-                        y: Math.round(rect.y + rect.height / 2),
-                        width: Math.round(rect.width),
-                        height: Math.round(rect.height),
+                        x: rect.x,
+                        y: rect.y,
+                        width: rect.width,
+                        height: rect.height,
                         text: el.textContent?.trim().substring(0, 80) || null,
                         href: el.getAttribute('href')
                     }};
@@ -1,4 +1,4 @@
 pub mod js;
 pub mod session;
 
-pub use session::{AuthInjectionReport, BrowserSession, DownloadInfo, SessionConfig, SessionEndpoints, ShutdownMode};
+pub use session::{AuthInjectionReport, BrowserSession, DownloadInfo, SessionConfig, SessionEndpoints, SessionPage, ShutdownMode};
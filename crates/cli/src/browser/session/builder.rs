@@ -3,6 +3,7 @@ use tracing::debug;
 
 use super::config::SessionConfig;
 use super::context_factory::{ContextFactoryInput, build_browser_context};
+use super::features::console::ConsoleBuffer;
 use super::features::{blocking, downloads, har};
 use super::{BrowserSession, ShutdownMode, page_selection};
 use crate::error::{PwError, Result};
@@ -23,6 +24,10 @@ pub(crate) async fn build(config: SessionConfig) -> Result<BrowserSession> {
 		har,
 		block,
 		download,
+		no_viewport,
+		insecure,
+		launch,
+		media,
 	} = config;
 
 	debug!(
@@ -45,6 +50,10 @@ pub(crate) async fn build(config: SessionConfig) -> Result<BrowserSession> {
 			needs_custom_context,
 			har: &har,
 			download: &download,
+			no_viewport,
+			insecure,
+			launch: &launch,
+			media: &media,
 		},
 	)
 	.await?;
@@ -55,6 +64,7 @@ pub(crate) async fn build(config: SessionConfig) -> Result<BrowserSession> {
 		preferred_url.as_deref(),
 	)
 	.await?;
+	let console = ConsoleBuffer::install(&page);
 	let har_recording = har::start_if_enabled(&context_build.context, &har).await?;
 	let route_subscriptions = blocking::install_routes(&page, &block).await?;
 	let download_tracking = downloads::install_tracking(&page, &download)?;
@@ -77,5 +87,6 @@ pub(crate) async fn build(config: SessionConfig) -> Result<BrowserSession> {
 		route_subscriptions,
 		download_subscription: download_tracking.subscription,
 		downloads: download_tracking.downloads,
+		console,
 	})
 }
@@ -1,6 +1,6 @@
 use pw_rs::{StorageState, WaitUntil};
 
-use crate::context::{BlockConfig, DownloadConfig, HarConfig};
+use crate::context::{BlockConfig, DownloadConfig, HarConfig, LaunchConfig, MediaConfig};
 use crate::types::BrowserKind;
 
 /// Fully owned browser-session configuration.
@@ -31,6 +31,14 @@ pub struct SessionConfig {
 	pub block: BlockConfig,
 	/// Download-tracking configuration.
 	pub download: DownloadConfig,
+	/// Whether new contexts disable viewport emulation.
+	pub no_viewport: bool,
+	/// Whether `wss://` browser server certificates go unvalidated.
+	pub insecure: bool,
+	/// Channel, devtools, and slow-mo options for browsers this session launches.
+	pub launch: LaunchConfig,
+	/// Color scheme, reduced motion, and forced colors emulation for new contexts.
+	pub media: MediaConfig,
 }
 
 impl SessionConfig {
@@ -48,12 +56,16 @@ impl SessionConfig {
 			har: HarConfig::default(),
 			block: BlockConfig::default(),
 			download: DownloadConfig::default(),
+			no_viewport: false,
+			insecure: false,
+			launch: LaunchConfig::default(),
+			media: MediaConfig::default(),
 		}
 	}
 
 	/// Returns true when context creation must use explicit options.
 	pub(crate) fn needs_custom_context(&self) -> bool {
-		self.storage_state.is_some() || self.har.is_enabled() || self.download.is_enabled()
+		self.storage_state.is_some() || self.har.is_enabled() || self.download.is_enabled() || self.no_viewport || self.media.is_enabled()
 	}
 }
 
@@ -87,4 +99,18 @@ mod tests {
 		dl_cfg.download.dir = Some("downloads".into());
 		assert!(dl_cfg.needs_custom_context());
 	}
+
+	#[test]
+	fn session_config_requires_custom_context_for_no_viewport() {
+		let mut cfg = SessionConfig::new(WaitUntil::NetworkIdle);
+		cfg.no_viewport = true;
+		assert!(cfg.needs_custom_context());
+	}
+
+	#[test]
+	fn session_config_requires_custom_context_for_media_emulation() {
+		let mut cfg = SessionConfig::new(WaitUntil::NetworkIdle);
+		cfg.media.reduced_motion = Some(pw_rs::ReducedMotion::Reduce);
+		assert!(cfg.needs_custom_context());
+	}
 }
@@ -1,8 +1,8 @@
-use pw_rs::{BrowserContextOptions, Playwright, StorageState};
+use pw_rs::{BrowserContextOptions, ConnectOptions, LaunchOptions, Playwright, StorageState};
 use tracing::debug;
 
 use super::types::SessionEndpoints;
-use crate::context::{DownloadConfig, HarConfig};
+use crate::context::{DownloadConfig, HarConfig, LaunchConfig, MediaConfig};
 use crate::error::{PwError, Result};
 use crate::types::BrowserKind;
 
@@ -16,6 +16,10 @@ pub(crate) struct ContextFactoryInput<'a> {
 	pub(crate) needs_custom_context: bool,
 	pub(crate) har: &'a HarConfig,
 	pub(crate) download: &'a DownloadConfig,
+	pub(crate) no_viewport: bool,
+	pub(crate) insecure: bool,
+	pub(crate) launch: &'a LaunchConfig,
+	pub(crate) media: &'a MediaConfig,
 }
 
 /// Browser/context build output used by session assembly.
@@ -38,11 +42,48 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 		needs_custom_context,
 		har,
 		download,
+		no_viewport,
+		insecure,
+		launch,
+		media,
 	} = input;
 
 	if let Some(endpoint) = cdp_endpoint {
 		if browser_kind != BrowserKind::Chromium {
-			return Err(PwError::BrowserLaunch("CDP endpoint connections require the chromium browser".to_string()));
+			// Firefox and WebKit have no CDP; stored endpoints point at a Playwright server.
+			let browser_type = match browser_kind {
+				BrowserKind::Firefox => playwright.firefox(),
+				_ => playwright.webkit(),
+			};
+			let options = ConnectOptions::new().insecure(insecure);
+			let browser = browser_type
+				.connect(endpoint, Some(options))
+				.await
+				.map_err(|e| PwError::BrowserLaunch(e.to_string()))?;
+			let context = if needs_custom_context {
+				let options = build_context_options(storage_state, har, download, no_viewport, media);
+				browser.new_context_with_options(options).await?
+			} else {
+				browser.new_context().await?
+			};
+
+			return Ok(ContextBuildResult {
+				browser,
+				context,
+				endpoints: SessionEndpoints {
+					ws: Some(endpoint.to_string()),
+					cdp: None,
+				},
+				launched_server: None,
+				reuse_existing_page: false,
+			});
+		}
+
+		if insecure {
+			// The driver opens CDP connections itself and always verifies certificates.
+			return Err(PwError::UnsupportedMode(
+				"--insecure applies to Firefox/WebKit browser server endpoints, not Chromium CDP endpoints".into(),
+			));
 		}
 
 		let connect_result = playwright
@@ -54,7 +95,7 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 		let browser = connect_result.browser;
 		let mut reuse_existing_page = false;
 		let context = if needs_custom_context {
-			let options = build_context_options(storage_state, har, download);
+			let options = build_context_options(storage_state, har, download, no_viewport, media);
 			browser.new_context_with_options(options).await?
 		} else if let Some(default_ctx) = connect_result.default_context {
 			reuse_existing_page = true;
@@ -77,10 +118,7 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 
 	if launch_server {
 		playwright.keep_server_running();
-		let launch_options = pw_rs::LaunchOptions {
-			headless: Some(headless),
-			..Default::default()
-		};
+		let launch_options = build_launch_options(headless, launch);
 		let launched = match browser_kind {
 			BrowserKind::Chromium => playwright
 				.chromium()
@@ -101,7 +139,7 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 
 		let browser = launched.browser().clone();
 		let context = if needs_custom_context {
-			let options = build_context_options(storage_state, har, download);
+			let options = build_context_options(storage_state, har, download, no_viewport, media);
 			browser.new_context_with_options(options).await?
 		} else {
 			browser.new_context().await?
@@ -119,17 +157,14 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 		});
 	}
 
-	let launch_options = pw_rs::LaunchOptions {
-		headless: Some(headless),
-		..Default::default()
-	};
+	let launch_options = build_launch_options(headless, launch);
 	let browser = match browser_kind {
 		BrowserKind::Chromium => playwright.chromium().launch_with_options(launch_options).await?,
 		BrowserKind::Firefox => playwright.firefox().launch_with_options(launch_options).await?,
 		BrowserKind::Webkit => playwright.webkit().launch_with_options(launch_options).await?,
 	};
 	let context = if needs_custom_context {
-		let options = build_context_options(storage_state, har, download);
+		let options = build_context_options(storage_state, har, download, no_viewport, media);
 		browser.new_context_with_options(options).await?
 	} else {
 		browser.new_context().await?
@@ -144,9 +179,46 @@ pub(crate) async fn build_browser_context(playwright: &mut Playwright, input: Co
 	})
 }
 
-fn build_context_options(storage_state: Option<StorageState>, har_config: &HarConfig, download_config: &DownloadConfig) -> BrowserContextOptions {
+/// Maps session headless mode and launch tuning onto Playwright launch options.
+///
+/// DevTools only opens in headful browsers, so it overrides `headless`.
+fn build_launch_options(headless: bool, launch: &LaunchConfig) -> LaunchOptions {
+	let mut options = LaunchOptions::default().headless(headless && !launch.forces_headful());
+	if let Some(channel) = launch.channel {
+		options = options.channel(channel.as_str().to_string());
+	}
+	if launch.devtools {
+		options = options.devtools(true);
+	}
+	if let Some(ms) = launch.slow_mo_ms {
+		options = options.slow_mo(ms as f64);
+	}
+	options
+}
+
+fn build_context_options(
+	storage_state: Option<StorageState>,
+	har_config: &HarConfig,
+	download_config: &DownloadConfig,
+	no_viewport: bool,
+	media: &MediaConfig,
+) -> BrowserContextOptions {
 	let mut builder = BrowserContextOptions::builder();
 
+	if no_viewport {
+		builder = builder.no_viewport(true);
+	}
+
+	if let Some(scheme) = media.color_scheme {
+		builder = builder.color_scheme(scheme);
+	}
+	if let Some(motion) = media.reduced_motion {
+		builder = builder.reduced_motion(motion);
+	}
+	if let Some(colors) = media.forced_colors {
+		builder = builder.forced_colors(colors);
+	}
+
 	if let Some(state) = storage_state {
 		builder = builder.storage_state(state);
 	}
@@ -187,4 +259,55 @@ mod tests {
 	fn endpoint_bundle_reports_empty_for_default() {
 		assert!(SessionEndpoints::default().is_empty());
 	}
+
+	#[test]
+	fn no_viewport_emits_null_viewport() {
+		let options = build_context_options(None, &HarConfig::default(), &DownloadConfig::default(), true, &MediaConfig::default());
+		let json = serde_json::to_value(options).unwrap();
+		assert!(json.get("viewport").is_none());
+		assert_eq!(json["noDefaultViewport"], serde_json::json!(true));
+
+		let options = build_context_options(None, &HarConfig::default(), &DownloadConfig::default(), false, &MediaConfig::default());
+		assert!(serde_json::to_value(options).unwrap().get("noDefaultViewport").is_none());
+	}
+
+	#[test]
+	fn media_emulation_serializes_into_new_context() {
+		let media = MediaConfig {
+			color_scheme: Some(pw_rs::ColorScheme::Dark),
+			reduced_motion: Some(pw_rs::ReducedMotion::Reduce),
+			forced_colors: None,
+		};
+		let options = build_context_options(None, &HarConfig::default(), &DownloadConfig::default(), false, &media);
+		let json = serde_json::to_value(options).unwrap();
+		assert_eq!(json["colorScheme"], "dark");
+		assert_eq!(json["reducedMotion"], "reduce");
+		assert!(json.get("forcedColors").is_none());
+	}
+
+	#[test]
+	fn launch_options_receive_channel_and_slow_mo() {
+		let launch = LaunchConfig {
+			channel: Some(crate::types::BrowserChannel::ChromeBeta),
+			devtools: false,
+			slow_mo_ms: Some(250),
+		};
+		let options = build_launch_options(true, &launch);
+		assert_eq!(options.channel.as_deref(), Some("chrome-beta"));
+		assert_eq!(options.slow_mo, Some(250.0));
+		assert_eq!(options.headless, Some(true));
+		assert!(options.devtools.is_none());
+	}
+
+	#[test]
+	fn devtools_forces_headful_launch() {
+		let launch = LaunchConfig {
+			devtools: true,
+			..LaunchConfig::default()
+		};
+		let options = build_launch_options(true, &launch);
+		assert_eq!(options.headless, Some(false));
+		assert_eq!(options.devtools, Some(true));
+		assert!(options.channel.is_none());
+	}
 }
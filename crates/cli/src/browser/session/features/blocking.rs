@@ -1,16 +1,24 @@
-use pw_rs::Subscription;
+use pw_rs::{AbortReason, Subscription};
 use tracing::debug;
 
 use crate::context::BlockConfig;
 use crate::error::{PwError, Result};
 
 /// Installs request-blocking routes and returns RAII subscriptions.
+///
+/// Matching requests are aborted with [`AbortReason::BlockedByClient`] and the
+/// pattern is recorded in [`BlockConfig::matched`].
 pub(crate) async fn install_routes(page: &pw_rs::Page, block_config: &BlockConfig) -> Result<Vec<Subscription>> {
 	let mut route_subscriptions = Vec::with_capacity(block_config.patterns.len());
 	for pattern in &block_config.patterns {
 		debug!(target = "pw", %pattern, "blocking pattern");
+		let matched = block_config.matched.clone();
+		let hit = pattern.clone();
 		let subscription = page
-			.route(pattern, |route| async move { route.abort(None).await })
+			.route(pattern, move |route| {
+				matched.record(&hit);
+				async move { route.abort(Some(AbortReason::BlockedByClient)).await }
+			})
 			.await
 			.map_err(|e| PwError::BrowserLaunch(format!("route setup failed: {e}")))?;
 		route_subscriptions.push(subscription);
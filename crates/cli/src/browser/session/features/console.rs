@@ -0,0 +1,56 @@
+//! Session-scoped console buffering.
+//!
+//! [`ConsoleBuffer`] subscribes to the session page's console events as soon as
+//! the page is acquired and keeps a bounded ring buffer that commands drain.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use pw_rs::ConsoleSubscription;
+
+use crate::types::ConsoleMessage;
+
+/// Console messages kept per session; older ones are dropped first.
+pub(crate) const CONSOLE_BUFFER_CAPACITY: usize = 500;
+
+/// Ring buffer of console messages recorded since the session page was acquired.
+///
+/// Installed before any navigation, so messages logged while the target loads
+/// are kept even though no command was listening yet.
+pub(crate) struct ConsoleBuffer {
+	messages: Arc<Mutex<VecDeque<ConsoleMessage>>>,
+	_subscription: ConsoleSubscription,
+}
+
+impl ConsoleBuffer {
+	/// Starts recording console messages emitted by `page`.
+	pub(crate) fn install(page: &pw_rs::Page) -> Self {
+		let messages = Arc::new(Mutex::new(VecDeque::with_capacity(CONSOLE_BUFFER_CAPACITY)));
+		let sink = Arc::clone(&messages);
+		let subscription = page.on_console(move |msg| {
+			let entry = ConsoleMessage {
+				msg_type: msg.kind().to_string(),
+				text: msg.text().to_string(),
+				stack: None,
+			};
+			if let Ok(mut messages) = sink.lock() {
+				if messages.len() == CONSOLE_BUFFER_CAPACITY {
+					messages.pop_front();
+				}
+				messages.push_back(entry);
+			}
+		});
+		Self {
+			messages,
+			_subscription: subscription,
+		}
+	}
+
+	/// Removes and returns the buffered messages, oldest first.
+	///
+	/// Each message is returned once, so a reused session does not report
+	/// output from earlier commands again.
+	pub(crate) fn drain(&self) -> Vec<ConsoleMessage> {
+		self.messages.lock().map(|mut messages| messages.drain(..).collect()).unwrap_or_default()
+	}
+}
@@ -1,3 +1,4 @@
 pub(crate) mod blocking;
+pub(crate) mod console;
 pub(crate) mod downloads;
 pub(crate) mod har;
@@ -15,14 +15,16 @@ pub use shutdown::ShutdownMode;
 use tracing::debug;
 pub use types::{AuthInjectionReport, DownloadInfo, SessionEndpoints};
 
+use self::features::console::ConsoleBuffer;
 use self::features::har::HarRecording;
+use crate::context::{BlockConfig, DownloadConfig, LaunchConfig, NetworkConfig};
 use crate::error::{PwError, Result};
 use crate::types::BrowserKind;
 
 /// Active browser session used by command flows.
 ///
 /// A session owns Playwright runtime handles and optional feature state
-/// (HAR recording, request blocking, download tracking), and exposes
+/// (HAR recording, request blocking, download tracking, console buffering), and exposes
 /// explicit shutdown semantics through [`ShutdownMode`].
 pub struct BrowserSession {
 	_playwright: Playwright,
@@ -39,6 +41,7 @@ pub struct BrowserSession {
 	#[allow(dead_code, reason = "RAII: stored to keep handler alive until drop")]
 	download_subscription: Option<Subscription>,
 	downloads: Arc<Mutex<Vec<DownloadInfo>>>,
+	console: ConsoleBuffer,
 }
 
 impl BrowserSession {
@@ -80,12 +83,19 @@ impl BrowserSession {
 	}
 
 	/// Creates a session in browser-server mode.
-	pub async fn launch_server_session(wait_until: WaitUntil, storage_state: Option<StorageState>, headless: bool, browser_kind: BrowserKind) -> Result<Self> {
+	pub async fn launch_server_session(
+		wait_until: WaitUntil,
+		storage_state: Option<StorageState>,
+		headless: bool,
+		browser_kind: BrowserKind,
+		launch: LaunchConfig,
+	) -> Result<Self> {
 		let mut config = SessionConfig::new(wait_until);
 		config.storage_state = storage_state;
 		config.headless = headless;
 		config.browser_kind = browser_kind;
 		config.launch_server = true;
+		config.launch = launch;
 		Self::with_config(config).await
 	}
 
@@ -127,6 +137,7 @@ impl BrowserSession {
 			browser.new_context().await?
 		};
 		let page = context.new_page().await?;
+		let console = ConsoleBuffer::install(&page);
 
 		Ok(Self {
 			_playwright: playwright,
@@ -148,6 +159,7 @@ impl BrowserSession {
 			route_subscriptions: Vec::new(),
 			download_subscription: None,
 			downloads: Arc::new(Mutex::new(Vec::new())),
+			console,
 		})
 	}
 
@@ -158,6 +170,13 @@ impl BrowserSession {
 
 	/// Navigates the active page to a URL with optional timeout.
 	pub async fn goto(&self, url: &str, timeout_ms: Option<u64>) -> Result<()> {
+		self.goto_response(url, timeout_ms).await.map(|_| ())
+	}
+
+	/// Like [`goto`](Self::goto), returning the main resource response.
+	///
+	/// `None` for URLs without an HTTP response, such as `data:` and `about:blank`.
+	pub async fn goto_response(&self, url: &str, timeout_ms: Option<u64>) -> Result<Option<pw_rs::Response>> {
 		let mut goto_opts = GotoOptions {
 			wait_until: Some(self.wait_until),
 			..Default::default()
@@ -166,7 +185,7 @@ impl BrowserSession {
 			goto_opts.timeout = Some(std::time::Duration::from_millis(ms));
 		}
 
-		self.page.goto(url, Some(goto_opts)).await.map(|_| ()).map_err(|e| PwError::Navigation {
+		self.page.goto(url, Some(goto_opts)).await.map_err(|e| PwError::Navigation {
 			url: url.to_string(),
 			source: anyhow::Error::new(e),
 		})
@@ -197,6 +216,40 @@ impl BrowserSession {
 		self.downloads.lock().unwrap().clone()
 	}
 
+	/// Takes console messages logged since the previous call (or since the
+	/// session page was acquired), oldest first.
+	///
+	/// Bounded to the most recent 500 messages.
+	pub fn take_console_messages(&self) -> Vec<crate::types::ConsoleMessage> {
+		self.console.drain()
+	}
+
+	/// Opens another page on this session's context, set up like the session page.
+	///
+	/// The page gets the request-blocking routes, download tracking and network
+	/// throttle that `block`, `download` and `network` describe. Context-wide
+	/// state (cookies, offline mode, emulation) is already shared. Blocked
+	/// requests are recorded per page, in [`SessionPage::block_config`].
+	pub async fn open_page(&self, block: &BlockConfig, download: &DownloadConfig, network: &NetworkConfig) -> Result<SessionPage> {
+		let page = self.context.new_page().await?;
+		let block = BlockConfig {
+			patterns: block.patterns.clone(),
+			matched: Default::default(),
+		};
+		let route_subscriptions = features::blocking::install_routes(&page, &block).await?;
+		let download_tracking = features::downloads::install_tracking(&page, download)?;
+		if let Some(preset) = network.throttle {
+			page.throttle_network(preset).await?;
+		}
+
+		Ok(SessionPage {
+			page,
+			block,
+			route_subscriptions,
+			download_subscription: download_tracking.subscription,
+		})
+	}
+
 	/// Updates default close behavior used by higher-level session handles.
 	pub fn set_shutdown_mode(&mut self, mode: ShutdownMode) {
 		self.shutdown_mode = mode;
@@ -247,8 +300,20 @@ impl BrowserSession {
 	}
 
 	/// Shuts down session resources according to explicit mode.
+	///
+	/// Closing the context may fail harmlessly (CDP default contexts cannot be
+	/// closed), but a storage-state save armed with
+	/// [`set_save_storage_state_on_close`](pw_rs::BrowserContext::set_save_storage_state_on_close)
+	/// is written first and its failure is returned once shutdown completes.
 	pub async fn shutdown(mut self, mode: ShutdownMode) -> Result<()> {
 		features::har::export_if_active(&self.context, self.har_recording.as_ref()).await;
+		let saved = match self.context.save_storage_state_on_close() {
+			Some(path) => {
+				self.context.set_save_storage_state_on_close(None);
+				self.context.save_storage_state(&path).await
+			}
+			None => Ok(()),
+		};
 		let _ = self.context.close().await;
 
 		match mode {
@@ -266,6 +331,37 @@ impl BrowserSession {
 			}
 		}
 
+		saved?;
+		Ok(())
+	}
+}
+
+/// Page opened with [`BrowserSession::open_page`].
+///
+/// Owns the page's route and download subscriptions, which end when it is dropped.
+pub struct SessionPage {
+	page: pw_rs::Page,
+	block: BlockConfig,
+	#[allow(dead_code, reason = "RAII: stored to keep handlers alive until drop")]
+	route_subscriptions: Vec<Subscription>,
+	#[allow(dead_code, reason = "RAII: stored to keep handler alive until drop")]
+	download_subscription: Option<Subscription>,
+}
+
+impl SessionPage {
+	/// Returns the page handle.
+	pub fn page(&self) -> &pw_rs::Page {
+		&self.page
+	}
+
+	/// Returns the page's blocking patterns and the ones that aborted its requests.
+	pub fn block_config(&self) -> &BlockConfig {
+		&self.block
+	}
+
+	/// Closes the page.
+	pub async fn close(self) -> Result<()> {
+		self.page.close().await?;
 		Ok(())
 	}
 }
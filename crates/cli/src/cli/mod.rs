@@ -7,7 +7,7 @@ use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use crate::output::OutputFormat;
 use crate::styles::cli_styles;
-use crate::types::BrowserKind;
+use crate::types::{BrowserChannel, BrowserKind};
 
 /// Root CLI for pw v2.
 #[derive(Parser, Debug)]
@@ -21,8 +21,38 @@ pub struct Cli {
 	pub verbose: u8,
 
 	/// Output format: toon (default), json, ndjson, or text
-	#[arg(short = 'f', long, global = true, value_enum, default_value = "toon")]
-	pub format: OutputFormat,
+	///
+	/// Falls back to `PW_FORMAT`, then `format` in `pw.toml`.
+	#[arg(short = 'f', long, global = true, value_enum)]
+	pub format: Option<OutputFormat>,
+
+	/// Exit non-zero whenever an emitted response has `ok: false`
+	///
+	/// By default only CLI-level failures (bad arguments, unreadable files) exit
+	/// non-zero; command failures are reported in the envelope and exit 0.
+	#[arg(long, global = true)]
+	pub strict_exit: bool,
+
+	/// Also print failed responses to stdout as a JSON envelope line, whatever the format
+	///
+	/// Covers CLI-level failures too, which otherwise only reach stderr.
+	#[arg(long, global = true)]
+	pub json_errors: bool,
+
+	/// Fail each command with CONSOLE_ERROR if the page logs a console error while it runs
+	///
+	/// Applies to `exec`, `batch`, `run` and `repl` requests that do not set
+	/// `failOnConsoleError` themselves.
+	#[arg(long, global = true)]
+	pub fail_on_console_error: bool,
+
+	/// Write every JSON-RPC message exchanged with the Playwright driver to FILE
+	///
+	/// FILE is truncated first. One JSON line per message with `ts`, `dir`
+	/// (`send` or `recv`) and the message; cookies, passwords, auth headers and
+	/// localStorage values are redacted.
+	#[arg(long, global = true, value_name = "FILE")]
+	pub dump_protocol: Option<PathBuf>,
 
 	#[command(subcommand)]
 	pub command: Commands,
@@ -34,10 +64,20 @@ pub enum Commands {
 	Exec(ExecArgs),
 	/// Stream request envelopes over stdin/stdout (NDJSON).
 	Batch(BatchArgs),
+	/// Run request envelopes from a script file, then continue with stdin (NDJSON).
+	Run(RunArgs),
+	/// Interactive shell: one human-friendly command per line (e.g. `text h1`).
+	Repl(ReplArgs),
 	/// Manage profile-scoped runtime configuration.
 	Profile(ProfileArgs),
+	/// List profile namespaces and choose the active one.
+	Context(ContextArgs),
 	/// Manage daemon lifecycle.
 	Daemon(DaemonArgs),
+	/// Inspect recorded Playwright traces.
+	Trace(TraceArgs),
+	/// Bridge CDP clients to a browser extension over WebSockets.
+	Relay(RelayArgs),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -54,20 +94,113 @@ pub struct ExecArgs {
 	#[arg(long, value_name = "FILE", conflicts_with = "input")]
 	pub file: Option<PathBuf>,
 
-	/// Runtime profile name.
-	#[arg(long, value_name = "NAME", default_value = "default")]
-	pub profile: String,
+	/// Resolve the input and report it without touching the browser.
+	#[arg(long)]
+	pub dry_run: bool,
+
+	/// Runtime profile name (default: the one selected by `pw context use`, else `default`).
+	#[arg(long, value_name = "NAME")]
+	pub profile: Option<String>,
 
 	/// Directory for failure artifacts.
 	#[arg(long, value_name = "DIR")]
 	pub artifacts_dir: Option<PathBuf>,
+
+	/// Run the operation once per URL in FILE (one per line), emitting one NDJSON envelope per URL.
+	///
+	/// Supported for `navigate`, `page.read` and `screenshot`. Blank lines and `#` comments are skipped.
+	#[arg(long, value_name = "FILE")]
+	pub urls_file: Option<PathBuf>,
+
+	/// Maximum number of `--urls-file` URLs processed at once.
+	#[arg(long, value_name = "N", default_value_t = 4, requires = "urls_file")]
+	pub concurrency: usize,
+
+	/// One-off cookie injected before navigation (repeatable), e.g. "name=value; Domain=example.com; Path=/".
+	#[arg(long = "cookie", value_name = "SPEC")]
+	pub cookies: Vec<String>,
+
+	/// Disable viewport emulation so pages use the OS window size.
+	#[arg(long)]
+	pub no_viewport: bool,
+
+	/// Launch a branded Chromium channel instead of bundled Chromium.
+	#[arg(long, value_enum, value_name = "CHANNEL")]
+	pub channel: Option<BrowserChannel>,
+
+	/// Open DevTools for each tab (implies headful).
+	#[arg(long)]
+	pub devtools: bool,
+
+	/// Navigation and action timeout in milliseconds (`0` disables timeouts).
+	#[arg(long = "timeout", value_name = "MS")]
+	pub timeout_ms: Option<u64>,
+
+	/// On timeout failures, attach a screenshot and the last 5 console errors to the error details.
+	#[arg(long)]
+	pub save_trace_on_timeout: bool,
+
+	/// Navigate to the target URL even when the page is already there.
+	#[arg(long)]
+	pub force_navigate: bool,
+
+	/// Slow down every Playwright operation by this many milliseconds.
+	#[arg(long = "slowmo", value_name = "MS")]
+	pub slow_mo_ms: Option<u64>,
+
+	/// Emulate the `prefers-color-scheme` media feature.
+	#[arg(long, value_enum, value_name = "SCHEME")]
+	pub color_scheme: Option<CliColorScheme>,
+
+	/// Emulate the `prefers-reduced-motion` media feature.
+	#[arg(long, value_enum, value_name = "MOTION")]
+	pub reduced_motion: Option<CliReducedMotion>,
+
+	/// Emulate the `forced-colors` media feature.
+	#[arg(long, value_enum, value_name = "MODE")]
+	pub forced_colors: Option<CliForcedColors>,
+
+	/// Emulate the network being offline.
+	#[arg(long)]
+	pub offline: bool,
+
+	/// Throttle the page's network to a preset (Chromium only).
+	#[arg(long, value_enum, value_name = "PRESET")]
+	pub throttle: Option<CliThrottle>,
+
+	/// Skip certificate validation when connecting to a `wss://` browser server.
+	#[arg(long)]
+	pub insecure: bool,
 }
 
 #[derive(Args, Debug, Clone)]
 pub struct BatchArgs {
-	/// Runtime profile name.
-	#[arg(long, value_name = "NAME", default_value = "default")]
-	pub profile: String,
+	/// Runtime profile name (default: the one selected by `pw context use`, else `default`).
+	#[arg(long, value_name = "NAME")]
+	pub profile: Option<String>,
+
+	/// Append a JSON-lines summary of each response (with timing) to this file.
+	#[arg(long, value_name = "FILE")]
+	pub log_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ReplArgs {
+	/// Runtime profile name (default: the one selected by `pw context use`, else `default`).
+	#[arg(long, value_name = "NAME")]
+	pub profile: Option<String>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct RunArgs {
+	/// NDJSON file of request envelopes executed before reading stdin.
+	///
+	/// Stdin is not read when the script ends with a `quit` request.
+	#[arg(long, value_name = "FILE")]
+	pub script: PathBuf,
+
+	#[command(flatten)]
+	pub batch: BatchArgs,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -99,6 +232,23 @@ pub enum ProfileAction {
 	},
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct ContextArgs {
+	#[command(subcommand)]
+	pub action: ContextAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ContextAction {
+	/// List stored namespaces with their last URL and endpoint.
+	List,
+	/// Make a namespace the default for requests that name no profile.
+	Use {
+		#[arg(value_name = "NAME")]
+		name: String,
+	},
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct DaemonArgs {
 	#[command(subcommand)]
@@ -115,6 +265,37 @@ pub enum DaemonAction {
 	Status,
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct TraceArgs {
+	#[command(subcommand)]
+	pub action: TraceAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum TraceAction {
+	/// Open a trace zip in the Playwright trace viewer.
+	Show {
+		/// Trace archive, e.g. one written by `context.tracing().stop()`.
+		#[arg(value_name = "FILE")]
+		file: PathBuf,
+	},
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct RelayArgs {
+	/// Interface to listen on.
+	#[arg(long, default_value = "127.0.0.1")]
+	pub host: String,
+
+	/// Port to listen on.
+	#[arg(long, default_value_t = 19988)]
+	pub port: u16,
+
+	/// Shared token the extension must present as `/extension?token=...`.
+	#[arg(long, value_name = "TOKEN")]
+	pub token: Option<String>,
+}
+
 /// HAR content policy (CLI wrapper for pw_rs::HarContentPolicy)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -158,6 +339,83 @@ impl From<CliHarMode> for pw_rs::HarMode {
 	}
 }
 
+/// Color scheme preference (CLI wrapper for pw_rs::ColorScheme)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CliColorScheme {
+	Light,
+	Dark,
+	NoPreference,
+}
+
+impl From<CliColorScheme> for pw_rs::ColorScheme {
+	fn from(scheme: CliColorScheme) -> Self {
+		match scheme {
+			CliColorScheme::Light => pw_rs::ColorScheme::Light,
+			CliColorScheme::Dark => pw_rs::ColorScheme::Dark,
+			CliColorScheme::NoPreference => pw_rs::ColorScheme::NoPreference,
+		}
+	}
+}
+
+/// Reduced motion preference (CLI wrapper for pw_rs::ReducedMotion)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CliReducedMotion {
+	Reduce,
+	NoPreference,
+}
+
+impl From<CliReducedMotion> for pw_rs::ReducedMotion {
+	fn from(motion: CliReducedMotion) -> Self {
+		match motion {
+			CliReducedMotion::Reduce => pw_rs::ReducedMotion::Reduce,
+			CliReducedMotion::NoPreference => pw_rs::ReducedMotion::NoPreference,
+		}
+	}
+}
+
+/// Forced colors mode (CLI wrapper for pw_rs::ForcedColors)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CliForcedColors {
+	Active,
+	None,
+}
+
+impl From<CliForcedColors> for pw_rs::ForcedColors {
+	fn from(colors: CliForcedColors) -> Self {
+		match colors {
+			CliForcedColors::Active => pw_rs::ForcedColors::Active,
+			CliForcedColors::None => pw_rs::ForcedColors::None,
+		}
+	}
+}
+
+/// Network throttling preset (CLI wrapper for pw_rs::NetworkPreset)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, serde::Serialize, serde::Deserialize)]
+pub enum CliThrottle {
+	#[value(name = "offline")]
+	#[serde(rename = "offline")]
+	Offline,
+	#[value(name = "slow-3g")]
+	#[serde(rename = "slow-3g")]
+	Slow3g,
+	#[value(name = "fast-3g")]
+	#[serde(rename = "fast-3g")]
+	Fast3g,
+}
+
+impl From<CliThrottle> for pw_rs::NetworkPreset {
+	fn from(throttle: CliThrottle) -> Self {
+		match throttle {
+			CliThrottle::Offline => pw_rs::NetworkPreset::Offline,
+			CliThrottle::Slow3g => pw_rs::NetworkPreset::Slow3g,
+			CliThrottle::Fast3g => pw_rs::NetworkPreset::Fast3g,
+		}
+	}
+}
+
 /// Project template type for init command.
 #[derive(Clone, Debug, ValueEnum, Default, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -11,7 +11,7 @@ fn parse_exec_with_input() {
 		Commands::Exec(args) => {
 			assert_eq!(args.op.as_deref(), Some("page.text"));
 			assert_eq!(args.input.as_deref(), Some(r#"{"selector":"h1"}"#));
-			assert_eq!(args.profile, "default");
+			assert_eq!(args.profile, None);
 		}
 		_ => panic!("expected exec"),
 	}
@@ -24,21 +24,206 @@ fn parse_exec_with_file() {
 		Commands::Exec(args) => {
 			assert!(args.op.is_none());
 			assert_eq!(args.file, Some(PathBuf::from("request.json")));
-			assert_eq!(args.profile, "agent-a");
+			assert_eq!(args.profile.as_deref(), Some("agent-a"));
 		}
 		_ => panic!("expected exec"),
 	}
 }
 
+#[test]
+fn parse_exec_with_repeated_cookies() {
+	let cli = Cli::try_parse_from([
+		"pw",
+		"exec",
+		"navigate",
+		"--cookie",
+		"a=1; Domain=example.com",
+		"--cookie",
+		"b=2; Domain=example.com; Path=/",
+	])
+	.unwrap();
+	match cli.command {
+		Commands::Exec(args) => assert_eq!(args.cookies, vec!["a=1; Domain=example.com", "b=2; Domain=example.com; Path=/"]),
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_global_json_errors() {
+	let cli = Cli::try_parse_from(["pw", "-f", "text", "exec", "page.text", "--json-errors"]).expect("--json-errors should parse after the subcommand");
+	assert!(cli.json_errors);
+}
+
+#[test]
+fn parse_global_dump_protocol() {
+	let cli = Cli::try_parse_from(["pw", "exec", "page.text", "--dump-protocol", "wire.ndjson"]).expect("--dump-protocol should parse after the subcommand");
+	assert_eq!(cli.dump_protocol, Some(PathBuf::from("wire.ndjson")));
+}
+
+#[test]
+fn parse_exec_with_dry_run() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--dry-run", "--input", r#"{"url":"https://example.com"}"#]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => assert!(args.dry_run),
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_exec_with_urls_file() {
+	let cli = Cli::try_parse_from(["pw", "exec", "page.read", "--urls-file", "urls.txt", "--concurrency", "2"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => {
+			assert_eq!(args.urls_file, Some(PathBuf::from("urls.txt")));
+			assert_eq!(args.concurrency, 2);
+		}
+		_ => panic!("expected exec"),
+	}
+	assert!(Cli::try_parse_from(["pw", "exec", "page.read", "--concurrency", "2"]).is_err());
+}
+
+#[test]
+fn parse_exec_with_force_navigate() {
+	let cli = Cli::try_parse_from(["pw", "exec", "page.text", "--force-navigate"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => assert!(args.force_navigate),
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_global_fail_on_console_error() {
+	for args in [
+		["pw", "--fail-on-console-error", "exec", "navigate"],
+		["pw", "exec", "navigate", "--fail-on-console-error"],
+		["pw", "batch", "--fail-on-console-error", "--profile=ci"],
+	] {
+		let cli = Cli::try_parse_from(args).unwrap_or_else(|err| panic!("{args:?}: {err}"));
+		assert!(cli.fail_on_console_error, "{args:?}");
+	}
+}
+
+#[test]
+fn parse_exec_with_save_trace_on_timeout() {
+	let cli = Cli::try_parse_from(["pw", "exec", "page.eval", "--save-trace-on-timeout"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => assert!(args.save_trace_on_timeout),
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_exec_with_no_viewport() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--no-viewport"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => assert!(args.no_viewport),
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_exec_with_launch_tuning() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--channel", "msedge", "--devtools", "--slowmo", "100"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => {
+			assert_eq!(args.channel, Some(BrowserChannel::Msedge));
+			assert!(args.devtools);
+			assert_eq!(args.slow_mo_ms, Some(100));
+		}
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_exec_with_media_emulation() {
+	let cli = Cli::try_parse_from([
+		"pw",
+		"exec",
+		"navigate",
+		"--color-scheme",
+		"dark",
+		"--reduced-motion",
+		"reduce",
+		"--forced-colors",
+		"active",
+	])
+	.unwrap();
+	match cli.command {
+		Commands::Exec(args) => {
+			assert_eq!(args.color_scheme, Some(CliColorScheme::Dark));
+			assert_eq!(args.reduced_motion, Some(CliReducedMotion::Reduce));
+			assert_eq!(args.forced_colors, Some(CliForcedColors::Active));
+		}
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_exec_with_network_emulation() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--offline", "--throttle", "slow-3g"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => {
+			assert!(args.offline);
+			assert_eq!(args.throttle, Some(CliThrottle::Slow3g));
+		}
+		_ => panic!("expected exec"),
+	}
+	assert!(Cli::try_parse_from(["pw", "exec", "navigate", "--throttle", "edge"]).is_err());
+}
+
+#[test]
+fn parse_exec_with_insecure() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--insecure"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => assert!(args.insecure),
+		_ => panic!("expected exec"),
+	}
+}
+
+#[test]
+fn parse_exec_rejects_unknown_channel() {
+	assert!(Cli::try_parse_from(["pw", "exec", "navigate", "--channel", "chromium-nightly"]).is_err());
+}
+
+#[test]
+fn parse_exec_zero_timeout() {
+	let cli = Cli::try_parse_from(["pw", "exec", "navigate", "--timeout", "0"]).unwrap();
+	match cli.command {
+		Commands::Exec(args) => assert_eq!(args.timeout_ms, Some(0)),
+		_ => panic!("expected exec"),
+	}
+}
+
 #[test]
 fn parse_batch() {
 	let cli = Cli::try_parse_from(["pw", "batch", "--profile", "ci"]).unwrap();
 	match cli.command {
-		Commands::Batch(args) => assert_eq!(args.profile, "ci"),
+		Commands::Batch(args) => assert_eq!(args.profile.as_deref(), Some("ci")),
+		_ => panic!("expected batch"),
+	}
+}
+
+#[test]
+fn parse_batch_with_log_file() {
+	let cli = Cli::try_parse_from(["pw", "batch", "--log-file", "run.log"]).unwrap();
+	match cli.command {
+		Commands::Batch(args) => assert_eq!(args.log_file, Some(PathBuf::from("run.log"))),
 		_ => panic!("expected batch"),
 	}
 }
 
+#[test]
+fn parse_run_with_script() {
+	let cli = Cli::try_parse_from(["pw", "run", "--script", "warmup.ndjson", "--profile", "ci"]).unwrap();
+	match cli.command {
+		Commands::Run(args) => {
+			assert_eq!(args.script, PathBuf::from("warmup.ndjson"));
+			assert_eq!(args.batch.profile.as_deref(), Some("ci"));
+		}
+		_ => panic!("expected run"),
+	}
+}
+
 #[test]
 fn parse_profile_set() {
 	let cli = Cli::try_parse_from(["pw", "profile", "set", "default", "--file", "cfg.json"]).unwrap();
@@ -64,7 +249,51 @@ fn parse_daemon_start_foreground() {
 	}
 }
 
+#[test]
+fn parse_trace_show() {
+	let cli = Cli::try_parse_from(["pw", "trace", "show", "trace.zip"]).unwrap();
+	match cli.command {
+		Commands::Trace(TraceArgs {
+			action: TraceAction::Show { file },
+		}) => assert_eq!(file, PathBuf::from("trace.zip")),
+		_ => panic!("expected trace show"),
+	}
+}
+
+#[test]
+fn parse_relay_with_token() {
+	let cli = Cli::try_parse_from(["pw", "relay", "--token", "s3cret"]).unwrap();
+	match cli.command {
+		Commands::Relay(RelayArgs { host, port, token }) => {
+			assert_eq!(host, "127.0.0.1");
+			assert_eq!(port, 19988);
+			assert_eq!(token.as_deref(), Some("s3cret"));
+		}
+		_ => panic!("expected relay"),
+	}
+}
+
 #[test]
 fn invalid_command_fails() {
 	assert!(Cli::try_parse_from(["pw", "navigate", "https://example.com"]).is_err());
 }
+
+#[test]
+fn parse_repl_profile() {
+	let cli = Cli::try_parse_from(["pw", "repl", "--profile", "work"]).unwrap();
+	match cli.command {
+		Commands::Repl(ReplArgs { profile }) => assert_eq!(profile.as_deref(), Some("work")),
+		_ => panic!("expected repl"),
+	}
+}
+
+#[test]
+fn parse_context_use() {
+	let cli = Cli::try_parse_from(["pw", "context", "use", "agent-b"]).unwrap();
+	match cli.command {
+		Commands::Context(ContextArgs {
+			action: ContextAction::Use { name },
+		}) => assert_eq!(name, "agent-b"),
+		_ => panic!("expected context use"),
+	}
+}
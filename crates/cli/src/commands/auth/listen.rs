@@ -1,6 +1,7 @@
 //! WebSocket server for receiving cookies from browser extension.
 
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use axum::Router;
@@ -10,7 +11,8 @@ use axum::response::IntoResponse;
 use axum::routing::get;
 use futures::SinkExt;
 use futures::stream::StreamExt;
-use pw_protocol::{ExtensionMessage, ServerMessage};
+use pw_protocol::{AUTH_PROTOCOL_VERSION, ExtensionMessage, ServerMessage, is_compatible_protocol_version};
+use pw_rs::{Cookie, StorageState};
 use tokio::sync::Mutex;
 
 use crate::context::CommandContext;
@@ -24,8 +26,8 @@ use crate::error::{PwError, Result};
 ///
 /// # Protocol
 ///
-/// 1. Extension connects and sends `Hello { token }`
-/// 2. Server validates token and responds with `Welcome` or `Rejected`
+/// 1. Extension connects and sends `Hello { token, protocol_version }`
+/// 2. Server validates protocol version and token, responding with `Welcome` or `Rejected`
 /// 3. Extension sends `PushCookies { domains }` with cookies grouped by domain
 /// 4. Server saves each domain to a separate `.json` file and responds with `Received`
 ///
@@ -37,16 +39,7 @@ use crate::error::{PwError, Result};
 pub async fn listen(host: &str, port: u16, ctx: &CommandContext) -> Result<()> {
 	let token = generate_token();
 
-	let auth_dir = match ctx.project {
-		Some(ref proj) => proj.paths.auth_dir(),
-		None => {
-			let config_root = dirs::config_dir()
-				.or_else(|| dirs::home_dir().map(|home| home.join(".config")))
-				.ok_or_else(|| PwError::Context("Could not determine config directory".into()))?;
-			config_root.join("pw").join("auth")
-		}
-	};
-
+	let auth_dir = auth_dir(ctx)?;
 	std::fs::create_dir_all(&auth_dir)?;
 
 	let state = ListenState {
@@ -75,10 +68,23 @@ pub async fn listen(host: &str, port: u16, ctx: &CommandContext) -> Result<()> {
 	Ok(())
 }
 
+/// Directory for per-domain auth files: the project's auth dir, else `<config>/pw/auth`.
+pub(super) fn auth_dir(ctx: &CommandContext) -> Result<PathBuf> {
+	match ctx.project {
+		Some(ref proj) => Ok(proj.paths.auth_dir()),
+		None => {
+			let config_root = dirs::config_dir()
+				.or_else(|| dirs::home_dir().map(|home| home.join(".config")))
+				.ok_or_else(|| PwError::Context("Could not determine config directory".into()))?;
+			Ok(config_root.join("pw").join("auth"))
+		}
+	}
+}
+
 #[derive(Clone)]
 struct ListenState {
 	token: String,
-	auth_dir: std::path::PathBuf,
+	auth_dir: PathBuf,
 	authenticated: Arc<Mutex<bool>>,
 }
 
@@ -121,27 +127,17 @@ async fn handle_socket(socket: WebSocket, state: ListenState) {
 		};
 
 		match ext_msg {
-			ExtensionMessage::Hello { token } => {
-				if token == state.token {
-					*state.authenticated.lock().await = true;
-					println!("Authentication successful");
-					let _ = send_response(
-						&mut sender,
-						ServerMessage::Welcome {
-							version: env!("CARGO_PKG_VERSION").into(),
-						},
-					)
-					.await;
-				} else {
-					println!("Authentication failed: invalid token");
-					let _ = send_response(
-						&mut sender,
-						ServerMessage::Rejected {
-							reason: "Invalid token".into(),
-						},
-					)
-					.await;
+			ExtensionMessage::Hello { token, protocol_version } => {
+				let response = hello_response(&token, protocol_version, &state.token);
+				match &response {
+					ServerMessage::Welcome { .. } => {
+						*state.authenticated.lock().await = true;
+						println!("Authentication successful");
+					}
+					ServerMessage::Rejected { reason } => println!("Authentication failed: {reason}"),
+					_ => {}
 				}
+				let _ = send_response(&mut sender, response).await;
 			}
 			ExtensionMessage::PushCookies { domains } => {
 				if !*state.authenticated.lock().await {
@@ -173,14 +169,32 @@ async fn handle_socket(socket: WebSocket, state: ListenState) {
 	}
 }
 
+/// Answers a `Hello` handshake. Protocol version is checked before the token so
+/// a stale extension learns it must be updated rather than re-paired.
+fn hello_response(token: &str, protocol_version: u32, expected_token: &str) -> ServerMessage {
+	if !is_compatible_protocol_version(protocol_version) {
+		return ServerMessage::Rejected {
+			reason: format!("Incompatible protocol version {protocol_version} (server speaks {AUTH_PROTOCOL_VERSION}); update the pw extension or CLI"),
+		};
+	}
+	if token != expected_token {
+		return ServerMessage::Rejected {
+			reason: "Invalid token".into(),
+		};
+	}
+	ServerMessage::Welcome {
+		version: env!("CARGO_PKG_VERSION").into(),
+		protocol_version: AUTH_PROTOCOL_VERSION,
+	}
+}
+
 fn save_domain_cookies(domains: &[pw_protocol::DomainCookies], auth_dir: &Path) -> (Vec<String>, Vec<String>) {
 	let mut saved_paths = Vec::new();
 	let mut errors = Vec::new();
 
 	for dc in domains {
 		let storage_state = dc.to_storage_state();
-		let filename = sanitize_domain(&dc.domain);
-		let path = auth_dir.join(format!("{filename}.json"));
+		let path = domain_auth_path(auth_dir, &dc.domain);
 
 		match storage_state.to_file(&path) {
 			Ok(()) => {
@@ -197,6 +211,33 @@ fn save_domain_cookies(domains: &[pw_protocol::DomainCookies], auth_dir: &Path)
 	(saved_paths, errors)
 }
 
+/// Writes one cookie-only storage state per cookie domain, like the files
+/// [`save_domain_cookies`] produces for extension pushes.
+///
+/// `.example.com` and `example.com` share a file. Cookies without a domain are
+/// skipped. Returns the written paths in domain order.
+pub(super) fn save_state_by_domain(state: &StorageState, auth_dir: &Path) -> Result<Vec<PathBuf>> {
+	let mut by_file: BTreeMap<PathBuf, Vec<Cookie>> = BTreeMap::new();
+	for cookie in &state.cookies {
+		let Some(domain) = cookie.domain.as_deref() else {
+			continue;
+		};
+		by_file.entry(domain_auth_path(auth_dir, domain)).or_default().push(cookie.clone());
+	}
+
+	std::fs::create_dir_all(auth_dir)?;
+	let mut paths = Vec::with_capacity(by_file.len());
+	for (path, cookies) in by_file {
+		StorageState { cookies, origins: Vec::new() }.to_file(&path)?;
+		paths.push(path);
+	}
+	Ok(paths)
+}
+
+fn domain_auth_path(auth_dir: &Path, domain: &str) -> PathBuf {
+	auth_dir.join(format!("{}.json", sanitize_domain(domain)))
+}
+
 async fn send_response(sender: &mut futures::stream::SplitSink<WebSocket, Message>, msg: ServerMessage) -> std::result::Result<(), axum::Error> {
 	let json = serde_json::to_string(&msg).expect("ServerMessage is always serializable");
 	sender.send(Message::Text(json.into())).await
@@ -211,3 +252,53 @@ fn generate_token() -> String {
 fn sanitize_domain(domain: &str) -> String {
 	domain.strip_prefix('.').unwrap_or(domain).replace('.', "_")
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn cookie(name: &str, domain: &str) -> Cookie {
+		Cookie {
+			name: name.to_string(),
+			value: "v".to_string(),
+			domain: Some(domain.to_string()),
+			path: Some("/".to_string()),
+			expires: None,
+			http_only: None,
+			secure: None,
+			same_site: None,
+			url: None,
+		}
+	}
+
+	#[test]
+	fn hello_with_mismatched_protocol_version_is_rejected() {
+		let response = hello_response("tok", AUTH_PROTOCOL_VERSION + 1, "tok");
+		let ServerMessage::Rejected { reason } = response else {
+			panic!("expected rejection, got {response:?}");
+		};
+		assert!(reason.contains("Incompatible protocol version"), "{reason}");
+
+		assert!(
+			matches!(hello_response("tok", AUTH_PROTOCOL_VERSION, "tok"), ServerMessage::Welcome { protocol_version, .. } if protocol_version == AUTH_PROTOCOL_VERSION)
+		);
+		assert!(matches!(hello_response("bad", AUTH_PROTOCOL_VERSION, "tok"), ServerMessage::Rejected { .. }));
+	}
+
+	#[test]
+	fn multi_domain_login_state_splits_into_files() {
+		let dir = tempfile::tempdir().unwrap();
+		let state = StorageState {
+			cookies: vec![cookie("sid", ".example.com"), cookie("pref", "example.com"), cookie("sso", "login.idp.test")],
+			origins: Vec::new(),
+		};
+
+		let paths = save_state_by_domain(&state, dir.path()).unwrap();
+		assert_eq!(paths, [dir.path().join("example_com.json"), dir.path().join("login_idp_test.json")]);
+
+		let example = StorageState::from_file(&paths[0]).unwrap();
+		let names: Vec<_> = example.cookies.iter().map(|c| c.name.as_str()).collect();
+		assert_eq!(names, ["sid", "pref"]);
+		assert_eq!(StorageState::from_file(&paths[1]).unwrap().cookies.len(), 1);
+	}
+}
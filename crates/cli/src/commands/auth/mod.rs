@@ -13,15 +13,16 @@ use std::path::{Path, PathBuf};
 
 use clap::Args;
 pub use listen::listen;
-use pw_rs::{StorageState, WaitUntil};
+use pw_rs::{Cookie, StorageState, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
+use crate::commands::contract::dry_run_inputs;
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
 use crate::context::CommandContext;
 use crate::error::{PwError, Result};
 use crate::output::CommandInputs;
-use crate::session::{SessionManager, SessionRequest};
+use crate::session::{SessionHandle, SessionManager, SessionRequest};
 use crate::target::{ResolveEnv, ResolvedTarget, Target, TargetPolicy};
 
 #[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
@@ -36,6 +37,10 @@ pub struct LoginRaw {
 	#[arg(id = "timeout", short = 't', long = "timeout", default_value = "60", value_name = "SECONDS")]
 	#[serde(default, alias = "timeout_secs")]
 	pub timeout_secs: Option<u64>,
+	/// Also write one `<domain>.json` per cookie domain into the auth dir
+	#[arg(long)]
+	#[serde(default, alias = "split_by_domain")]
+	pub split_by_domain: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +48,7 @@ pub struct LoginResolved {
 	pub target: ResolvedTarget,
 	pub output: PathBuf,
 	pub timeout_secs: u64,
+	pub split_by_domain: bool,
 }
 
 impl LoginResolved {
@@ -59,7 +65,12 @@ impl Resolve for LoginRaw {
 		let output = self.output.unwrap_or_else(|| PathBuf::from("auth.json"));
 		let timeout_secs = self.timeout_secs.unwrap_or(300);
 
-		Ok(LoginResolved { target, output, timeout_secs })
+		Ok(LoginResolved {
+			target,
+			output,
+			timeout_secs,
+			split_by_domain: self.split_by_domain,
+		})
 	}
 }
 
@@ -74,6 +85,10 @@ impl CommandDef for LoginCommand {
 	type Resolved = LoginResolved;
 	type Data = serde_json::Value;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -96,6 +111,8 @@ impl CommandDef for LoginCommand {
 					output: Some(resolved.output.clone()),
 					selector: None,
 				},
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -145,6 +162,10 @@ impl CommandDef for CookiesCommand {
 	type Resolved = CookiesResolved;
 	type Data = serde_json::Value;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -164,6 +185,8 @@ impl CommandDef for CookiesCommand {
 					output: None,
 					selector: None,
 				},
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -212,6 +235,8 @@ impl CommandDef for ShowCommand {
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -284,6 +309,8 @@ impl CommandDef for ListenCommand {
 					"port": args.port,
 				}),
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -308,6 +335,58 @@ async fn login_resolved(
 				.with_preferred_url(preferred_url),
 		)
 		.await?;
+
+	if let Some(parent) = args.output.parent() {
+		if !parent.as_os_str().is_empty() && !parent.exists() {
+			std::fs::create_dir_all(parent)?;
+		}
+	}
+
+	// Safety net: if the capture below fails, closing the session still writes
+	// whatever state the context holds, to a sidecar so `output` is never
+	// replaced by a half-finished login.
+	let recovery = recovery_path(&args.output);
+	session.context().set_save_storage_state_on_close(Some(recovery.clone()));
+
+	let captured = capture_login_state(args, ctx, &session, interactive_messages).await;
+	if captured.is_ok() {
+		// Already written (with the CDP page fallback); no sidecar needed.
+		session.context().set_save_storage_state_on_close(None);
+	}
+	let closed = session.close().await;
+	if captured.is_err() && closed.is_ok() && interactive_messages {
+		eprintln!("Login was not saved; the browser's last state was written to {}", recovery.display());
+	}
+	let (state, domain_files) = captured?;
+	closed?;
+
+	let mut data = serde_json::json!({
+		"path": args.output,
+		"cookies": state.cookies.len(),
+		"origins": state.origins.len(),
+		"url": args.target.url_str(),
+	});
+	if args.split_by_domain {
+		data["domainFiles"] = serde_json::json!(domain_files);
+	}
+	Ok(data)
+}
+
+/// Sidecar `auth.login` saves the context to when capturing the login fails.
+fn recovery_path(output: &Path) -> PathBuf {
+	let mut name = output.file_name().map(std::ffi::OsString::from).unwrap_or_else(|| "auth.json".into());
+	name.push(".partial");
+	output.with_file_name(name)
+}
+
+/// Waits for the user to finish logging in, then writes the storage state to `args.output`.
+async fn capture_login_state(
+	args: &LoginResolved,
+	ctx: &CommandContext,
+	session: &SessionHandle,
+	interactive_messages: bool,
+) -> Result<(StorageState, Vec<PathBuf>)> {
+	let url_display = args.target.url_str().unwrap_or("<current page>");
 	session.goto_target(&args.target.target, ctx.timeout_ms()).await?;
 
 	if interactive_messages {
@@ -337,33 +416,34 @@ async fn login_resolved(
 		}
 	}
 
-	let state = session.context().storage_state(None).await?;
-
-	if let Some(parent) = args.output.parent() {
-		if !parent.as_os_str().is_empty() && !parent.exists() {
-			std::fs::create_dir_all(parent)?;
-		}
-	}
+	// CDP-attached browsers frequently report no localStorage origins over the protocol.
+	let state = if ctx.cdp_endpoint().is_some() {
+		session.context().storage_state_with_page_fallback().await?
+	} else {
+		session.context().storage_state(None).await?
+	};
 
 	state.to_file(&args.output)?;
 
+	let domain_files = if args.split_by_domain {
+		listen::save_state_by_domain(&state, &listen::auth_dir(ctx)?)?
+	} else {
+		Vec::new()
+	};
+
 	if interactive_messages {
 		eprintln!();
 		eprintln!("Authentication state saved to: {}", args.output.display());
 		eprintln!("  Cookies: {}", state.cookies.len());
 		eprintln!("  Origins with localStorage: {}", state.origins.len());
+		for path in &domain_files {
+			eprintln!("  Domain cookies: {}", path.display());
+		}
 		eprintln!();
 		eprintln!("Use with other commands: pw --auth {} <command>", args.output.display());
 	}
 
-	session.close().await?;
-
-	Ok(serde_json::json!({
-		"path": args.output,
-		"cookies": state.cookies.len(),
-		"origins": state.origins.len(),
-		"url": args.target.url_str(),
-	}))
+	Ok((state, domain_files))
 }
 
 async fn cookies_resolved(args: &CookiesResolved, ctx: &CommandContext, session: &mut SessionManager<'_>, last_url: Option<&str>) -> Result<serde_json::Value> {
@@ -396,6 +476,7 @@ async fn cookies_resolved(args: &CookiesResolved, ctx: &CommandContext, session:
 async fn show(file: &Path) -> Result<serde_json::Value> {
 	let state = StorageState::from_file(file).map_err(|e| PwError::BrowserLaunch(format!("Failed to load auth file: {e}")))?;
 
+	let now = unix_now();
 	let cookies: Vec<_> = state
 		.cookies
 		.iter()
@@ -403,7 +484,7 @@ async fn show(file: &Path) -> Result<serde_json::Value> {
 			serde_json::json!({
 				"name": cookie.name,
 				"domain": cookie.domain,
-				"expires": format_expiry(cookie.expires),
+				"expires": format_expiry(cookie.expires, now),
 			})
 		})
 		.collect();
@@ -428,6 +509,7 @@ async fn show(file: &Path) -> Result<serde_json::Value> {
 		"file": file,
 		"cookies": cookies,
 		"cookieCount": state.cookies.len(),
+		"health": CookieHealth::assess(&state.cookies, now),
 		"origins": origins,
 		"originCount": state.origins.len(),
 	}))
@@ -440,26 +522,174 @@ fn resolve_auth_output(ctx: &CommandContext, output: &Path) -> PathBuf {
 	ctx.namespace_auth_dir().join(output)
 }
 
-fn format_expiry(expires: Option<f64>) -> String {
+/// Valid cookies expiring within this many seconds make a state `expiring-soon`.
+const EXPIRING_SOON_SECS: i64 = 86400;
+
+fn unix_now() -> i64 {
+	std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|d| d.as_secs() as i64)
+		.unwrap_or(0)
+}
+
+/// Where a cookie stands relative to `now`.
+enum Expiry {
+	/// No expiry: lives as long as the browser session.
+	Session,
+	Expired,
+	/// Seconds until the cookie expires.
+	Remaining(i64),
+}
+
+fn expiry(expires: Option<f64>, now: i64) -> Expiry {
 	let ts = match expires {
-		None => return "session".into(),
-		Some(ts) if ts < 0.0 => return "session".into(),
+		None => return Expiry::Session,
+		Some(ts) if ts < 0.0 => return Expiry::Session,
 		Some(ts) => ts as i64,
 	};
 
-	let now = std::time::SystemTime::now()
-		.duration_since(std::time::UNIX_EPOCH)
-		.map(|d| d.as_secs() as i64)
-		.unwrap_or(0);
+	if ts < now { Expiry::Expired } else { Expiry::Remaining(ts - now) }
+}
 
-	if ts < now {
-		return "expired".into();
+fn format_expiry(expires: Option<f64>, now: i64) -> String {
+	match expiry(expires, now) {
+		Expiry::Session => "session".into(),
+		Expiry::Expired => "expired".into(),
+		Expiry::Remaining(secs) => format_remaining(secs),
 	}
+}
 
-	let diff = ts - now;
-	match diff {
+fn format_remaining(secs: i64) -> String {
+	match secs {
 		d if d < 3600 => format!("{}m", d / 60),
 		d if d < 86400 => format!("{}h", d / 3600),
 		d => format!("{}d", d / 86400),
 	}
 }
+
+/// Overall readiness of a saved auth state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum HealthStatus {
+	/// No cookie has expired and none expires within a day.
+	Healthy,
+	/// Some cookies expired, or a valid one expires within a day.
+	ExpiringSoon,
+	/// Every cookie has expired.
+	Expired,
+}
+
+/// Expiry summary for `auth.show`, so callers can decide whether to log in again.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CookieHealth {
+	status: HealthStatus,
+	/// Cookies that have not expired, including session cookies.
+	valid: usize,
+	expired: usize,
+	/// Valid cookies without an expiry.
+	session: usize,
+	/// Time until the first valid cookie with an expiry runs out (`45m`, `3h`, `12d`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	nearest_expiry: Option<String>,
+	/// One-line human summary of the counts above.
+	summary: String,
+}
+
+impl CookieHealth {
+	fn assess(cookies: &[Cookie], now: i64) -> Self {
+		let (mut valid, mut expired, mut session) = (0, 0, 0);
+		let mut nearest: Option<i64> = None;
+		for cookie in cookies {
+			match expiry(cookie.expires, now) {
+				Expiry::Session => {
+					valid += 1;
+					session += 1;
+				}
+				Expiry::Expired => expired += 1,
+				Expiry::Remaining(secs) => {
+					valid += 1;
+					nearest = Some(nearest.map_or(secs, |n| n.min(secs)));
+				}
+			}
+		}
+
+		let status = if expired > 0 && valid == 0 {
+			HealthStatus::Expired
+		} else if expired > 0 || nearest.is_some_and(|secs| secs < EXPIRING_SOON_SECS) {
+			HealthStatus::ExpiringSoon
+		} else {
+			HealthStatus::Healthy
+		};
+
+		let nearest_expiry = nearest.map(format_remaining);
+		let mut summary = format!("{valid} valid ({session} session), {expired} expired");
+		if let Some(nearest) = &nearest_expiry {
+			summary.push_str(&format!("; nearest expiry in {nearest}"));
+		}
+
+		Self {
+			status,
+			valid,
+			expired,
+			session,
+			nearest_expiry,
+			summary,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const NOW: i64 = 1_700_000_000;
+
+	fn cookie(name: &str, expires: Option<f64>) -> Cookie {
+		Cookie {
+			name: name.to_string(),
+			value: "v".to_string(),
+			domain: Some("example.com".to_string()),
+			path: Some("/".to_string()),
+			expires,
+			http_only: None,
+			secure: None,
+			same_site: None,
+			url: None,
+		}
+	}
+
+	#[test]
+	fn health_counts_expired_and_valid_cookies() {
+		let cookies = [
+			cookie("old", Some((NOW - 60) as f64)),
+			cookie("sid", None),
+			cookie("soon", Some((NOW + 3 * 3600) as f64)),
+			cookie("later", Some((NOW + 30 * 86400) as f64)),
+		];
+		let health = CookieHealth::assess(&cookies, NOW);
+		assert_eq!((health.valid, health.expired, health.session), (3, 1, 1));
+		assert_eq!(health.nearest_expiry.as_deref(), Some("3h"));
+		assert_eq!(health.status, HealthStatus::ExpiringSoon);
+		assert_eq!(health.summary, "3 valid (1 session), 1 expired; nearest expiry in 3h");
+		assert_eq!(format_expiry(cookies[0].expires, NOW), "expired");
+	}
+
+	#[test]
+	fn login_recovery_goes_to_a_sidecar() {
+		assert_eq!(recovery_path(Path::new("auth/site.json")), Path::new("auth/site.json.partial"));
+		assert_eq!(recovery_path(Path::new("site.json")), Path::new("site.json.partial"));
+	}
+
+	#[test]
+	fn health_status_covers_fresh_and_fully_expired_states() {
+		let fresh = [cookie("sid", None), cookie("later", Some((NOW + 30 * 86400) as f64))];
+		assert_eq!(CookieHealth::assess(&fresh, NOW).status, HealthStatus::Healthy);
+
+		let stale = [cookie("a", Some((NOW - 1) as f64)), cookie("b", Some(0.0))];
+		let health = CookieHealth::assess(&stale, NOW);
+		assert_eq!(health.status, HealthStatus::Expired);
+		assert_eq!(serde_json::to_value(health.status).unwrap(), "expired");
+		assert_eq!(serde_json::to_value(HealthStatus::ExpiringSoon).unwrap(), "expiring-soon");
+	}
+}
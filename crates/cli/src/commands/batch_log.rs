@@ -0,0 +1,139 @@
+//! Structured per-request log for `pw batch`.
+//!
+//! Each response written to stdout is summarized as one JSON line in a
+//! separate file, so agents can review what happened without re-parsing the
+//! response stream.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::output::ErrorCode;
+use crate::protocol::CommandResponse;
+
+/// Append-only JSON-lines sink for batch request summaries.
+#[derive(Debug)]
+pub(crate) struct BatchLog {
+	path: PathBuf,
+	file: File,
+}
+
+/// One log line per batch response.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchLogEntry<'a> {
+	/// Unix timestamp (milliseconds) when the response was written.
+	ts_ms: u64,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	request_id: Option<&'a str>,
+	op: &'a str,
+	ok: bool,
+	/// Wall-clock time from reading the request line to writing its response.
+	duration_ms: u64,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	error_code: Option<ErrorCode>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	error_message: Option<&'a str>,
+}
+
+impl BatchLog {
+	/// Opens `path` for appending, creating it and its parent directories.
+	pub(crate) fn open(path: &Path) -> Result<Self> {
+		if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+			std::fs::create_dir_all(parent)?;
+		}
+		let file = OpenOptions::new().create(true).append(true).open(path)?;
+		Ok(Self {
+			path: path.to_path_buf(),
+			file,
+		})
+	}
+
+	pub(crate) fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Appends a summary of `response`, flushing so the file is readable mid-run.
+	pub(crate) fn record(&mut self, response: &CommandResponse, elapsed: Duration) -> Result<()> {
+		let entry = BatchLogEntry {
+			ts_ms: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64,
+			request_id: response.request_id.as_deref(),
+			op: &response.op,
+			ok: response.ok,
+			duration_ms: elapsed.as_millis() as u64,
+			error_code: response.error.as_ref().map(|err| err.code),
+			error_message: response.error.as_ref().map(|err| err.message.as_str()),
+		};
+		let mut line = serde_json::to_vec(&entry)?;
+		line.push(b'\n');
+		self.file.write_all(&line)?;
+		self.file.flush()?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+	use crate::output::CommandError;
+	use crate::protocol::SCHEMA_VERSION;
+
+	fn response(request_id: &str, op: &str, error: Option<CommandError>) -> CommandResponse {
+		CommandResponse {
+			schema_version: SCHEMA_VERSION,
+			request_id: Some(request_id.to_string()),
+			op: op.to_string(),
+			ok: error.is_none(),
+			inputs: None,
+			data: error.is_none().then(|| json!({})),
+			error,
+			duration_ms: None,
+			artifacts: Vec::new(),
+			diagnostics: Vec::new(),
+			context_delta: None,
+			effective_runtime: None,
+			seq: None,
+			done: None,
+		}
+	}
+
+	#[test]
+	fn two_responses_produce_two_lines() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("logs/run.log");
+		let mut log = BatchLog::open(&path).unwrap();
+
+		log.record(&response("1", "ping", None), Duration::from_millis(3)).unwrap();
+		log.record(
+			&response(
+				"2",
+				"page.text",
+				Some(CommandError {
+					code: ErrorCode::Timeout,
+					message: "timed out".to_string(),
+					details: None,
+				}),
+			),
+			Duration::from_millis(1200),
+		)
+		.unwrap();
+
+		let contents = std::fs::read_to_string(&path).unwrap();
+		let lines: Vec<serde_json::Value> = contents.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+		assert_eq!(lines.len(), 2);
+		assert_eq!(lines[0]["requestId"], "1");
+		assert_eq!(lines[0]["ok"], true);
+		assert_eq!(lines[0]["durationMs"], 3);
+		assert!(lines[0].get("errorCode").is_none());
+		assert_eq!(lines[1]["op"], "page.text");
+		assert_eq!(lines[1]["ok"], false);
+		assert_eq!(lines[1]["durationMs"], 1200);
+		assert_eq!(lines[1]["errorCode"], "TIMEOUT");
+	}
+}
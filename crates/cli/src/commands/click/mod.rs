@@ -7,9 +7,10 @@ use pw_rs::{ClickOptions, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_and_selector, standard_delta_with_url, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_match_index, resolve_target_and_selector, standard_delta_with_url, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
+use crate::commands::flow::retry::{retry_diagnostics, retry_on_selector};
 use crate::error::Result;
 use crate::output::{ClickData, DownloadedFile};
 use crate::session_helpers::ArtifactsPolicy;
@@ -41,6 +42,21 @@ pub struct ClickRaw {
 	#[arg(long, default_value = "500")]
 	#[serde(default, alias = "wait_ms")]
 	pub wait_ms: Option<u64>,
+
+	/// Act on the first match when the selector matches several elements
+	#[arg(long, conflicts_with = "nth")]
+	#[serde(default)]
+	pub first: bool,
+
+	/// Act on the Nth match (0-based) when the selector matches several elements
+	#[arg(long, value_name = "N")]
+	#[serde(default)]
+	pub nth: Option<usize>,
+
+	/// Re-resolve and retry when the element is detached or missing mid-action
+	#[arg(long)]
+	#[serde(default, alias = "retry_on_selector")]
+	pub retry_on_selector: bool,
 }
 
 /// Resolved inputs ready for execution.
@@ -49,6 +65,10 @@ pub struct ClickResolved {
 	pub target: ResolvedTarget,
 	pub selector: String,
 	pub wait_ms: u64,
+	/// Zero-based match index from `--first`/`--nth`; `None` keeps strict matching.
+	pub nth: Option<usize>,
+	/// Retry the click while the element is detached or missing, within the timeout.
+	pub retry_on_selector: bool,
 }
 
 impl Resolve for ClickRaw {
@@ -57,8 +77,15 @@ impl Resolve for ClickRaw {
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, Some("css=button"))?;
 		let wait_ms = self.wait_ms.unwrap_or(0);
+		let nth = resolve_match_index(self.first, self.nth)?;
 
-		Ok(ClickResolved { target, selector, wait_ms })
+		Ok(ClickResolved {
+			target,
+			selector,
+			wait_ms,
+			nth,
+			retry_on_selector: self.retry_on_selector,
+		})
 	}
 }
 
@@ -71,6 +98,10 @@ impl CommandDef for ClickCommand {
 	type Resolved = ClickResolved;
 	type Data = ClickData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -82,8 +113,10 @@ impl CommandDef for ClickCommand {
 			let selector = args.selector.clone();
 			let selector_for_outcome = selector.clone();
 			let wait_ms = args.wait_ms;
+			let nth = args.nth;
+			let retry_enabled = args.retry_on_selector;
 
-			let (after_url, data) = run_page_flow(
+			let (after_url, data, retries) = run_page_flow(
 				&mut exec,
 				&args.target,
 				WaitUntil::NetworkIdle,
@@ -99,38 +132,50 @@ impl CommandDef for ClickCommand {
 							.await
 							.unwrap_or_else(|_| session.page().url());
 
-						let locator = session.page().locator(&selector).await;
-						let click_opts = ClickOptions::builder()
-							// We compute navigation ourselves via before/after URL checks.
-							// Disabling auto-wait avoids false 30s timeouts on non-navigating clicks.
-							.no_wait_after(true)
-							.timeout(flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64) as f64)
-							.build();
-						match locator.click(Some(click_opts)).await {
-							Ok(()) => {}
-							Err(err) => {
-								let msg = err.to_string();
-								if msg.to_lowercase().contains("timeout") {
-									// Playwright 1.57+ can intermittently hang on locator click
-									// for simple static elements. Fallback to a DOM click.
-									let selector_json = serde_json::to_string(&selector)?;
-									let expr = format!(
-										r#"(() => {{
-                                                const el = document.querySelector({selector});
+						let timeout_ms = flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+						let selector = &selector;
+						let ((), retries) = retry_on_selector(retry_enabled, Duration::from_millis(timeout_ms), move |budget| async move {
+							let locator = session.page().locator(selector).await;
+							let locator = match nth {
+								Some(index) => locator.nth(index as i32),
+								None => locator,
+							};
+							let click_opts = ClickOptions::builder()
+								// We compute navigation ourselves via before/after URL checks.
+								// Disabling auto-wait avoids false 30s timeouts on non-navigating clicks.
+								.no_wait_after(true)
+								.timeout(budget.as_millis() as f64)
+								.build();
+							match locator.click(Some(click_opts)).await {
+								Ok(()) => {}
+								Err(err) => {
+									let msg = err.to_string();
+									if msg.to_lowercase().contains("timeout") {
+										// Playwright 1.57+ can intermittently hang on locator click
+										// for simple static elements. Fallback to a DOM click.
+										let selector_json = serde_json::to_string(&selector)?;
+										let expr = format!(
+											r#"(() => {{
+                                                const el = document.querySelectorAll({selector})[{index}];
                                                 if (!el) {{
                                                     throw new Error("selector not found for click fallback");
                                                 }}
                                                 el.click();
                                                 return true;
                                             }})()"#,
-										selector = selector_json
-									);
-									session.page().evaluate_value(&expr).await?;
-								} else {
-									return Err(err.into());
+											selector = selector_json,
+											index = nth.unwrap_or(0)
+										);
+										session.page().evaluate_value(&expr).await?;
+									} else {
+										return Err(err.into());
+									}
 								}
 							}
-						}
+
+							Ok(())
+						})
+						.await?;
 
 						if wait_ms > 0 {
 							tokio::time::sleep(Duration::from_millis(wait_ms)).await;
@@ -162,18 +207,26 @@ impl CommandDef for ClickCommand {
 							downloads,
 						};
 
-						Ok((after_url, data))
+						Ok((after_url, data, retries))
 					})
 				},
 			)
 			.await?;
 
-			let inputs = standard_inputs(&args.target, Some(&selector_for_outcome), None, None, None);
+			let inputs = standard_inputs(
+				&args.target,
+				Some(&selector_for_outcome),
+				None,
+				None,
+				args.nth.map(|nth| serde_json::json!({ "nth": nth })),
+			);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
 				delta: standard_delta_with_url(Some(after_url), Some(&selector_for_outcome), None),
+				diagnostics: retry_diagnostics(Self::NAME, retries),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -192,6 +245,14 @@ mod tests {
 		assert_eq!(raw.wait_ms, Some(1000));
 	}
 
+	#[test]
+	fn click_raw_accepts_first_and_nth() {
+		let raw: ClickRaw = serde_json::from_str(r#"{"selector": "button", "first": true}"#).unwrap();
+		assert!(raw.first);
+		let raw: ClickRaw = serde_json::from_str(r#"{"selector": "button", "nth": 2}"#).unwrap();
+		assert_eq!(raw.nth, Some(2));
+	}
+
 	#[test]
 	fn click_raw_default_wait_ms() {
 		let json = r#"{"selector": "button"}"#;
@@ -1,7 +1,8 @@
 //! Connect to or launch a browser with remote debugging enabled.
 //!
 //! This command enables control of a real browser (with your cookies, extensions, etc.)
-//! to bypass bot detection systems like Cloudflare.
+//! to bypass bot detection systems like Cloudflare. Firefox and WebKit have no CDP,
+//! so `--browser firefox|webkit` targets a Playwright server endpoint instead.
 
 use std::path::PathBuf;
 
@@ -10,11 +11,12 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
-use crate::error::Result;
+use crate::error::{PwError, Result};
 use crate::output::CommandInputs;
-use crate::session::connect::resolve_connect_port;
+use crate::session::connect::{ConnectTransport, resolve_connect_port};
 use crate::session::connect_service::ConnectService;
 use crate::target::ResolveEnv;
+use crate::types::BrowserKind;
 
 #[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -43,10 +45,18 @@ pub struct ConnectRaw {
 	#[arg(long, short)]
 	#[serde(default)]
 	pub port: Option<u16>,
+	/// Browser engine; firefox and webkit connect to a Playwright server instead of CDP.
+	#[arg(long, value_enum)]
+	#[serde(default)]
+	pub browser: Option<BrowserKind>,
 	/// Optional user-data-dir used for launched browser profiles.
 	#[arg(long)]
 	#[serde(default)]
 	pub user_data_dir: Option<PathBuf>,
+	/// With `--launch`, adds switches that hide common automation tells.
+	#[arg(long, requires = "launch")]
+	#[serde(default)]
+	pub stealth: bool,
 }
 
 /// Parsed and validated inputs for `connect`.
@@ -64,14 +74,33 @@ pub struct ConnectResolved {
 	pub kill: bool,
 	/// Explicit remote-debugging port.
 	pub port: Option<u16>,
+	/// Browser engine selecting the connect transport.
+	pub browser: Option<BrowserKind>,
 	/// Optional user-data-dir used for launched browser profiles.
 	pub user_data_dir: Option<PathBuf>,
+	/// Adds automation-hiding switches to a launched browser.
+	pub stealth: bool,
 }
 
 impl Resolve for ConnectRaw {
 	type Output = ConnectResolved;
 
 	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		if let Some(browser) = self.browser.filter(|b| ConnectTransport::for_browser(*b) == ConnectTransport::Playwright) {
+			let flag = [("launch", self.launch), ("discover", self.discover), ("kill", self.kill)]
+				.into_iter()
+				.find_map(|(name, set)| set.then_some(name));
+			if let Some(flag) = flag {
+				return Err(PwError::Context(format!(
+					"`{flag}` uses CDP and requires chromium; {browser} connects to a Playwright server endpoint"
+				)));
+			}
+		}
+
+		if self.stealth && !self.launch {
+			return Err(PwError::Context("`stealth` only applies to `launch`".to_string()));
+		}
+
 		Ok(ConnectResolved {
 			endpoint: self.endpoint,
 			clear: self.clear,
@@ -79,7 +108,9 @@ impl Resolve for ConnectRaw {
 			discover: self.discover,
 			kill: self.kill,
 			port: self.port,
+			browser: self.browser,
 			user_data_dir: self.user_data_dir,
+			stealth: self.stealth,
 		})
 	}
 }
@@ -101,13 +132,16 @@ impl CommandDef for ConnectCommand {
 		Box::pin(async move {
 			let port = resolve_connect_port(exec.ctx_state, args.port);
 			let mut service = ConnectService::new(exec.ctx_state, exec.ctx.auth_file());
+			let transport = ConnectTransport::for_browser(args.browser.unwrap_or_default());
 
 			let data = if args.kill {
 				service.kill(port).await?
 			} else if args.clear {
 				service.clear()
+			} else if let (ConnectTransport::Playwright, Some(browser)) = (transport, args.browser) {
+				service.set_server_endpoint(browser, args.endpoint.as_deref(), port)
 			} else if args.launch {
-				service.launch(port, args.user_data_dir.as_deref()).await?
+				service.launch(port, args.user_data_dir.as_deref(), args.stealth).await?
 			} else if args.discover {
 				service.discover(port).await?
 			} else if let Some(ep) = &args.endpoint {
@@ -125,12 +159,16 @@ impl CommandDef for ConnectCommand {
 						"discover": args.discover,
 						"kill": args.kill,
 						"port": args.port,
+						"browser": args.browser,
 						"userDataDir": args.user_data_dir,
+						"stealth": args.stealth,
 					})),
 					..Default::default()
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -0,0 +1,119 @@
+//! Namespace listing and switching (`context.list`, `context.use`).
+//!
+//! A context is a profile namespace under the workspace state root; the active
+//! one is used by requests that name no profile.
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
+use crate::context_store::ContextState;
+use crate::error::Result;
+use crate::output::CommandInputs;
+use crate::target::ResolveEnv;
+use crate::workspace::{normalize_profile, set_active_profile};
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextListRaw {}
+
+#[derive(Debug, Clone)]
+pub struct ContextListResolved;
+
+impl Resolve for ContextListRaw {
+	type Output = ContextListResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(ContextListResolved)
+	}
+}
+
+pub struct ContextListCommand;
+
+impl CommandDef for ContextListCommand {
+	const NAME: &'static str = "context.list";
+
+	type Raw = ContextListRaw;
+	type Resolved = ContextListResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(_args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let root = exec.ctx_state.workspace_root();
+			let data = json!({
+				"active": ContextState::active_namespace(root),
+				"contexts": ContextState::namespaces(root)?,
+			});
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs::default(),
+				data,
+				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextUseRaw {
+	/// Profile to make active; it need not have stored state yet.
+	#[arg(value_name = "NAME")]
+	pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContextUseResolved {
+	pub name: String,
+}
+
+impl Resolve for ContextUseRaw {
+	type Output = ContextUseResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(ContextUseResolved {
+			name: normalize_profile(&self.name),
+		})
+	}
+}
+
+pub struct ContextUseCommand;
+
+impl CommandDef for ContextUseCommand {
+	const NAME: &'static str = "context.use";
+
+	type Raw = ContextUseRaw;
+	type Resolved = ContextUseResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let root = exec.ctx_state.workspace_root();
+			let previous = ContextState::active_namespace(root);
+			set_active_profile(root, &args.name)?;
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({ "name": args.name })),
+					..Default::default()
+				},
+				data: json!({
+					"active": args.name,
+					"previous": previous,
+				}),
+				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
@@ -9,7 +9,7 @@ use std::path::{Path, PathBuf};
 
 use crate::args;
 use crate::commands::def::ContextDelta;
-use crate::error::Result;
+use crate::error::{PwError, Result};
 use crate::output::CommandInputs;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
 
@@ -50,6 +50,18 @@ pub fn resolve_target_and_explicit_selector(
 	Ok((target, selector))
 }
 
+/// Resolve `--first` / `--nth` into the zero-based match index to act on.
+///
+/// `None` keeps Playwright's strict mode, so multi-match selectors fail with
+/// `SELECTOR_AMBIGUOUS` instead of acting on an arbitrary element.
+pub fn resolve_match_index(first: bool, nth: Option<usize>) -> Result<Option<usize>> {
+	match (first, nth) {
+		(true, Some(_)) => Err(PwError::Context("first and nth are mutually exclusive".to_string())),
+		(true, None) => Ok(Some(0)),
+		(false, nth) => Ok(nth),
+	}
+}
+
 /// Build standard command input metadata.
 pub fn standard_inputs(
 	target: &ResolvedTarget,
@@ -67,6 +79,23 @@ pub fn standard_inputs(
 	}
 }
 
+/// Dry-run report of a resolved target: its URL (`null` for the current page) and where it came from.
+pub fn dry_run_target(target: &ResolvedTarget) -> serde_json::Value {
+	serde_json::json!({
+		"url": target.url_str(),
+		"source": target.source,
+	})
+}
+
+/// Dry-run report of a command's resolved target and, when it takes one, its selector.
+pub fn dry_run_inputs(target: &ResolvedTarget, selector: Option<&str>) -> serde_json::Value {
+	let mut report = serde_json::json!({ "target": dry_run_target(target) });
+	if let Some(selector) = selector {
+		report["selector"] = serde_json::Value::from(selector);
+	}
+	report
+}
+
 /// Build a standard context delta using the resolved target URL.
 pub fn standard_delta(target: &ResolvedTarget, selector: Option<&str>, output: Option<&Path>) -> ContextDelta {
 	standard_delta_with_url(target.url_str().map(String::from), selector, output)
@@ -127,4 +156,23 @@ mod tests {
 		assert_eq!(delta.selector.as_deref(), Some("#x"));
 		assert!(delta.output.is_none());
 	}
+
+	#[test]
+	fn dry_run_inputs_reports_target_and_selector() {
+		let target = resolved_target("https://example.com/form");
+		let report = dry_run_inputs(&target, Some("#submit"));
+		assert_eq!(report["target"]["url"], "https://example.com/form");
+		assert_eq!(report["target"]["source"], "explicit");
+		assert_eq!(report["selector"], "#submit");
+
+		assert!(dry_run_inputs(&target, None).get("selector").is_none());
+	}
+
+	#[test]
+	fn match_index_from_first_or_nth() {
+		assert_eq!(resolve_match_index(false, None).unwrap(), None);
+		assert_eq!(resolve_match_index(true, None).unwrap(), Some(0));
+		assert_eq!(resolve_match_index(false, Some(2)).unwrap(), Some(2));
+		assert!(resolve_match_index(true, Some(2)).is_err());
+	}
 }
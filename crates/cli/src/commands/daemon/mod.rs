@@ -55,9 +55,9 @@ impl CommandDef for DaemonStartCommand {
 	type Data = serde_json::Value;
 
 	fn validate_mode(raw: &Self::Raw, mode: ExecMode) -> Result<()> {
-		if mode == ExecMode::Batch && raw.foreground {
+		if mode != ExecMode::Cli && raw.foreground {
 			return Err(PwError::UnsupportedMode(
-				"command 'daemon.start' with --foreground is not available in batch/ndjson mode".to_string(),
+				"command 'daemon.start' with --foreground is only available as a one-shot command".to_string(),
 			));
 		}
 		Ok(())
@@ -98,6 +98,8 @@ impl CommandDef for DaemonStartCommand {
 						"foreground": true
 					}),
 					delta: ContextDelta::default(),
+					diagnostics: Vec::new(),
+					artifacts: Vec::new(),
 				});
 			}
 
@@ -127,6 +129,8 @@ impl CommandDef for DaemonStartCommand {
 							"message": "daemon already running"
 						}),
 						delta: ContextDelta::default(),
+						diagnostics: Vec::new(),
+						artifacts: Vec::new(),
 					});
 				}
 
@@ -177,6 +181,8 @@ impl CommandDef for DaemonStartCommand {
 						"pid": pid
 					}),
 					delta: ContextDelta::default(),
+					diagnostics: Vec::new(),
+					artifacts: Vec::new(),
 				})
 			}
 		})
@@ -230,6 +236,8 @@ impl CommandDef for DaemonStopCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -266,9 +274,11 @@ impl CommandDef for DaemonStatusCommand {
 		Box::pin(async move {
 			let data = if let Some(true) = daemon::ping().await? {
 				let list = daemon::list_browsers().await?.unwrap_or_default();
+				let driver = daemon::driver_status().await?;
 				json!({
 					"running": true,
-					"browsers": list
+					"browsers": list,
+					"driver": driver
 				})
 			} else {
 				json!({
@@ -281,6 +291,8 @@ impl CommandDef for DaemonStatusCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -12,7 +12,7 @@ use serde::de::DeserializeOwned;
 use crate::context::CommandContext;
 use crate::context_store::ContextState;
 use crate::error::Result;
-use crate::output::{CommandInputs, OutputFormat};
+use crate::output::{Artifact, CommandInputs, Diagnostic, OutputFormat};
 use crate::session::SessionManager;
 use crate::target::ResolveEnv;
 
@@ -33,12 +33,23 @@ pub enum ExecMode {
 	Cli,
 	/// Batch / NDJSON command invocation.
 	Batch,
+	/// Line-by-line `pw repl` invocation; a human is at the terminal.
+	Interactive,
 }
 
+/// Sender for intermediate `data` chunks of a streamed batch reply.
+///
+/// Bounded so a producer waits for the batch loop to write each chunk instead of
+/// buffering the whole result set.
+pub type ChunkSender = tokio::sync::mpsc::Sender<serde_json::Value>;
+
 /// Unified execution context; replaces per-command parameter drift.
 pub struct ExecCtx<'exec, 'ctx> {
 	pub mode: ExecMode,
 
+	/// Stop after resolution and report [`CommandDef::dry_run`] instead of executing.
+	pub dry_run: bool,
+
 	/// Immutable CLI/global context (browser, CDP endpoint, timeouts, etc.).
 	pub ctx: &'ctx CommandContext,
 
@@ -56,6 +67,9 @@ pub struct ExecCtx<'exec, 'ctx> {
 
 	/// Last URL from context store (for `Target::CurrentPage` preference).
 	pub last_url: Option<&'exec str>,
+
+	/// Chunk sink for streaming commands; only set in batch/run mode.
+	pub chunks: Option<ChunkSender>,
 }
 
 /// State mutations to apply after successful command execution.
@@ -78,6 +92,10 @@ pub struct CommandOutcome<T> {
 	pub inputs: CommandInputs,
 	pub data: T,
 	pub delta: ContextDelta,
+	/// Non-fatal notes surfaced alongside the payload.
+	pub diagnostics: Vec<Diagnostic>,
+	/// Files written by a successful command.
+	pub artifacts: Vec<Artifact>,
 }
 
 /// Type-erased outcome for the dispatcher; wrapper prints `data` (serde_json::Value).
@@ -87,6 +105,8 @@ pub struct ErasedOutcome {
 	pub inputs: CommandInputs,
 	pub data: serde_json::Value,
 	pub delta: ContextDelta,
+	pub diagnostics: Vec<Diagnostic>,
+	pub artifacts: Vec<Artifact>,
 }
 
 impl<T: Serialize> CommandOutcome<T> {
@@ -97,10 +117,27 @@ impl<T: Serialize> CommandOutcome<T> {
 			inputs: self.inputs,
 			data: serde_json::to_value(self.data)?,
 			delta: self.delta,
+			diagnostics: self.diagnostics,
+			artifacts: self.artifacts,
 		})
 	}
 }
 
+impl ErasedOutcome {
+	/// Outcome of a dry run: `data` carries the resolution report and nothing is
+	/// written back to the profile.
+	pub fn dry_run(command: &'static str, resolved: serde_json::Value) -> Self {
+		Self {
+			command,
+			inputs: CommandInputs::default(),
+			data: serde_json::json!({ "dryRun": true, "resolved": resolved }),
+			delta: ContextDelta::default(),
+			diagnostics: Vec::new(),
+			artifacts: Vec::new(),
+		}
+	}
+}
+
 /// Boxing alias: stable async in trait without `async_trait`.
 pub type BoxFut<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
 
@@ -123,6 +160,14 @@ pub trait CommandDef: 'static {
 		Ok(())
 	}
 
+	/// Describe the resolved inputs for a dry run, which skips [`execute`](Self::execute).
+	///
+	/// Defaults to an empty object; commands with a navigation target report it,
+	/// and their selector, via [`dry_run_inputs`](crate::commands::contract::dry_run_inputs).
+	fn dry_run(_resolved: &Self::Resolved) -> serde_json::Value {
+		serde_json::json!({})
+	}
+
 	/// Execute the command. **Must not print**. Wrapper prints.
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
@@ -1,155 +1,361 @@
 use std::io::Write;
 use std::path::Path;
+use std::time::Instant;
 
 use serde_json::{Value, json};
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
+use tokio::sync::mpsc;
 
-use crate::cli::{BatchArgs, DaemonAction, ExecArgs, ProfileAction};
-use crate::commands::def::{ExecCtx, ExecMode};
+use crate::cli::{BatchArgs, ContextAction, DaemonAction, ExecArgs, ProfileAction, RunArgs};
+use crate::commands::batch_log::BatchLog;
+use crate::commands::def::{ChunkSender, ExecCtx, ExecMode};
+use crate::commands::fanout;
 use crate::commands::registry::{command_name, lookup_command_exact, run_command};
-use crate::error::{PwError, Result};
-use crate::output::{CommandError, ErrorCode, OutputFormat};
+use crate::context::BlockConfig;
+use crate::error::{PwError, Result, with_match_picking_hint};
+use crate::output::{CommandError, Diagnostic, DiagnosticLevel, ErrorCode, OutputFormat};
 use crate::protocol::{CommandRequest, CommandResponse, EffectiveRuntime, RuntimeSpec, SCHEMA_VERSION, print_response};
-use crate::runtime::{RuntimeConfig, build_runtime};
+use crate::runtime::{RuntimeConfig, RuntimeInfo, build_runtime};
 use crate::session::SessionManager;
 use crate::workspace::normalize_profile;
 
-pub async fn run_exec(args: ExecArgs, format: OutputFormat) -> Result<()> {
-	let request = parse_exec_request(&args)?;
-	let response = execute_request(request, Some(args.profile), ExecMode::Cli, args.artifacts_dir.as_deref()).await;
+/// Chunks a streaming command may produce ahead of the batch loop writing them.
+const STREAM_CHUNK_BUFFER: usize = 1;
+
+/// Runs one `pw exec` request. Returns the emitted response's `ok`.
+pub async fn run_exec(args: ExecArgs, format: OutputFormat, fail_on_console_error: bool) -> Result<bool> {
+	let mut request = parse_exec_request(&args)?;
+	if fail_on_console_error {
+		default_fail_on_console_error(&mut request);
+	}
+	if let Some(urls_file) = &args.urls_file {
+		return fanout::run_url_fanout(request, urls_file, &args).await;
+	}
+	let response = execute_request(request, args.profile, ExecMode::Cli, args.artifacts_dir.as_deref(), None).await;
 	print_response(&response, format);
-	Ok(())
+	Ok(response.ok)
 }
 
-pub async fn run_batch(args: BatchArgs, format: OutputFormat) -> Result<()> {
-	let stdin = tokio::io::stdin();
-	let mut reader = BufReader::new(stdin);
-	let mut line = String::new();
-	let mut stdout = std::io::stdout();
-	let default_profile = args.profile;
-
-	loop {
-		line.clear();
-		match reader.read_line(&mut line).await {
-			Ok(0) => break,
-			Ok(_) => {}
-			Err(err) => {
-				tracing::error!(target = "pw.batch", error = %err, "stdin read failed");
-				break;
+/// Serves batch requests until EOF or `quit`. Returns `true` when every emitted response was `ok`.
+pub async fn run_batch(args: BatchArgs, format: OutputFormat, fail_on_console_error: bool) -> Result<bool> {
+	let mut session = BatchSession::new(args, format, fail_on_console_error)?;
+	session.serve(BufReader::new(tokio::io::stdin()), "stdin").await;
+	Ok(session.all_ok)
+}
+
+/// Serves requests from `args.script`, then from stdin unless the script ended with `quit`.
+/// Returns `true` when every emitted response was `ok`.
+pub async fn run_script(args: RunArgs, format: OutputFormat, fail_on_console_error: bool) -> Result<bool> {
+	let script = tokio::fs::File::open(&args.script)
+		.await
+		.map_err(|err| PwError::Context(format!("failed to open script {}: {err}", args.script.display())))?;
+	let mut session = BatchSession::new(args.batch, format, fail_on_console_error)?;
+	if session.serve(BufReader::new(script), "script").await == BatchEnd::Quit {
+		return Ok(session.all_ok);
+	}
+	session.serve(BufReader::new(tokio::io::stdin()), "stdin").await;
+	Ok(session.all_ok)
+}
+
+/// How a request source stopped being read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BatchEnd {
+	Eof,
+	Quit,
+}
+
+/// Response sink and state shared by every request source of one batch run.
+struct BatchSession {
+	stdout: std::io::Stdout,
+	log: Option<BatchLog>,
+	default_profile: Option<String>,
+	format: OutputFormat,
+	/// Global `--fail-on-console-error`, applied to requests that leave it unset.
+	fail_on_console_error: bool,
+	all_ok: bool,
+}
+
+impl BatchSession {
+	fn new(args: BatchArgs, format: OutputFormat, fail_on_console_error: bool) -> Result<Self> {
+		Ok(Self {
+			stdout: std::io::stdout(),
+			log: args.log_file.as_deref().map(BatchLog::open).transpose()?,
+			default_profile: args.profile,
+			format,
+			fail_on_console_error,
+			all_ok: true,
+		})
+	}
+
+	/// Reads NDJSON requests from `reader` until EOF, a read error, or `quit`.
+	async fn serve<R: AsyncBufRead + Unpin>(&mut self, mut reader: R, source: &str) -> BatchEnd {
+		let mut line = String::new();
+
+		loop {
+			line.clear();
+			match reader.read_line(&mut line).await {
+				Ok(0) => return BatchEnd::Eof,
+				Ok(_) => {}
+				Err(err) => {
+					tracing::error!(target = "pw.batch", error = %err, source = %source, "request read failed");
+					return BatchEnd::Eof;
+				}
 			}
-		}
 
-		let line = line.trim();
-		if line.is_empty() {
-			continue;
-		}
+			let line = line.trim();
+			if line.is_empty() {
+				continue;
+			}
+			let started = Instant::now();
+
+			let mut request: CommandRequest = match serde_json::from_str(line) {
+				Ok(value) => value,
+				Err(err) => {
+					let response = error_response(
+						None,
+						"unknown".to_string(),
+						CommandError {
+							code: ErrorCode::InvalidInput,
+							message: format!("Invalid request JSON: {err}"),
+							details: None,
+						},
+						None,
+					);
+					self.emit(&response, started);
+					continue;
+				}
+			};
 
-		let request: CommandRequest = match serde_json::from_str(line) {
-			Ok(value) => value,
-			Err(err) => {
-				let response = error_response(
-					None,
-					"unknown".to_string(),
-					CommandError {
-						code: ErrorCode::InvalidInput,
-						message: format!("Invalid request JSON: {err}"),
-						details: None,
-					},
-					None,
-				);
-				write_batch_response(&mut stdout, &response, format);
+			if request.op == "quit" || request.op == "exit" {
+				let response = control_response(request.request_id, "quit", json!({ "quit": true }));
+				self.emit(&response, started);
+				return BatchEnd::Quit;
+			}
+
+			if request.op == "ping" {
+				let response = control_response(request.request_id, "ping", json!({ "alive": true }));
+				self.emit(&response, started);
 				continue;
 			}
-		};
 
-		if request.op == "quit" || request.op == "exit" {
-			let response = CommandResponse {
-				schema_version: SCHEMA_VERSION,
-				request_id: request.request_id,
-				op: "quit".to_string(),
-				ok: true,
-				inputs: None,
-				data: Some(json!({ "quit": true })),
-				error: None,
-				duration_ms: None,
-				artifacts: Vec::new(),
-				diagnostics: Vec::new(),
-				context_delta: None,
-				effective_runtime: None,
-			};
-			write_batch_response(&mut stdout, &response, format);
-			break;
+			if request.op == "config" {
+				let response = match configure_batch(&request.input, &mut self.log) {
+					Ok(data) => control_response(request.request_id, "config", data),
+					Err(err) => error_response(request.request_id, "config".to_string(), err.to_command_error(), None),
+				};
+				self.emit(&response, started);
+				continue;
+			}
+
+			if self.fail_on_console_error {
+				default_fail_on_console_error(&mut request);
+			}
+			self.execute_streaming(request, started).await;
 		}
+	}
 
-		if request.op == "ping" {
-			let response = CommandResponse {
-				schema_version: SCHEMA_VERSION,
-				request_id: request.request_id,
-				op: "ping".to_string(),
-				ok: true,
-				inputs: None,
-				data: Some(json!({ "alive": true })),
-				error: None,
-				duration_ms: None,
-				artifacts: Vec::new(),
-				diagnostics: Vec::new(),
-				context_delta: None,
-				effective_runtime: None,
-			};
-			write_batch_response(&mut stdout, &response, format);
-			continue;
+	/// Executes one request, writing any streamed chunks as they arrive.
+	///
+	/// Chunked replies share the request id: each chunk carries `seq` and
+	/// `done: false`, and the final response carries the next `seq` and `done: true`.
+	async fn execute_streaming(&mut self, request: CommandRequest, started: Instant) {
+		let request_id = request.request_id.clone();
+		let op = request.op.clone();
+		let (tx, mut rx) = mpsc::channel(STREAM_CHUNK_BUFFER);
+		let execution = execute_request(request, self.default_profile.clone(), ExecMode::Batch, None, Some(tx));
+		tokio::pin!(execution);
+
+		let mut seq = 0;
+		let response = loop {
+			tokio::select! {
+				response = &mut execution => break response,
+				Some(data) = rx.recv() => {
+					self.emit(&CommandResponse::chunk(request_id.clone(), op.clone(), seq, data), started);
+					seq += 1;
+				}
+			}
+		};
+		while let Ok(data) = rx.try_recv() {
+			self.emit(&CommandResponse::chunk(request_id.clone(), op.clone(), seq, data), started);
+			seq += 1;
 		}
 
-		let response = execute_request(request, Some(default_profile.clone()), ExecMode::Batch, None).await;
-		write_batch_response(&mut stdout, &response, format);
+		// Even a stream that produced no chunks ends with `done: true`, so readers
+		// never wait for a terminal response that already arrived.
+		self.emit(&response.with_stream_end(seq), started);
 	}
 
-	Ok(())
+	fn emit(&mut self, response: &CommandResponse, started: Instant) {
+		self.all_ok &= response.ok;
+		emit_batch_response(&mut self.stdout, self.log.as_mut(), response, self.format, started);
+	}
+}
+
+/// Applies a batch `config` directive; currently only `logFile` is supported.
+fn configure_batch(input: &Value, log: &mut Option<BatchLog>) -> Result<Value> {
+	let Some(log_file) = input.get("logFile").or_else(|| input.get("log_file")) else {
+		return Err(PwError::Context("config requires logFile".to_string()));
+	};
+	let path = log_file
+		.as_str()
+		.filter(|path| !path.is_empty())
+		.ok_or_else(|| PwError::Context("logFile must be a non-empty path".to_string()))?;
+
+	let opened = BatchLog::open(Path::new(path))?;
+	let data = json!({ "logFile": opened.path() });
+	*log = Some(opened);
+	Ok(data)
+}
+
+fn control_response(request_id: Option<String>, op: &str, data: Value) -> CommandResponse {
+	CommandResponse {
+		schema_version: SCHEMA_VERSION,
+		request_id,
+		op: op.to_string(),
+		ok: true,
+		inputs: None,
+		data: Some(data),
+		error: None,
+		duration_ms: None,
+		artifacts: Vec::new(),
+		diagnostics: Vec::new(),
+		context_delta: None,
+		effective_runtime: None,
+		seq: None,
+		done: None,
+	}
 }
 
-pub async fn run_profile(action: ProfileAction, format: OutputFormat) -> Result<()> {
+fn emit_batch_response(stdout: &mut std::io::Stdout, log: Option<&mut BatchLog>, response: &CommandResponse, format: OutputFormat, started: Instant) {
+	write_batch_response(stdout, response, format);
+	let Some(log) = log else {
+		return;
+	};
+	if let Err(err) = log.record(response, started.elapsed()) {
+		tracing::warn!(target = "pw.batch", error = %err, path = %log.path().display(), "failed to write batch log");
+	}
+}
+
+pub async fn run_profile(action: ProfileAction, format: OutputFormat) -> Result<bool> {
 	let request = request_from_profile_action(action);
-	let response = execute_request(request, Some("default".to_string()), ExecMode::Cli, None).await;
+	let response = execute_request(request, Some("default".to_string()), ExecMode::Cli, None, None).await;
+	print_response(&response, format);
+	Ok(response.ok)
+}
+
+pub async fn run_context(action: ContextAction, format: OutputFormat) -> Result<bool> {
+	let request = request_from_context_action(action);
+	let response = execute_request(request, Some("default".to_string()), ExecMode::Cli, None, None).await;
 	print_response(&response, format);
-	Ok(())
+	Ok(response.ok)
 }
 
-pub async fn run_daemon(action: DaemonAction, format: OutputFormat) -> Result<()> {
+pub async fn run_daemon(action: DaemonAction, format: OutputFormat) -> Result<bool> {
 	let request = request_from_daemon_action(action);
-	let response = execute_request(request, Some("default".to_string()), ExecMode::Cli, None).await;
+	let response = execute_request(request, Some("default".to_string()), ExecMode::Cli, None, None).await;
 	print_response(&response, format);
-	Ok(())
+	Ok(response.ok)
 }
 
 fn parse_exec_request(args: &ExecArgs) -> Result<CommandRequest> {
-	if let Some(file) = &args.file {
+	let mut request = if let Some(file) = &args.file {
 		let content = std::fs::read_to_string(file)?;
-		return serde_json::from_str::<CommandRequest>(&content).map_err(PwError::Json);
-	}
-
-	let op = args
-		.op
-		.clone()
-		.ok_or_else(|| PwError::Context("missing operation: use `pw exec <op>` or `--file`".to_string()))?;
+		serde_json::from_str::<CommandRequest>(&content).map_err(PwError::Json)?
+	} else {
+		let op = args
+			.op
+			.clone()
+			.ok_or_else(|| PwError::Context("missing operation: use `pw exec <op>` or `--file`".to_string()))?;
+
+		let input = match &args.input {
+			Some(raw) => serde_json::from_str::<Value>(raw)?,
+			None => Value::Object(Default::default()),
+		};
 
-	let input = match &args.input {
-		Some(raw) => serde_json::from_str::<Value>(raw)?,
-		None => Value::Object(Default::default()),
+		CommandRequest {
+			schema_version: SCHEMA_VERSION,
+			request_id: None,
+			op,
+			input,
+			runtime: Some(RuntimeSpec {
+				profile: args.profile.clone(),
+				overrides: None,
+			}),
+			dry_run: false,
+		}
 	};
+	request.dry_run |= args.dry_run;
+
+	if !args.cookies.is_empty()
+		|| args.no_viewport
+		|| args.channel.is_some()
+		|| args.devtools
+		|| args.timeout_ms.is_some()
+		|| args.slow_mo_ms.is_some()
+		|| args.save_trace_on_timeout
+		|| args.force_navigate
+		|| args.color_scheme.is_some()
+		|| args.reduced_motion.is_some()
+		|| args.forced_colors.is_some()
+		|| args.offline
+		|| args.throttle.is_some()
+		|| args.insecure
+	{
+		let runtime = request.runtime.get_or_insert_with(Default::default);
+		let overrides = runtime.overrides.get_or_insert_with(Default::default);
+		overrides.cookies.extend(args.cookies.iter().cloned());
+		if args.no_viewport {
+			overrides.no_viewport = Some(true);
+		}
+		if args.channel.is_some() {
+			overrides.channel = args.channel;
+		}
+		if args.devtools {
+			overrides.devtools = Some(true);
+		}
+		if args.timeout_ms.is_some() {
+			overrides.timeout_ms = args.timeout_ms;
+		}
+		if args.slow_mo_ms.is_some() {
+			overrides.slow_mo_ms = args.slow_mo_ms;
+		}
+		if args.save_trace_on_timeout {
+			overrides.save_trace_on_timeout = Some(true);
+		}
+		if args.force_navigate {
+			overrides.force_navigate = Some(true);
+		}
+		if args.color_scheme.is_some() {
+			overrides.color_scheme = args.color_scheme;
+		}
+		if args.reduced_motion.is_some() {
+			overrides.reduced_motion = args.reduced_motion;
+		}
+		if args.forced_colors.is_some() {
+			overrides.forced_colors = args.forced_colors;
+		}
+		if args.offline {
+			overrides.offline = Some(true);
+		}
+		if args.throttle.is_some() {
+			overrides.throttle = args.throttle;
+		}
+		if args.insecure {
+			overrides.insecure = Some(true);
+		}
+	}
 
-	Ok(CommandRequest {
-		schema_version: SCHEMA_VERSION,
-		request_id: None,
-		op,
-		input,
-		runtime: Some(RuntimeSpec {
-			profile: Some(args.profile.clone()),
-			overrides: None,
-		}),
-	})
+	Ok(request)
+}
+
+/// Turns on `failOnConsoleError` for `request` unless it sets the override itself.
+pub(crate) fn default_fail_on_console_error(request: &mut CommandRequest) {
+	let runtime = request.runtime.get_or_insert_with(Default::default);
+	let overrides = runtime.overrides.get_or_insert_with(Default::default);
+	overrides.fail_on_console_error.get_or_insert(true);
 }
 
-fn write_batch_response(stdout: &mut std::io::Stdout, response: &CommandResponse, format: OutputFormat) {
+pub(crate) fn write_batch_response(stdout: &mut std::io::Stdout, response: &CommandResponse, format: OutputFormat) {
 	match format {
 		OutputFormat::Ndjson => {
 			if let Ok(line) = serde_json::to_string(response) {
@@ -162,7 +368,27 @@ fn write_batch_response(stdout: &mut std::io::Stdout, response: &CommandResponse
 	}
 }
 
-async fn execute_request(request: CommandRequest, fallback_profile: Option<String>, mode: ExecMode, artifacts_dir: Option<&Path>) -> CommandResponse {
+/// Runs one request, recording its wall-clock time in `durationMs`.
+pub(crate) async fn execute_request(
+	request: CommandRequest,
+	fallback_profile: Option<String>,
+	mode: ExecMode,
+	artifacts_dir: Option<&Path>,
+	chunks: Option<ChunkSender>,
+) -> CommandResponse {
+	let started = Instant::now();
+	let mut response = execute_request_untimed(request, fallback_profile, mode, artifacts_dir, chunks).await;
+	response.duration_ms = Some(started.elapsed().as_millis() as u64);
+	response
+}
+
+async fn execute_request_untimed(
+	request: CommandRequest,
+	fallback_profile: Option<String>,
+	mode: ExecMode,
+	artifacts_dir: Option<&Path>,
+	chunks: Option<ChunkSender>,
+) -> CommandResponse {
 	if request.schema_version != SCHEMA_VERSION {
 		return error_response(
 			request.request_id,
@@ -177,13 +403,10 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 	}
 
 	let runtime = request.runtime.clone().unwrap_or_default();
-	let profile = normalize_profile(runtime.profile.as_deref().or(fallback_profile.as_deref()).unwrap_or("default"));
+	let profile = runtime.profile.as_deref().or(fallback_profile.as_deref()).map(normalize_profile);
 	let overrides = runtime.overrides.unwrap_or_default();
 
-	let runtime_config = RuntimeConfig {
-		profile: profile.clone(),
-		overrides,
-	};
+	let runtime_config = RuntimeConfig { profile, overrides };
 
 	let crate::runtime::RuntimeContext { ctx, mut ctx_state, info } = match build_runtime(&runtime_config) {
 		Ok(runtime) => runtime,
@@ -192,12 +415,7 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 		}
 	};
 
-	let effective_runtime = EffectiveRuntime {
-		profile: info.profile.clone(),
-		browser: Some(info.browser.to_string()),
-		cdp_endpoint: info.cdp_endpoint.clone(),
-		timeout_ms: info.timeout_ms,
-	};
+	let mut effective_runtime = effective_runtime(&info);
 
 	let mut session = SessionManager::new(
 		&ctx,
@@ -224,15 +442,23 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 	let last_url = ctx_state.last_url().map(str::to_string);
 	let exec = ExecCtx {
 		mode,
+		dry_run: request.dry_run,
 		ctx: &ctx,
 		ctx_state: &mut ctx_state,
 		session: &mut session,
 		format: OutputFormat::Json,
 		artifacts_dir,
 		last_url: last_url.as_deref(),
+		chunks,
 	};
 
-	match run_command(cmd_id, request.input, has_cdp, exec).await {
+	let result = run_command(cmd_id, request.input, has_cdp, exec).await;
+	if let Some(browser) = session.browser_info().await {
+		effective_runtime.browser_version = Some(browser.version.clone());
+		effective_runtime.user_agent = browser.user_agent.clone();
+	}
+
+	match result {
 		Ok(outcome) => {
 			let op = outcome.command.to_string();
 			let request_id = request.request_id;
@@ -242,17 +468,56 @@ async fn execute_request(request: CommandRequest, fallback_profile: Option<Strin
 				return error_response(request_id, op, err.to_command_error(), Some(effective_runtime.clone()));
 			}
 
+			let mut diagnostics = outcome.diagnostics;
+			diagnostics.extend(blocked_request_diagnostics(ctx.block_config()));
+
 			CommandResponse::success(request_id, op, outcome.inputs, outcome.data, delta, effective_runtime)
+				.with_diagnostics(diagnostics)
+				.with_artifacts(outcome.artifacts)
+		}
+		Err(err) => {
+			let op = command_name(cmd_id);
+			let artifacts = err.failure_with_artifacts().map(|failure| failure.artifacts.clone()).unwrap_or_default();
+			error_response(
+				request.request_id,
+				op.to_string(),
+				with_match_picking_hint(err.to_command_error(), op),
+				Some(effective_runtime),
+			)
+			.with_artifacts(artifacts)
+			.with_diagnostics(blocked_request_diagnostics(ctx.block_config()))
 		}
-		Err(err) => error_response(
-			request.request_id,
-			command_name(cmd_id).to_string(),
-			err.to_command_error(),
-			Some(effective_runtime),
-		),
 	}
 }
 
+/// Runtime settings reported in every response built from `info`.
+pub(crate) fn effective_runtime(info: &RuntimeInfo) -> EffectiveRuntime {
+	EffectiveRuntime {
+		profile: info.profile.clone(),
+		browser: Some(info.browser.to_string()),
+		cdp_endpoint: info.cdp_endpoint.clone(),
+		timeout_ms: info.timeout_ms,
+		max_text_length: info.max_text_length,
+		base_url: info.base_url.clone(),
+		..Default::default()
+	}
+}
+
+/// One info diagnostic per block pattern that aborted a request, so a page
+/// missing resources is explained by the response itself.
+pub(crate) fn blocked_request_diagnostics(block_config: &BlockConfig) -> Vec<Diagnostic> {
+	block_config
+		.matched
+		.snapshot()
+		.into_iter()
+		.map(|pattern| Diagnostic {
+			level: DiagnosticLevel::Info,
+			message: format!("blocked requests matching '{pattern}'"),
+			source: Some("network".to_string()),
+		})
+		.collect()
+}
+
 fn request_from_daemon_action(action: DaemonAction) -> CommandRequest {
 	let (op, input) = match action {
 		DaemonAction::Start { foreground } => ("daemon.start".to_string(), json!({ "foreground": foreground })),
@@ -272,6 +537,14 @@ fn request_from_profile_action(action: ProfileAction) -> CommandRequest {
 	command_request(op, input)
 }
 
+fn request_from_context_action(action: ContextAction) -> CommandRequest {
+	let (op, input) = match action {
+		ContextAction::List => ("context.list".to_string(), json!({})),
+		ContextAction::Use { name } => ("context.use".to_string(), json!({ "name": name })),
+	};
+	command_request(op, input)
+}
+
 fn command_request(op: String, input: Value) -> CommandRequest {
 	CommandRequest {
 		schema_version: SCHEMA_VERSION,
@@ -282,6 +555,7 @@ fn command_request(op: String, input: Value) -> CommandRequest {
 			profile: Some("default".to_string()),
 			overrides: None,
 		}),
+		dry_run: false,
 	}
 }
 
@@ -0,0 +1,276 @@
+//! `pw exec --urls-file`: one operation over many URLs on a single session.
+//!
+//! The runtime and browser session are set up once. Every URL then runs on
+//! its own page of that session's context, at most `--concurrency` pages at a
+//! time, and envelopes are written as NDJSON in file order. The context delta
+//! of the last successful URL is applied once, after all URLs finished.
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use futures::StreamExt;
+use pw_rs::{GotoOptions, WaitUntil};
+use serde_json::Value;
+
+use crate::cli::ExecArgs;
+use crate::commands::contract::{standard_delta, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{CommandOutcome, ContextDelta, Resolve};
+use crate::commands::engine::{blocked_request_diagnostics, effective_runtime, write_batch_response};
+use crate::commands::input::parse_raw;
+use crate::commands::navigate::{NavigateRaw, NavigateResolved, check_status, snapshot_page, status_skipped_diagnostic};
+use crate::commands::page::read::{ReadRaw, ReadResolved, read_page, write_content};
+use crate::commands::screenshot::{ScreenshotRaw, ScreenshotResolved, capture_screenshot};
+use crate::context::CommandContext;
+use crate::context_store::ContextState;
+use crate::error::{PwError, Result};
+use crate::output::{Diagnostic, OutputFormat, ScreenshotData};
+use crate::protocol::{CommandRequest, CommandResponse, SCHEMA_VERSION};
+use crate::runtime::{RuntimeConfig, build_runtime};
+use crate::session::{SessionHandle, SessionManager, SessionRequest};
+use crate::target::ResolveEnv;
+use crate::workspace::normalize_profile;
+
+/// Operations `pw exec --urls-file` can fan out over.
+const URL_FANOUT_OPS: &[&str] = &["navigate", "page.read", "screenshot"];
+
+/// Runs `request` once per URL listed in `urls_file`. Returns `true` when every envelope was `ok`.
+pub(crate) async fn run_url_fanout(request: CommandRequest, urls_file: &Path, args: &ExecArgs) -> Result<bool> {
+	if !URL_FANOUT_OPS.contains(&request.op.as_str()) {
+		return Err(PwError::Context(format!(
+			"--urls-file supports {}, not {}",
+			URL_FANOUT_OPS.join(", "),
+			request.op
+		)));
+	}
+	if request.schema_version != SCHEMA_VERSION {
+		return Err(PwError::Context(format!(
+			"unsupported schemaVersion {} (expected {})",
+			request.schema_version, SCHEMA_VERSION
+		)));
+	}
+	if request.dry_run {
+		return Err(PwError::Context("--urls-file does not support dry runs".into()));
+	}
+	let content = std::fs::read_to_string(urls_file).map_err(|err| PwError::Context(format!("failed to read urls file {}: {err}", urls_file.display())))?;
+	let urls = parse_url_lines(&content);
+	if urls.is_empty() {
+		return Err(PwError::Context(format!("urls file {} lists no URLs", urls_file.display())));
+	}
+
+	let runtime = request.runtime.clone().unwrap_or_default();
+	let profile = runtime.profile.as_deref().or(args.profile.as_deref()).map(normalize_profile);
+	let crate::runtime::RuntimeContext { ctx, mut ctx_state, info } = build_runtime(&RuntimeConfig {
+		profile,
+		overrides: runtime.overrides.unwrap_or_default(),
+	})?;
+	let mut effective_runtime = effective_runtime(&info);
+
+	let jobs: Vec<Result<Job>> = urls.iter().map(|url| Job::resolve(&request, url, &ctx, &mut ctx_state)).collect();
+
+	let mut manager = SessionManager::new(
+		&ctx,
+		ctx_state.session_descriptor_path(),
+		Some(ctx_state.profile_id()),
+		ctx_state.refresh_requested(),
+	);
+	let wait_until = if request.op == "navigate" { WaitUntil::Load } else { WaitUntil::NetworkIdle };
+	let session = manager.session(SessionRequest::from_context(wait_until, &ctx)).await?;
+	if let Some(browser) = manager.browser_info().await {
+		effective_runtime.browser_version = Some(browser.version.clone());
+		effective_runtime.user_agent = browser.user_agent.clone();
+	}
+
+	let (shared_session, shared_ctx) = (&session, &ctx);
+	let mut outcomes = futures::stream::iter(jobs.into_iter().map(|job| async move {
+		let started = Instant::now();
+		let (outcome, blocked) = match job {
+			Ok(job) => job.run(shared_session, shared_ctx, wait_until).await,
+			Err(err) => (Err(err), Vec::new()),
+		};
+		(outcome, blocked, started.elapsed().as_millis() as u64)
+	}))
+	.buffered(args.concurrency.max(1));
+
+	let mut stdout = std::io::stdout();
+	let mut all_ok = true;
+	let mut last_delta = None;
+	while let Some((outcome, blocked, duration_ms)) = outcomes.next().await {
+		let mut response = match outcome {
+			Ok(outcome) => {
+				last_delta = Some(outcome.delta.clone());
+				let mut diagnostics = outcome.diagnostics;
+				diagnostics.extend(blocked);
+				CommandResponse::success(
+					request.request_id.clone(),
+					request.op.clone(),
+					outcome.inputs,
+					outcome.data,
+					ContextDelta::default(),
+					effective_runtime.clone(),
+				)
+				.with_diagnostics(diagnostics)
+				.with_artifacts(outcome.artifacts)
+			}
+			Err(err) => CommandResponse::error(
+				request.request_id.clone(),
+				request.op.clone(),
+				err.to_command_error(),
+				Some(effective_runtime.clone()),
+			)
+			.with_diagnostics(blocked),
+		};
+		response.duration_ms = Some(duration_ms);
+		all_ok &= response.ok;
+		write_batch_response(&mut stdout, &response, OutputFormat::Ndjson);
+	}
+	drop(outcomes);
+
+	session.close().await?;
+	if let Some(delta) = last_delta {
+		delta.apply(&mut ctx_state);
+	}
+	ctx_state.persist_if_dirty()?;
+	Ok(all_ok)
+}
+
+/// Non-empty lines of a URL list, skipping `#` comments.
+fn parse_url_lines(content: &str) -> Vec<&str> {
+	content
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty() && !line.starts_with('#'))
+		.collect()
+}
+
+/// One URL's resolved operation, with any output template already expanded.
+enum Job {
+	Navigate(NavigateResolved),
+	Read(ReadResolved, Option<PathBuf>),
+	Screenshot(ScreenshotResolved, PathBuf),
+}
+
+impl Job {
+	/// Resolves `request` for `url` the way a single request would be resolved.
+	fn resolve(request: &CommandRequest, url: &str, ctx: &CommandContext, ctx_state: &mut ContextState) -> Result<Self> {
+		let mut input = request.input.clone();
+		if let Value::Object(fields) = &mut input {
+			fields.insert("url".to_string(), Value::String(url.to_string()));
+		}
+		let has_cdp = ctx.cdp_endpoint().is_some();
+
+		match request.op.as_str() {
+			"navigate" => {
+				let raw: NavigateRaw = parse_raw("navigate", input)?;
+				Ok(Self::Navigate(raw.resolve(&ResolveEnv::new(ctx_state, has_cdp, "navigate"))?))
+			}
+			"page.read" => {
+				let raw: ReadRaw = parse_raw("page.read", input)?;
+				let args = raw.resolve(&ResolveEnv::new(ctx_state, has_cdp, "page.read"))?;
+				let path = args
+					.output
+					.as_deref()
+					.map(|template| ctx_state.resolve_output(ctx, template, args.target.url_str()));
+				Ok(Self::Read(args, path))
+			}
+			"screenshot" => {
+				let raw: ScreenshotRaw = parse_raw("screenshot", input)?;
+				let args = raw.resolve(&ResolveEnv::new(ctx_state, has_cdp, "screenshot"))?;
+				let path = ctx_state.resolve_output(ctx, &args.output, args.target.url_str());
+				Ok(Self::Screenshot(args, path))
+			}
+			op => Err(PwError::Context(format!("--urls-file does not support {op}"))),
+		}
+	}
+
+	/// Runs the operation on a fresh page of `session`, closing the page afterwards.
+	///
+	/// The page gets the same blocking routes, download tracking and throttling as the session
+	/// page. Also returns a diagnostic per block pattern that aborted one of the page's requests,
+	/// whether or not the operation succeeded.
+	async fn run(&self, session: &SessionHandle, ctx: &CommandContext, wait_until: WaitUntil) -> (Result<CommandOutcome<Value>>, Vec<Diagnostic>) {
+		let page = match session.open_page(ctx).await {
+			Ok(page) => page,
+			Err(err) => return (Err(err), Vec::new()),
+		};
+		let outcome = self.run_on(page.page(), wait_until, ctx.timeout_ms()).await;
+		let blocked = blocked_request_diagnostics(page.block_config());
+		let _ = page.close().await;
+		(outcome, blocked)
+	}
+
+	async fn run_on(&self, page: &pw_rs::Page, wait_until: WaitUntil, timeout_ms: Option<u64>) -> Result<CommandOutcome<Value>> {
+		let target = match self {
+			Self::Navigate(args) => &args.target,
+			Self::Read(args, _) => &args.target,
+			Self::Screenshot(args, _) => &args.target,
+		};
+		let url = target.url_str().ok_or_else(|| PwError::Context("--urls-file entries must be URLs".into()))?;
+		let goto_opts = GotoOptions {
+			wait_until: Some(wait_until),
+			timeout: timeout_ms.map(std::time::Duration::from_millis),
+		};
+		let response = page.goto(url, Some(goto_opts)).await.map_err(|e| PwError::Navigation {
+			url: url.to_string(),
+			source: anyhow::Error::new(e),
+		})?;
+
+		match self {
+			Self::Navigate(args) => {
+				let status_skipped = match args.expect_status {
+					Some(expected) => check_status(url, expected, response.as_ref())?,
+					None => false,
+				};
+				let data = snapshot_page(page).await?;
+				let extra = args.expect_status.map(|range| serde_json::json!({ "expectStatus": range.to_string() }));
+				Ok(CommandOutcome {
+					inputs: standard_inputs(target, None, None, None, extra),
+					delta: standard_delta_with_url(Some(data.url.clone()), None, None),
+					data: serde_json::to_value(data)?,
+					diagnostics: if status_skipped { vec![status_skipped_diagnostic()] } else { Vec::new() },
+					artifacts: Vec::new(),
+				})
+			}
+			Self::Read(args, path) => {
+				let mut data = read_page(page, Some(url), args.output_format, args.include_metadata).await?;
+				let artifacts = match path {
+					Some(path) => vec![write_content(&mut data, path)?],
+					None => Vec::new(),
+				};
+				let output = args.output.as_deref();
+				Ok(CommandOutcome {
+					inputs: standard_inputs(target, None, None, output, None),
+					delta: standard_delta(target, None, output),
+					data: serde_json::to_value(data)?,
+					diagnostics: Vec::new(),
+					artifacts,
+				})
+			}
+			Self::Screenshot(args, path) => {
+				capture_screenshot(page, path, args.full_page).await?;
+				let data = ScreenshotData {
+					path: path.clone(),
+					full_page: args.full_page,
+					width: None,
+					height: None,
+				};
+				Ok(CommandOutcome {
+					inputs: standard_inputs(target, None, None, Some(&args.output), None),
+					delta: standard_delta(target, None, Some(&args.output)),
+					data: serde_json::to_value(data)?,
+					diagnostics: Vec::new(),
+					artifacts: Vec::new(),
+				})
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn url_lines_skip_blanks_and_comments() {
+		assert_eq!(parse_url_lines("a\n\n  # c\n b \n"), ["a", "b"]);
+	}
+}
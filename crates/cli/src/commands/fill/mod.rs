@@ -9,14 +9,17 @@
 //! pw fill --selector "input[name=email]" --text "user@example.com"
 //! ```
 
+use std::time::Duration;
+
 use clap::Args;
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_match_index, resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
+use crate::commands::flow::retry::{retry_diagnostics, retry_on_selector};
 use crate::error::Result;
 use crate::output::FillData;
 use crate::session_helpers::ArtifactsPolicy;
@@ -38,6 +41,21 @@ pub struct FillRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default)]
 	pub url: Option<String>,
+
+	/// Act on the first match when the selector matches several elements
+	#[arg(long, conflicts_with = "nth")]
+	#[serde(default)]
+	pub first: bool,
+
+	/// Act on the Nth match (0-based) when the selector matches several elements
+	#[arg(long, value_name = "N")]
+	#[serde(default)]
+	pub nth: Option<usize>,
+
+	/// Re-resolve and retry when the element is detached or missing mid-action
+	#[arg(long)]
+	#[serde(default, alias = "retry_on_selector")]
+	pub retry_on_selector: bool,
 }
 
 /// Resolved inputs ready for execution.
@@ -51,6 +69,12 @@ pub struct FillResolved {
 
 	/// Text to fill into the element.
 	pub text: String,
+
+	/// Zero-based match index from `--first`/`--nth`; `None` keeps strict matching.
+	pub nth: Option<usize>,
+
+	/// Retry the fill while the element is detached or missing, within the timeout.
+	pub retry_on_selector: bool,
 }
 
 impl Resolve for FillRaw {
@@ -60,8 +84,15 @@ impl Resolve for FillRaw {
 		let target = resolve_target_from_url_pair(self.url, None, env, TargetPolicy::AllowCurrentPage)?;
 		let selector = env.resolve_selector(self.selector, None)?;
 		let text = self.text.unwrap_or_default();
-
-		Ok(FillResolved { target, selector, text })
+		let nth = resolve_match_index(self.first, self.nth)?;
+
+		Ok(FillResolved {
+			target,
+			selector,
+			text,
+			nth,
+			retry_on_selector: self.retry_on_selector,
+		})
 	}
 }
 
@@ -74,6 +105,10 @@ impl CommandDef for FillCommand {
 	type Resolved = FillResolved;
 	type Data = FillData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -84,8 +119,10 @@ impl CommandDef for FillCommand {
 
 			let selector = args.selector.clone();
 			let text = args.text.clone();
+			let nth = args.nth;
+			let retry_enabled = args.retry_on_selector;
 
-			let data = run_page_flow(
+			let (data, retries) = run_page_flow(
 				&mut exec,
 				&args.target,
 				WaitUntil::Load,
@@ -96,21 +133,40 @@ impl CommandDef for FillCommand {
 					Box::pin(async move {
 						session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-						let locator = session.page().locator(&selector).await;
-						locator.fill(&text, None).await?;
-
-						Ok(FillData { selector, text })
+						let timeout_ms = flow.timeout_ms.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+						let (selector_ref, text_ref) = (&selector, &text);
+						let ((), retries) = retry_on_selector(retry_enabled, Duration::from_millis(timeout_ms), move |budget| async move {
+							let locator = session.page().locator(selector_ref).await;
+							let locator = match nth {
+								Some(index) => locator.nth(index as i32),
+								None => locator,
+							};
+							let fill_opts = pw_rs::FillOptions::builder().timeout(budget.as_millis() as f64).build();
+							locator.fill(text_ref, Some(fill_opts)).await?;
+							Ok(())
+						})
+						.await?;
+
+						Ok((FillData { selector, text }, retries))
 					})
 				},
 			)
 			.await?;
 
-			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, None);
+			let inputs = standard_inputs(
+				&args.target,
+				Some(&args.selector),
+				None,
+				None,
+				args.nth.map(|nth| serde_json::json!({ "nth": nth })),
+			);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, Some(&args.selector), None),
+				diagnostics: retry_diagnostics(Self::NAME, retries),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -127,5 +183,12 @@ mod tests {
 		assert_eq!(raw.url, Some("https://example.com".into()));
 		assert_eq!(raw.selector, Some("input".into()));
 		assert_eq!(raw.text, Some("hello".into()));
+		assert!(!raw.retry_on_selector);
+	}
+
+	#[test]
+	fn fill_raw_accepts_retry_on_selector() {
+		let raw: FillRaw = serde_json::from_str(r#"{"selector": "input", "retryOnSelector": true}"#).unwrap();
+		assert!(raw.retry_on_selector);
 	}
 }
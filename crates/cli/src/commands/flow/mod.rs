@@ -1,3 +1,4 @@
 //! Shared execution-flow helpers for command modules.
 
 pub mod page;
+pub mod retry;
@@ -0,0 +1,169 @@
+//! Element-level retry for action commands.
+//!
+//! Re-runs an action when it failed because its element was detached or briefly
+//! missing while the page re-rendered. Navigation failures are never retried here:
+//! only errors raised while acting on an already-loaded page qualify.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::error::{PwError, Result};
+use crate::output::{Diagnostic, DiagnosticLevel};
+
+/// Pause between attempts.
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// With retries enabled, one attempt may use at most `timeout / ATTEMPT_SHARE`,
+/// so a stalled attempt leaves budget for re-resolved ones.
+const ATTEMPT_SHARE: u32 = 4;
+
+/// Lowercased message fragments Playwright uses for elements lost to DOM churn.
+const TRANSIENT_ELEMENT_ERRORS: &[&str] = &[
+	"element is not attached to the dom",
+	"element is detached",
+	"element was detached",
+	"node is detached",
+	"selector not found for click fallback",
+];
+
+/// Runs `action` until it succeeds, fails with a non-transient error, or `timeout` elapses.
+///
+/// The action is expected to re-resolve its locator on every call and to
+/// finish within the attempt budget it is passed: the time left before the
+/// deadline, capped at a quarter of `timeout` when retrying, so all attempts
+/// together stay within `timeout`. Returns the action's value together with
+/// the number of retries that were needed. With `enabled` false the action
+/// runs exactly once with the whole `timeout`.
+///
+/// A zero `timeout` means no timeout: every attempt gets a zero budget, which
+/// Playwright treats as unbounded, and transient failures are retried until
+/// the action succeeds or fails for another reason.
+pub async fn retry_on_selector<T, F, Fut>(enabled: bool, timeout: Duration, mut action: F) -> Result<(T, u32)>
+where
+	F: FnMut(Duration) -> Fut,
+	Fut: Future<Output = Result<T>>,
+{
+	if !enabled {
+		return Ok((action(timeout).await?, 0));
+	}
+	let deadline = (!timeout.is_zero()).then(|| Instant::now() + timeout);
+	let attempt_cap = timeout / ATTEMPT_SHARE;
+	let mut retries = 0;
+	loop {
+		let budget = match deadline {
+			Some(deadline) => deadline
+				.saturating_duration_since(Instant::now())
+				.min(attempt_cap)
+				.max(Duration::from_millis(1)),
+			None => Duration::ZERO,
+		};
+		match action(budget).await {
+			Ok(value) => return Ok((value, retries)),
+			Err(err) if is_transient_element_error(&err) && deadline.is_none_or(|deadline| Instant::now() + RETRY_INTERVAL < deadline) => {
+				retries += 1;
+				tokio::time::sleep(RETRY_INTERVAL).await;
+			}
+			Err(err) => return Err(err),
+		}
+	}
+}
+
+/// Whether `err` means the target element was detached or not present yet.
+pub fn is_transient_element_error(err: &PwError) -> bool {
+	match err {
+		PwError::ElementNotFound { .. } | PwError::Playwright(pw_rs::Error::ElementNotFound(_)) => true,
+		PwError::Navigation { .. } => false,
+		PwError::Playwright(_) | PwError::Anyhow(_) | PwError::JsEval(_) => {
+			let message = err.to_string().to_lowercase();
+			TRANSIENT_ELEMENT_ERRORS.iter().any(|needle| message.contains(needle))
+		}
+		_ => false,
+	}
+}
+
+/// Info diagnostic reporting how many element retries `command` needed, if any.
+pub fn retry_diagnostics(command: &str, retries: u32) -> Vec<Diagnostic> {
+	if retries == 0 {
+		return Vec::new();
+	}
+	vec![Diagnostic {
+		level: DiagnosticLevel::Info,
+		message: format!("{command} succeeded after {retries} element retr{}", if retries == 1 { "y" } else { "ies" }),
+		source: Some("retry-on-selector".to_string()),
+	}]
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::{AtomicU32, Ordering};
+
+	use super::*;
+
+	#[test]
+	fn classifies_detached_and_missing_elements_as_transient() {
+		assert!(is_transient_element_error(&PwError::ElementNotFound { selector: "#go".into() }));
+		assert!(is_transient_element_error(&PwError::Anyhow(anyhow::anyhow!(
+			"locator.click: Element is not attached to the DOM"
+		))));
+		assert!(!is_transient_element_error(&PwError::Navigation {
+			url: "https://example.com".into(),
+			source: anyhow::anyhow!("element is detached"),
+		}));
+		assert!(!is_transient_element_error(&PwError::Anyhow(anyhow::anyhow!("strict mode violation"))));
+	}
+
+	#[tokio::test]
+	async fn retries_transient_failures_until_success() {
+		let attempts = &AtomicU32::new(0);
+		let (value, retries) = retry_on_selector(true, Duration::from_secs(5), move |_| async move {
+			if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+				Err(PwError::ElementNotFound { selector: "#go".into() })
+			} else {
+				Ok("done")
+			}
+		})
+		.await
+		.unwrap();
+		assert_eq!((value, retries), ("done", 2));
+
+		let attempts = &AtomicU32::new(0);
+		let result = retry_on_selector(false, Duration::from_secs(5), move |budget| async move {
+			assert_eq!(budget, Duration::from_secs(5));
+			attempts.fetch_add(1, Ordering::SeqCst);
+			Err::<(), _>(PwError::ElementNotFound { selector: "#go".into() })
+		})
+		.await;
+		assert!(result.is_err());
+		assert_eq!(attempts.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn zero_timeout_retries_with_unbounded_attempts() {
+		let attempts = &AtomicU32::new(0);
+		let (value, retries) = retry_on_selector(true, Duration::ZERO, move |budget| async move {
+			assert_eq!(budget, Duration::ZERO);
+			if attempts.fetch_add(1, Ordering::SeqCst) < 3 {
+				Err(PwError::ElementNotFound { selector: "#go".into() })
+			} else {
+				Ok("done")
+			}
+		})
+		.await
+		.unwrap();
+		assert_eq!((value, retries), ("done", 3));
+	}
+
+	#[tokio::test]
+	async fn splits_the_timeout_across_attempts() {
+		let timeout = Duration::from_millis(800);
+		let started = Instant::now();
+		let result = retry_on_selector(true, timeout, move |budget| async move {
+			assert!(budget <= timeout / ATTEMPT_SHARE, "{budget:?}");
+			tokio::time::sleep(budget).await;
+			Err::<(), _>(PwError::ElementNotFound { selector: "#go".into() })
+		})
+		.await;
+		assert!(result.is_err());
+		assert!(started.elapsed() < timeout + RETRY_INTERVAL * 2, "{:?}", started.elapsed());
+	}
+}
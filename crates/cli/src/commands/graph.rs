@@ -42,12 +42,42 @@ command_graph! {
 		PageSnapshot => crate::commands::page::snapshot::SnapshotCommand {
 			names: ["page.snapshot"],
 		},
+		PageStreamElements => crate::commands::page::stream_elements::StreamElementsCommand {
+			names: ["page.stream_elements"],
+		},
 		PageCoords => crate::commands::page::coords::CoordsCommand {
 			names: ["page.coords"],
 		},
 		PageCoordsAll => crate::commands::page::coords::CoordsAllCommand {
 			names: ["page.coords-all"],
 		},
+		PageAccessibility => crate::commands::page::accessibility::AccessibilityCommand {
+			names: ["page.accessibility", "page.ax"],
+		},
+		PageExists => crate::commands::page::exists::ExistsCommand {
+			names: ["page.exists"],
+		},
+		PageFrames => crate::commands::page::frames::FramesCommand {
+			names: ["page.frames"],
+		},
+		PagePause => crate::commands::page::pause::PauseCommand {
+			names: ["page.pause"],
+		},
+		PagePdf => crate::commands::page::pdf::PdfCommand {
+			names: ["page.pdf"],
+		},
+		PageUpload => crate::commands::page::upload::UploadCommand {
+			names: ["page.upload"],
+		},
+		PageHighlight => crate::commands::page::highlight::HighlightCommand {
+			names: ["page.highlight"],
+		},
+		PageAddStyle => crate::commands::page::inject::AddStyleCommand {
+			names: ["page.add-style"],
+		},
+		PageAddScript => crate::commands::page::inject::AddScriptCommand {
+			names: ["page.add-script"],
+		},
 		AuthLogin => crate::commands::auth::LoginCommand {
 			names: ["auth.login"],
 		},
@@ -72,6 +102,9 @@ command_graph! {
 		SessionStop => crate::commands::session::SessionStopCommand {
 			names: ["session.stop"],
 		},
+		SessionBaseUrl => crate::commands::session::SessionBaseUrlCommand {
+			names: ["session.base-url"],
+		},
 		DaemonStart => crate::commands::daemon::DaemonStartCommand {
 			names: ["daemon.start"],
 		},
@@ -93,6 +126,12 @@ command_graph! {
 		ProfileDelete => crate::commands::profile::ProfileDeleteCommand {
 			names: ["profile.delete"],
 		},
+		ContextList => crate::commands::context::ContextListCommand {
+			names: ["context.list"],
+		},
+		ContextUse => crate::commands::context::ContextUseCommand {
+			names: ["context.use"],
+		},
 		Connect => crate::commands::connect::ConnectCommand {
 			names: ["connect"],
 		},
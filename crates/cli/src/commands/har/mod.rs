@@ -80,6 +80,8 @@ impl CommandDef for HarSetCommand {
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -124,6 +126,8 @@ impl CommandDef for HarShowCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -168,6 +172,8 @@ impl CommandDef for HarClearCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
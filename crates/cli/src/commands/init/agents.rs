@@ -0,0 +1,102 @@
+//! `AGENTS.md` generation for `init --agent`.
+//!
+//! The command list comes from the command graph and each command's clap
+//! definition, so the document always matches the binary that wrote it.
+
+use std::fmt::Write as _;
+
+use crate::commands::registry::{all_commands, command_args};
+use crate::protocol::SCHEMA_VERSION;
+
+/// File written into the project root by `init --agent`.
+pub const AGENTS_FILE: &str = "AGENTS.md";
+
+/// Renders the agent guide listing every batch-enabled command and its input fields.
+pub fn render_agents_md() -> String {
+	let mut out = String::new();
+	let _ = writeln!(out, "# pw commands");
+	let _ = writeln!(out);
+	let _ = writeln!(
+		out,
+		"Generated by `pw init --agent`. Drive the browser by writing one JSON request per line to `pw batch` \
+		 (or a `pw run --script` file) and reading one JSON response per line:"
+	);
+	let _ = writeln!(out);
+	let _ = writeln!(out, "```json");
+	let _ = writeln!(
+		out,
+		r#"{{"schemaVersion":{SCHEMA_VERSION},"requestId":"1","op":"navigate","input":{{"url":"https://example.com"}}}}"#
+	);
+	let _ = writeln!(out, "```");
+	let _ = writeln!(out);
+	let _ = writeln!(
+		out,
+		"Check `ok` on every response; failures carry `error.code` and `error.message`. \
+		 Input fields are camelCase. Single commands also run as `pw exec <op> --input '<json>'`."
+	);
+	let _ = writeln!(out);
+	let _ = writeln!(out, "## Commands");
+
+	for meta in all_commands().iter().filter(|meta| meta.batch_enabled && !meta.interactive_only) {
+		let _ = writeln!(out);
+		let _ = writeln!(out, "### `{}`", meta.canonical);
+		if !meta.aliases.is_empty() {
+			let aliases: Vec<String> = meta.aliases.iter().map(|alias| format!("`{alias}`")).collect();
+			let _ = writeln!(out, "Aliases: {}", aliases.join(", "));
+		}
+
+		let command = command_args(meta.id);
+		let mut fields = command.get_arguments().filter(|arg| !arg.is_hide_set()).peekable();
+		if fields.peek().is_none() {
+			let _ = writeln!(out);
+			let _ = writeln!(out, "No input fields.");
+			continue;
+		}
+		let _ = writeln!(out);
+		for arg in fields {
+			let name = camel_case(arg.get_id().as_str());
+			match arg.get_help() {
+				Some(help) => {
+					let _ = writeln!(out, "* `{name}`: {help}");
+				}
+				None => {
+					let _ = writeln!(out, "* `{name}`");
+				}
+			}
+		}
+	}
+
+	out
+}
+
+/// Converts a clap argument id (the Rust field name) to its JSON input key.
+fn camel_case(id: &str) -> String {
+	let mut out = String::with_capacity(id.len());
+	let mut upper = false;
+	for ch in id.chars() {
+		if ch == '_' {
+			upper = true;
+		} else if upper {
+			out.extend(ch.to_uppercase());
+			upper = false;
+		} else {
+			out.push(ch);
+		}
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn agents_md_lists_batch_commands_with_camel_case_fields() {
+		let doc = render_agents_md();
+		assert!(doc.contains("### `navigate`"));
+		assert!(doc.contains("### `page.text`"));
+		assert!(doc.contains("* `urlFlag`"));
+		assert!(!doc.contains("url_flag"));
+		assert_eq!(camel_case("retry_on_selector"), "retryOnSelector");
+	}
+}
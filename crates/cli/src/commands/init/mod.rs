@@ -5,6 +5,8 @@
 //! ```text
 //! project-root/
 //! ├── playwright.config.js    # Centralized config
+//! ├── pw.toml                 # pw CLI defaults
+//! ├── AGENTS.md               # Batch command reference (--agent only)
 //! └── playwright/
 //!     ├── tests/              # Test specifications
 //!     ├── scripts/            # Automation utilities (standard template)
@@ -15,6 +17,7 @@
 //!     └── .gitignore
 //! ```
 
+mod agents;
 mod templates;
 
 use std::fs;
@@ -28,6 +31,7 @@ use crate::cli::InitTemplate;
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
 use crate::error::{PwError, Result};
 use crate::output::CommandInputs;
+use crate::project_defaults::{PROJECT_DEFAULTS_FILE, PW_TOML_TEMPLATE};
 use crate::target::ResolveEnv;
 
 fn default_init_path() -> PathBuf {
@@ -58,6 +62,10 @@ pub struct InitRaw {
 	#[arg(long)]
 	#[serde(default)]
 	pub nix: bool,
+	/// Write an AGENTS.md listing batch commands and their input fields
+	#[arg(long)]
+	#[serde(default)]
+	pub agent: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -78,6 +86,7 @@ impl Resolve for InitRaw {
 				typescript: self.typescript,
 				force: self.force,
 				nix: self.nix,
+				agent: self.agent,
 			},
 		})
 	}
@@ -115,11 +124,14 @@ impl CommandDef for InitCommand {
 						"typescript": args.options.typescript,
 						"force": args.options.force,
 						"nix": args.options.nix,
+						"agent": args.options.agent,
 					})),
 					..Default::default()
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -135,6 +147,7 @@ pub struct InitOptions {
 	pub typescript: bool,
 	pub force: bool,
 	pub nix: bool,
+	pub agent: bool,
 }
 
 /// Result of initialization
@@ -304,6 +317,14 @@ fn scaffold_project(options: InitOptions) -> Result<InitResult> {
 		};
 		let config_file = project_root.join(config_filename);
 		write_file_if_missing(&config_file, config_content, options.force, &mut files_created)?;
+
+		let defaults_file = project_root.join(PROJECT_DEFAULTS_FILE);
+		write_file_if_missing(&defaults_file, PW_TOML_TEMPLATE, options.force, &mut files_created)?;
+	}
+
+	if options.agent {
+		let agents_file = project_root.join(agents::AGENTS_FILE);
+		write_file_if_missing(&agents_file, &agents::render_agents_md(), options.force, &mut files_created)?;
 	}
 
 	Ok(InitResult {
@@ -364,6 +385,7 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
@@ -374,6 +396,7 @@ mod tests {
 		assert!(pw_dir.join(dirs::TESTS).join("example.spec.js").exists());
 		assert!(pw_dir.join(".gitignore").exists());
 		assert!(result.project_root.join(dirs::CONFIG_JS).exists());
+		assert!(result.project_root.join(PROJECT_DEFAULTS_FILE).exists());
 
 		// Minimal should NOT create scripts, results, reports, screenshots
 		assert!(!pw_dir.join(dirs::SCRIPTS).exists());
@@ -391,6 +414,7 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
@@ -420,6 +444,7 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
@@ -443,6 +468,7 @@ mod tests {
 			typescript: true,
 			force: false,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
@@ -462,6 +488,7 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
@@ -482,12 +509,14 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
 
 		assert!(!result.project_root.join(dirs::CONFIG_JS).exists());
 		assert!(!result.project_root.join(dirs::CONFIG_TS).exists());
+		assert!(!result.project_root.join(PROJECT_DEFAULTS_FILE).exists());
 	}
 
 	#[test]
@@ -505,6 +534,7 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options);
@@ -526,6 +556,7 @@ mod tests {
 			typescript: false,
 			force: true,
 			nix: false,
+			agent: false,
 		};
 
 		let result = scaffold_project(options);
@@ -543,6 +574,7 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: true,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
@@ -572,6 +604,7 @@ mod tests {
 			typescript: false,
 			force: false,
 			nix: true,
+			agent: false,
 		};
 
 		let result = scaffold_project(options).unwrap();
@@ -581,4 +614,27 @@ mod tests {
 		assert!(scripts_dir.join("common.sh").exists());
 		assert!(scripts_dir.join("setup-browsers.sh").exists());
 	}
+
+	#[test]
+	fn test_scaffold_agent_writes_command_reference() {
+		let temp = TempDir::new().unwrap();
+		let options = InitOptions {
+			path: temp.path().to_path_buf(),
+			template: InitTemplate::Minimal,
+			no_config: true,
+			no_example: true,
+			typescript: false,
+			force: false,
+			nix: false,
+			agent: true,
+		};
+
+		let result = scaffold_project(options).unwrap();
+
+		let agents_md = result.project_root.join(agents::AGENTS_FILE);
+		assert!(result.files_created.contains(&agents_md));
+		let content = fs::read_to_string(agents_md).unwrap();
+		assert!(content.contains("### `navigate`"));
+		assert!(content.contains("\"op\":\"navigate\""));
+	}
 }
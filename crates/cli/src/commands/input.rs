@@ -0,0 +1,119 @@
+//! Parsing of JSON command input into `*Raw` argument types.
+//!
+//! `serde_json::from_value` errors name neither the offending field nor the
+//! accepted ones, which leaves agents guessing. [`parse_raw`] turns a failure
+//! into [`PwError::InvalidInput`] carrying both, so the caller can correct the
+//! request in one step.
+
+use serde::de::{self, DeserializeOwned, Visitor};
+use serde_json::Value;
+
+use crate::error::{PwError, Result};
+
+/// Deserializes `args` into `T`, reporting failures with field context.
+pub(crate) fn parse_raw<T: DeserializeOwned>(op: &str, args: Value) -> Result<T> {
+	match serde_json::from_value::<T>(args.clone()) {
+		Ok(raw) => Ok(raw),
+		Err(err) => Err(invalid_input::<T>(op, &args, &err)),
+	}
+}
+
+fn invalid_input<T: DeserializeOwned>(op: &str, args: &Value, err: &serde_json::Error) -> PwError {
+	let expected = struct_fields::<T>();
+	let field = offending_field::<T>(args, err);
+
+	let mut message = match &field {
+		Some(field) => format!("invalid input for '{op}' at field '{field}': {err}"),
+		None => format!("invalid input for '{op}': {err}"),
+	};
+	if !expected.is_empty() {
+		message.push_str(&format!(" (expected fields: {})", expected.join(", ")));
+	}
+
+	PwError::InvalidInput { message, field, expected }
+}
+
+/// Locates the field responsible for `err`.
+///
+/// Missing and unknown fields are named in serde's message. Type errors are
+/// not, so each top-level key is re-parsed on its own: a key whose value is
+/// fine only trips the "missing field" check, while the bad one fails first.
+fn offending_field<T: DeserializeOwned>(args: &Value, err: &serde_json::Error) -> Option<String> {
+	let msg = err.to_string();
+	for prefix in ["missing field `", "unknown field `", "duplicate field `"] {
+		if let Some(rest) = msg.strip_prefix(prefix) {
+			return rest.split('`').next().map(str::to_string);
+		}
+	}
+
+	let obj = args.as_object()?;
+	obj.iter()
+		.find(|(key, value)| {
+			let single = Value::Object([((*key).clone(), (*value).clone())].into_iter().collect());
+			match serde_json::from_value::<T>(single) {
+				Ok(_) => false,
+				Err(err) => !err.to_string().starts_with("missing field `"),
+			}
+		})
+		.map(|(key, _)| key.clone())
+}
+
+/// Returns the serialized field names `T` accepts, or empty for non-struct types.
+fn struct_fields<T: DeserializeOwned>() -> Vec<&'static str> {
+	let mut fields = None;
+	let _ = T::deserialize(FieldNames(&mut fields));
+	fields.map(<[_]>::to_vec).unwrap_or_default()
+}
+
+/// Deserializer that records the field list passed to `deserialize_struct` and bails out.
+struct FieldNames<'a>(&'a mut Option<&'static [&'static str]>);
+
+impl<'de> de::Deserializer<'de> for FieldNames<'_> {
+	type Error = de::value::Error;
+
+	fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+		Err(de::Error::custom("not a struct"))
+	}
+
+	fn deserialize_struct<V: Visitor<'de>>(
+		self,
+		_name: &'static str,
+		fields: &'static [&'static str],
+		_visitor: V,
+	) -> std::result::Result<V::Value, Self::Error> {
+		*self.0 = Some(fields);
+		Err(de::Error::custom("field names captured"))
+	}
+
+	serde::forward_to_deserialize_any! {
+		bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+		bytes byte_buf option unit unit_struct newtype_struct seq tuple
+		tuple_struct map enum identifier ignored_any
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+	use crate::commands::click::ClickRaw;
+
+	#[test]
+	fn wrong_type_names_the_field_and_lists_expected_fields() {
+		let err = parse_raw::<ClickRaw>("click", json!({ "selector": "button", "wait_ms": "soon" })).unwrap_err();
+		let (message, field, expected) = match err {
+			PwError::InvalidInput { message, field, expected } => (message, field, expected),
+			other => panic!("expected InvalidInput, got {other:?}"),
+		};
+		assert_eq!(field.as_deref(), Some("wait_ms"));
+		assert!(message.contains("wait_ms"), "{message}");
+		assert!(expected.contains(&"selector"), "{expected:?}");
+	}
+
+	#[test]
+	fn valid_input_parses() {
+		let raw = parse_raw::<ClickRaw>("click", json!({ "selector": "button", "waitMs": 10 })).unwrap();
+		assert_eq!(raw.wait_ms, Some(10));
+	}
+}
@@ -1,37 +1,84 @@
 mod auth;
+mod batch_log;
 pub(crate) mod click;
 mod connect;
+mod context;
 pub(crate) mod contract;
 mod daemon;
 pub(crate) mod def;
 mod engine;
 pub(crate) mod exec_flow;
+mod fanout;
 pub(crate) mod fill;
 pub(crate) mod flow;
 pub(crate) mod graph;
 mod har;
 pub mod init;
+mod input;
 pub(crate) mod navigate;
 pub(crate) mod page;
 mod profile;
 mod protect;
 pub(crate) mod registry;
+mod repl;
 pub(crate) mod screenshot;
 mod session;
 mod tabs;
 pub mod test;
+mod trace;
 pub(crate) mod wait;
 
 use crate::cli::{Cli, Commands};
 use crate::error::Result;
+use crate::output::OutputFormat;
+use crate::project_defaults::ProjectDefaults;
+use crate::protocol::{CommandResponse, print_response};
+use crate::workspace::WorkspaceScope;
 
-pub async fn dispatch(cli: Cli) -> Result<()> {
-	match cli.command {
-		Commands::Exec(args) => engine::run_exec(args, cli.format).await?,
-		Commands::Batch(args) => engine::run_batch(args, cli.format).await?,
-		Commands::Profile(args) => engine::run_profile(args.action, cli.format).await?,
-		Commands::Daemon(args) => engine::run_daemon(args.action, cli.format).await?,
+/// Runs the parsed CLI command. Returns `false` when any emitted response had `ok: false`.
+pub async fn dispatch(cli: Cli) -> Result<bool> {
+	crate::protocol::set_json_errors(cli.json_errors);
+	let scope = WorkspaceScope::resolve(None, None, false)?;
+	let defaults = match ProjectDefaults::from_env().and_then(|env| Ok(env.or(ProjectDefaults::load(scope.root())?))) {
+		Ok(defaults) => defaults,
+		Err(err) => {
+			let op = match &cli.command {
+				Commands::Exec(args) => args.op.clone(),
+				_ => None,
+			};
+			let response = CommandResponse::error(None, op.unwrap_or_else(|| "pw".to_string()), err.to_command_error(), None);
+			print_response(&response, cli.format.unwrap_or_default());
+			return Ok(false);
+		}
+	};
+	let format = cli.format.or(defaults.format).unwrap_or_default();
+	if let Some(path) = &cli.dump_protocol {
+		pw_rs::pw_runtime::install_protocol_dump(path)?;
 	}
 
-	Ok(())
+	let ok = match cli.command {
+		Commands::Exec(mut args) => {
+			args.artifacts_dir = args.artifacts_dir.or(defaults.artifacts_dir);
+			engine::run_exec(args, format, cli.fail_on_console_error).await?
+		}
+		Commands::Batch(args) => engine::run_batch(args, format, cli.fail_on_console_error).await?,
+		Commands::Run(args) => engine::run_script(args, format, cli.fail_on_console_error).await?,
+		Commands::Repl(args) => {
+			let format = cli.format.or(defaults.format).unwrap_or(OutputFormat::Text);
+			repl::run_repl(args, format, cli.fail_on_console_error).await?
+		}
+		Commands::Profile(args) => engine::run_profile(args.action, format).await?,
+		Commands::Context(args) => engine::run_context(args.action, format).await?,
+		Commands::Daemon(args) => engine::run_daemon(args.action, format).await?,
+		Commands::Trace(args) => {
+			trace::run(args.action)?;
+			true
+		}
+		Commands::Relay(args) => {
+			crate::relay::run_relay_server(&args.host, args.port, args.token).await?;
+			true
+		}
+	};
+
+	Ok(ok)
 }
@@ -1,16 +1,18 @@
 //! Navigation command.
 
+use std::fmt;
+
 use clap::Args;
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::commands::page::snapshot::{EXTRACT_ELEMENTS_JS, EXTRACT_META_JS, EXTRACT_TEXT_JS, PageMeta, RawElement};
-use crate::error::Result;
-use crate::output::{InteractiveElement, SnapshotData};
+use crate::error::{PwError, Result};
+use crate::output::{Diagnostic, DiagnosticLevel, InteractiveElement, SnapshotData};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, Target, TargetPolicy};
 
@@ -28,12 +30,58 @@ pub struct NavigateRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
+
+	/// Fail unless the main response status is in RANGE, e.g. `200` or `200-299`
+	#[arg(long, value_name = "RANGE")]
+	#[serde(default, alias = "expect_status")]
+	pub expect_status: Option<String>,
+}
+
+/// Inclusive HTTP status range accepted by `--expect-status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusRange {
+	pub min: u16,
+	pub max: u16,
+}
+
+impl StatusRange {
+	/// Parses `200` or `200-299`; both bounds must be valid HTTP statuses.
+	pub fn parse(spec: &str) -> Result<Self> {
+		let invalid = || PwError::Context(format!("invalid expectStatus {spec:?}: expected a status like 200 or a range like 200-299"));
+		let parse_status = |part: &str| part.trim().parse::<u16>().ok().filter(|status| (100..=599).contains(status));
+		let (min, max) = match spec.split_once('-') {
+			Some((min, max)) => (parse_status(min).ok_or_else(invalid)?, parse_status(max).ok_or_else(invalid)?),
+			None => {
+				let status = parse_status(spec).ok_or_else(invalid)?;
+				(status, status)
+			}
+		};
+		if min > max {
+			return Err(invalid());
+		}
+		Ok(Self { min, max })
+	}
+
+	pub fn contains(self, status: u16) -> bool {
+		(self.min..=self.max).contains(&status)
+	}
+}
+
+impl fmt::Display for StatusRange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.min == self.max {
+			write!(f, "{}", self.min)
+		} else {
+			write!(f, "{}-{}", self.min, self.max)
+		}
+	}
 }
 
 /// Resolved inputs ready for execution.
 #[derive(Debug, Clone)]
 pub struct NavigateResolved {
 	pub target: ResolvedTarget,
+	pub expect_status: Option<StatusRange>,
 }
 
 impl Resolve for NavigateRaw {
@@ -41,7 +89,8 @@ impl Resolve for NavigateRaw {
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
-		Ok(NavigateResolved { target })
+		let expect_status = self.expect_status.as_deref().map(StatusRange::parse).transpose()?;
+		Ok(NavigateResolved { target, expect_status })
 	}
 }
 
@@ -54,6 +103,10 @@ impl CommandDef for NavigateCommand {
 	type Resolved = NavigateResolved;
 	type Data = SnapshotData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -62,55 +115,94 @@ impl CommandDef for NavigateCommand {
 			let url_display = args.target.url_str().unwrap_or("<current page>");
 			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "navigate");
 
-			let (final_url, data) = run_page_flow(&mut exec, &args.target, WaitUntil::Load, ArtifactsPolicy::Never, move |session, flow| {
+			let expect_status = args.expect_status;
+			let (final_url, data, status_skipped) = run_page_flow(&mut exec, &args.target, WaitUntil::Load, ArtifactsPolicy::Never, move |session, flow| {
 				Box::pin(async move {
-					match &flow.target {
-						Target::Navigate(url) => {
+					let mut status_skipped = false;
+					match (&flow.target, expect_status) {
+						// A reused page has no fresh response, so a status check always navigates.
+						(Target::Navigate(url), Some(expected)) => {
+							let response = session.goto_response(url.as_str(), flow.timeout_ms).await?;
+							status_skipped = check_status(url.as_str(), expected, response.as_ref())?;
+						}
+						(Target::Navigate(url), None) => {
 							session.goto_if_needed(url.as_str(), flow.timeout_ms).await?;
 						}
-						Target::CurrentPage => {}
+						(Target::CurrentPage, expected) => status_skipped = expected.is_some(),
 					}
 
 					session.page().bring_to_front().await?;
 
-					let meta_js = format!("JSON.stringify({})", EXTRACT_META_JS);
-					let meta: PageMeta = serde_json::from_str(&session.page().evaluate_value(&meta_js).await?)?;
-
-					let text_js = format!("JSON.stringify({}({}, {}))", EXTRACT_TEXT_JS, DEFAULT_MAX_TEXT_LENGTH, false);
-					let text: String = serde_json::from_str(&session.page().evaluate_value(&text_js).await?)?;
-
-					let elements_js = format!("JSON.stringify({})", EXTRACT_ELEMENTS_JS);
-					let raw_elements: Vec<RawElement> = serde_json::from_str(&session.page().evaluate_value(&elements_js).await?)?;
-
-					let elements: Vec<InteractiveElement> = raw_elements.into_iter().map(Into::into).collect();
-					let element_count = elements.len();
-
-					let data = SnapshotData {
-						url: meta.url.clone(),
-						title: meta.title,
-						viewport_width: meta.viewport_width,
-						viewport_height: meta.viewport_height,
-						text,
-						elements,
-						element_count,
-					};
-
-					Ok((meta.url, data))
+					let data = snapshot_page(session.page()).await?;
+					Ok((data.url.clone(), data, status_skipped))
 				})
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let extra = args.expect_status.map(|range| serde_json::json!({ "expectStatus": range.to_string() }));
+			let inputs = standard_inputs(&args.target, None, None, None, extra);
+			let diagnostics = if status_skipped { vec![status_skipped_diagnostic()] } else { Vec::new() };
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
 				delta: standard_delta_with_url(Some(final_url), None, None),
+				diagnostics,
+				artifacts: Vec::new(),
 			})
 		})
 	}
 }
 
+/// Fails when `response` is outside `expected`; returns `true` when there was no response to check.
+pub(crate) fn check_status(url: &str, expected: StatusRange, response: Option<&pw_rs::Response>) -> Result<bool> {
+	match response {
+		Some(response) if !expected.contains(response.status()) => Err(PwError::UnexpectedStatus {
+			url: url.to_string(),
+			status: response.status(),
+			expected: expected.to_string(),
+		}),
+		Some(_) => Ok(false),
+		None => Ok(true),
+	}
+}
+
+/// Warning for an `expectStatus` that could not be checked.
+pub(crate) fn status_skipped_diagnostic() -> Diagnostic {
+	Diagnostic {
+		level: DiagnosticLevel::Warning,
+		message: "expectStatus not checked: the page has no HTTP response".to_string(),
+		source: Some("navigate".to_string()),
+	}
+}
+
+/// Captures the navigate payload (metadata, visible text, interactive elements) of `page`.
+pub(crate) async fn snapshot_page(page: &pw_rs::Page) -> Result<SnapshotData> {
+	let meta_js = format!("JSON.stringify({})", EXTRACT_META_JS);
+	let meta: PageMeta = serde_json::from_str(&page.evaluate_value(&meta_js).await?)?;
+
+	let text_js = format!("JSON.stringify({}({}, {}))", EXTRACT_TEXT_JS, DEFAULT_MAX_TEXT_LENGTH, false);
+	let text: String = serde_json::from_str(&page.evaluate_value(&text_js).await?)?;
+
+	let elements_js = format!("JSON.stringify({}({}))", EXTRACT_ELEMENTS_JS, false);
+	let raw_elements: Vec<RawElement> = serde_json::from_str(&page.evaluate_value(&elements_js).await?)?;
+
+	let elements: Vec<InteractiveElement> = raw_elements.into_iter().map(Into::into).collect();
+	let element_count = elements.len();
+
+	Ok(SnapshotData {
+		url: meta.url,
+		title: meta.title,
+		viewport_width: meta.viewport_width,
+		viewport_height: meta.viewport_height,
+		meta: meta.meta,
+		text,
+		elements,
+		element_count,
+		diff: None,
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -121,4 +213,16 @@ mod tests {
 		let raw: NavigateRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.url, Some("https://example.com".into()));
 	}
+
+	#[test]
+	fn status_range_parses_single_status_and_range() {
+		assert_eq!(StatusRange::parse("200").unwrap(), StatusRange { min: 200, max: 200 });
+		let success = StatusRange::parse("200-299").unwrap();
+		assert!(success.contains(204) && !success.contains(404));
+		assert_eq!(success.to_string(), "200-299");
+
+		for invalid in ["", "ok", "299-200", "200-", "42", "200-700"] {
+			assert!(StatusRange::parse(invalid).is_err(), "{invalid:?} should be rejected");
+		}
+	}
 }
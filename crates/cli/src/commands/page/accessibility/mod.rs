@@ -0,0 +1,161 @@
+//! Accessibility tree snapshot command.
+
+use clap::Args;
+use pw_rs::{AccessibilityNode, AccessibilitySnapshotOptions, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// CSS selector for the subtree root (whole page when omitted)
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Include every node instead of only semantically interesting ones
+	#[arg(long = "all")]
+	#[serde(default)]
+	pub all: bool,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct AccessibilityResolved {
+	pub target: ResolvedTarget,
+	pub selector: Option<String>,
+	pub interesting_only: bool,
+}
+
+impl Resolve for AccessibilityRaw {
+	type Output = AccessibilityResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		Ok(AccessibilityResolved {
+			target,
+			selector: self.selector,
+			interesting_only: !self.all,
+		})
+	}
+}
+
+/// Output data for the accessibility command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityData {
+	/// Root of the captured tree, `None` when the page exposes no accessibility tree.
+	pub snapshot: Option<AccessibilityNode>,
+	pub node_count: usize,
+	pub interesting_only: bool,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub selector: Option<String>,
+}
+
+pub struct AccessibilityCommand;
+
+impl CommandDef for AccessibilityCommand {
+	const NAME: &'static str = "page.accessibility";
+
+	type Raw = AccessibilityRaw;
+	type Resolved = AccessibilityResolved;
+	type Data = AccessibilityData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, resolved.selector.as_deref())
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "accessibility snapshot");
+
+			let selector = args.selector.clone();
+			let interesting_only = args.interesting_only;
+
+			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+				let selector = selector.clone();
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let mut options = AccessibilitySnapshotOptions::builder().interesting_only(interesting_only);
+					if let Some(selector) = selector.as_deref() {
+						let root = session.page().query_selector(selector).await?.ok_or_else(|| PwError::ElementNotFound {
+							selector: selector.to_string(),
+						})?;
+						options = options.root(root);
+					}
+
+					let snapshot = session.page().accessibility().snapshot(Some(options.build())).await?;
+
+					Ok(AccessibilityData {
+						node_count: snapshot.as_ref().map(count_nodes).unwrap_or(0),
+						snapshot,
+						interesting_only,
+						selector,
+					})
+				})
+			})
+			.await?;
+
+			let inputs = standard_inputs(&args.target, args.selector.as_deref(), None, None, None);
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta(&args.target, None, None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+fn count_nodes(node: &AccessibilityNode) -> usize {
+	1 + node.children.iter().flatten().map(count_nodes).sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn accessibility_raw_defaults_to_interesting_only() {
+		let raw: AccessibilityRaw = serde_json::from_str(r#"{"url": "https://example.com"}"#).unwrap();
+		assert!(!raw.all);
+		assert!(raw.selector.is_none());
+	}
+
+	#[test]
+	fn count_nodes_walks_children() {
+		let node: AccessibilityNode = serde_json::from_value(serde_json::json!({
+			"role": "WebArea",
+			"children": [
+				{ "role": "button", "name": "Save" },
+				{ "role": "group", "children": [{ "role": "link", "name": "Docs" }] }
+			]
+		}))
+		.unwrap();
+		assert_eq!(count_nodes(&node), 4);
+	}
+}
@@ -1,8 +1,11 @@
 //! Console message capture command.
 //!
 //! Captures JavaScript console output (log, warn, error, etc.) from a page.
-//! Injects a capture script before navigation, then collects messages after
-//! a configurable timeout.
+//! Console messages come from the session's ring buffer, which records from
+//! the moment the session page is acquired, so messages logged while the
+//! target loads are included. Uncaught page errors are captured by a script
+//! injected after navigation. Messages are collected after a configurable
+//! timeout.
 //!
 //! # Examples
 //!
@@ -18,7 +21,7 @@ use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
 use crate::browser::js::console_capture_injection_js;
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::Result;
@@ -89,6 +92,10 @@ impl CommandDef for ConsoleCommand {
 	type Resolved = ConsoleResolved;
 	type Data = ConsoleData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -101,21 +108,23 @@ impl CommandDef for ConsoleCommand {
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
 					if let Err(err) = session.page().evaluate(console_capture_injection_js()).await {
-						warn!(target = "pw.browser.console", error = %err, "failed to inject console capture");
+						warn!(target = "pw.browser.console", error = %err, "failed to inject page error capture");
 					}
 
-					session.goto_target(&flow.target, flow.timeout_ms).await?;
-
 					tokio::time::sleep(Duration::from_millis(capture_timeout_ms)).await;
 
-					let messages_json = session
+					let injected_json = session
 						.page()
 						.evaluate_value("JSON.stringify(window.__consoleMessages || [])")
 						.await
 						.unwrap_or_else(|_| "[]".to_string());
+					let injected: Vec<ConsoleMessage> = serde_json::from_str(&injected_json).unwrap_or_default();
 
-					let messages: Vec<ConsoleMessage> = serde_json::from_str(&messages_json).unwrap_or_default();
+					let mut messages = session.take_console_messages();
+					messages.extend(injected.into_iter().filter(|m| m.msg_type == "pageerror"));
 
 					for msg in &messages {
 						info!(
@@ -147,6 +156,8 @@ impl CommandDef for ConsoleCommand {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, None, None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
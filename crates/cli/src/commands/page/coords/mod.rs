@@ -4,6 +4,15 @@
 //! of elements matching a CSS selector. Useful for visual automation and
 //! click coordinate calculation.
 //!
+//! `point` switches `x`/`y` between the box center (default) and its top-left
+//! corner. `devicePixels` multiplies every value by `window.devicePixelRatio`
+//! for OS-level input tools that work in physical pixels.
+//!
+//! `coords-all` reports matches in DOM order unless `sort` asks for a spatial
+//! order. `reading_order` groups boxes into rows (a box joins the current row
+//! when its vertical center falls within the row's first box) and reads each
+//! row left to right. Each entry keeps its DOM `index` either way.
+//!
 //! # Commands
 //!
 //! * `coords`: Get coordinates of the first matching element
@@ -22,13 +31,13 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::browser::js;
-use crate::commands::contract::{resolve_target_and_explicit_selector, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_and_explicit_selector, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::{PwError, Result};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget};
-use crate::types::{ElementCoords, IndexedElementCoords};
+use crate::types::{CoordsOrder, CoordsPoint, ElementBox, ElementCoords, IndexedElementCoords};
 
 /// Raw inputs from CLI or batch JSON before resolution.
 #[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
@@ -51,6 +60,16 @@ pub struct CoordsRaw {
 	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
 	#[serde(default, alias = "selector_flag")]
 	pub selector_flag: Option<String>,
+
+	/// Which point of the bounding box `x`/`y` report
+	#[arg(long = "point", value_enum, default_value_t = CoordsPoint::Center)]
+	#[serde(default)]
+	pub point: CoordsPoint,
+
+	/// Scale all values by `window.devicePixelRatio`
+	#[arg(long = "device-pixels")]
+	#[serde(default, alias = "device_pixels")]
+	pub device_pixels: bool,
 }
 
 /// Resolved inputs ready for execution.
@@ -63,6 +82,12 @@ pub struct CoordsResolved {
 
 	/// CSS selector for the target element(s).
 	pub selector: String,
+
+	/// Reported point of each bounding box.
+	pub point: CoordsPoint,
+
+	/// Whether values are scaled to device pixels.
+	pub device_pixels: bool,
 }
 
 impl Resolve for CoordsRaw {
@@ -70,15 +95,67 @@ impl Resolve for CoordsRaw {
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let (target, selector) = resolve_target_and_explicit_selector(self.url, self.url_flag, self.selector, self.selector_flag, env, None)?;
-		Ok(CoordsResolved { target, selector })
+		Ok(CoordsResolved {
+			target,
+			selector,
+			point: self.point,
+			device_pixels: self.device_pixels,
+		})
 	}
 }
 
-/// Alias for [`CoordsRaw`] used by the `coords-all` command.
-pub type CoordsAllRaw = CoordsRaw;
+/// Raw inputs for the `coords-all` command: [`CoordsRaw`] plus an ordering.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoordsAllRaw {
+	#[command(flatten)]
+	#[serde(flatten)]
+	pub coords: CoordsRaw,
+
+	/// Order of the reported matches
+	#[arg(long = "sort", value_enum, default_value_t = CoordsOrder::Dom)]
+	#[serde(default)]
+	pub sort: CoordsOrder,
+}
+
+/// Resolved inputs for the `coords-all` command.
+#[derive(Debug, Clone)]
+pub struct CoordsAllResolved {
+	pub coords: CoordsResolved,
+
+	/// Order of the reported matches.
+	pub sort: CoordsOrder,
+}
 
-/// Alias for [`CoordsResolved`] used by the `coords-all` command.
-pub type CoordsAllResolved = CoordsResolved;
+impl Resolve for CoordsAllRaw {
+	type Output = CoordsAllResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(CoordsAllResolved {
+			coords: self.coords.resolve(env)?,
+			sort: self.sort,
+		})
+	}
+}
+
+/// Reorders `boxes` in place; [`CoordsOrder::Dom`] leaves them untouched.
+fn sort_boxes(boxes: &mut [ElementBox], order: CoordsOrder) {
+	match order {
+		CoordsOrder::Dom => {}
+		CoordsOrder::TopToBottom => boxes.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))),
+		CoordsOrder::LeftToRight => boxes.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))),
+		CoordsOrder::ReadingOrder => {
+			boxes.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+			let mut start = 0;
+			while start < boxes.len() {
+				let row_bottom = boxes[start].y + boxes[start].height;
+				let end = start + boxes[start..].iter().take_while(|b| b.y + b.height / 2.0 <= row_bottom).count();
+				boxes[start..end].sort_by(|a, b| a.x.total_cmp(&b.x));
+				start = end;
+			}
+		}
+	}
+}
 
 /// Output for single element coordinates.
 #[derive(Debug, Serialize)]
@@ -86,6 +163,9 @@ pub type CoordsAllResolved = CoordsResolved;
 pub struct CoordsData {
 	pub coords: ElementCoords,
 	pub selector: String,
+	/// Ratio applied to the coordinates when `devicePixels` was requested.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub device_pixel_ratio: Option<f64>,
 }
 
 /// Output for multiple element coordinates.
@@ -95,6 +175,22 @@ pub struct CoordsAllData {
 	pub coords: Vec<IndexedElementCoords>,
 	pub selector: String,
 	pub count: usize,
+	/// Ratio applied to the coordinates when `devicePixels` was requested.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub device_pixel_ratio: Option<f64>,
+}
+
+/// Reads `window.devicePixelRatio` when `device_pixels` is set.
+async fn device_pixel_ratio(page: &pw_rs::Page, device_pixels: bool) -> Result<Option<f64>> {
+	if !device_pixels {
+		return Ok(None);
+	}
+	let raw = page.evaluate_value("window.devicePixelRatio").await?;
+	let ratio = raw
+		.trim()
+		.parse::<f64>()
+		.map_err(|_| PwError::JsEval(format!("unexpected devicePixelRatio: {raw}")))?;
+	Ok(Some(ratio))
 }
 
 pub struct CoordsCommand;
@@ -106,6 +202,10 @@ impl CommandDef for CoordsCommand {
 	type Resolved = CoordsResolved;
 	type Data = CoordsData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -115,6 +215,7 @@ impl CommandDef for CoordsCommand {
 			info!(target = "pw", url = %url_display, selector = %args.selector, browser = %exec.ctx.browser, "coords single");
 
 			let selector = args.selector.clone();
+			let (point, device_pixels) = (args.point, args.device_pixels);
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let selector = selector.clone();
@@ -127,9 +228,15 @@ impl CommandDef for CoordsCommand {
 						return Err(PwError::ElementNotFound { selector: selector.clone() });
 					}
 
-					let coords: ElementCoords = serde_json::from_str(&result_json)?;
+					let bbox: ElementBox = serde_json::from_str(&result_json)?;
+					let ratio = device_pixel_ratio(session.page(), device_pixels).await?;
+					let coords = bbox.project(point, ratio.unwrap_or(1.0));
 
-					Ok(CoordsData { coords, selector })
+					Ok(CoordsData {
+						coords,
+						selector,
+						device_pixel_ratio: ratio,
+					})
 				})
 			})
 			.await?;
@@ -140,6 +247,8 @@ impl CommandDef for CoordsCommand {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, Some(&args.selector), None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -154,37 +263,55 @@ impl CommandDef for CoordsAllCommand {
 	type Resolved = CoordsAllResolved;
 	type Data = CoordsAllData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.coords.target, Some(&resolved.coords.selector))
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
 	{
 		Box::pin(async move {
-			let url_display = args.target.url_str().unwrap_or("<current page>");
-			info!(target = "pw", url = %url_display, selector = %args.selector, browser = %exec.ctx.browser, "coords all");
+			let (base, sort) = (&args.coords, args.sort);
+			let url_display = base.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %base.selector, browser = %exec.ctx.browser, "coords all");
 
-			let selector = args.selector.clone();
+			let selector = base.selector.clone();
+			let (point, device_pixels) = (base.point, base.device_pixels);
 
-			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+			let data = run_page_flow(&mut exec, &base.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let selector = selector.clone();
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
 					let results_json = session.page().evaluate_value(&js::get_all_element_coords_js(&selector)).await?;
 
-					let coords: Vec<IndexedElementCoords> = serde_json::from_str(&results_json)?;
+					let mut boxes: Vec<ElementBox> = serde_json::from_str(&results_json)?;
+					sort_boxes(&mut boxes, sort);
+					let ratio = device_pixel_ratio(session.page(), device_pixels).await?;
+					let scale = ratio.unwrap_or(1.0);
+					let coords: Vec<IndexedElementCoords> = boxes.into_iter().map(|bbox| bbox.project_indexed(point, scale)).collect();
 					let count = coords.len();
 
-					Ok(CoordsAllData { coords, selector, count })
+					Ok(CoordsAllData {
+						coords,
+						selector,
+						count,
+						device_pixel_ratio: ratio,
+					})
 				})
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, None);
+			let extra = (sort != CoordsOrder::Dom).then(|| serde_json::json!({ "sort": sort }));
+			let inputs = standard_inputs(&base.target, Some(&base.selector), None, None, extra);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
-				delta: standard_delta(&args.target, Some(&args.selector), None),
+				delta: standard_delta(&base.target, Some(&base.selector), None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -200,5 +327,53 @@ mod tests {
 		let raw: CoordsRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.url, Some("https://example.com".into()));
 		assert_eq!(raw.selector, Some("button".into()));
+		assert_eq!(raw.point, CoordsPoint::Center);
+		assert!(!raw.device_pixels);
+	}
+
+	fn bbox(index: usize, x: f64, y: f64) -> ElementBox {
+		ElementBox {
+			index,
+			x,
+			y,
+			width: 40.0,
+			height: 20.0,
+			..Default::default()
+		}
+	}
+
+	fn order(boxes: &[ElementBox], sort: CoordsOrder) -> Vec<usize> {
+		let mut boxes = boxes.to_vec();
+		sort_boxes(&mut boxes, sort);
+		boxes.iter().map(|b| b.index).collect()
+	}
+
+	#[test]
+	fn sort_boxes_orders_spatially() {
+		// DOM order 0..4; rows at y=0 (x 100, 0) and y=50 (x 50, 0), with #2 nudged 3px down.
+		let boxes = [bbox(0, 100.0, 0.0), bbox(1, 0.0, 50.0), bbox(2, 0.0, 3.0), bbox(3, 50.0, 50.0)];
+		assert_eq!(order(&boxes, CoordsOrder::Dom), [0, 1, 2, 3]);
+		assert_eq!(order(&boxes, CoordsOrder::TopToBottom), [0, 2, 1, 3]);
+		assert_eq!(order(&boxes, CoordsOrder::LeftToRight), [2, 1, 3, 0]);
+		assert_eq!(order(&boxes, CoordsOrder::ReadingOrder), [2, 0, 1, 3]);
+	}
+
+	#[test]
+	fn coords_all_raw_accepts_sort() {
+		let raw: CoordsAllRaw = serde_json::from_str(r#"{"selector": "li", "sort": "top_to_bottom", "point": "topleft"}"#).unwrap();
+		assert_eq!(raw.sort, CoordsOrder::TopToBottom);
+		assert_eq!(raw.coords.selector.as_deref(), Some("li"));
+		assert_eq!(raw.coords.point, CoordsPoint::TopLeft);
+
+		let raw: CoordsAllRaw = serde_json::from_str(r#"{"selector": "li"}"#).unwrap();
+		assert_eq!(raw.sort, CoordsOrder::Dom);
+	}
+
+	#[test]
+	fn coords_raw_accepts_point_and_device_pixels() {
+		let json = r#"{"selector": "button", "point": "topleft", "devicePixels": true}"#;
+		let raw: CoordsRaw = serde_json::from_str(json).unwrap();
+		assert_eq!(raw.point, CoordsPoint::TopLeft);
+		assert!(raw.device_pixels);
 	}
 }
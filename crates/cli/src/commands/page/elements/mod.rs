@@ -22,7 +22,7 @@ use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::Result;
@@ -93,6 +93,10 @@ impl CommandDef for ElementsCommand {
 	type Resolved = ElementsResolved;
 	type Data = ElementsData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -122,25 +126,7 @@ impl CommandDef for ElementsCommand {
 							serde_json::from_str(&raw_result)?
 						};
 
-						let elements: Vec<InteractiveElement> = raw_elements
-							.into_iter()
-							.map(|e| InteractiveElement {
-								tag: e.kind,
-								selector: e.selector,
-								text: if e.label.is_empty() || e.label == "(unlabeled)" {
-									None
-								} else {
-									Some(e.label)
-								},
-								href: None,
-								name: e.extra,
-								id: None,
-								x: e.x,
-								y: e.y,
-								width: e.width,
-								height: e.height,
-							})
-							.collect();
+						let elements: Vec<InteractiveElement> = raw_elements.into_iter().map(InteractiveElement::from).collect();
 
 						let count = elements.len();
 
@@ -156,6 +142,8 @@ impl CommandDef for ElementsCommand {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, None, None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -163,26 +151,62 @@ impl CommandDef for ElementsCommand {
 
 /// Element data as returned by the extraction JavaScript.
 #[derive(Debug, Deserialize)]
-struct RawElement {
+pub(crate) struct RawElement {
 	kind: String,
 	label: String,
 	selector: String,
 	extra: Option<String>,
 	#[serde(default)]
+	value: Option<String>,
+	#[serde(default)]
+	checked: Option<bool>,
+	#[serde(default)]
 	x: i32,
 	#[serde(default)]
 	y: i32,
 	#[serde(default)]
+	page_x: i32,
+	#[serde(default)]
+	page_y: i32,
+	#[serde(default)]
 	width: i32,
 	#[serde(default)]
 	height: i32,
 }
 
+impl From<RawElement> for InteractiveElement {
+	fn from(e: RawElement) -> Self {
+		InteractiveElement {
+			tag: e.kind,
+			selector: e.selector,
+			text: if e.label.is_empty() || e.label == "(unlabeled)" {
+				None
+			} else {
+				Some(e.label)
+			},
+			href: None,
+			name: e.extra,
+			id: None,
+			value: e.value,
+			checked: e.checked,
+			x: e.x,
+			y: e.y,
+			page_x: e.page_x,
+			page_y: e.page_y,
+			width: e.width,
+			height: e.height,
+			visible: None,
+			aria_hidden: None,
+			count: None,
+		}
+	}
+}
+
 /// JavaScript that extracts interactive elements from the page.
 ///
 /// Generates stable selectors preferring: ID > name attribute > text content >
 /// aria-label > class combination > nth-of-type fallback.
-const EXTRACT_ELEMENTS_JS: &str = r#"
+pub(crate) const EXTRACT_ELEMENTS_JS: &str = r#"
 (() => {
     const elements = [];
     const seen = new Set();
@@ -262,6 +286,19 @@ const EXTRACT_ELEMENTS_JS: &str = r#"
         return true;
     }
     
+    function formState(el) {
+        if (el.tagName === 'INPUT' && (el.type === 'checkbox' || el.type === 'radio')) {
+            return { value: null, checked: el.checked };
+        }
+        if (el.tagName === 'INPUT' || el.tagName === 'SELECT' || el.tagName === 'TEXTAREA') {
+            if (el.type === 'submit' || el.type === 'button' || el.type === 'password') {
+                return { value: null, checked: null };
+            }
+            return { value: el.value, checked: null };
+        }
+        return { value: null, checked: null };
+    }
+    
     function addElement(el, kind, extra) {
         if (!isVisible(el)) return;
         const selector = getStableSelector(el);
@@ -271,14 +308,19 @@ const EXTRACT_ELEMENTS_JS: &str = r#"
         
         const label = getLabel(el) || '(unlabeled)';
         const rect = el.getBoundingClientRect();
+        const state = formState(el);
         
         elements.push({
             kind: kind,
             label: label.substring(0, 60),
             selector: selector,
             extra: extra || null,
+            value: state.value,
+            checked: state.checked,
             x: Math.round(rect.x),
             y: Math.round(rect.y),
+            page_x: Math.round(rect.x + window.scrollX),
+            page_y: Math.round(rect.y + window.scrollY),
             width: Math.round(rect.width),
             height: Math.round(rect.height)
         });
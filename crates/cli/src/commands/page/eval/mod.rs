@@ -1,17 +1,18 @@
 //! JavaScript evaluation command.
 
 use std::path::PathBuf;
+use std::time::Duration;
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::{PwError, Result};
-use crate::output::EvalData;
+use crate::output::{Diagnostic, DiagnosticLevel, EvalData, EvalResultType};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
 
@@ -41,13 +42,76 @@ pub struct EvalRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
+
+	/// Maximum time to wait for the expression to settle (defaults to the context timeout)
+	#[arg(long = "timeout-ms", value_name = "MS")]
+	#[serde(default, alias = "timeout_ms")]
+	pub timeout_ms: Option<u64>,
+
+	/// Maximum serialized result size in bytes before truncation
+	#[arg(long = "max-result-bytes", value_name = "BYTES")]
+	#[serde(default, alias = "max_result_bytes")]
+	pub max_result_bytes: Option<usize>,
+
+	/// Where `JSON`, `Array` and other built-ins come from; `fresh` briefly appends a hidden iframe to the page
+	#[arg(long = "builtins", value_enum, default_value_t = EvalBuiltins::Page)]
+	#[serde(default)]
+	pub builtins: EvalBuiltins,
+
+	/// Pipeline stages: the first is an expression, each later one a function called with the previous result
+	#[arg(long = "pipe", value_name = "EXPRESSION")]
+	#[serde(default)]
+	pub expressions: Vec<String>,
+
+	/// Also return every pipeline stage's result
+	#[arg(long)]
+	#[serde(default, alias = "keep_intermediates")]
+	pub keep_intermediates: bool,
+}
+
+/// Which built-ins an evaluated expression sees.
+///
+/// Both run in the page's main world; neither is an isolated world.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvalBuiltins {
+	/// The page's own globals, including anything page scripts replaced
+	#[default]
+	Page,
+	/// Unmodified built-ins (`JSON`, `Object`, `Array`, ...) from a temporary hidden iframe
+	Fresh,
 }
 
+/// Built-ins rebound from the temporary iframe for [`EvalBuiltins::Fresh`].
+const FRESH_BUILTINS: &str =
+	"JSON, Object, Array, String, Number, Boolean, Symbol, Math, Date, RegExp, Promise, Map, Set, WeakMap, WeakSet, Reflect, Proxy, Error, TypeError";
+
+/// Default cap on the serialized eval result size (1 MiB).
+pub const DEFAULT_MAX_RESULT_BYTES: usize = 1024 * 1024;
+
 /// Resolved inputs ready for execution.
 #[derive(Debug, Clone)]
 pub struct EvalResolved {
 	pub target: ResolvedTarget,
+	/// Display form of the expression, or of the pipeline stages joined with ` |> `.
 	pub expression: String,
+	/// Pipeline stages; empty for a single expression.
+	pub stages: Vec<String>,
+	pub keep_intermediates: bool,
+	pub timeout_ms: Option<u64>,
+	pub max_result_bytes: usize,
+	pub builtins: EvalBuiltins,
+}
+
+impl EvalResolved {
+	/// Expression the page evaluates; pipelines return a [`PipelineOutput`] object.
+	fn page_expression(&self) -> String {
+		if self.stages.is_empty() {
+			self.expression.clone()
+		} else {
+			pipeline_expression(&self.stages)
+		}
+	}
 }
 
 impl Resolve for EvalRaw {
@@ -56,15 +120,35 @@ impl Resolve for EvalRaw {
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
 
-		let expression = self
+		let single = self
 			.file
 			.as_ref()
 			.and_then(|p| std::fs::read_to_string(p).ok())
 			.or(self.expression_flag)
-			.or(self.expression)
-			.ok_or_else(|| PwError::Context("expression is required (provide positionally, via --expr, or via --file)".into()))?;
+			.or(self.expression);
 
-		Ok(EvalResolved { target, expression })
+		let (expression, stages) = match (single, self.expressions.is_empty()) {
+			(Some(_), false) => {
+				return Err(PwError::Context("use either expression or expressions, not both".into()));
+			}
+			(Some(expression), true) => (expression, Vec::new()),
+			(None, false) => (self.expressions.join(" |> "), self.expressions),
+			(None, true) => {
+				return Err(PwError::Context(
+					"expression is required (provide positionally, via --expr, via --file, or as expressions)".into(),
+				));
+			}
+		};
+
+		Ok(EvalResolved {
+			target,
+			expression,
+			stages,
+			keep_intermediates: self.keep_intermediates,
+			timeout_ms: self.timeout_ms,
+			max_result_bytes: self.max_result_bytes.unwrap_or(DEFAULT_MAX_RESULT_BYTES),
+			builtins: self.builtins,
+		})
 	}
 }
 
@@ -77,6 +161,10 @@ impl CommandDef for EvalCommand {
 	type Resolved = EvalResolved;
 	type Data = EvalData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -87,35 +175,176 @@ impl CommandDef for EvalCommand {
 			debug!(target = "pw", expression = %args.expression, "expression");
 
 			let expression = args.expression.clone();
+			let page_expression = args.page_expression();
+			let pipeline = !args.stages.is_empty();
+			let keep_intermediates = args.keep_intermediates;
 			let expression_for_inputs = truncate_expression(&expression);
+			let timeout_override = args.timeout_ms;
+			let max_result_bytes = args.max_result_bytes;
+			let builtins = args.builtins;
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let expression = expression.clone();
+				let page_expression = page_expression.clone();
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
-					let wrapped_expr = format!("JSON.stringify({})", expression);
-					let raw_result = session.page().evaluate_value(&wrapped_expr).await;
+					let timeout_ms = timeout_override.or(flow.timeout_ms).unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS as u64);
+					let wrapped_expr = wrap_expression(&page_expression, builtins);
+					let evaluation = session.page().evaluate_value(&wrapped_expr);
+					// A zero timeout disables the deadline, matching Playwright's convention.
+					let raw_result = if timeout_ms == 0 {
+						evaluation.await
+					} else {
+						tokio::time::timeout(Duration::from_millis(timeout_ms), evaluation)
+							.await
+							.map_err(|_| PwError::Timeout {
+								ms: timeout_ms,
+								condition: "page.eval expression to return".into(),
+							})?
+					};
 
-					let json_str = raw_result.map_err(|e| PwError::JsEval(e.to_string()))?;
-					let value: serde_json::Value = serde_json::from_str(&json_str).unwrap_or(serde_json::Value::Null);
+					let mut json_str = raw_result.map_err(|e| PwError::JsEval(e.to_string()))?;
+					let mut intermediates = None;
+					if pipeline {
+						let output: PipelineOutput =
+							serde_json::from_str(&json_str).map_err(|e| PwError::JsEval(format!("unreadable pipeline result: {e}")))?;
+						json_str = output.value.unwrap_or_else(|| "undefined".to_string());
+						if keep_intermediates {
+							let values = output
+								.intermediates
+								.iter()
+								.map(|stage| stage.as_deref().and_then(|s| serde_json::from_str(s).ok()).unwrap_or(serde_json::Value::Null))
+								.collect();
+							intermediates = Some(values);
+						}
+					}
+					let (result, truncated) = cap_result(&json_str, max_result_bytes);
 
-					Ok(EvalData { result: value, expression })
+					Ok(EvalData {
+						result,
+						result_type: result_type(&json_str),
+						expression,
+						truncated,
+						intermediates,
+					})
 				})
 			})
 			.await?;
 
+			let mut diagnostics = Vec::new();
+			if data.truncated {
+				diagnostics.push(Diagnostic {
+					level: DiagnosticLevel::Warning,
+					message: format!("eval result exceeded {max_result_bytes} bytes and was truncated"),
+					source: Some(Self::NAME.to_string()),
+				});
+			}
+
 			let inputs = standard_inputs(&args.target, None, Some(expression_for_inputs), None, None);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, None, None),
+				diagnostics,
+				artifacts: Vec::new(),
 			})
 		})
 	}
 }
 
+/// Wraps `expression` so the page returns its result as a JSON string.
+///
+/// [`EvalBuiltins::Fresh`] shadows the common built-ins with those of a hidden
+/// `about:blank` iframe, so page scripts that replace `JSON.stringify` or
+/// patch `Array.prototype` do not leak into the expression or its
+/// serialization. This mutates the page: the iframe is appended to
+/// `document.documentElement` for the duration of the evaluation, which
+/// mutation observers and the expression itself can see. DOM access still goes
+/// through the page's `document`, and `[]`/`{}` literals keep the page's
+/// prototypes; use `Array.from` and friends.
+fn wrap_expression(expression: &str, builtins: EvalBuiltins) -> String {
+	match builtins {
+		EvalBuiltins::Page => format!("JSON.stringify({expression})"),
+		EvalBuiltins::Fresh => format!(
+			"(() => {{ \
+			const __pwRealm = document.createElement('iframe'); \
+			__pwRealm.style.display = 'none'; \
+			document.documentElement.appendChild(__pwRealm); \
+			try {{ const {{ {FRESH_BUILTINS} }} = __pwRealm.contentWindow; return JSON.stringify({expression}); }} \
+			finally {{ __pwRealm.remove(); }} \
+			}})()"
+		),
+	}
+}
+
+/// Serialized stage results returned by a [`pipeline_expression`].
+///
+/// Each entry is the stage's own `JSON.stringify` output; `None` stands for `undefined`.
+#[derive(Debug, Deserialize)]
+struct PipelineOutput {
+	value: Option<String>,
+	intermediates: Vec<Option<String>>,
+}
+
+/// Builds one expression that runs `stages` in order in a single evaluation.
+///
+/// The first stage is evaluated as is; each later stage must evaluate to a
+/// function, which is called with the previous stage's result. The first
+/// throwing stage aborts the pipeline with an error naming its 1-based index;
+/// later stages do not run. Stages are synchronous like a single expression.
+fn pipeline_expression(stages: &[String]) -> String {
+	let mut body = String::from("(() => { let __pwValue; const __pwSteps = []; ");
+	for (index, stage) in stages.iter().enumerate() {
+		let step = if index == 0 { format!("({stage})") } else { format!("({stage})(__pwValue)") };
+		body.push_str(&format!(
+			"try {{ __pwValue = {step}; }} catch (e) {{ throw new Error('pipeline stage {n} failed: ' + (e && e.message ? e.message : e)); }} \
+			 __pwSteps.push(JSON.stringify(__pwValue)); ",
+			n = index + 1
+		));
+	}
+	body.push_str("return { value: __pwSteps[__pwSteps.length - 1], intermediates: __pwSteps }; })()");
+	body
+}
+
+/// Parse a serialized eval result, truncating it to `max_bytes` when oversized.
+///
+/// Oversized results are returned as the truncated JSON text (a string value),
+/// since a cut-off document cannot be parsed back into structured JSON.
+fn cap_result(json_str: &str, max_bytes: usize) -> (serde_json::Value, bool) {
+	if json_str.len() <= max_bytes {
+		let value = serde_json::from_str(json_str).unwrap_or(serde_json::Value::Null);
+		return (value, false);
+	}
+
+	let mut cut = max_bytes;
+	while !json_str.is_char_boundary(cut) {
+		cut -= 1;
+	}
+	(serde_json::Value::String(json_str[..cut].to_string()), true)
+}
+
+/// Classifies the page's `JSON.stringify` output by its leading token.
+///
+/// `JSON.stringify(undefined)` returns `undefined` rather than a string, which
+/// comes back as the bare text `undefined`; no JSON document looks like that.
+/// Works on the full text, so truncated results keep their original type.
+fn result_type(json_str: &str) -> EvalResultType {
+	let trimmed = json_str.trim_start();
+	if trimmed == "undefined" {
+		return EvalResultType::Undefined;
+	}
+	match trimmed.chars().next() {
+		Some('"') => EvalResultType::String,
+		Some('[') => EvalResultType::Array,
+		Some('{') => EvalResultType::Object,
+		Some('t' | 'f') => EvalResultType::Boolean,
+		Some('-' | '0'..='9') => EvalResultType::Number,
+		_ => EvalResultType::Null,
+	}
+}
+
 /// Truncate expression for output (avoid huge expressions in output)
 fn truncate_expression(expr: &str) -> String {
 	const MAX_LEN: usize = 500;
@@ -150,6 +379,84 @@ mod tests {
 		assert_eq!(truncate_expression(s), "short");
 	}
 
+	#[test]
+	fn cap_result_passes_small_results_through() {
+		let (value, truncated) = cap_result("[1,2,3]", 64);
+		assert_eq!(value, serde_json::json!([1, 2, 3]));
+		assert!(!truncated);
+	}
+
+	#[test]
+	fn cap_result_truncates_huge_array() {
+		let huge = serde_json::to_string(&vec![0u32; 100_000]).unwrap();
+		let (value, truncated) = cap_result(&huge, 1024);
+		assert!(truncated);
+		assert_eq!(value.as_str().unwrap().len(), 1024);
+	}
+
+	#[test]
+	fn cap_result_respects_utf8_boundaries() {
+		let (value, truncated) = cap_result("\"─────\"", 4);
+		assert!(truncated);
+		assert_eq!(value, serde_json::json!("\"─"));
+	}
+
+	#[test]
+	fn result_type_covers_every_js_type() {
+		assert_eq!(result_type("\"hi\""), EvalResultType::String);
+		assert_eq!(result_type("-1.5"), EvalResultType::Number);
+		assert_eq!(result_type("true"), EvalResultType::Boolean);
+		assert_eq!(result_type("false"), EvalResultType::Boolean);
+		assert_eq!(result_type("{\"a\":1}"), EvalResultType::Object);
+		assert_eq!(result_type("[1]"), EvalResultType::Array);
+		assert_eq!(result_type("null"), EvalResultType::Null);
+		assert_eq!(result_type("undefined"), EvalResultType::Undefined);
+		assert_eq!(serde_json::to_value(EvalResultType::Undefined).unwrap(), "undefined");
+
+		let (value, _) = cap_result("undefined", 64);
+		assert_eq!(value, serde_json::Value::Null);
+	}
+
+	#[test]
+	fn eval_raw_deserialize_limits() {
+		let json = r#"{"expression": "1", "timeoutMs": 250, "maxResultBytes": 16}"#;
+		let raw: EvalRaw = serde_json::from_str(json).unwrap();
+		assert_eq!(raw.timeout_ms, Some(250));
+		assert_eq!(raw.max_result_bytes, Some(16));
+	}
+
+	#[test]
+	fn eval_builtins_default_to_page() {
+		let raw: EvalRaw = serde_json::from_str(r#"{"expression": "1"}"#).unwrap();
+		assert_eq!(raw.builtins, EvalBuiltins::Page);
+		assert_eq!(wrap_expression("1 + 1", EvalBuiltins::Page), "JSON.stringify(1 + 1)");
+
+		let raw: EvalRaw = serde_json::from_str(r#"{"expression": "1", "builtins": "fresh"}"#).unwrap();
+		assert_eq!(raw.builtins, EvalBuiltins::Fresh);
+		let wrapped = wrap_expression("[1, 2]", EvalBuiltins::Fresh);
+		assert!(wrapped.contains("__pwRealm.contentWindow; return JSON.stringify([1, 2]);"), "{wrapped}");
+	}
+
+	#[test]
+	fn resolve_builds_pipeline_from_expressions() {
+		let state = crate::context_store::test_state();
+		let ctx_state = crate::context_store::ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+		let env = ResolveEnv::new(&ctx_state, false, EvalCommand::NAME);
+
+		let raw: EvalRaw =
+			serde_json::from_str(r#"{"url": "https://example.com", "expressions": ["[1, 2, 3]", "xs => xs.length"], "keepIntermediates": true}"#).unwrap();
+		let resolved = raw.resolve(&env).unwrap();
+		assert_eq!(resolved.expression, "[1, 2, 3] |> xs => xs.length");
+		assert!(resolved.keep_intermediates);
+		let script = resolved.page_expression();
+		assert!(script.contains("__pwValue = ([1, 2, 3]);"), "{script}");
+		assert!(script.contains("__pwValue = (xs => xs.length)(__pwValue);"), "{script}");
+		assert!(script.contains("pipeline stage 2 failed"), "{script}");
+
+		let both: EvalRaw = serde_json::from_str(r#"{"url": "https://example.com", "expression": "1", "expressions": ["2"]}"#).unwrap();
+		assert!(both.resolve(&env).is_err());
+	}
+
 	#[test]
 	fn eval_raw_deserialize() {
 		let json = r#"{"url": "https://example.com", "expression": "document.title"}"#;
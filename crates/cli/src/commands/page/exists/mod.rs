@@ -0,0 +1,119 @@
+//! Selector existence probe.
+//!
+//! Reports whether a selector matches and how many elements it matches.
+//! Zero matches is a successful result, not a `SELECTOR_NOT_FOUND` failure.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw exec page.exists --input '{"selector":".cookie-banner"}'
+//! ```
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::Result;
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON before resolution.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExistsRaw {
+	/// CSS selector to probe (positional)
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default)]
+	pub url: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct ExistsResolved {
+	/// Navigation target (URL or current page).
+	pub target: ResolvedTarget,
+
+	/// CSS selector to probe.
+	pub selector: String,
+}
+
+impl Resolve for ExistsRaw {
+	type Output = ExistsResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, None, env, TargetPolicy::AllowCurrentPage)?;
+		let selector = env.resolve_selector(self.selector, None)?;
+
+		Ok(ExistsResolved { target, selector })
+	}
+}
+
+/// Output data for the exists command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExistsData {
+	pub selector: String,
+	pub exists: bool,
+	pub count: usize,
+}
+
+pub struct ExistsCommand;
+
+impl CommandDef for ExistsCommand {
+	const NAME: &'static str = "page.exists";
+
+	type Raw = ExistsRaw;
+	type Resolved = ExistsResolved;
+	type Data = ExistsData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %args.selector, "exists");
+
+			let selector = args.selector.clone();
+			let count = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::NetworkIdle,
+				ArtifactsPolicy::OnError { command: "page.exists" },
+				move |session, flow| {
+					let selector = selector.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+						let count = session.page().locator(&selector).await.count().await?;
+						Ok(count)
+					})
+				},
+			)
+			.await?;
+
+			Ok(CommandOutcome {
+				inputs: standard_inputs(&args.target, Some(&args.selector), None, None, None),
+				data: ExistsData {
+					selector: args.selector.clone(),
+					exists: count > 0,
+					count,
+				},
+				delta: standard_delta(&args.target, Some(&args.selector), None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
@@ -0,0 +1,175 @@
+//! Frame listing command.
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use pw_rs::pw_runtime::channel_owner::ChannelOwner;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::Result;
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FramesRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct FramesResolved {
+	pub target: ResolvedTarget,
+}
+
+impl Resolve for FramesRaw {
+	type Output = FramesResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		Ok(FramesResolved { target })
+	}
+}
+
+/// One frame of the page, in attach order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameEntry {
+	/// Position in `frames`; the main frame is `0`.
+	pub index: usize,
+	/// The iframe's `name` or `id` attribute; empty when unset.
+	pub name: String,
+	pub url: String,
+	/// Index of the parent frame, `None` for the main frame.
+	pub parent: Option<usize>,
+	/// Nesting depth; the main frame is `0`.
+	pub depth: usize,
+}
+
+/// Output data for the frames command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FramesData {
+	pub frames: Vec<FrameEntry>,
+	pub count: usize,
+}
+
+pub struct FramesCommand;
+
+impl CommandDef for FramesCommand {
+	const NAME: &'static str = "page.frames";
+
+	type Raw = FramesRaw;
+	type Resolved = FramesResolved;
+	type Data = FramesData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "list frames");
+
+			let data = run_page_flow(&mut exec, &args.target, WaitUntil::Load, ArtifactsPolicy::Never, move |session, flow| {
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let mut rows = Vec::new();
+					for frame in session.page().frames().await? {
+						let parent = frame.parent_frame().await?.map(|parent| parent.guid().to_string());
+						rows.push(FrameRow {
+							guid: frame.guid().to_string(),
+							parent,
+							name: frame.name(),
+							url: frame.url(),
+						});
+					}
+
+					let frames = index_frames(rows);
+					Ok(FramesData { count: frames.len(), frames })
+				})
+			})
+			.await?;
+
+			Ok(CommandOutcome {
+				inputs: standard_inputs(&args.target, None, None, None, None),
+				data,
+				delta: standard_delta(&args.target, None, None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+/// Protocol view of a frame before parent guids are mapped to indices.
+struct FrameRow {
+	guid: String,
+	parent: Option<String>,
+	name: String,
+	url: String,
+}
+
+/// Numbers frames in list order and resolves each parent guid to its index.
+///
+/// Parents always attach before their children, so a single pass suffices.
+fn index_frames(rows: Vec<FrameRow>) -> Vec<FrameEntry> {
+	let mut guids: Vec<String> = Vec::with_capacity(rows.len());
+	let mut entries: Vec<FrameEntry> = Vec::with_capacity(rows.len());
+	for (index, row) in rows.into_iter().enumerate() {
+		let parent = row.parent.and_then(|parent| guids.iter().position(|guid| *guid == parent));
+		let depth = parent.map_or(0, |parent| entries[parent].depth + 1);
+		entries.push(FrameEntry {
+			index,
+			name: row.name,
+			url: row.url,
+			parent,
+			depth,
+		});
+		guids.push(row.guid);
+	}
+	entries
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn row(guid: &str, parent: Option<&str>, name: &str) -> FrameRow {
+		FrameRow {
+			guid: guid.to_string(),
+			parent: parent.map(str::to_string),
+			name: name.to_string(),
+			url: "about:srcdoc".to_string(),
+		}
+	}
+
+	#[test]
+	fn index_frames_maps_parents_to_indices() {
+		let frames = index_frames(vec![
+			row("frame@main", None, ""),
+			row("frame@outer", Some("frame@main"), "outer"),
+			row("frame@inner", Some("frame@outer"), "inner"),
+			row("frame@side", Some("frame@main"), "side"),
+		]);
+		let tree: Vec<(usize, Option<usize>, usize)> = frames.iter().map(|f| (f.index, f.parent, f.depth)).collect();
+		assert_eq!(tree, [(0, None, 0), (1, Some(0), 1), (2, Some(1), 2), (3, Some(0), 1)]);
+		assert_eq!(frames[2].name, "inner");
+	}
+}
@@ -0,0 +1,166 @@
+//! Selector highlight command, mirroring Playwright's `locator.highlight()`.
+//!
+//! Draws Playwright's inspector overlay over every element the selector
+//! matches so selectors can be checked by eye. The overlay is only visible in
+//! a headful browser (profile `defaults.headless = false`, `--devtools`, or a
+//! CDP-attached window); headless runs skip the browser entirely and report a
+//! warning instead.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw exec page.highlight --devtools --input '{"selector":"nav a.active"}'
+//! ```
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::Result;
+use crate::output::{Diagnostic, DiagnosticLevel};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON before resolution.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HighlightRaw {
+	/// CSS selector to highlight (positional)
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default)]
+	pub url: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct HighlightResolved {
+	/// Navigation target (URL or current page).
+	pub target: ResolvedTarget,
+
+	/// CSS selector to highlight.
+	pub selector: String,
+}
+
+impl Resolve for HighlightRaw {
+	type Output = HighlightResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, None, env, TargetPolicy::AllowCurrentPage)?;
+		let selector = env.resolve_selector(self.selector, None)?;
+
+		Ok(HighlightResolved { target, selector })
+	}
+}
+
+/// Output data for the highlight command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HighlightData {
+	pub selector: String,
+	/// Elements matched when the overlay was drawn; `None` when skipped.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub count: Option<usize>,
+	/// Whether the overlay was drawn.
+	pub highlighted: bool,
+}
+
+pub struct HighlightCommand;
+
+impl CommandDef for HighlightCommand {
+	const NAME: &'static str = "page.highlight";
+
+	type Raw = HighlightRaw;
+	type Resolved = HighlightResolved;
+	type Data = HighlightData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, None);
+
+			if !exec.ctx.is_headful() {
+				return Ok(CommandOutcome {
+					inputs,
+					data: HighlightData {
+						selector: args.selector.clone(),
+						count: None,
+						highlighted: false,
+					},
+					delta: ContextDelta::default(),
+					diagnostics: vec![Diagnostic {
+						level: DiagnosticLevel::Warning,
+						message: "page.highlight has no visible effect in a headless browser; set defaults.headless = false, use --devtools, or attach with a CDP endpoint".to_string(),
+						source: Some(Self::NAME.to_string()),
+					}],
+					artifacts: Vec::new(),
+				});
+			}
+
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %args.selector, "highlight");
+
+			let selector = args.selector.clone();
+
+			let data = run_page_flow(&mut exec, &args.target, WaitUntil::Load, ArtifactsPolicy::Never, move |session, flow| {
+				let selector = selector.clone();
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let locator = session.page().locator(&selector).await;
+					let count = locator.count().await?;
+					locator.highlight().await?;
+
+					Ok(HighlightData {
+						selector,
+						count: Some(count),
+						highlighted: true,
+					})
+				})
+			})
+			.await?;
+
+			let mut diagnostics = Vec::new();
+			if data.count == Some(0) {
+				diagnostics.push(Diagnostic {
+					level: DiagnosticLevel::Warning,
+					message: format!("selector '{}' matched no elements", args.selector),
+					source: Some(Self::NAME.to_string()),
+				});
+			}
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta(&args.target, Some(&args.selector), None),
+				diagnostics,
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn highlight_raw_deserialize_from_json() {
+		let raw: HighlightRaw = serde_json::from_str(r#"{"selector": "nav a", "url": "https://example.com"}"#).unwrap();
+		assert_eq!(raw.selector.as_deref(), Some("nav a"));
+		assert_eq!(raw.url.as_deref(), Some("https://example.com"));
+	}
+}
@@ -1,14 +1,19 @@
 //! HTML content extraction command.
+//!
+//! Returns the element's `outerHTML` by default (`which: "inner"` for
+//! `innerHTML`). `pretty` re-indents the markup one tag per line; text inside
+//! leaf elements and raw-text elements (`pre`, `script`, `style`, `textarea`)
+//! is kept verbatim.
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_and_selector, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_and_selector, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::error::Result;
+use crate::error::{PwError, Result};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget};
 
@@ -33,6 +38,27 @@ pub struct HtmlRaw {
 	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
 	#[serde(default, alias = "selector_flag")]
 	pub selector_flag: Option<String>,
+
+	/// Return the element's own tag (outer) or only its children (inner)
+	#[arg(long = "which", value_enum, default_value_t = HtmlWhich::Outer)]
+	#[serde(default)]
+	pub which: HtmlWhich,
+
+	/// Re-indent the HTML for readability
+	#[arg(long)]
+	#[serde(default)]
+	pub pretty: bool,
+}
+
+/// Which HTML serialization to return.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HtmlWhich {
+	/// `outerHTML`, including the element's own tag
+	#[default]
+	Outer,
+	/// `innerHTML`, children only
+	Inner,
 }
 
 /// Resolved inputs ready for execution.
@@ -42,6 +68,10 @@ pub struct HtmlResolved {
 	pub target: ResolvedTarget,
 	/// Resolved CSS selector.
 	pub selector: String,
+	/// Outer or inner serialization.
+	pub which: HtmlWhich,
+	/// Whether to re-indent the output.
+	pub pretty: bool,
 }
 
 impl Resolve for HtmlRaw {
@@ -49,7 +79,12 @@ impl Resolve for HtmlRaw {
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, Some("html"))?;
-		Ok(HtmlResolved { target, selector })
+		Ok(HtmlResolved {
+			target,
+			selector,
+			which: self.which,
+			pretty: self.pretty,
+		})
 	}
 }
 
@@ -72,6 +107,10 @@ impl CommandDef for HtmlCommand {
 	type Resolved = HtmlResolved;
 	type Data = HtmlData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -86,6 +125,7 @@ impl CommandDef for HtmlCommand {
 			}
 
 			let selector = args.selector.clone();
+			let (which, pretty) = (args.which, args.pretty);
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let selector = selector.clone();
@@ -93,7 +133,14 @@ impl CommandDef for HtmlCommand {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
 					let locator = session.page().locator(&selector).await;
-					let html = locator.inner_html().await?;
+					let html = match which {
+						HtmlWhich::Inner => locator.inner_html().await?,
+						HtmlWhich::Outer => match locator.evaluate("el => el.outerHTML", None).await? {
+							serde_json::Value::String(html) => html,
+							other => return Err(PwError::JsEval(format!("unexpected outerHTML result: {other}"))),
+						},
+					};
+					let html = if pretty { pretty_html(&html) } else { html };
 
 					Ok(HtmlData {
 						length: Some(html.len()),
@@ -110,11 +157,127 @@ impl CommandDef for HtmlCommand {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, Some(&args.selector), None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
 }
 
+const VOID_ELEMENTS: &[&str] = &[
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+const RAW_TEXT_ELEMENTS: &[&str] = &["pre", "script", "style", "textarea"];
+const INDENT: &str = "  ";
+
+/// Re-indents `html` with one tag or text run per line.
+///
+/// Leaf elements whose only child is text stay on one line, and raw-text
+/// elements are copied verbatim, so text content is never rewritten. Whitespace
+/// between tags is replaced by the new indentation.
+pub fn pretty_html(html: &str) -> String {
+	let mut out = String::with_capacity(html.len() + html.len() / 4);
+	let mut depth = 0usize;
+	let mut rest = html;
+
+	while !rest.is_empty() {
+		if rest.starts_with("<!--") {
+			let end = rest.find("-->").map(|i| i + 3).unwrap_or(rest.len());
+			push_line(&mut out, depth, &rest[..end]);
+			rest = &rest[end..];
+		} else if rest.starts_with("</") {
+			let end = tag_end(rest);
+			depth = depth.saturating_sub(1);
+			push_line(&mut out, depth, &rest[..end]);
+			rest = &rest[end..];
+		} else if rest.starts_with('<') {
+			let end = tag_end(rest);
+			let tag = &rest[..end];
+			let name = tag_name(tag);
+			rest = &rest[end..];
+
+			if tag.starts_with("<!") || tag.starts_with("<?") || tag.ends_with("/>") || VOID_ELEMENTS.contains(&name.as_str()) {
+				push_line(&mut out, depth, tag);
+				continue;
+			}
+
+			let close = format!("</{name}");
+			let raw_text = RAW_TEXT_ELEMENTS.contains(&name.as_str());
+			let leaf_end = if raw_text {
+				find_ascii_ci(rest, &close)
+			} else {
+				rest.find('<').filter(|&i| {
+					rest.as_bytes()[i..]
+						.get(..close.len())
+						.is_some_and(|b| b.eq_ignore_ascii_case(close.as_bytes()))
+				})
+			};
+
+			match leaf_end {
+				Some(content_end) => {
+					let close_end = content_end + tag_end(&rest[content_end..]);
+					push_line(&mut out, depth, tag);
+					out.push_str(&rest[..close_end]);
+					rest = &rest[close_end..];
+				}
+				None => {
+					push_line(&mut out, depth, tag);
+					depth += 1;
+				}
+			}
+		} else {
+			let end = rest.find('<').unwrap_or(rest.len());
+			let text = rest[..end].trim();
+			if !text.is_empty() {
+				push_line(&mut out, depth, text);
+			}
+			rest = &rest[end..];
+		}
+	}
+
+	out
+}
+
+fn push_line(out: &mut String, depth: usize, content: &str) {
+	if !out.is_empty() {
+		out.push('\n');
+	}
+	for _ in 0..depth {
+		out.push_str(INDENT);
+	}
+	out.push_str(content);
+}
+
+/// Returns the byte length of the tag at the start of `s`, honoring quoted attribute values.
+fn tag_end(s: &str) -> usize {
+	let mut quote = None;
+	for (i, c) in s.char_indices() {
+		match (quote, c) {
+			(Some(q), c) if c == q => quote = None,
+			(Some(_), _) => {}
+			(None, '"' | '\'') => quote = Some(c),
+			(None, '>') => return i + 1,
+			_ => {}
+		}
+	}
+	s.len()
+}
+
+fn tag_name(tag: &str) -> String {
+	tag.trim_start_matches('<')
+		.chars()
+		.take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+		.collect::<String>()
+		.to_ascii_lowercase()
+}
+
+fn find_ascii_ci(haystack: &str, needle: &str) -> Option<usize> {
+	haystack
+		.as_bytes()
+		.windows(needle.len())
+		.position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -142,4 +305,53 @@ mod tests {
 		assert_eq!(raw.url_flag, Some("https://example.com".into()));
 		assert_eq!(raw.selector_flag, Some(".content".into()));
 	}
+
+	#[test]
+	fn html_raw_defaults_to_outer_unformatted() {
+		let raw: HtmlRaw = serde_json::from_str("{}").unwrap();
+		assert_eq!(raw.which, HtmlWhich::Outer);
+		assert!(!raw.pretty);
+
+		let raw: HtmlRaw = serde_json::from_str(r#"{"which": "inner", "pretty": true}"#).unwrap();
+		assert_eq!(raw.which, HtmlWhich::Inner);
+		assert!(raw.pretty);
+	}
+
+	fn text_of(html: &str) -> String {
+		let mut text = String::new();
+		let mut in_tag = false;
+		for c in html.chars() {
+			match c {
+				'<' => in_tag = true,
+				'>' => {
+					in_tag = false;
+					text.push(' ');
+				}
+				c if !in_tag => text.push(c),
+				_ => {}
+			}
+		}
+		text.split_whitespace().collect::<Vec<_>>().join(" ")
+	}
+
+	#[test]
+	fn pretty_indents_nested_elements() {
+		let html = r#"<div class="card"><h1>Title</h1><ul><li>One</li><li>Two <b>bold</b></li></ul><br><img src="a.png"/></div>"#;
+		let pretty = pretty_html(html);
+		assert_eq!(
+			pretty,
+			"<div class=\"card\">\n  <h1>Title</h1>\n  <ul>\n    <li>One</li>\n    <li>\n      Two\n      <b>bold</b>\n    </li>\n  </ul>\n  <br>\n  <img src=\"a.png\"/>\n</div>"
+		);
+		assert_eq!(text_of(&pretty), text_of(html));
+	}
+
+	#[test]
+	fn pretty_keeps_raw_text_and_quoted_brackets_verbatim() {
+		let html = "<section><pre>  a\n   b</pre><p title=\"x > y\">Hi</p><script>if (a < b) {}</script></section>";
+		let pretty = pretty_html(html);
+		assert!(pretty.contains("  <pre>  a\n   b</pre>"), "{pretty}");
+		assert!(pretty.contains("  <p title=\"x > y\">Hi</p>"), "{pretty}");
+		assert!(pretty.contains("  <script>if (a < b) {}</script>"), "{pretty}");
+		assert!(pretty.ends_with("\n</section>"), "{pretty}");
+	}
 }
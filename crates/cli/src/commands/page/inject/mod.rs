@@ -0,0 +1,255 @@
+//! Stylesheet and script injection commands.
+//!
+//! Adds a `<style>`/`<link>` or `<script>` tag to the current page, mirroring
+//! Playwright's `page.addStyleTag()` and `page.addScriptTag()`. Exactly one of
+//! `content`, `src`, or `path` supplies the tag's source; `path` files are read
+//! locally and injected inline.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw exec page.add-style --input '{"content":"* { animation: none !important; }"}'
+//! pw exec page.add-script --input '{"src":"https://cdn.example/polyfill.js"}'
+//! pw exec page.add-script --input '{"path":"helpers.mjs","module":true}'
+//! ```
+
+use std::path::PathBuf;
+
+use clap::Args;
+use pw_rs::pw_runtime::channel_owner::ChannelOwner;
+use pw_rs::{TagSource, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs for `page.add-style`.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddStyleRaw {
+	/// Inline CSS or JavaScript to inject
+	#[arg(long, value_name = "CODE")]
+	#[serde(default)]
+	pub content: Option<String>,
+
+	/// URL the page loads the stylesheet or script from
+	#[arg(long, value_name = "URL")]
+	#[serde(default)]
+	pub src: Option<String>,
+
+	/// Local file injected inline
+	#[arg(long, value_name = "FILE")]
+	#[serde(default)]
+	pub path: Option<PathBuf>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default)]
+	pub url: Option<String>,
+}
+
+/// Raw inputs for `page.add-script`.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddScriptRaw {
+	#[command(flatten)]
+	#[serde(flatten)]
+	pub tag: AddStyleRaw,
+
+	/// Inject as an ES module (`type="module"`)
+	#[arg(long)]
+	#[serde(default)]
+	pub module: bool,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct InjectResolved {
+	/// Navigation target (URL or current page).
+	pub target: ResolvedTarget,
+
+	/// Where the tag's content comes from.
+	pub source: TagSource,
+
+	/// Whether a script is injected as an ES module.
+	pub module: bool,
+}
+
+impl Resolve for AddStyleRaw {
+	type Output = InjectResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, None, env, TargetPolicy::AllowCurrentPage)?;
+		let source = resolve_tag_source(self.content, self.src, self.path)?;
+		Ok(InjectResolved { target, source, module: false })
+	}
+}
+
+impl Resolve for AddScriptRaw {
+	type Output = InjectResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let module = self.module;
+		Ok(InjectResolved {
+			module,
+			..self.tag.resolve(env)?
+		})
+	}
+}
+
+/// Requires exactly one of `content`, `src`, or `path`; `path` must name an existing file.
+fn resolve_tag_source(content: Option<String>, src: Option<String>, path: Option<PathBuf>) -> Result<TagSource> {
+	match (content, src, path) {
+		(Some(content), None, None) => Ok(TagSource::Content(content)),
+		(None, Some(src), None) => Ok(TagSource::Url(src)),
+		(None, None, Some(path)) => {
+			if !path.is_file() {
+				return Err(PwError::Context(format!("file not found: {}", path.display())));
+			}
+			Ok(TagSource::Path(std::path::absolute(&path)?))
+		}
+		_ => Err(PwError::Context("exactly one of content, src, or path is required".to_string())),
+	}
+}
+
+/// Output data for the injection commands.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectData {
+	/// Tag name of the injected element: `style`, `link`, or `script`.
+	pub tag: &'static str,
+	/// Protocol guid of the injected element handle.
+	pub element_guid: String,
+}
+
+pub struct AddStyleCommand;
+
+impl CommandDef for AddStyleCommand {
+	const NAME: &'static str = "page.add-style";
+
+	type Raw = AddStyleRaw;
+	type Resolved = InjectResolved;
+	type Data = InjectData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		execute_inject(Self::NAME, args, exec)
+	}
+}
+
+pub struct AddScriptCommand;
+
+impl CommandDef for AddScriptCommand {
+	const NAME: &'static str = "page.add-script";
+
+	type Raw = AddScriptRaw;
+	type Resolved = InjectResolved;
+	type Data = InjectData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		execute_inject(Self::NAME, args, exec)
+	}
+}
+
+fn execute_inject<'exec, 'ctx>(
+	command: &'static str,
+	args: &'exec InjectResolved,
+	mut exec: ExecCtx<'exec, 'ctx>,
+) -> BoxFut<'exec, Result<CommandOutcome<InjectData>>>
+where
+	'ctx: 'exec,
+{
+	Box::pin(async move {
+		let url_display = args.target.url_str().unwrap_or("<current page>");
+		info!(target = "pw", url = %url_display, command, "inject tag");
+
+		let source = args.source.clone();
+		let module = args.module;
+
+		let data = run_page_flow(
+			&mut exec,
+			&args.target,
+			WaitUntil::Load,
+			ArtifactsPolicy::OnError { command },
+			move |session, flow| {
+				let source = source.clone();
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let page = session.page();
+					let linked = matches!(source, TagSource::Url(_));
+					let (tag, element) = match (command == AddScriptCommand::NAME, linked) {
+						(true, _) => ("script", page.add_script_tag(source, module.then_some("module")).await?),
+						(false, true) => ("link", page.add_style_tag(source).await?),
+						(false, false) => ("style", page.add_style_tag(source).await?),
+					};
+
+					Ok(InjectData {
+						tag,
+						element_guid: element.guid().to_string(),
+					})
+				})
+			},
+		)
+		.await?;
+
+		let source = match &args.source {
+			TagSource::Content(_) => serde_json::json!({ "content": true }),
+			TagSource::Url(src) => serde_json::json!({ "src": src }),
+			TagSource::Path(path) => serde_json::json!({ "path": path }),
+		};
+		let inputs = standard_inputs(&args.target, None, None, None, Some(source));
+
+		Ok(CommandOutcome {
+			inputs,
+			data,
+			delta: standard_delta(&args.target, None, None),
+			diagnostics: Vec::new(),
+			artifacts: Vec::new(),
+		})
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn add_script_raw_deserializes_flattened_source() {
+		let raw: AddScriptRaw = serde_json::from_str(r#"{"src": "https://cdn.example/x.js", "module": true}"#).unwrap();
+		assert_eq!(raw.tag.src.as_deref(), Some("https://cdn.example/x.js"));
+		assert!(raw.module);
+	}
+
+	#[test]
+	fn tag_source_requires_exactly_one_input() {
+		assert_eq!(resolve_tag_source(Some("a{}".into()), None, None).unwrap(), TagSource::Content("a{}".into()));
+		assert!(matches!(resolve_tag_source(None, None, None), Err(PwError::Context(_))));
+		assert!(matches!(
+			resolve_tag_source(Some("a{}".into()), Some("https://x".into()), None),
+			Err(PwError::Context(_))
+		));
+
+		let dir = tempfile::tempdir().unwrap();
+		let err = resolve_tag_source(None, None, Some(dir.path().join("missing.css"))).unwrap_err();
+		assert!(err.to_string().contains("missing.css"), "{err}");
+	}
+}
@@ -1,10 +1,19 @@
 //! Page content extraction commands.
 
+pub mod accessibility;
 pub mod console;
 pub mod coords;
 pub mod elements;
 pub mod eval;
+pub mod exists;
+pub mod frames;
+pub mod highlight;
 pub mod html;
+pub mod inject;
+pub mod pause;
+pub mod pdf;
 pub mod read;
 pub mod snapshot;
+pub mod stream_elements;
 pub mod text;
+pub mod upload;
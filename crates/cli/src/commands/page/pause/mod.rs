@@ -0,0 +1,182 @@
+//! Interactive breakpoint command, mirroring Playwright's `page.pause()`.
+//!
+//! Leaves the headful browser open for manual inspection and blocks until the
+//! user presses Enter. The keypress is read from the controlling terminal, not
+//! stdin, so `pw batch` can pause between NDJSON requests.
+
+use std::time::Instant;
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::context::CommandContext;
+use crate::error::{PwError, Result};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PauseRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct PauseResolved {
+	pub target: ResolvedTarget,
+}
+
+impl Resolve for PauseRaw {
+	type Output = PauseResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		Ok(PauseResolved { target })
+	}
+}
+
+/// Output data for the pause command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PauseData {
+	pub url: String,
+	/// How long the flow was paused, in milliseconds.
+	pub paused_ms: u64,
+}
+
+pub struct PauseCommand;
+
+impl CommandDef for PauseCommand {
+	const NAME: &'static str = "page.pause";
+
+	type Raw = PauseRaw;
+	type Resolved = PauseResolved;
+	type Data = PauseData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			ensure_headful(exec.ctx)?;
+
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, browser = %exec.ctx.browser, "pause");
+
+			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+					let url = session.page().url();
+					eprintln!("pw: paused on {url}; inspect the browser, then press Enter to continue");
+
+					let started = Instant::now();
+					tokio::task::spawn_blocking(wait_for_enter)
+						.await
+						.map_err(|e| PwError::Context(format!("pause interrupted: {e}")))??;
+
+					Ok(PauseData {
+						url,
+						paused_ms: started.elapsed().as_millis() as u64,
+					})
+				})
+			})
+			.await?;
+
+			let inputs = standard_inputs(&args.target, None, None, None, None);
+
+			Ok(CommandOutcome {
+				inputs,
+				delta: standard_delta_with_url(Some(data.url.clone()), None, None),
+				data,
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+/// Rejects pausing when nobody can see the browser.
+fn ensure_headful(ctx: &CommandContext) -> Result<()> {
+	if ctx.is_headful() {
+		return Ok(());
+	}
+	Err(PwError::UnsupportedMode(
+		"page.pause requires a headful browser; set defaults.headless = false, use --devtools, or attach with a CDP endpoint".to_string(),
+	))
+}
+
+/// Blocks until a line is read from the controlling terminal.
+fn wait_for_enter() -> Result<()> {
+	use std::io::BufRead;
+
+	#[cfg(unix)]
+	const TTY: &str = "/dev/tty";
+	#[cfg(windows)]
+	const TTY: &str = "CONIN$";
+
+	let tty = std::fs::File::open(TTY).map_err(|e| PwError::UnsupportedMode(format!("page.pause needs an interactive terminal: {e}")))?;
+	let mut line = String::new();
+	std::io::BufReader::new(tty).read_line(&mut line)?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::context::{CommandContextConfig, LaunchConfig};
+
+	#[test]
+	fn pause_is_unsupported_headless() {
+		let ctx = CommandContext::with_config(CommandContextConfig {
+			no_project: true,
+			..Default::default()
+		});
+		let err = ensure_headful(&ctx).unwrap_err();
+		assert!(matches!(err, PwError::UnsupportedMode(_)), "{err}");
+	}
+
+	#[test]
+	fn pause_allowed_with_devtools_or_cdp() {
+		let devtools = CommandContext::with_config(CommandContextConfig {
+			no_project: true,
+			launch_config: LaunchConfig {
+				devtools: true,
+				..Default::default()
+			},
+			..Default::default()
+		});
+		assert!(ensure_headful(&devtools).is_ok());
+
+		let attached = CommandContext::with_config(CommandContextConfig {
+			no_project: true,
+			cdp_endpoint: Some("http://127.0.0.1:9222".to_string()),
+			..Default::default()
+		});
+		assert!(ensure_headful(&attached).is_ok());
+
+		let profile = CommandContext::with_config(CommandContextConfig {
+			no_project: true,
+			headful: true,
+			..Default::default()
+		});
+		assert!(ensure_headful(&profile).is_ok());
+	}
+}
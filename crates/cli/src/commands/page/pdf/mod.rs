@@ -0,0 +1,255 @@
+//! PDF export command.
+//!
+//! Renders the page with Chromium's print pipeline. Header and footer
+//! templates are HTML; elements with the classes `pageNumber`, `totalPages`,
+//! `date`, `title` and `url` receive the print values. Setting either template
+//! turns on `displayHeaderFooter`. Only headless Chromium can produce PDFs.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw exec page.pdf --input '{"output":"report.pdf","footerTemplate":"<span class=pageNumber></span>","margin":"1cm 2cm"}'
+//! ```
+
+use std::path::PathBuf;
+
+use clap::Args;
+use pw_rs::{PdfMargin, PdfOptions, WaitUntil};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::output::{Artifact, ArtifactType};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON before resolution.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfRaw {
+	/// Target URL (positional, uses context when omitted)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Output file path; supports `{ts}`, `{host}` and `{n}` tokens (default: page.pdf)
+	#[arg(short, long, value_name = "FILE")]
+	#[serde(default)]
+	pub output: Option<PathBuf>,
+
+	/// Paper format such as Letter or A4
+	#[arg(long, value_name = "FORMAT")]
+	#[serde(default)]
+	pub format: Option<String>,
+
+	/// Landscape orientation
+	#[arg(long)]
+	#[serde(default)]
+	pub landscape: bool,
+
+	/// Print background graphics
+	#[arg(long)]
+	#[serde(default, alias = "print_background")]
+	pub print_background: bool,
+
+	/// HTML template for the page header
+	#[arg(long, value_name = "HTML")]
+	#[serde(default, alias = "header_template")]
+	pub header_template: Option<String>,
+
+	/// HTML template for the page footer
+	#[arg(long, value_name = "HTML")]
+	#[serde(default, alias = "footer_template")]
+	pub footer_template: Option<String>,
+
+	/// Page margins as CSS shorthand: `1cm`, `1cm 2cm`, or `top right bottom left`
+	#[arg(long, value_name = "MARGIN")]
+	#[serde(default)]
+	pub margin: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct PdfResolved {
+	pub target: ResolvedTarget,
+	pub output: PathBuf,
+	pub format: Option<String>,
+	pub landscape: bool,
+	pub print_background: bool,
+	pub header_template: Option<String>,
+	pub footer_template: Option<String>,
+	pub margin: Option<PdfMargin>,
+}
+
+impl PdfResolved {
+	/// Protocol options for this export.
+	fn options(&self) -> PdfOptions {
+		PdfOptions {
+			format: self.format.clone(),
+			landscape: self.landscape.then_some(true),
+			print_background: self.print_background.then_some(true),
+			header_template: self.header_template.clone(),
+			footer_template: self.footer_template.clone(),
+			margin: self.margin.clone(),
+			..Default::default()
+		}
+	}
+}
+
+impl Resolve for PdfRaw {
+	type Output = PdfResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		let output = self.output.unwrap_or_else(|| PathBuf::from("page.pdf"));
+		let margin = self.margin.as_deref().map(parse_margin).transpose()?;
+
+		Ok(PdfResolved {
+			target,
+			output,
+			format: self.format,
+			landscape: self.landscape,
+			print_background: self.print_background,
+			header_template: self.header_template,
+			footer_template: self.footer_template,
+			margin,
+		})
+	}
+}
+
+/// Parses CSS margin shorthand (one to four lengths) into per-side margins.
+fn parse_margin(value: &str) -> Result<PdfMargin> {
+	let parts: Vec<&str> = value.split_whitespace().collect();
+	let [top, right, bottom, left] = match parts.as_slice() {
+		[all] => [all, all, all, all],
+		[vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+		[top, horizontal, bottom] => [top, horizontal, bottom, horizontal],
+		[top, right, bottom, left] => [top, right, bottom, left],
+		_ => {
+			return Err(PwError::Context(format!("margin expects one to four CSS lengths, got {value:?}")));
+		}
+	};
+	Ok(PdfMargin {
+		top: Some(top.to_string()),
+		right: Some(right.to_string()),
+		bottom: Some(bottom.to_string()),
+		left: Some(left.to_string()),
+	})
+}
+
+/// Output data for the PDF command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfData {
+	pub path: PathBuf,
+	/// Size of the written PDF in bytes.
+	pub bytes: usize,
+	/// Whether the header/footer templates were rendered.
+	pub display_header_footer: bool,
+}
+
+pub struct PdfCommand;
+
+impl CommandDef for PdfCommand {
+	const NAME: &'static str = "page.pdf";
+
+	type Raw = PdfRaw;
+	type Resolved = PdfResolved;
+	type Data = PdfData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			let path = exec.ctx_state.resolve_output(exec.ctx, &args.output, args.target.url_str().or(exec.last_url));
+			info!(target = "pw", url = %url_display, path = %path.display(), browser = %exec.ctx.browser, "pdf");
+
+			if let Some(parent) = path.parent() {
+				if !parent.as_os_str().is_empty() && !parent.exists() {
+					std::fs::create_dir_all(parent)?;
+				}
+			}
+
+			let output = path.clone();
+			let options = args.options();
+			let display_header_footer = options.header_template.is_some() || options.footer_template.is_some();
+
+			let bytes = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+				let output = output.clone();
+				let options = options.clone();
+				Box::pin(async move {
+					session.goto_target(&flow.target, flow.timeout_ms).await?;
+					let bytes = session.page().pdf_to_file(&output, Some(options)).await?;
+					Ok(bytes.len())
+				})
+			})
+			.await?;
+
+			let inputs = standard_inputs(&args.target, None, None, Some(&args.output), None);
+
+			Ok(CommandOutcome {
+				inputs,
+				data: PdfData {
+					path: path.clone(),
+					bytes,
+					display_header_footer,
+				},
+				delta: standard_delta(&args.target, None, Some(&args.output)),
+				diagnostics: Vec::new(),
+				artifacts: vec![Artifact {
+					artifact_type: ArtifactType::Pdf,
+					path,
+					size_bytes: Some(bytes as u64),
+				}],
+			})
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_margin_expands_css_shorthand() {
+		assert_eq!(parse_margin("1cm").unwrap(), PdfMargin::all("1cm"));
+		let margin = parse_margin("10px 20px 30px").unwrap();
+		assert_eq!(
+			(margin.top.as_deref(), margin.right.as_deref(), margin.bottom.as_deref(), margin.left.as_deref()),
+			(Some("10px"), Some("20px"), Some("30px"), Some("20px"))
+		);
+		assert!(parse_margin("").is_err());
+		assert!(parse_margin("1 2 3 4 5").is_err());
+	}
+
+	#[test]
+	fn resolve_maps_templates_and_margin_into_options() {
+		let state = crate::context_store::test_state();
+		let ctx_state = crate::context_store::ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+		let env = ResolveEnv::new(&ctx_state, false, PdfCommand::NAME);
+
+		let raw: PdfRaw = serde_json::from_str(
+			r#"{"url": "https://example.com", "headerTemplate": "<b>Acme</b>", "footerTemplate": "<span class=pageNumber></span>", "margin": "1cm 2cm"}"#,
+		)
+		.unwrap();
+		let options = raw.resolve(&env).unwrap().options();
+
+		assert_eq!(options.header_template.as_deref(), Some("<b>Acme</b>"));
+		assert_eq!(options.footer_template.as_deref(), Some("<span class=pageNumber></span>"));
+		let margin = options.margin.unwrap();
+		assert_eq!((margin.top.as_deref(), margin.left.as_deref()), (Some("1cm"), Some("2cm")));
+	}
+}
@@ -14,7 +14,13 @@
 //!
 //! ```bash
 //! pw read https://example.com/article --metadata
+//! pw read https://example.com/article --output article.md
 //! ```
+//!
+//! With `--output`, the full content is written to the file and recorded as
+//! an artifact; `data.content` then holds only a short preview.
+
+use std::path::{Path, PathBuf};
 
 use clap::Args;
 use pw_rs::WaitUntil;
@@ -22,10 +28,11 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::cli::ReadOutputFormat;
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::Result;
+use crate::output::{Artifact, ArtifactType};
 use crate::readable::{ReadableContent, extract_readable};
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
@@ -52,6 +59,11 @@ pub struct ReadRaw {
 	#[arg(long, short = 'm')]
 	#[serde(default)]
 	pub metadata: Option<bool>,
+
+	/// Write the extracted content to FILE instead of returning it inline; supports `{ts}`, `{host}` and `{n}` tokens
+	#[arg(long, value_name = "FILE")]
+	#[serde(default)]
+	pub output: Option<PathBuf>,
 }
 
 /// Resolved inputs ready for execution.
@@ -67,6 +79,9 @@ pub struct ReadResolved {
 
 	/// Whether to include article metadata.
 	pub include_metadata: bool,
+
+	/// File that receives the full content, if any.
+	pub output: Option<PathBuf>,
 }
 
 impl Resolve for ReadRaw {
@@ -80,6 +95,7 @@ impl Resolve for ReadRaw {
 			target,
 			output_format,
 			include_metadata: self.metadata.unwrap_or(false),
+			output: self.output,
 		})
 	}
 }
@@ -93,6 +109,10 @@ impl CommandDef for ReadCommand {
 	type Resolved = ReadResolved;
 	type Data = ReadData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -105,25 +125,32 @@ impl CommandDef for ReadCommand {
 			let include_metadata = args.include_metadata;
 			let url_str = args.target.url_str().map(String::from);
 
-			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
+			let mut data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let url_str = url_str.clone();
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
-
-					let locator = session.page().locator("html").await;
-					let html = locator.inner_html().await?;
-					let readable = extract_readable(&html, url_str.as_deref());
-					Ok(ReadData::from_readable(readable, output_format, include_metadata))
+					read_page(session.page(), url_str.as_deref(), output_format, include_metadata).await
 				})
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, None, None, None, None);
+			let artifacts = match &args.output {
+				Some(template) => {
+					let path = exec.ctx_state.resolve_output(exec.ctx, template, args.target.url_str().or(exec.last_url));
+					vec![write_content(&mut data, &path)?]
+				}
+				None => Vec::new(),
+			};
+
+			let output = args.output.as_deref();
+			let inputs = standard_inputs(&args.target, None, None, output, None);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
-				delta: standard_delta(&args.target, None, None),
+				delta: standard_delta(&args.target, None, output),
+				diagnostics: Vec::new(),
+				artifacts,
 			})
 		})
 	}
@@ -142,6 +169,10 @@ pub struct ReadData {
 	/// Word count of the extracted content.
 	pub word_count: usize,
 
+	/// File holding the full content when `--output` was given.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub path: Option<PathBuf>,
+
 	/// Page title from metadata.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub title: Option<String>,
@@ -182,6 +213,7 @@ impl ReadData {
 				content,
 				format,
 				word_count,
+				path: None,
 				title: readable.metadata.title,
 				author: readable.metadata.author,
 				published: readable.metadata.published,
@@ -194,6 +226,7 @@ impl ReadData {
 				content,
 				format,
 				word_count,
+				path: None,
 				title: None,
 				author: None,
 				published: None,
@@ -205,6 +238,42 @@ impl ReadData {
 	}
 }
 
+/// Extracts the readable content of `page`; `url` resolves relative links.
+pub(crate) async fn read_page(page: &pw_rs::Page, url: Option<&str>, output_format: ReadOutputFormat, include_metadata: bool) -> Result<ReadData> {
+	let html = page.locator("html").await.inner_html().await?;
+	let readable = extract_readable(&html, url);
+	Ok(ReadData::from_readable(readable, output_format, include_metadata))
+}
+
+/// Characters of content kept inline when the full text goes to a file.
+const PREVIEW_CHARS: usize = 280;
+
+/// Writes `data.content` to `path` and replaces it with a short preview.
+pub(crate) fn write_content(data: &mut ReadData, path: &Path) -> Result<Artifact> {
+	if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+		std::fs::create_dir_all(parent)?;
+	}
+	std::fs::write(path, &data.content)?;
+
+	let artifact_type = if data.format == "html" { ArtifactType::Html } else { ArtifactType::Text };
+	let size_bytes = data.content.len() as u64;
+	data.content = preview(&data.content);
+	data.path = Some(path.to_path_buf());
+
+	Ok(Artifact {
+		artifact_type,
+		path: path.to_path_buf(),
+		size_bytes: Some(size_bytes),
+	})
+}
+
+fn preview(content: &str) -> String {
+	match content.char_indices().nth(PREVIEW_CHARS) {
+		Some((end, _)) => format!("{}…", &content[..end]),
+		None => content.to_string(),
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -225,4 +294,31 @@ mod tests {
 		assert_eq!(raw.output_format, None);
 		assert_eq!(raw.metadata, None);
 	}
+
+	#[test]
+	fn write_content_saves_file_and_keeps_preview() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("out/article.md");
+		let full = "word ".repeat(200);
+		let mut data = ReadData {
+			content: full.clone(),
+			format: "markdown".to_string(),
+			word_count: 200,
+			path: None,
+			title: None,
+			author: None,
+			published: None,
+			description: None,
+			image: None,
+			site: None,
+		};
+
+		let artifact = write_content(&mut data, &path).unwrap();
+		assert_eq!(std::fs::read_to_string(&path).unwrap(), full);
+		assert_eq!(artifact.artifact_type, ArtifactType::Text);
+		assert_eq!(artifact.size_bytes, Some(full.len() as u64));
+		assert_eq!(data.path.as_deref(), Some(path.as_path()));
+		assert_eq!(data.content.chars().count(), PREVIEW_CHARS + 1);
+		assert_eq!(data.word_count, 200);
+	}
 }
@@ -0,0 +1,226 @@
+//! Baseline comparison for `page.snapshot --baseline`.
+//!
+//! Elements are matched by selector, with repeated selectors paired in document
+//! order. Geometry is ignored so layout shifts do not show up as changes.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::error::{PwError, Result};
+use crate::output::{ChangedElement, InteractiveElement, SnapshotData, SnapshotDiff};
+
+/// Computes added, removed, and changed interactive elements between two snapshots.
+pub fn diff_snapshots(baseline: &SnapshotData, current: &SnapshotData) -> SnapshotDiff {
+	let before = keyed(&baseline.elements);
+	let after = keyed(&current.elements);
+	let before_by_key: HashMap<_, _> = before.iter().copied().collect();
+	let after_by_key: HashMap<_, _> = after.iter().copied().collect();
+
+	let mut diff = SnapshotDiff {
+		text_delta: current.text.chars().count() as i64 - baseline.text.chars().count() as i64,
+		url_changed: baseline.url != current.url,
+		title_changed: baseline.title != current.title,
+		..SnapshotDiff::default()
+	};
+
+	for (key, element) in &after {
+		match before_by_key.get(key) {
+			None => diff.added.push((*element).clone()),
+			Some(old) if !same_content(old, element) => diff.changed.push(ChangedElement {
+				selector: element.selector.clone(),
+				before: (*old).clone(),
+				after: (*element).clone(),
+			}),
+			Some(_) => {}
+		}
+	}
+
+	for (key, element) in &before {
+		if !after_by_key.contains_key(key) {
+			diff.removed.push((*element).clone());
+		}
+	}
+
+	diff
+}
+
+/// Loads a baseline written by `--update-baseline` or saved from a `pw` result envelope.
+///
+/// Returns `None` when the file does not exist yet.
+pub fn load_baseline(path: &Path) -> Result<Option<SnapshotData>> {
+	let contents = match std::fs::read_to_string(path) {
+		Ok(contents) => contents,
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+		Err(err) => return Err(err.into()),
+	};
+
+	let mut value: Value = serde_json::from_str(&contents)?;
+	if let Some(data) = value.get_mut("data").filter(|data| data.is_object()) {
+		value = data.take();
+	}
+
+	serde_json::from_value(value)
+		.map(Some)
+		.map_err(|err| PwError::Context(format!("invalid snapshot baseline {}: {err}", path.display())))
+}
+
+/// Writes `snapshot` as the next baseline, creating parent directories as needed.
+pub fn write_baseline(path: &Path, snapshot: &SnapshotData) -> Result<()> {
+	if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+		std::fs::create_dir_all(parent)?;
+	}
+	std::fs::write(path, serde_json::to_string_pretty(snapshot)?)?;
+	Ok(())
+}
+
+/// Pairs each element with `(selector, occurrence)` so duplicate selectors match in order.
+fn keyed(elements: &[InteractiveElement]) -> Vec<((&str, usize), &InteractiveElement)> {
+	let mut seen: HashMap<&str, usize> = HashMap::new();
+	elements
+		.iter()
+		.map(|element| {
+			let occurrence = seen.entry(element.selector.as_str()).or_default();
+			let key = (element.selector.as_str(), *occurrence);
+			*occurrence += 1;
+			(key, element)
+		})
+		.collect()
+}
+
+fn same_content(a: &InteractiveElement, b: &InteractiveElement) -> bool {
+	a.tag == b.tag && a.text == b.text && a.name == b.name && a.href == b.href && a.id == b.id && a.value == b.value && a.checked == b.checked
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn element(tag: &str, selector: &str, text: &str) -> InteractiveElement {
+		InteractiveElement {
+			tag: tag.to_string(),
+			selector: selector.to_string(),
+			text: Some(text.to_string()),
+			href: None,
+			name: None,
+			id: None,
+			value: None,
+			checked: None,
+			x: 0,
+			y: 0,
+			page_x: 0,
+			page_y: 0,
+			width: 10,
+			height: 10,
+			visible: None,
+			aria_hidden: None,
+			count: None,
+		}
+	}
+
+	fn snapshot(elements: Vec<InteractiveElement>, text: &str) -> SnapshotData {
+		SnapshotData {
+			url: "https://example.com/".to_string(),
+			title: "Example".to_string(),
+			viewport_width: 1280,
+			viewport_height: 720,
+			meta: Default::default(),
+			text: text.to_string(),
+			element_count: elements.len(),
+			elements,
+			diff: None,
+		}
+	}
+
+	#[test]
+	fn lists_added_button() {
+		let baseline = snapshot(vec![element("button", "button:has-text(\"Save\")", "Save")], "Save");
+		let current = snapshot(
+			vec![
+				element("button", "button:has-text(\"Save\")", "Save"),
+				element("button", "button:has-text(\"Delete\")", "Delete"),
+			],
+			"Save Delete",
+		);
+
+		let diff = diff_snapshots(&baseline, &current);
+		assert_eq!(diff.added.len(), 1);
+		assert_eq!(diff.added[0].selector, "button:has-text(\"Delete\")");
+		assert!(diff.removed.is_empty());
+		assert!(diff.changed.is_empty());
+		assert_eq!(diff.text_delta, 7);
+		assert!(!diff.url_changed);
+	}
+
+	#[test]
+	fn reports_removed_and_changed_but_ignores_geometry() {
+		let mut moved = element("input", "#email", "Email");
+		moved.y = 200;
+		let mut renamed = element("a", "#docs", "Docs");
+		renamed.text = Some("Documentation".to_string());
+
+		let baseline = snapshot(
+			vec![
+				element("input", "#email", "Email"),
+				element("a", "#docs", "Docs"),
+				element("button", "#old", "Old"),
+			],
+			"",
+		);
+		let current = snapshot(vec![moved, renamed], "");
+
+		let diff = diff_snapshots(&baseline, &current);
+		assert!(diff.added.is_empty());
+		assert_eq!(diff.removed.len(), 1);
+		assert_eq!(diff.removed[0].selector, "#old");
+		assert_eq!(diff.changed.len(), 1);
+		assert_eq!(diff.changed[0].selector, "#docs");
+		assert_eq!(diff.changed[0].after.text.as_deref(), Some("Documentation"));
+	}
+
+	#[test]
+	fn reports_typed_value_and_toggled_checkbox_as_changed() {
+		let mut unchecked = element("input", "#agree", "");
+		unchecked.checked = Some(false);
+		let mut checked = unchecked.clone();
+		checked.checked = Some(true);
+		let mut empty = element("input", "#q", "");
+		empty.value = Some(String::new());
+		let mut typed = empty.clone();
+		typed.value = Some("rust".to_string());
+
+		let diff = diff_snapshots(&snapshot(vec![unchecked, empty], ""), &snapshot(vec![checked, typed], ""));
+		assert_eq!(diff.changed.len(), 2);
+		assert_eq!(diff.changed[0].selector, "#agree");
+		assert_eq!(diff.changed[0].after.checked, Some(true));
+		assert_eq!(diff.changed[1].selector, "#q");
+		assert_eq!(diff.changed[1].after.value.as_deref(), Some("rust"));
+	}
+
+	#[test]
+	fn duplicate_selectors_pair_in_order() {
+		let baseline = snapshot(vec![element("button", "button", "A")], "");
+		let current = snapshot(vec![element("button", "button", "A"), element("button", "button", "B")], "");
+
+		let diff = diff_snapshots(&baseline, &current);
+		assert_eq!(diff.added.len(), 1);
+		assert_eq!(diff.added[0].text.as_deref(), Some("B"));
+	}
+
+	#[test]
+	fn baseline_round_trips_and_accepts_envelopes() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("nested/baseline.json");
+		assert!(load_baseline(&path).unwrap().is_none());
+
+		let data = snapshot(vec![element("button", "#go", "Go")], "Go");
+		write_baseline(&path, &data).unwrap();
+		let loaded = load_baseline(&path).unwrap().unwrap();
+		assert_eq!(loaded.elements, data.elements);
+
+		let envelope = dir.path().join("envelope.json");
+		std::fs::write(&envelope, serde_json::json!({ "ok": true, "data": data }).to_string()).unwrap();
+		assert_eq!(load_baseline(&envelope).unwrap().unwrap().elements.len(), 1);
+	}
+}
@@ -12,7 +12,7 @@
 //!
 //! # Output Contents
 //!
-//! * Page metadata (URL, title, viewport dimensions)
+//! * Page metadata (URL, title, viewport dimensions, language and key meta tags)
 //! * Interactive elements (buttons, links, inputs) with stable CSS selectors
 //! * Visible text content (configurable length limit)
 //!
@@ -23,18 +23,25 @@
 //! pw snapshot --text-only   # Skip interactive elements (faster)
 //! pw snapshot --full        # Include all text, not just visible
 //! pw snapshot --max-text-length 10000
+//! pw snapshot --dedup-by name   # Collapse repeated controls into one entry with a count
+//! pw snapshot --baseline before.json --update-baseline   # Diff against and refresh a baseline
 //! ```
 
-use clap::Args;
+mod diff;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta_with_url, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
-use crate::error::Result;
-use crate::output::{InteractiveElement, SnapshotData};
+use crate::error::{PwError, Result};
+use crate::output::{InteractiveElement, SnapshotData, SnapshotMeta};
 use crate::session::SessionHandle;
 use crate::session_helpers::ArtifactsPolicy;
 use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
@@ -62,12 +69,45 @@ pub struct SnapshotRaw {
 	#[serde(default)]
 	pub full: Option<bool>,
 
-	/// Maximum text length to extract (default: 5000)
-	#[arg(long, default_value = "5000")]
+	/// Maximum text length to extract (default: profile `defaults.maxTextLength`, else 5000)
+	#[arg(long)]
 	#[serde(default, alias = "max_text_length")]
 	pub max_text_length: Option<usize>,
+
+	/// Also report hidden interactive elements, tagged with `visible` and `ariaHidden`
+	#[arg(long)]
+	#[serde(default, alias = "include_hidden")]
+	pub include_hidden: bool,
+
+	/// Baseline snapshot JSON to diff against; the diff is reported in `data.diff`
+	#[arg(long, value_name = "FILE")]
+	#[serde(default)]
+	pub baseline: Option<PathBuf>,
+
+	/// Write this snapshot to `--baseline` after comparing (creates it when missing)
+	#[arg(long, requires = "baseline")]
+	#[serde(default, alias = "update_baseline")]
+	pub update_baseline: bool,
+
+	/// Collapse interactive elements sharing an accessible name or selector into one entry with a `count`
+	#[arg(long, value_enum, value_name = "KEY")]
+	#[serde(default, alias = "dedup_by")]
+	pub dedup_by: Option<SnapshotDedup>,
+}
+
+/// Key used to collapse repeated interactive elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotDedup {
+	/// Same kind and accessible name (label); unlabeled elements are never merged
+	Name,
+	/// Same kind and generated selector
+	Selector,
 }
 
+/// Text cap used when neither the request nor the profile config sets one.
+pub const DEFAULT_MAX_TEXT_LENGTH: usize = 5000;
+
 /// Resolved inputs ready for execution.
 ///
 /// All optional fields have been validated and defaults applied.
@@ -86,6 +126,18 @@ pub struct SnapshotResolved {
 
 	/// Maximum text length to extract in characters.
 	pub max_text_length: usize,
+
+	/// Keep elements that fail the visibility filter.
+	pub include_hidden: bool,
+
+	/// Baseline file to diff against.
+	pub baseline: Option<PathBuf>,
+
+	/// Overwrite the baseline with this snapshot after diffing.
+	pub update_baseline: bool,
+
+	/// Collapse repeated elements by this key, if set.
+	pub dedup_by: Option<SnapshotDedup>,
 }
 
 impl Resolve for SnapshotRaw {
@@ -93,12 +145,19 @@ impl Resolve for SnapshotRaw {
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		if self.update_baseline && self.baseline.is_none() {
+			return Err(PwError::Context("updateBaseline requires baseline".into()));
+		}
 
 		Ok(SnapshotResolved {
 			target,
 			text_only: self.text_only.unwrap_or(false),
 			full: self.full.unwrap_or(false),
-			max_text_length: self.max_text_length.unwrap_or(5000),
+			max_text_length: self.max_text_length.or(env.ctx_state.max_text_length()).unwrap_or(DEFAULT_MAX_TEXT_LENGTH),
+			include_hidden: self.include_hidden,
+			baseline: self.baseline,
+			update_baseline: self.update_baseline,
+			dedup_by: self.dedup_by,
 		})
 	}
 }
@@ -112,6 +171,10 @@ impl CommandDef for SnapshotCommand {
 	type Resolved = SnapshotResolved;
 	type Data = SnapshotData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -123,8 +186,24 @@ impl CommandDef for SnapshotCommand {
 			let text_only = args.text_only;
 			let full = args.full;
 			let max_text_length = args.max_text_length;
+			let include_hidden = args.include_hidden;
+			let dedup_by = args.dedup_by;
+
+			let baseline = match &args.baseline {
+				Some(path) => match diff::load_baseline(path)? {
+					Some(snapshot) => Some(snapshot),
+					None if args.update_baseline => None,
+					None => {
+						return Err(PwError::Context(format!(
+							"baseline {} not found; pass --update-baseline to create it",
+							path.display()
+						)));
+					}
+				},
+				None => None,
+			};
 
-			let (final_url, data) = run_page_flow(
+			let (final_url, mut data) = run_page_flow(
 				&mut exec,
 				&args.target,
 				WaitUntil::NetworkIdle,
@@ -139,7 +218,7 @@ impl CommandDef for SnapshotCommand {
 						let text_js = format!("JSON.stringify({}({}, {}))", EXTRACT_TEXT_JS, max_text_length, full);
 						let text: String = serde_json::from_str(&session.page().evaluate_value(&text_js).await?)?;
 
-						let elements = extract_elements_if_needed(session, text_only).await?;
+						let elements = extract_elements_if_needed(session, text_only, include_hidden, dedup_by).await?;
 						let element_count = elements.len();
 
 						let final_url = meta.url.clone();
@@ -149,9 +228,11 @@ impl CommandDef for SnapshotCommand {
 							title: meta.title,
 							viewport_width: meta.viewport_width,
 							viewport_height: meta.viewport_height,
+							meta: meta.meta,
 							text,
 							elements,
 							element_count,
+							diff: None,
 						};
 
 						Ok((final_url, data))
@@ -160,12 +241,19 @@ impl CommandDef for SnapshotCommand {
 			)
 			.await?;
 
+			if let (Some(path), true) = (&args.baseline, args.update_baseline) {
+				diff::write_baseline(path, &data)?;
+			}
+			data.diff = baseline.as_ref().map(|baseline| diff::diff_snapshots(baseline, &data));
+
 			let inputs = standard_inputs(&args.target, None, None, None, None);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
 				delta: standard_delta_with_url(Some(final_url), None, None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -179,13 +267,28 @@ pub(crate) struct RawElement {
 	pub selector: String,
 	pub extra: Option<String>,
 	#[serde(default)]
+	pub value: Option<String>,
+	#[serde(default)]
+	pub checked: Option<bool>,
+	#[serde(default)]
 	pub x: i32,
 	#[serde(default)]
 	pub y: i32,
 	#[serde(default)]
+	pub page_x: i32,
+	#[serde(default)]
+	pub page_y: i32,
+	#[serde(default)]
 	pub width: i32,
 	#[serde(default)]
 	pub height: i32,
+	#[serde(default)]
+	pub visible: Option<bool>,
+	#[serde(default)]
+	pub aria_hidden: Option<bool>,
+	/// Elements that produced the same kind and selector (at least 1).
+	#[serde(default)]
+	pub count: Option<usize>,
 }
 
 /// Page metadata returned by the browser extraction script.
@@ -195,16 +298,45 @@ pub(crate) struct PageMeta {
 	pub title: String,
 	pub viewport_width: i32,
 	pub viewport_height: i32,
+	#[serde(default)]
+	pub meta: SnapshotMeta,
 }
 
-/// JavaScript that extracts page metadata (URL, title, viewport size).
+/// JavaScript that extracts page metadata (URL, title, viewport size, language and meta tags).
+///
+/// Only tags present with a non-empty value are reported. `charset` comes from
+/// `<meta charset>` or the `charset=` parameter of `<meta http-equiv="Content-Type">`,
+/// never from the encoding the browser fell back to.
 pub(crate) const EXTRACT_META_JS: &str = r#"
 (() => {
     return {
         url: window.location.href,
         title: document.title || '',
         viewport_width: window.innerWidth,
-        viewport_height: window.innerHeight
+        viewport_height: window.innerHeight,
+        meta: (() => {
+            const meta = {};
+            const put = (key, value) => {
+                if (value && value.trim()) meta[key] = value.trim();
+            };
+            const content = (selector) => {
+                const el = document.querySelector(selector);
+                return el ? el.getAttribute('content') : null;
+            };
+            put('lang', document.documentElement.getAttribute('lang'));
+            const charset = () => {
+                const declared = document.querySelector('meta[charset]');
+                if (declared) return declared.getAttribute('charset');
+                const type = content('meta[http-equiv="content-type" i]');
+                const match = type && /charset\s*=\s*["']?([^;"'\s]+)/i.exec(type);
+                return match ? match[1] : null;
+            };
+            put('charset', charset());
+            put('description', content('meta[name="description" i]'));
+            put('ogTitle', content('meta[property="og:title"]'));
+            put('ogImage', content('meta[property="og:image"]'));
+            return meta;
+        })()
     };
 })()
 "#;
@@ -212,7 +344,8 @@ pub(crate) const EXTRACT_META_JS: &str = r#"
 /// JavaScript that extracts visible text content using TreeWalker.
 ///
 /// Accepts `maxLength` (character limit) and `full` (include non-visible text) parameters.
-/// Skips script, style, noscript, iframe, and SVG elements.
+/// Skips script, style, noscript, iframe, and SVG elements. Descends into open
+/// shadow roots; closed ones are not reachable from script and are skipped.
 pub(crate) const EXTRACT_TEXT_JS: &str = r#"
 ((maxLength, full) => {
     const texts = [];
@@ -233,30 +366,49 @@ pub(crate) const EXTRACT_TEXT_JS: &str = r#"
         return true;
     }
     
-    const walker = document.createTreeWalker(
-        document.body || document.documentElement,
-        NodeFilter.SHOW_TEXT,
-        {
-            acceptNode: (node) => {
-                const parent = node.parentElement;
-                if (!parent) return NodeFilter.FILTER_REJECT;
-                if (ignoreTags.has(parent.tagName)) return NodeFilter.FILTER_REJECT;
-                if (!isVisible(parent)) return NodeFilter.FILTER_REJECT;
-                const text = node.textContent.trim();
-                if (!text) return NodeFilter.FILTER_REJECT;
-                return NodeFilter.FILTER_ACCEPT;
-            }
-        }
-    );
+    function textParent(node) {
+        if (node.parentElement) return node.parentElement;
+        const root = node.parentNode;
+        return root && root.host ? root.host : null;
+    }
     
-    while (walker.nextNode() && totalLength < maxLength) {
-        const text = walker.currentNode.textContent.trim();
-        if (text) {
-            texts.push(text);
-            totalLength += text.length + 1;
+    function collect(root) {
+        const walker = document.createTreeWalker(
+            root,
+            NodeFilter.SHOW_ELEMENT | NodeFilter.SHOW_TEXT,
+            {
+                acceptNode: (node) => {
+                    if (node.nodeType === Node.ELEMENT_NODE) {
+                        return ignoreTags.has(node.tagName) ? NodeFilter.FILTER_REJECT : NodeFilter.FILTER_ACCEPT;
+                    }
+                    const parent = textParent(node);
+                    if (!parent) return NodeFilter.FILTER_REJECT;
+                    if (ignoreTags.has(parent.tagName)) return NodeFilter.FILTER_REJECT;
+                    if (!isVisible(parent)) return NodeFilter.FILTER_REJECT;
+                    const text = node.textContent.trim();
+                    if (!text) return NodeFilter.FILTER_REJECT;
+                    return NodeFilter.FILTER_ACCEPT;
+                }
+            }
+        );
+        
+        while (walker.nextNode() && totalLength < maxLength) {
+            const node = walker.currentNode;
+            if (node.nodeType === Node.ELEMENT_NODE) {
+                // Closed shadow roots report null here and are skipped.
+                if (node.shadowRoot) collect(node.shadowRoot);
+                continue;
+            }
+            const text = node.textContent.trim();
+            if (text) {
+                texts.push(text);
+                totalLength += text.length + 1;
+            }
         }
     }
     
+    collect(document.body || document.documentElement);
+    
     return texts.join(' ').substring(0, maxLength);
 })
 "#;
@@ -264,12 +416,17 @@ pub(crate) const EXTRACT_TEXT_JS: &str = r#"
 /// JavaScript that extracts interactive elements with stable selectors.
 ///
 /// Generates selectors preferring: ID > name attribute > text content >
-/// aria-label > class combination > nth-of-type fallback. Duplicated from
-/// [`elements`](super::elements) module for bundle isolation.
+/// aria-label > class combination > nth-of-type fallback. Elements inside open
+/// shadow roots are included. Duplicated from [`elements`](super::elements)
+/// module for bundle isolation.
+///
+/// Accepts `includeHidden`; when true, elements failing the visibility check
+/// are kept and every element reports `visible` and `aria_hidden`. Elements
+/// repeating an earlier kind and selector are dropped but tallied in `count`.
 pub(crate) const EXTRACT_ELEMENTS_JS: &str = r#"
-(() => {
+((includeHidden) => {
     const elements = [];
-    const seen = new Set();
+    const seen = new Map();
     
     function getStableSelector(el) {
         if (el.id) return '#' + CSS.escape(el.id);
@@ -346,73 +503,158 @@ pub(crate) const EXTRACT_ELEMENTS_JS: &str = r#"
         return true;
     }
     
+    function queryAllDeep(selector) {
+        const found = [];
+        const roots = [document];
+        while (roots.length > 0) {
+            const root = roots.shift();
+            root.querySelectorAll(selector).forEach(el => found.push(el));
+            root.querySelectorAll('*').forEach(el => {
+                if (el.shadowRoot) roots.push(el.shadowRoot);
+            });
+        }
+        return found;
+    }
+    
+    function formState(el) {
+        if (el.tagName === 'INPUT' && (el.type === 'checkbox' || el.type === 'radio')) {
+            return { value: null, checked: el.checked };
+        }
+        if (el.tagName === 'INPUT' || el.tagName === 'SELECT' || el.tagName === 'TEXTAREA') {
+            if (el.type === 'submit' || el.type === 'button' || el.type === 'password') {
+                return { value: null, checked: null };
+            }
+            return { value: el.value, checked: null };
+        }
+        return { value: null, checked: null };
+    }
+    
+    function isAriaHidden(el) {
+        for (let node = el; node; node = node.parentElement || (node.getRootNode() && node.getRootNode().host)) {
+            if (node.getAttribute && node.getAttribute('aria-hidden') === 'true') return true;
+        }
+        return false;
+    }
+    
     function addElement(el, kind, extra) {
-        if (!isVisible(el)) return;
+        const visible = isVisible(el);
+        if (!visible && !includeHidden) return;
         const selector = getStableSelector(el);
         const key = kind + ':' + selector;
-        if (seen.has(key)) return;
-        seen.add(key);
+        if (seen.has(key)) {
+            seen.get(key).count += 1;
+            return;
+        }
         
         const label = getLabel(el) || '(unlabeled)';
         const rect = el.getBoundingClientRect();
+        const state = formState(el);
         
-        elements.push({
+        const entry = {
             kind: kind,
             label: label.substring(0, 60),
             selector: selector,
             extra: extra || null,
+            value: state.value,
+            checked: state.checked,
             x: Math.round(rect.x),
             y: Math.round(rect.y),
+            page_x: Math.round(rect.x + window.scrollX),
+            page_y: Math.round(rect.y + window.scrollY),
             width: Math.round(rect.width),
-            height: Math.round(rect.height)
-        });
+            height: Math.round(rect.height),
+            visible: includeHidden ? visible : null,
+            aria_hidden: includeHidden ? isAriaHidden(el) : null,
+            count: 1
+        };
+        seen.set(key, entry);
+        elements.push(entry);
     }
     
-    document.querySelectorAll('button, [role="button"], input[type="submit"], input[type="button"]').forEach(el => {
+    queryAllDeep('button, [role="button"], input[type="submit"], input[type="button"]').forEach(el => {
         addElement(el, 'button', null);
     });
     
-    document.querySelectorAll('a[href]').forEach(el => {
+    queryAllDeep('a[href]').forEach(el => {
         const href = el.getAttribute('href');
         if (href && !href.startsWith('javascript:') && !href.startsWith('#')) {
             addElement(el, 'link', null);
         }
     });
     
-    document.querySelectorAll('input:not([type="hidden"]):not([type="submit"]):not([type="button"]):not([type="checkbox"]):not([type="radio"])').forEach(el => {
+    queryAllDeep('input:not([type="hidden"]):not([type="submit"]):not([type="button"]):not([type="checkbox"]):not([type="radio"])').forEach(el => {
         addElement(el, 'input', el.type || 'text');
     });
     
-    document.querySelectorAll('textarea').forEach(el => {
+    queryAllDeep('textarea').forEach(el => {
         addElement(el, 'textarea', null);
     });
     
-    document.querySelectorAll('select').forEach(el => {
+    queryAllDeep('select').forEach(el => {
         addElement(el, 'select', null);
     });
     
-    document.querySelectorAll('input[type="checkbox"]').forEach(el => {
+    queryAllDeep('input[type="checkbox"]').forEach(el => {
         addElement(el, 'checkbox', el.checked ? 'checked' : 'unchecked');
     });
     
-    document.querySelectorAll('input[type="radio"]').forEach(el => {
+    queryAllDeep('input[type="radio"]').forEach(el => {
         addElement(el, 'radio', el.checked ? 'checked' : 'unchecked');
     });
     
     return elements;
-})()
+})
 "#;
 
 /// Extracts interactive elements unless `text_only` mode is enabled.
-async fn extract_elements_if_needed(session: &SessionHandle, text_only: bool) -> Result<Vec<InteractiveElement>> {
+async fn extract_elements_if_needed(
+	session: &SessionHandle,
+	text_only: bool,
+	include_hidden: bool,
+	dedup_by: Option<SnapshotDedup>,
+) -> Result<Vec<InteractiveElement>> {
 	if text_only {
 		return Ok(Vec::new());
 	}
 
-	let elements_js = format!("JSON.stringify({})", EXTRACT_ELEMENTS_JS);
+	let elements_js = format!("JSON.stringify({}({}))", EXTRACT_ELEMENTS_JS, include_hidden);
 	let raw_elements: Vec<RawElement> = serde_json::from_str(&session.page().evaluate_value(&elements_js).await?)?;
 
-	Ok(raw_elements.into_iter().map(Into::into).collect())
+	Ok(match dedup_by {
+		Some(by) => dedup_elements(raw_elements, by),
+		None => raw_elements.into_iter().map(Into::into).collect(),
+	})
+}
+
+/// Collapses elements sharing `by` into their first occurrence, summing counts.
+///
+/// Groups keep document order; `count` is set only on entries standing for
+/// more than one element.
+fn dedup_elements(raw_elements: Vec<RawElement>, by: SnapshotDedup) -> Vec<InteractiveElement> {
+	let mut elements: Vec<InteractiveElement> = Vec::new();
+	let mut groups: HashMap<(String, String), usize> = HashMap::new();
+
+	for raw in raw_elements {
+		let occurrences = raw.count.unwrap_or(1).max(1);
+		let key = match by {
+			SnapshotDedup::Name if raw.label.is_empty() || raw.label == "(unlabeled)" => None,
+			SnapshotDedup::Name => Some((raw.kind.clone(), raw.label.clone())),
+			SnapshotDedup::Selector => Some((raw.kind.clone(), raw.selector.clone())),
+		};
+
+		if let Some(&index) = key.as_ref().and_then(|key| groups.get(key)) {
+			*elements[index].count.get_or_insert(1) += occurrences;
+			continue;
+		}
+		if let Some(key) = key {
+			groups.insert(key, elements.len());
+		}
+		let mut element = InteractiveElement::from(raw);
+		element.count = (occurrences > 1).then_some(occurrences);
+		elements.push(element);
+	}
+
+	elements
 }
 
 impl From<RawElement> for InteractiveElement {
@@ -428,10 +670,17 @@ impl From<RawElement> for InteractiveElement {
 			href: None,
 			name: e.extra,
 			id: None,
+			value: e.value,
+			checked: e.checked,
 			x: e.x,
 			y: e.y,
+			page_x: e.page_x,
+			page_y: e.page_y,
 			width: e.width,
 			height: e.height,
+			visible: e.visible,
+			aria_hidden: e.aria_hidden,
+			count: None,
 		}
 	}
 }
@@ -458,6 +707,33 @@ mod tests {
 		assert_eq!(raw.max_text_length, Some(2000));
 	}
 
+	fn resolve_env_state(max_text_length: Option<usize>) -> crate::context_store::ContextState {
+		let mut state = crate::context_store::test_state();
+		state.config.defaults.max_text_length = max_text_length;
+		crate::context_store::ContextState::test_new(state, "ws1".to_string(), "default".to_string())
+	}
+
+	#[test]
+	fn resolve_uses_config_default_when_flag_omitted() {
+		let ctx_state = resolve_env_state(Some(20_000));
+		let env = ResolveEnv::new(&ctx_state, false, SnapshotCommand::NAME);
+
+		let raw: SnapshotRaw = serde_json::from_str(r#"{"url": "https://example.com"}"#).unwrap();
+		assert_eq!(raw.resolve(&env).unwrap().max_text_length, 20_000);
+
+		let raw: SnapshotRaw = serde_json::from_str(r#"{"url": "https://example.com", "maxTextLength": 100}"#).unwrap();
+		assert_eq!(raw.resolve(&env).unwrap().max_text_length, 100);
+	}
+
+	#[test]
+	fn resolve_falls_back_to_builtin_default() {
+		let ctx_state = resolve_env_state(None);
+		let env = ResolveEnv::new(&ctx_state, false, SnapshotCommand::NAME);
+
+		let raw: SnapshotRaw = serde_json::from_str(r#"{"url": "https://example.com"}"#).unwrap();
+		assert_eq!(raw.resolve(&env).unwrap().max_text_length, DEFAULT_MAX_TEXT_LENGTH);
+	}
+
 	#[test]
 	fn deserialize_empty_uses_defaults() {
 		let raw: SnapshotRaw = serde_json::from_str("{}").unwrap();
@@ -466,5 +742,57 @@ mod tests {
 		assert_eq!(raw.text_only, None);
 		assert_eq!(raw.full, None);
 		assert_eq!(raw.max_text_length, None);
+		assert_eq!(raw.baseline, None);
+		assert!(!raw.update_baseline);
+		assert!(!raw.include_hidden);
+		assert_eq!(raw.dedup_by, None);
+	}
+
+	fn raw_element(kind: &str, label: &str, selector: &str) -> RawElement {
+		serde_json::from_value(serde_json::json!({ "kind": kind, "label": label, "selector": selector, "extra": null })).unwrap()
+	}
+
+	#[test]
+	fn dedup_by_name_collapses_identical_labels() {
+		let mut raw: Vec<RawElement> = (1..=5)
+			.map(|i| raw_element("button", "Add to cart", &format!("button:nth-of-type({i})")))
+			.collect();
+		raw.push(raw_element("link", "Add to cart", "a"));
+		raw.push(raw_element("input", "(unlabeled)", "input:nth-of-type(1)"));
+		raw.push(raw_element("input", "(unlabeled)", "input:nth-of-type(2)"));
+
+		let elements = dedup_elements(raw, SnapshotDedup::Name);
+		assert_eq!(elements.len(), 4);
+		assert_eq!(elements[0].selector, "button:nth-of-type(1)");
+		assert_eq!(elements[0].count, Some(5));
+		assert_eq!(elements[1].tag, "link");
+		assert_eq!(elements[1].count, None);
+		assert!(elements[2..].iter().all(|el| el.count.is_none()));
+	}
+
+	#[test]
+	fn dedup_by_selector_keeps_distinct_selectors() {
+		let mut repeated = raw_element("button", "Add to cart", "button:has-text(\"Add to cart\")");
+		repeated.count = Some(5);
+		let raw = vec![repeated, raw_element("button", "Add to cart", "#special")];
+
+		let elements = dedup_elements(raw, SnapshotDedup::Selector);
+		assert_eq!(elements.len(), 2);
+		assert_eq!(elements[0].count, Some(5));
+		assert_eq!(elements[1].count, None);
+	}
+
+	#[test]
+	fn resolve_rejects_update_without_baseline() {
+		let ctx_state = resolve_env_state(None);
+		let env = ResolveEnv::new(&ctx_state, false, SnapshotCommand::NAME);
+
+		let raw: SnapshotRaw = serde_json::from_str(r#"{"url": "https://example.com", "updateBaseline": true}"#).unwrap();
+		assert!(raw.resolve(&env).is_err());
+
+		let raw: SnapshotRaw = serde_json::from_str(r#"{"url": "https://example.com", "baseline": "b.json", "updateBaseline": true}"#).unwrap();
+		let resolved = raw.resolve(&env).unwrap();
+		assert_eq!(resolved.baseline, Some(PathBuf::from("b.json")));
+		assert!(resolved.update_baseline);
 	}
 }
@@ -0,0 +1,202 @@
+//! Batch-only streaming variant of `page.elements`.
+//!
+//! Extracts the same interactive elements as `page.elements`, but parks the
+//! result in the page and pulls it back in fixed-size chunks. Each chunk is
+//! written as its own NDJSON response sharing the request id, so neither the
+//! CLI nor the consuming agent holds the full element list at once.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw -f ndjson batch <<'EOF'
+//! {"schemaVersion":5,"requestId":"1","op":"page.stream_elements","input":{"url":"https://example.com","chunkSize":50}}
+//! EOF
+//! ```
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, ExecMode, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::commands::page::elements::{EXTRACT_ELEMENTS_JS, RawElement};
+use crate::error::{PwError, Result};
+use crate::output::InteractiveElement;
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Elements per chunk when the request omits `chunkSize`.
+const DEFAULT_CHUNK_SIZE: usize = 100;
+
+/// Page global holding extracted elements between chunk reads.
+const STASH: &str = "window.__pwStreamElements";
+
+/// Raw inputs from CLI or batch JSON before resolution.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamElementsRaw {
+	/// Target URL (positional)
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default, alias = "url_flag")]
+	pub url_flag: Option<String>,
+
+	/// Elements per streamed chunk (default: 100)
+	#[arg(long)]
+	#[serde(default, alias = "chunk_size")]
+	pub chunk_size: Option<usize>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct StreamElementsResolved {
+	/// Navigation target (URL or current page).
+	pub target: ResolvedTarget,
+
+	/// Elements per streamed chunk.
+	pub chunk_size: usize,
+}
+
+impl Resolve for StreamElementsRaw {
+	type Output = StreamElementsResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, self.url_flag, env, TargetPolicy::AllowCurrentPage)?;
+		let chunk_size = self.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+		if chunk_size == 0 {
+			return Err(PwError::Context("chunkSize must be greater than 0".into()));
+		}
+
+		Ok(StreamElementsResolved { target, chunk_size })
+	}
+}
+
+/// One streamed chunk of elements.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ElementsChunk {
+	/// Index of the first element in this chunk.
+	offset: usize,
+	elements: Vec<InteractiveElement>,
+}
+
+/// Summary returned in the terminal response.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamElementsData {
+	/// Total elements streamed across all chunks.
+	count: usize,
+	/// Number of chunk responses emitted before this one.
+	chunks: usize,
+}
+
+pub struct StreamElementsCommand;
+
+impl CommandDef for StreamElementsCommand {
+	const NAME: &'static str = "page.stream_elements";
+
+	type Raw = StreamElementsRaw;
+	type Resolved = StreamElementsResolved;
+	type Data = StreamElementsData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
+	fn validate_mode(_raw: &Self::Raw, mode: ExecMode) -> Result<()> {
+		if mode != ExecMode::Batch {
+			return Err(PwError::UnsupportedMode(
+				"command 'page.stream_elements' streams chunked responses and is only available in batch/run mode".to_string(),
+			));
+		}
+		Ok(())
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, chunk_size = args.chunk_size, browser = %exec.ctx.browser, "stream elements");
+
+			let chunks = exec
+				.chunks
+				.clone()
+				.ok_or_else(|| PwError::UnsupportedMode("command 'page.stream_elements' requires a streaming batch session".to_string()))?;
+			let chunk_size = args.chunk_size;
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::NetworkIdle,
+				ArtifactsPolicy::OnError { command: "stream_elements" },
+				move |session, flow| {
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let page = session.page();
+						let count: usize = serde_json::from_str(
+							&page
+								.evaluate_value(&format!("JSON.stringify(({STASH} = {EXTRACT_ELEMENTS_JS}).length)"))
+								.await?,
+						)?;
+
+						let mut sent = 0;
+						for offset in (0..count).step_by(chunk_size) {
+							let js = format!("JSON.stringify({STASH}.slice({offset}, {}))", offset + chunk_size);
+							let raw: Vec<RawElement> = serde_json::from_str(&page.evaluate_value(&js).await?)?;
+							let chunk = ElementsChunk {
+								offset,
+								elements: raw.into_iter().map(InteractiveElement::from).collect(),
+							};
+							chunks
+								.send(serde_json::to_value(chunk)?)
+								.await
+								.map_err(|_| PwError::Context("stream consumer went away".into()))?;
+							sent += 1;
+						}
+
+						page.evaluate_value(&format!("JSON.stringify(delete {STASH})")).await?;
+
+						Ok(StreamElementsData { count, chunks: sent })
+					})
+				},
+			)
+			.await?;
+
+			let inputs = standard_inputs(&args.target, None, None, None, Some(serde_json::json!({ "chunkSize": args.chunk_size })));
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta(&args.target, None, None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn stream_elements_raw_deserialize_chunk_size() {
+		let raw: StreamElementsRaw = serde_json::from_str(r#"{"chunkSize": 5}"#).unwrap();
+		assert_eq!(raw.chunk_size, Some(5));
+	}
+
+	#[test]
+	fn stream_elements_rejects_cli_mode() {
+		let err = StreamElementsCommand::validate_mode(&StreamElementsRaw::default(), ExecMode::Cli).unwrap_err();
+		assert!(matches!(err, PwError::UnsupportedMode(_)));
+		assert!(StreamElementsCommand::validate_mode(&StreamElementsRaw::default(), ExecMode::Batch).is_ok());
+	}
+}
@@ -1,11 +1,11 @@
 //! Text content extraction command.
 
 use clap::Args;
-use pw_rs::WaitUntil;
+use pw_rs::{WaitForSelectorState, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_and_selector, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_and_selector, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::{PwError, Result};
@@ -34,6 +34,46 @@ pub struct TextRaw {
 	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
 	#[serde(default, alias = "selector_flag")]
 	pub selector_flag: Option<String>,
+
+	/// Trim leading and trailing whitespace (default: true)
+	#[arg(long)]
+	#[serde(default)]
+	pub trim: Option<bool>,
+
+	/// Collapse whitespace runs, including newlines, into single spaces (default: false)
+	#[arg(long)]
+	#[serde(default, alias = "collapse_whitespace")]
+	pub collapse_whitespace: Option<bool>,
+
+	/// Use rendered `innerText` (true, default) or raw `textContent` (false), which includes hidden text
+	#[arg(long)]
+	#[serde(default, alias = "inner_text")]
+	pub inner_text: Option<bool>,
+
+	/// Read only the light DOM, skipping shadow roots and slotted content (default: false)
+	#[arg(long)]
+	#[serde(default, alias = "light_dom_only")]
+	pub light_dom_only: Option<bool>,
+}
+
+/// Text normalization applied after extraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextOptions {
+	pub trim: bool,
+	pub collapse_whitespace: bool,
+	pub inner_text: bool,
+	pub light_dom_only: bool,
+}
+
+impl Default for TextOptions {
+	fn default() -> Self {
+		Self {
+			trim: true,
+			collapse_whitespace: false,
+			inner_text: true,
+			light_dom_only: false,
+		}
+	}
 }
 
 /// Resolved inputs ready for execution.
@@ -41,6 +81,7 @@ pub struct TextRaw {
 pub struct TextResolved {
 	pub target: ResolvedTarget,
 	pub selector: String,
+	pub options: TextOptions,
 }
 
 impl Resolve for TextRaw {
@@ -48,7 +89,14 @@ impl Resolve for TextRaw {
 
 	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
 		let (target, selector) = resolve_target_and_selector(self.url, self.selector, self.url_flag, self.selector_flag, env, None)?;
-		Ok(TextResolved { target, selector })
+		let defaults = TextOptions::default();
+		let options = TextOptions {
+			trim: self.trim.unwrap_or(defaults.trim),
+			collapse_whitespace: self.collapse_whitespace.unwrap_or(defaults.collapse_whitespace),
+			inner_text: self.inner_text.unwrap_or(defaults.inner_text),
+			light_dom_only: self.light_dom_only.unwrap_or(defaults.light_dom_only),
+		};
+		Ok(TextResolved { target, selector, options })
 	}
 }
 
@@ -61,6 +109,10 @@ impl CommandDef for TextCommand {
 	type Resolved = TextResolved;
 	type Data = TextData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -70,6 +122,7 @@ impl CommandDef for TextCommand {
 			info!(target = "pw", url = %url_display, selector = %args.selector, browser = %exec.ctx.browser, "get text");
 
 			let selector = args.selector.clone();
+			let options = args.options;
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let selector = selector.clone();
@@ -77,18 +130,30 @@ impl CommandDef for TextCommand {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
 					let locator = session.page().locator(&selector).await;
+					match locator
+						.first()
+						.wait_for(WaitForSelectorState::Attached, flow.timeout_ms.map(|ms| ms as f64))
+						.await
+					{
+						Ok(()) => {}
+						Err(err) if err.is_timeout() => return Err(PwError::ElementNotFound { selector }),
+						Err(err) => return Err(err.into()),
+					}
 					let count = locator.count().await?;
 
 					if count == 0 {
 						return Err(PwError::ElementNotFound { selector });
 					}
 
-					let text = locator.inner_text().await?;
-					let filtered = filter_garbage(&text);
-					let trimmed = filtered.trim().to_string();
+					let text = match (options.inner_text, options.light_dom_only) {
+						(true, false) => locator.composed_inner_text().await?,
+						(false, false) => locator.text_content().await?.unwrap_or_default(),
+						(true, true) => locator.inner_text().await?,
+						(false, true) => locator.light_text_content().await?.unwrap_or_default(),
+					};
 
 					Ok(TextData {
-						text: trimmed,
+						text: normalize_text(&text, options),
 						selector,
 						match_count: count,
 					})
@@ -96,17 +161,38 @@ impl CommandDef for TextCommand {
 			})
 			.await?;
 
-			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, None);
+			let extra = (args.options != TextOptions::default()).then(|| {
+				serde_json::json!({
+					"trim": args.options.trim,
+					"collapseWhitespace": args.options.collapse_whitespace,
+					"innerText": args.options.inner_text,
+					"lightDomOnly": args.options.light_dom_only,
+				})
+			});
+			let inputs = standard_inputs(&args.target, Some(&args.selector), None, None, extra);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, Some(&args.selector), None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
 }
 
+/// Drops garbage lines, then applies whitespace collapsing and trimming per `options`.
+fn normalize_text(text: &str, options: TextOptions) -> String {
+	let filtered = filter_garbage(text);
+	let collapsed = if options.collapse_whitespace {
+		filtered.split_whitespace().collect::<Vec<_>>().join(" ")
+	} else {
+		filtered
+	};
+	if options.trim { collapsed.trim().to_string() } else { collapsed }
+}
+
 /// Heuristically detect if a line looks like minified JavaScript or garbage
 fn is_garbage_line(line: &str) -> bool {
 	let trimmed = line.trim();
@@ -235,6 +321,35 @@ mod tests {
 		assert_eq!(output, "Hello\n\nWorld");
 	}
 
+	#[test]
+	fn normalize_text_defaults_trim_only() {
+		assert_eq!(normalize_text("  Hello\n  world  ", TextOptions::default()), "Hello\n  world");
+	}
+
+	#[test]
+	fn normalize_text_collapses_and_keeps_edges() {
+		let collapse = TextOptions {
+			collapse_whitespace: true,
+			..TextOptions::default()
+		};
+		assert_eq!(normalize_text("  Hello\n\t  world  ", collapse), "Hello world");
+
+		let raw = TextOptions {
+			trim: false,
+			..TextOptions::default()
+		};
+		assert_eq!(normalize_text("  Hello  ", raw), "  Hello  ");
+	}
+
+	#[test]
+	fn text_raw_deserializes_normalization_options() {
+		let raw: TextRaw = serde_json::from_str(r#"{"selector": "p", "collapseWhitespace": true, "innerText": false, "lightDomOnly": true}"#).unwrap();
+		assert_eq!(raw.collapse_whitespace, Some(true));
+		assert_eq!(raw.inner_text, Some(false));
+		assert_eq!(raw.light_dom_only, Some(true));
+		assert_eq!(raw.trim, None);
+	}
+
 	#[test]
 	fn text_raw_deserialize() {
 		let json = r#"{"url": "https://example.com", "selector": "main"}"#;
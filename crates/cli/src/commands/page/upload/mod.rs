@@ -0,0 +1,205 @@
+//! File upload command.
+//!
+//! Sets the files of an `<input type=file>` element, mirroring Playwright's
+//! `locator.setInputFiles()`.
+//!
+//! # Examples
+//!
+//! ```bash
+//! pw exec page.upload --input '{"selector":"input[type=file]","files":["report.pdf","photo.png"]}'
+//! ```
+
+use std::path::PathBuf;
+
+use clap::Args;
+use pw_rs::WaitUntil;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::commands::contract::{dry_run_inputs, resolve_match_index, resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
+use crate::commands::flow::page::run_page_flow;
+use crate::error::{PwError, Result};
+use crate::session_helpers::ArtifactsPolicy;
+use crate::target::{ResolveEnv, ResolvedTarget, TargetPolicy};
+
+/// Raw inputs from CLI or batch JSON before resolution.
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadRaw {
+	/// Files to upload (repeatable)
+	#[serde(default)]
+	pub files: Vec<PathBuf>,
+
+	/// CSS selector for the file input
+	#[arg(long = "selector", short = 's', value_name = "SELECTOR")]
+	#[serde(default)]
+	pub selector: Option<String>,
+
+	/// Target URL (named alternative)
+	#[arg(long = "url", short = 'u', value_name = "URL")]
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Act on the first match when the selector matches several elements
+	#[arg(long, conflicts_with = "nth")]
+	#[serde(default)]
+	pub first: bool,
+
+	/// Act on the Nth match (0-based) when the selector matches several elements
+	#[arg(long, value_name = "N")]
+	#[serde(default)]
+	pub nth: Option<usize>,
+}
+
+/// Resolved inputs ready for execution.
+#[derive(Debug, Clone)]
+pub struct UploadResolved {
+	/// Navigation target (URL or current page).
+	pub target: ResolvedTarget,
+
+	/// CSS selector for the file input.
+	pub selector: String,
+
+	/// Absolute paths of existing files to upload.
+	pub files: Vec<PathBuf>,
+
+	/// Zero-based match index from `--first`/`--nth`; `None` keeps strict matching.
+	pub nth: Option<usize>,
+}
+
+impl Resolve for UploadRaw {
+	type Output = UploadResolved;
+
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let target = resolve_target_from_url_pair(self.url, None, env, TargetPolicy::AllowCurrentPage)?;
+		let selector = env.resolve_selector(self.selector, None)?;
+		let files = resolve_upload_files(self.files)?;
+		let nth = resolve_match_index(self.first, self.nth)?;
+
+		Ok(UploadResolved { target, selector, files, nth })
+	}
+}
+
+/// Requires at least one path and makes each absolute, rejecting missing files.
+fn resolve_upload_files(files: Vec<PathBuf>) -> Result<Vec<PathBuf>> {
+	if files.is_empty() {
+		return Err(PwError::Context("page.upload requires at least one file".to_string()));
+	}
+	files
+		.into_iter()
+		.map(|file| {
+			if !file.is_file() {
+				return Err(PwError::Context(format!("upload file not found: {}", file.display())));
+			}
+			Ok(std::path::absolute(&file)?)
+		})
+		.collect()
+}
+
+/// Output data for the upload command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadData {
+	pub selector: String,
+	pub files: Vec<PathBuf>,
+	/// `input.files.length` read back from the page after the upload.
+	pub file_count: usize,
+}
+
+pub struct UploadCommand;
+
+impl CommandDef for UploadCommand {
+	const NAME: &'static str = "page.upload";
+
+	type Raw = UploadRaw;
+	type Resolved = UploadResolved;
+	type Data = UploadData;
+
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, Some(&resolved.selector))
+	}
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let url_display = args.target.url_str().unwrap_or("<current page>");
+			info!(target = "pw", url = %url_display, selector = %args.selector, files = args.files.len(), "upload");
+
+			let selector = args.selector.clone();
+			let files = args.files.clone();
+			let nth = args.nth;
+
+			let data = run_page_flow(
+				&mut exec,
+				&args.target,
+				WaitUntil::Load,
+				ArtifactsPolicy::OnError { command: "page.upload" },
+				move |session, flow| {
+					let selector = selector.clone();
+					let files = files.clone();
+					Box::pin(async move {
+						session.goto_target(&flow.target, flow.timeout_ms).await?;
+
+						let locator = session.page().locator(&selector).await;
+						let locator = match nth {
+							Some(index) => locator.nth(index as i32),
+							None => locator,
+						};
+						let paths: Vec<&PathBuf> = files.iter().collect();
+						locator.set_input_files_multiple(&paths, None).await?;
+
+						let file_count = locator.evaluate("el => el.files ? el.files.length : 0", None).await?.as_u64().unwrap_or(0) as usize;
+
+						Ok(UploadData { selector, files, file_count })
+					})
+				},
+			)
+			.await?;
+
+			let inputs = standard_inputs(
+				&args.target,
+				Some(&args.selector),
+				None,
+				None,
+				Some(serde_json::json!({ "files": args.files, "nth": args.nth })),
+			);
+
+			Ok(CommandOutcome {
+				inputs,
+				data,
+				delta: standard_delta(&args.target, Some(&args.selector), None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn upload_raw_deserialize_from_json() {
+		let json = r#"{"selector": "input[type=file]", "files": ["a.txt", "b.png"], "nth": 1}"#;
+		let raw: UploadRaw = serde_json::from_str(json).unwrap();
+		assert_eq!(raw.selector.as_deref(), Some("input[type=file]"));
+		assert_eq!(raw.files, vec![PathBuf::from("a.txt"), PathBuf::from("b.png")]);
+		assert_eq!(raw.nth, Some(1));
+	}
+
+	#[test]
+	fn upload_files_must_exist() {
+		let dir = tempfile::tempdir().unwrap();
+		let file = dir.path().join("a.txt");
+		std::fs::write(&file, "a").unwrap();
+
+		assert_eq!(resolve_upload_files(vec![file.clone()]).unwrap(), vec![file.clone()]);
+		assert!(matches!(resolve_upload_files(Vec::new()), Err(PwError::Context(_))));
+		let err = resolve_upload_files(vec![file, dir.path().join("missing.txt")]).unwrap_err();
+		assert!(err.to_string().contains("missing.txt"), "{err}");
+	}
+}
@@ -61,6 +61,8 @@ impl CommandDef for ProfileListCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -118,6 +120,8 @@ impl CommandDef for ProfileShowCommand {
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -189,6 +193,8 @@ impl CommandDef for ProfileSetCommand {
 					"written": true,
 				}),
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -248,6 +254,8 @@ impl CommandDef for ProfileDeleteCommand {
 					"removed": removed,
 				}),
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
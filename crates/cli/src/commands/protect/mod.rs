@@ -56,6 +56,8 @@ impl CommandDef for ProtectAddCommand {
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -110,6 +112,8 @@ impl CommandDef for ProtectRemoveCommand {
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -155,6 +159,8 @@ impl CommandDef for ProtectListCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
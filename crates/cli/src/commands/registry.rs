@@ -1,7 +1,7 @@
 //! Command registry and generated dispatch glue.
 
 #[allow(unused_imports, reason = "registry module re-exports generated command graph symbols")]
-pub use crate::commands::graph::{CommandId, CommandMeta, all_commands, command_meta, command_name, lookup_command, run_command};
+pub use crate::commands::graph::{CommandId, CommandMeta, all_commands, command_args, command_meta, command_name, lookup_command, run_command};
 
 /// Looks up only canonical command ids.
 pub fn lookup_command_exact(op: &str) -> Option<CommandId> {
@@ -0,0 +1,308 @@
+//! Line-oriented interactive shell over the command dispatch.
+//!
+//! Each line names an operation followed by positional arguments and
+//! `key=value` / `--flag` options:
+//!
+//! ```text
+//! pw> navigate example.com
+//! pw> text h1
+//! pw> fill "input[name=q]" "rust playwright"
+//! pw> snapshot --text-only
+//! pw> coords-all li sort=top_to_bottom
+//! ```
+//!
+//! Operation names resolve through the command graph; a bare name is also
+//! tried under the `page.` namespace, so `text` means `page.text`. Tokens with
+//! a URL scheme fill `url`; remaining positionals fill the command's slots in
+//! order (`selector` for most commands). `navigate` accepts a bare host and
+//! prepends `https://`. A `key=value` word is an option only when `key` is one
+//! of the command's input fields; anything else, and any quoted word, is
+//! positional, so `text "nth=1"` and `text id=main` both select by text.
+//! Option values are parsed as JSON when possible, so `nth=2` and
+//! `first=true` arrive typed; kebab-case keys become camelCase.
+//!
+//! Requests run through the same executor as `pw exec`, so profile context
+//! (last URL, selector, session) carries from one line to the next.
+
+use std::io::Write;
+
+use serde_json::{Map, Value};
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use crate::cli::ReplArgs;
+use crate::commands::def::ExecMode;
+use crate::commands::engine::{default_fail_on_console_error, execute_request};
+use crate::commands::registry::{CommandId, all_commands, command_args, command_name, lookup_command};
+use crate::error::{PwError, Result};
+use crate::output::OutputFormat;
+use crate::protocol::{CommandRequest, RuntimeSpec, SCHEMA_VERSION, print_response};
+
+/// One parsed REPL line.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ReplLine {
+	Empty,
+	Help,
+	Quit,
+	Command { op: &'static str, input: Value },
+}
+
+/// Reads lines from stdin until EOF or `quit`. Returns `true` when every command succeeded.
+pub async fn run_repl(args: ReplArgs, format: OutputFormat, fail_on_console_error: bool) -> Result<bool> {
+	let mut lines = BufReader::new(tokio::io::stdin()).lines();
+	let mut all_ok = true;
+
+	loop {
+		eprint!("pw> ");
+		let _ = std::io::stderr().flush();
+
+		let Some(line) = lines.next_line().await? else {
+			eprintln!();
+			return Ok(all_ok);
+		};
+
+		match parse_line(&line) {
+			Ok(ReplLine::Empty) => {}
+			Ok(ReplLine::Quit) => return Ok(all_ok),
+			Ok(ReplLine::Help) => print_help(),
+			Ok(ReplLine::Command { op, input }) => {
+				let mut request = CommandRequest {
+					schema_version: SCHEMA_VERSION,
+					request_id: None,
+					op: op.to_string(),
+					input,
+					runtime: Some(RuntimeSpec {
+						profile: args.profile.clone(),
+						overrides: None,
+					}),
+					dry_run: false,
+				};
+				if fail_on_console_error {
+					default_fail_on_console_error(&mut request);
+				}
+				let response = execute_request(request, args.profile.clone(), ExecMode::Interactive, None, None).await;
+				all_ok &= response.ok;
+				print_response(&response, format);
+			}
+			Err(err) => eprintln!("error: {}", err.to_command_error().message),
+		}
+	}
+}
+
+fn print_help() {
+	eprintln!("usage: <op> [positional...] [key=value...] [--flag]; `quit` exits");
+	eprintln!("operations:");
+	for meta in all_commands() {
+		eprintln!("  {}", meta.canonical);
+	}
+}
+
+/// Parses one REPL line into a builtin or a command request.
+pub(crate) fn parse_line(line: &str) -> Result<ReplLine> {
+	let words = split_words(line)?;
+	let Some((name, rest)) = words.split_first() else {
+		return Ok(ReplLine::Empty);
+	};
+	let name = &name.text;
+
+	match name.as_str() {
+		"quit" | "exit" => return Ok(ReplLine::Quit),
+		"help" | "?" => return Ok(ReplLine::Help),
+		_ => {}
+	}
+
+	let id = lookup_command(name)
+		.or_else(|| lookup_command(&format!("page.{name}")))
+		.ok_or_else(|| PwError::Context(format!("unknown command '{name}' (try `help`)")))?;
+	let op = command_name(id);
+	let options = option_keys(id);
+
+	let mut input = Map::new();
+	let mut slots = positional_slots(op).iter();
+	for Word { text: word, quoted } in rest {
+		if !quoted {
+			if let Some(flag) = word.strip_prefix("--") {
+				let (key, value) = match flag.split_once('=') {
+					Some((key, value)) => (key, parse_value(value)),
+					None => (flag, Value::Bool(true)),
+				};
+				input.insert(camel_case(key), value);
+				continue;
+			}
+			if let Some((key, value)) = word.split_once('=').filter(|(key, _)| options.contains(&camel_case(key))) {
+				input.insert(camel_case(key), parse_value(value));
+				continue;
+			}
+		}
+
+		if has_scheme(word) && !input.contains_key("url") {
+			input.insert("url".to_string(), Value::String(word.clone()));
+		} else {
+			let slot = slots
+				.find(|slot| !input.contains_key(**slot))
+				.ok_or_else(|| PwError::Context(format!("unexpected argument '{word}' for {op}")))?;
+			let value = if *slot == "url" && !has_scheme(word) {
+				format!("https://{word}")
+			} else {
+				word.clone()
+			};
+			input.insert(slot.to_string(), Value::String(value));
+		}
+	}
+
+	Ok(ReplLine::Command {
+		op,
+		input: Value::Object(input),
+	})
+}
+
+/// Input fields filled by bare positional words, in order.
+fn positional_slots(op: &str) -> &'static [&'static str] {
+	match op {
+		"navigate" => &["url"],
+		"fill" => &["selector", "text"],
+		"page.eval" => &["expression"],
+		"wait" => &["condition"],
+		"screenshot" => &["output"],
+		_ => &["selector"],
+	}
+}
+
+/// Input field names of command `id`, camelCased as in a request.
+fn option_keys(id: CommandId) -> Vec<String> {
+	command_args(id).get_arguments().map(|arg| camel_case(arg.get_id().as_str())).collect()
+}
+
+/// One word of a REPL line.
+#[derive(Debug, Clone, PartialEq)]
+struct Word {
+	text: String,
+	/// Any part of the word was quoted, so it is taken literally as a positional.
+	quoted: bool,
+}
+
+/// Splits `line` on whitespace, honoring single and double quotes.
+fn split_words(line: &str) -> Result<Vec<Word>> {
+	let mut words = Vec::new();
+	let mut current = String::new();
+	let mut in_word = false;
+	let mut quoted = false;
+	let mut quote = None;
+
+	for c in line.chars() {
+		match (quote, c) {
+			(Some(q), c) if c == q => quote = None,
+			(Some(_), c) => current.push(c),
+			(None, '"' | '\'') => {
+				quote = Some(c);
+				in_word = true;
+				quoted = true;
+			}
+			(None, c) if c.is_whitespace() => {
+				if in_word {
+					words.push(Word {
+						text: std::mem::take(&mut current),
+						quoted,
+					});
+					in_word = false;
+					quoted = false;
+				}
+			}
+			(None, c) => {
+				current.push(c);
+				in_word = true;
+			}
+		}
+	}
+	if quote.is_some() {
+		return Err(PwError::Context("unterminated quote".to_string()));
+	}
+	if in_word {
+		words.push(Word { text: current, quoted });
+	}
+	Ok(words)
+}
+
+fn has_scheme(word: &str) -> bool {
+	word.contains("://") || ["data:", "about:", "file:"].iter().any(|prefix| word.starts_with(prefix))
+}
+
+/// Parses an option value as JSON, falling back to a plain string.
+fn parse_value(raw: &str) -> Value {
+	serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+/// Converts `kebab-case` or `snake_case` keys to camelCase.
+fn camel_case(key: &str) -> String {
+	let mut out = String::with_capacity(key.len());
+	let mut upper = false;
+	for c in key.chars() {
+		if c == '-' || c == '_' {
+			upper = true;
+		} else if upper {
+			out.push(c.to_ascii_uppercase());
+			upper = false;
+		} else {
+			out.push(c);
+		}
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+
+	fn command(line: &str) -> (&'static str, Value) {
+		match parse_line(line).unwrap() {
+			ReplLine::Command { op, input } => (op, input),
+			other => panic!("expected command for {line:?}, got {other:?}"),
+		}
+	}
+
+	#[test]
+	fn maps_short_names_and_positionals() {
+		assert_eq!(command("navigate example.com"), ("navigate", json!({ "url": "https://example.com" })));
+		assert_eq!(command("text h1"), ("page.text", json!({ "selector": "h1" })));
+		assert_eq!(command("page.text h1"), ("page.text", json!({ "selector": "h1" })));
+		assert_eq!(
+			command("click https://example.com button.accept"),
+			("click", json!({ "url": "https://example.com", "selector": "button.accept" }))
+		);
+	}
+
+	#[test]
+	fn quotes_options_and_flags() {
+		assert_eq!(
+			command(r#"fill "input[name=q]" 'rust playwright' nth=1"#),
+			("fill", json!({ "selector": "input[name=q]", "text": "rust playwright", "nth": 1 }))
+		);
+		assert_eq!(
+			command("snapshot --text-only max-text-length=200"),
+			("page.snapshot", json!({ "textOnly": true, "maxTextLength": 200 }))
+		);
+		assert_eq!(
+			command("coords-all li sort=top_to_bottom").1,
+			json!({ "selector": "li", "sort": "top_to_bottom" })
+		);
+	}
+
+	#[test]
+	fn only_known_unquoted_keys_are_options() {
+		assert_eq!(command("text id=main").1, json!({ "selector": "id=main" }));
+		assert_eq!(command(r#"text "nth=1""#).1, json!({ "selector": "nth=1" }));
+		assert_eq!(command("click h1 nth=1").1, json!({ "selector": "h1", "nth": 1 }));
+		assert_eq!(command(r#"fill #q "--not-a-flag""#).1, json!({ "selector": "#q", "text": "--not-a-flag" }));
+	}
+
+	#[test]
+	fn builtins_and_errors() {
+		assert_eq!(parse_line("   ").unwrap(), ReplLine::Empty);
+		assert_eq!(parse_line("quit").unwrap(), ReplLine::Quit);
+		assert_eq!(parse_line("help").unwrap(), ReplLine::Help);
+		assert!(parse_line("frobnicate").is_err());
+		assert!(parse_line("text h1 h2").is_err());
+		assert!(parse_line("text \"h1").is_err());
+	}
+}
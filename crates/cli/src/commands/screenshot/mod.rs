@@ -1,13 +1,13 @@
 //! Screenshot capture command.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::Args;
 use pw_rs::{ScreenshotOptions, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::Result;
@@ -23,7 +23,7 @@ pub struct ScreenshotRaw {
 	#[serde(default)]
 	pub url: Option<String>,
 
-	/// Output file path (uses context or defaults when omitted)
+	/// Output file path; supports `{ts}`, `{host}` and `{n}` tokens (default: screenshot.png)
 	#[arg(short, long, value_name = "FILE")]
 	#[serde(default)]
 	pub output: Option<PathBuf>,
@@ -68,49 +68,40 @@ impl CommandDef for ScreenshotCommand {
 	type Resolved = ScreenshotResolved;
 	type Data = ScreenshotData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
 	{
 		Box::pin(async move {
 			let url_display = args.target.url_str().unwrap_or("<current page>");
+			let path = exec.ctx_state.resolve_output(exec.ctx, &args.output, args.target.url_str().or(exec.last_url));
 			info!(
 				target = "pw",
 				url = %url_display,
-				path = %args.output.display(),
+				path = %path.display(),
 				full_page = %args.full_page,
 				browser = %exec.ctx.browser,
 				"screenshot"
 			);
 
-			if let Some(parent) = args.output.parent() {
-				if !parent.as_os_str().is_empty() && !parent.exists() {
-					std::fs::create_dir_all(parent)?;
-				}
-			}
-
-			let output = args.output.clone();
+			let output = path.clone();
 			let full_page = args.full_page;
 
 			run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let output = output.clone();
 				Box::pin(async move {
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
-
-					let screenshot_opts = ScreenshotOptions {
-						full_page: Some(full_page),
-						..Default::default()
-					};
-
-					session.page().screenshot_to_file(&output, Some(screenshot_opts)).await?;
-
-					Ok(())
+					capture_screenshot(session.page(), &output, full_page).await
 				})
 			})
 			.await?;
 
 			let data = ScreenshotData {
-				path: args.output.clone(),
+				path,
 				full_page: args.full_page,
 				width: None,
 				height: None,
@@ -122,11 +113,29 @@ impl CommandDef for ScreenshotCommand {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, None, Some(&args.output)),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
 }
 
+/// Screenshots `page` to `path`, creating missing parent directories.
+pub(crate) async fn capture_screenshot(page: &pw_rs::Page, path: &Path, full_page: bool) -> Result<()> {
+	if let Some(parent) = path.parent() {
+		if !parent.as_os_str().is_empty() && !parent.exists() {
+			std::fs::create_dir_all(parent)?;
+		}
+	}
+
+	let screenshot_opts = ScreenshotOptions {
+		full_page: Some(full_page),
+		..Default::default()
+	};
+	page.screenshot_to_file(path, Some(screenshot_opts)).await?;
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
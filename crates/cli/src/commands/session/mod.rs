@@ -2,6 +2,7 @@ use clap::Args;
 use pw_rs::WaitUntil;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use url::Url;
 
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ContextDelta, ExecCtx, Resolve};
 use crate::error::{PwError, Result};
@@ -46,6 +47,8 @@ impl CommandDef for SessionStatusCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -86,6 +89,8 @@ impl CommandDef for SessionClearCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -166,6 +171,8 @@ impl CommandDef for SessionStartCommand {
 				},
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -206,7 +213,108 @@ impl CommandDef for SessionStopCommand {
 				inputs: CommandInputs::default(),
 				data,
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
 }
+
+#[derive(Debug, Clone, Default, Args, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionBaseUrlRaw {
+	/// Absolute URL that relative navigation targets resolve against
+	#[arg(value_name = "URL", required_unless_present = "clear")]
+	#[serde(default)]
+	pub url: Option<String>,
+
+	/// Remove the stored base URL
+	#[arg(long, conflicts_with = "url")]
+	#[serde(default)]
+	pub clear: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionBaseUrlResolved {
+	/// `None` clears the stored base URL.
+	pub url: Option<String>,
+}
+
+impl Resolve for SessionBaseUrlRaw {
+	type Output = SessionBaseUrlResolved;
+
+	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		Ok(SessionBaseUrlResolved {
+			url: resolve_base_url(self.url, self.clear)?,
+		})
+	}
+}
+
+/// Validates that `url` is absolute and can have relative paths joined onto it.
+fn resolve_base_url(url: Option<String>, clear: bool) -> Result<Option<String>> {
+	match (url, clear) {
+		(Some(_), true) => Err(PwError::Context("session.base-url accepts either url or clear, not both".to_string())),
+		(None, false) => Err(PwError::Context("session.base-url requires a url (or clear: true)".to_string())),
+		(None, true) => Ok(None),
+		(Some(url), false) => {
+			let parsed = Url::parse(&url).map_err(|e| PwError::Context(format!("invalid base URL '{url}': {e}")))?;
+			if parsed.cannot_be_a_base() {
+				return Err(PwError::Context(format!("'{url}' cannot be used as a base URL")));
+			}
+			Ok(Some(parsed.to_string()))
+		}
+	}
+}
+
+pub struct SessionBaseUrlCommand;
+
+impl CommandDef for SessionBaseUrlCommand {
+	const NAME: &'static str = "session.base-url";
+
+	type Raw = SessionBaseUrlRaw;
+	type Resolved = SessionBaseUrlResolved;
+	type Data = serde_json::Value;
+
+	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
+	where
+		'ctx: 'exec,
+	{
+		Box::pin(async move {
+			let changed = exec.ctx_state.set_base_url(args.url.clone())?;
+
+			Ok(CommandOutcome {
+				inputs: CommandInputs {
+					extra: Some(json!({ "url": args.url })),
+					..Default::default()
+				},
+				data: json!({
+					"baseUrl": args.url,
+					"changed": changed,
+				}),
+				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
+			})
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn base_url_must_be_absolute() {
+		assert_eq!(
+			resolve_base_url(Some("http://localhost:3000".to_string()), false).unwrap().as_deref(),
+			Some("http://localhost:3000/")
+		);
+		assert_eq!(resolve_base_url(None, true).unwrap(), None);
+		assert!(matches!(resolve_base_url(Some("app/home".to_string()), false), Err(PwError::Context(_))));
+		assert!(matches!(
+			resolve_base_url(Some("data:text/html,hi".to_string()), false),
+			Err(PwError::Context(_))
+		));
+		assert!(matches!(resolve_base_url(None, false), Err(PwError::Context(_))));
+	}
+}
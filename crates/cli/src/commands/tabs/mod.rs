@@ -75,6 +75,8 @@ impl CommandDef for TabsListCommand {
 					"count": count,
 				}),
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -100,6 +102,8 @@ impl Resolve for TabsSwitchRaw {
 	}
 }
 
+/// Selects a tab by index or URL/title substring and brings it to front,
+/// which also focuses its OS window when headful or CDP-attached.
 pub struct TabsSwitchCommand;
 
 impl CommandDef for TabsSwitchCommand {
@@ -135,7 +139,12 @@ impl CommandDef for TabsSwitchCommand {
 					"title": title,
 					"url": url,
 				}),
-				delta: ContextDelta::default(),
+				delta: ContextDelta {
+					url: Some(url),
+					..Default::default()
+				},
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -197,6 +206,8 @@ impl CommandDef for TabsCloseCommand {
 					"url": url,
 				}),
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
@@ -217,8 +228,9 @@ pub struct TabsNewResolved {
 impl Resolve for TabsNewRaw {
 	type Output = TabsNewResolved;
 
-	fn resolve(self, _env: &ResolveEnv<'_>) -> Result<Self::Output> {
-		Ok(TabsNewResolved { url: self.url })
+	fn resolve(self, env: &ResolveEnv<'_>) -> Result<Self::Output> {
+		let url = self.url.map(|url| env.resolve_url(&url)).transpose()?.map(String::from);
+		Ok(TabsNewResolved { url })
 	}
 }
 
@@ -263,6 +275,8 @@ impl CommandDef for TabsNewCommand {
 					"url": final_url,
 				}),
 				delta: ContextDelta::default(),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
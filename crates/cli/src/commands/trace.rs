@@ -0,0 +1,62 @@
+//! Trace viewer passthrough.
+//!
+//! Opens a recorded trace with the Playwright driver's `show-trace`
+//! command, so users don't need a separate Node.js install.
+
+use std::path::Path;
+use std::process::Command;
+
+use pw_rs::pw_runtime;
+
+use crate::cli::TraceAction;
+use crate::error::{PwError, Result};
+
+pub fn run(action: TraceAction) -> Result<()> {
+	match action {
+		TraceAction::Show { file } => show(&file),
+	}
+}
+
+/// Opens `file` in the trace viewer and waits for it to close.
+fn show(file: &Path) -> Result<()> {
+	if !file.is_file() {
+		return Err(PwError::Context(format!("trace file not found: {}", file.display())));
+	}
+
+	let (node_exe, cli_js) =
+		pw_runtime::get_driver_executable().map_err(|err| PwError::Init(format!("Playwright driver not found, cannot open the trace viewer: {err}")))?;
+
+	let status = show_trace_command(&node_exe, &cli_js, file).status()?;
+	if !status.success() {
+		return Err(PwError::Context(format!("show-trace exited with {status}")));
+	}
+	Ok(())
+}
+
+fn show_trace_command(node_exe: &Path, cli_js: &Path, file: &Path) -> Command {
+	let mut cmd = Command::new(node_exe);
+	cmd.arg(cli_js).arg("show-trace").arg(file);
+	cmd
+}
+
+#[cfg(test)]
+mod tests {
+	use std::ffi::OsStr;
+
+	use super::*;
+
+	#[test]
+	fn show_trace_argv_includes_subcommand_and_file() {
+		let cmd = show_trace_command(Path::new("/opt/node"), Path::new("/opt/driver/cli.js"), Path::new("traces/run.zip"));
+
+		assert_eq!(cmd.get_program(), OsStr::new("/opt/node"));
+		let args: Vec<&OsStr> = cmd.get_args().collect();
+		assert_eq!(args, ["/opt/driver/cli.js", "show-trace", "traces/run.zip"]);
+	}
+
+	#[test]
+	fn missing_trace_file_is_rejected() {
+		let err = show(Path::new("does/not/exist.zip")).unwrap_err();
+		assert!(matches!(err, PwError::Context(_)), "{err}");
+	}
+}
@@ -3,7 +3,7 @@
 //! Waits for a specified condition before continuing. Supports:
 //! * Timeout: numeric milliseconds (e.g., `"1000"`)
 //! * Load state: `"load"`, `"domcontentloaded"`, `"networkidle"`
-//! * Selector: CSS selector to wait for element presence
+//! * Selector: CSS selector to wait for, in the given `state` (default `attached`)
 //!
 //! # Examples
 //!
@@ -11,16 +11,17 @@
 //! pw wait --condition 2000           # wait 2 seconds
 //! pw wait --condition networkidle    # wait for network idle
 //! pw wait --condition ".loaded"      # wait for element
+//! pw wait --condition ".spinner" --state hidden
 //! ```
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use clap::Args;
-use pw_rs::WaitUntil;
+use pw_rs::{WaitForSelectorState, WaitUntil};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use crate::commands::contract::{resolve_target_from_url_pair, standard_delta, standard_inputs};
+use crate::commands::contract::{dry_run_inputs, resolve_target_from_url_pair, standard_delta, standard_inputs};
 use crate::commands::def::{BoxFut, CommandDef, CommandOutcome, ExecCtx, Resolve};
 use crate::commands::flow::page::run_page_flow;
 use crate::error::{PwError, Result};
@@ -46,6 +47,11 @@ pub struct WaitRaw {
 	#[arg(long = "url", short = 'u', value_name = "URL")]
 	#[serde(default, alias = "url_flag")]
 	pub url_flag: Option<String>,
+
+	/// Selector state to wait for: attached, detached, visible, or hidden (default: attached)
+	#[arg(long, value_name = "STATE")]
+	#[serde(default)]
+	pub state: Option<WaitForSelectorState>,
 }
 
 /// Resolved inputs ready for execution.
@@ -56,6 +62,9 @@ pub struct WaitResolved {
 
 	/// Wait condition (timeout ms, load state, or CSS selector).
 	pub condition: String,
+
+	/// State a selector condition waits for.
+	pub state: WaitForSelectorState,
 }
 
 impl Resolve for WaitRaw {
@@ -67,7 +76,11 @@ impl Resolve for WaitRaw {
 			.condition
 			.ok_or_else(|| PwError::Context("No condition provided for wait command".into()))?;
 
-		Ok(WaitResolved { target, condition })
+		Ok(WaitResolved {
+			target,
+			condition,
+			state: self.state.unwrap_or(WaitForSelectorState::Attached),
+		})
 	}
 }
 
@@ -75,11 +88,19 @@ impl Resolve for WaitRaw {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WaitData {
+	/// Condition that was waited on, prefixed with its kind (`timeout:`, `loadstate:`, `selector:`).
 	condition: String,
+	/// Wall-clock time spent waiting, in milliseconds.
+	elapsed_ms: u64,
+	/// Whether the condition held when the wait returned.
+	///
+	/// Timeouts are satisfied once the full delay has elapsed, load states once
+	/// `document.readyState` reached them, and selectors once the matches are
+	/// re-checked against `state` after the wait.
+	satisfied: bool,
+	/// Elements matching a selector condition at the moment it was satisfied.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	waited_ms: Option<u64>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	selector_found: Option<bool>,
+	match_count: Option<usize>,
 }
 
 pub struct WaitCommand;
@@ -91,6 +112,10 @@ impl CommandDef for WaitCommand {
 	type Resolved = WaitResolved;
 	type Data = WaitData;
 
+	fn dry_run(resolved: &Self::Resolved) -> serde_json::Value {
+		dry_run_inputs(&resolved.target, None)
+	}
+
 	fn execute<'exec, 'ctx>(args: &'exec Self::Resolved, mut exec: ExecCtx<'exec, 'ctx>) -> BoxFut<'exec, Result<CommandOutcome<Self::Data>>>
 	where
 		'ctx: 'exec,
@@ -100,81 +125,113 @@ impl CommandDef for WaitCommand {
 			info!(target = "pw", url = %url_display, condition = %args.condition, browser = %exec.ctx.browser, "wait");
 
 			let condition = args.condition.clone();
+			let state = args.state;
 
 			let data = run_page_flow(&mut exec, &args.target, WaitUntil::NetworkIdle, ArtifactsPolicy::Never, move |session, flow| {
 				let condition = condition.clone();
 				Box::pin(async move {
+					let started = Instant::now();
 					session.goto_target(&flow.target, flow.timeout_ms).await?;
 
 					if let Ok(ms) = condition.parse::<u64>() {
 						tokio::time::sleep(Duration::from_millis(ms)).await;
+						let elapsed_ms = elapsed_ms(started);
 
 						return Ok(WaitData {
 							condition: format!("timeout:{ms}ms"),
-							waited_ms: Some(ms),
-							selector_found: None,
+							elapsed_ms,
+							satisfied: elapsed_ms >= ms,
+							match_count: None,
 						});
 					}
 
+					// Load states are awaited by navigation itself, so elapsed time covers `goto`.
 					if matches!(condition.as_str(), "load" | "domcontentloaded" | "networkidle") {
+						let ready_state = session.page().evaluate_value("document.readyState").await?;
 						return Ok(WaitData {
 							condition: format!("loadstate:{condition}"),
-							waited_ms: None,
-							selector_found: None,
+							elapsed_ms: elapsed_ms(started),
+							satisfied: load_state_reached(&condition, ready_state.trim_matches('"')),
+							match_count: None,
 						});
 					}
 
-					wait_for_selector(session, &condition).await
+					wait_for_selector(session, &condition, state).await
 				})
 			})
 			.await?;
 
-			let inputs = build_inputs(&args.target, args.condition.as_str());
+			let inputs = build_inputs(&args.target, args.condition.as_str(), args.state);
 
 			Ok(CommandOutcome {
 				inputs,
 				data,
 				delta: standard_delta(&args.target, None, None),
+				diagnostics: Vec::new(),
+				artifacts: Vec::new(),
 			})
 		})
 	}
 }
 
-fn build_inputs(target: &ResolvedTarget, condition: &str) -> CommandInputs {
+fn build_inputs(target: &ResolvedTarget, condition: &str, state: WaitForSelectorState) -> CommandInputs {
 	if condition.parse::<u64>().is_ok() || matches!(condition, "load" | "domcontentloaded" | "networkidle") {
 		standard_inputs(target, None, None, None, Some(serde_json::json!({ "condition": condition })))
-	} else {
+	} else if state == WaitForSelectorState::Attached {
 		standard_inputs(target, Some(condition), None, None, None)
+	} else {
+		standard_inputs(target, Some(condition), None, None, Some(serde_json::json!({ "state": state })))
 	}
 }
 
-/// Polls for a CSS selector until it appears or times out.
-async fn wait_for_selector(session: &SessionHandle, selector: &str) -> Result<WaitData> {
-	let escaped = selector.replace('\\', "\\\\").replace('\'', "\\'");
-	let max_attempts = 30u64;
-
-	for attempt in 0..max_attempts {
-		let visible = session
-			.page()
-			.evaluate_value(&format!("document.querySelector('{escaped}') !== null"))
-			.await
-			.unwrap_or_else(|_| "false".to_string());
-
-		if visible == "true" {
-			return Ok(WaitData {
+/// Timeout for selector conditions.
+const SELECTOR_TIMEOUT_MS: u64 = 30_000;
+
+/// Waits for the first match of a CSS selector to reach `state` or times out.
+///
+/// Waiting on the first match keeps selectors that match several elements from
+/// failing strict mode; `match_count` still counts every match.
+async fn wait_for_selector(session: &SessionHandle, selector: &str, state: WaitForSelectorState) -> Result<WaitData> {
+	let started = Instant::now();
+	let locator = session.page().locator(selector).await;
+
+	match locator.first().wait_for(state, Some(SELECTOR_TIMEOUT_MS as f64)).await {
+		Ok(()) => {
+			let elapsed_ms = elapsed_ms(started);
+			let match_count = locator.count().await?;
+			let satisfied = match state {
+				WaitForSelectorState::Attached => match_count > 0,
+				WaitForSelectorState::Detached => match_count == 0,
+				WaitForSelectorState::Visible => locator.first().is_visible().await?,
+				WaitForSelectorState::Hidden => match_count == 0 || !locator.first().is_visible().await?,
+			};
+			Ok(WaitData {
 				condition: format!("selector:{selector}"),
-				waited_ms: Some(attempt * 1000),
-				selector_found: Some(true),
-			});
+				elapsed_ms,
+				satisfied,
+				match_count: Some(match_count),
+			})
 		}
+		Err(err) if err.is_timeout() => Err(PwError::Timeout {
+			ms: SELECTOR_TIMEOUT_MS,
+			condition: format!("{selector} ({state})"),
+		}),
+		Err(err) => Err(err.into()),
+	}
+}
 
-		tokio::time::sleep(Duration::from_secs(1)).await;
+/// Whether `document.readyState` has reached the load state named by `condition`.
+///
+/// `networkidle` has no ready state of its own, so it counts as reached with `load`.
+fn load_state_reached(condition: &str, ready_state: &str) -> bool {
+	match condition {
+		"domcontentloaded" => matches!(ready_state, "interactive" | "complete"),
+		_ => ready_state == "complete",
 	}
+}
 
-	Err(PwError::Timeout {
-		ms: max_attempts * 1000,
-		condition: selector.to_string(),
-	})
+fn elapsed_ms(started: Instant) -> u64 {
+	started.elapsed().as_millis() as u64
 }
 
 #[cfg(test)]
@@ -194,5 +251,33 @@ mod tests {
 		let json = r#"{"condition": ".loaded"}"#;
 		let raw: WaitRaw = serde_json::from_str(json).unwrap();
 		assert_eq!(raw.condition, Some(".loaded".into()));
+		assert_eq!(raw.state, None);
+	}
+
+	#[test]
+	fn wait_raw_deserialize_selector_state() {
+		let json = r#"{"condition": ".spinner", "state": "hidden"}"#;
+		let raw: WaitRaw = serde_json::from_str(json).unwrap();
+		assert_eq!(raw.state, Some(WaitForSelectorState::Hidden));
+	}
+
+	#[test]
+	fn load_state_reached_follows_ready_state() {
+		assert!(load_state_reached("domcontentloaded", "interactive"));
+		assert!(!load_state_reached("load", "interactive"));
+		assert!(load_state_reached("networkidle", "complete"));
+		assert!(!load_state_reached("domcontentloaded", "loading"));
+	}
+
+	#[test]
+	fn wait_data_serializes_match_count_only_for_selectors() {
+		let data = WaitData {
+			condition: "timeout:10ms".into(),
+			elapsed_ms: 10,
+			satisfied: true,
+			match_count: None,
+		};
+		let json = serde_json::to_value(&data).unwrap();
+		assert_eq!(json, serde_json::json!({ "condition": "timeout:10ms", "elapsedMs": 10, "satisfied": true }));
 	}
 }
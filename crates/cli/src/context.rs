@@ -3,12 +3,13 @@
 //! Provides shared context (project, browser, auth) to all commands.
 
 use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
-use pw_rs::{HarContentPolicy, HarMode};
+use pw_rs::{ColorScheme, Cookie, ForcedColors, HarContentPolicy, HarMode, NetworkPreset, ReducedMotion};
 
 use crate::output::CdpEndpointSource;
 use crate::project::Project;
-use crate::types::BrowserKind;
+use crate::types::{BrowserChannel, BrowserKind};
 use crate::workspace::STATE_VERSION_DIR;
 
 /// HAR recording configuration
@@ -41,10 +42,15 @@ impl HarConfig {
 /// * `*://google-analytics.com/**` - block analytics
 ///
 /// [`Page::route`]: pw_rs::Page::route
+///
+/// Clones share one [`BlockedPatterns`] record, so routes installed from the
+/// session's copy report back to the command context that configured them.
 #[derive(Debug, Clone, Default)]
 pub struct BlockConfig {
 	/// URL glob patterns to block.
 	pub patterns: Vec<String>,
+	/// Patterns that aborted at least one request.
+	pub matched: BlockedPatterns,
 }
 
 impl BlockConfig {
@@ -67,6 +73,25 @@ impl BlockConfig {
 	}
 }
 
+/// Distinct block patterns that matched a request, in first-hit order.
+#[derive(Debug, Clone, Default)]
+pub struct BlockedPatterns(Arc<Mutex<Vec<String>>>);
+
+impl BlockedPatterns {
+	/// Records a hit for `pattern`; repeated hits are ignored.
+	pub fn record(&self, pattern: &str) {
+		let mut matched = self.0.lock().unwrap_or_else(|e| e.into_inner());
+		if !matched.iter().any(|p| p == pattern) {
+			matched.push(pattern.to_string());
+		}
+	}
+
+	/// Returns the patterns recorded so far.
+	pub fn snapshot(&self) -> Vec<String> {
+		self.0.lock().unwrap_or_else(|e| e.into_inner()).clone()
+	}
+}
+
 /// Configuration for download management.
 ///
 /// When `dir` is set, downloads are automatically saved and tracked.
@@ -83,6 +108,59 @@ impl DownloadConfig {
 	}
 }
 
+/// Browser launch tuning passed through to [`pw_rs::LaunchOptions`].
+///
+/// Only applies when pw launches the browser; attached and daemon browsers
+/// keep whatever they were started with.
+#[derive(Debug, Clone, Default)]
+pub struct LaunchConfig {
+	/// Branded Chromium channel to launch instead of bundled Chromium.
+	pub channel: Option<BrowserChannel>,
+	/// Open DevTools for each tab (implies headful).
+	pub devtools: bool,
+	/// Delay between Playwright operations in milliseconds.
+	pub slow_mo_ms: Option<u64>,
+}
+
+impl LaunchConfig {
+	/// Returns `true` if any launch option differs from the defaults.
+	pub fn is_enabled(&self) -> bool {
+		self.channel.is_some() || self.devtools || self.slow_mo_ms.is_some()
+	}
+
+	/// Returns `true` when the browser must run headful.
+	pub fn forces_headful(&self) -> bool {
+		self.devtools
+	}
+}
+
+/// Media feature emulation applied to new browser contexts.
+#[derive(Debug, Clone, Default)]
+pub struct MediaConfig {
+	/// Emulated `prefers-color-scheme`.
+	pub color_scheme: Option<ColorScheme>,
+	/// Emulated `prefers-reduced-motion`.
+	pub reduced_motion: Option<ReducedMotion>,
+	/// Emulated `forced-colors`.
+	pub forced_colors: Option<ForcedColors>,
+}
+
+impl MediaConfig {
+	/// Returns `true` if any media feature is emulated.
+	pub fn is_enabled(&self) -> bool {
+		self.color_scheme.is_some() || self.reduced_motion.is_some() || self.forced_colors.is_some()
+	}
+}
+
+/// Network condition emulation applied to every acquired session.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkConfig {
+	/// Whether the context is switched offline.
+	pub offline: bool,
+	/// CDP throttling preset for the page (Chromium only).
+	pub throttle: Option<NetworkPreset>,
+}
+
 /// Configuration for creating a [`CommandContext`].
 #[derive(Debug, Clone, Default)]
 pub struct CommandContextConfig {
@@ -96,7 +174,17 @@ pub struct CommandContextConfig {
 	pub har_config: HarConfig,
 	pub block_config: BlockConfig,
 	pub download_config: DownloadConfig,
+	pub cookies: Vec<Cookie>,
+	pub no_viewport: bool,
+	pub insecure: bool,
+	pub headful: bool,
+	pub launch_config: LaunchConfig,
+	pub media_config: MediaConfig,
+	pub network_config: NetworkConfig,
 	pub timeout_ms: Option<u64>,
+	pub save_trace_on_timeout: bool,
+	pub fail_on_console_error: bool,
+	pub force_navigate: bool,
 	pub workspace_root: Option<PathBuf>,
 	pub workspace_id: Option<String>,
 	pub namespace: Option<String>,
@@ -127,8 +215,28 @@ pub struct CommandContext {
 	block_config: BlockConfig,
 	/// Download management configuration
 	download_config: DownloadConfig,
+	/// One-off cookies injected into each acquired browser context
+	cookies: Vec<Cookie>,
+	/// Whether new browser contexts are created without viewport emulation
+	no_viewport: bool,
+	/// Whether `wss://` browser server certificates go unvalidated
+	insecure: bool,
+	/// Whether the profile asks for a visible browser (`defaults.headless = false`)
+	headful: bool,
+	/// Channel, devtools, and slow-mo options for browsers pw launches
+	launch_config: LaunchConfig,
+	/// Color scheme, reduced motion, and forced colors emulation for new contexts
+	media_config: MediaConfig,
+	/// Offline and throttling emulation applied to each acquired session
+	network_config: NetworkConfig,
 	/// Timeout for navigation and wait operations (milliseconds)
 	timeout_ms: Option<u64>,
+	/// Whether timeout failures carry a screenshot and recent console errors
+	save_trace_on_timeout: bool,
+	/// Whether console errors logged during a command fail it
+	fail_on_console_error: bool,
+	/// Whether targets are re-navigated even when the page is already on them
+	force_navigate: bool,
 	/// Workspace root used for strict state/session isolation.
 	workspace_root: PathBuf,
 	/// Deterministic workspace identifier.
@@ -186,7 +294,17 @@ impl CommandContext {
 			har_config,
 			block_config,
 			download_config,
+			cookies,
+			no_viewport,
+			insecure,
+			headful,
+			launch_config,
+			media_config,
+			network_config,
 			timeout_ms,
+			save_trace_on_timeout,
+			fail_on_console_error,
+			force_navigate,
 			workspace_root,
 			workspace_id,
 			namespace,
@@ -249,7 +367,17 @@ impl CommandContext {
 			har_config: resolved_har_config,
 			block_config,
 			download_config: resolved_download_config,
+			cookies,
+			no_viewport,
+			insecure,
+			headful,
+			launch_config,
+			media_config,
+			network_config,
 			timeout_ms,
+			save_trace_on_timeout,
+			fail_on_console_error,
+			force_navigate,
 			workspace_root: resolved_workspace_root,
 			workspace_id: resolved_workspace_id,
 			namespace: resolved_namespace,
@@ -294,7 +422,71 @@ impl CommandContext {
 		&self.download_config
 	}
 
-	/// Get the timeout for navigation and wait operations
+	/// Get the one-off cookies to inject before navigation
+	pub fn cookies(&self) -> &[Cookie] {
+		&self.cookies
+	}
+
+	/// Whether new browser contexts skip viewport emulation
+	pub fn no_viewport(&self) -> bool {
+		self.no_viewport
+	}
+
+	/// Whether `wss://` browser server certificates go unvalidated
+	pub fn insecure(&self) -> bool {
+		self.insecure
+	}
+
+	/// Whether timeout failures carry a screenshot and recent console errors
+	pub fn save_trace_on_timeout(&self) -> bool {
+		self.save_trace_on_timeout
+	}
+
+	/// Whether console errors logged during a command fail it
+	pub fn fail_on_console_error(&self) -> bool {
+		self.fail_on_console_error
+	}
+
+	/// Whether targets are re-navigated even when the page is already on them
+	pub fn force_navigate(&self) -> bool {
+		self.force_navigate
+	}
+
+	/// Get the launch options applied to browsers pw launches
+	pub fn launch_config(&self) -> &LaunchConfig {
+		&self.launch_config
+	}
+
+	/// Get the media feature emulation applied to new contexts
+	pub fn media_config(&self) -> &MediaConfig {
+		&self.media_config
+	}
+
+	/// Get the network condition emulation applied to each session
+	pub fn network_config(&self) -> &NetworkConfig {
+		&self.network_config
+	}
+
+	/// Whether browsers pw launches should be headless by default.
+	///
+	/// False when the profile sets `defaults.headless = false` or DevTools forces
+	/// a window. Commands can still override this per request.
+	pub fn headless(&self) -> bool {
+		!(self.headful || self.launch_config.forces_headful())
+	}
+
+	/// Whether the user can see the browser a default session request acquires.
+	///
+	/// Follows the request's `headless` flag, which reused descriptors must
+	/// match, and also holds when attached over CDP to a browser the user started.
+	pub fn is_headful(&self) -> bool {
+		!self.headless() || self.cdp_endpoint.is_some()
+	}
+
+	/// Get the timeout for navigation and wait operations.
+	///
+	/// `Some(0)` means no timeout: it is passed through to Playwright, which
+	/// treats zero as "wait indefinitely", and CLI-side deadlines are skipped.
 	pub fn timeout_ms(&self) -> Option<u64> {
 		self.timeout_ms
 	}
@@ -396,11 +588,7 @@ fn expand_home_path(path: PathBuf) -> PathBuf {
 		Component::Normal(part) if part == "~" => match dirs::home_dir() {
 			Some(home) => {
 				let rest: PathBuf = components.collect();
-				if rest.as_os_str().is_empty() {
-					home
-				} else {
-					home.join(rest)
-				}
+				if rest.as_os_str().is_empty() { home } else { home.join(rest) }
 			}
 			None => path,
 		},
@@ -425,6 +613,20 @@ mod tests {
 		assert_eq!(ctx.namespace(), "default");
 	}
 
+	#[test]
+	fn blocked_patterns_dedupe_across_clones() {
+		let config = BlockConfig {
+			patterns: vec!["**/*.png".to_string(), "**/ads/**".to_string()],
+			..Default::default()
+		};
+		let session_copy = config.clone();
+		session_copy.matched.record("**/*.png");
+		session_copy.matched.record("**/ads/**");
+		session_copy.matched.record("**/*.png");
+
+		assert_eq!(config.matched.snapshot(), ["**/*.png", "**/ads/**"]);
+	}
+
 	#[test]
 	fn test_cdp_endpoint_round_trip() {
 		let ctx = CommandContext::new(BrowserKind::Chromium, true, None, Some("ws://localhost:19988/cdp".into()), false, false);
@@ -6,10 +6,14 @@
 
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
+
 use crate::context::CommandContext;
 use crate::error::{PwError, Result};
 use crate::types::BrowserKind;
+use crate::workspace::{DEFAULT_PROFILE, active_profile};
 
+mod output_template;
 pub mod storage;
 pub mod types;
 
@@ -21,6 +25,34 @@ pub use types::{CliCache, CliConfig, Defaults, HarDefaults};
 
 const SESSION_TIMEOUT_SECS: u64 = 3600;
 
+/// `data:` URLs longer than this are not remembered as `last_url`.
+const MAX_RECORDED_DATA_URL_LEN: usize = 2048;
+
+/// Empty `default` profile state under `/tmp/test-workspace`, for [`ContextState::test_new`].
+#[cfg(test)]
+pub(crate) fn test_state() -> LoadedState {
+	LoadedState {
+		config: CliConfig::new(),
+		cache: CliCache::new(),
+		paths: storage::StatePaths::new(Path::new("/tmp/test-workspace"), "default"),
+	}
+}
+
+/// Browser-internal schemes that never make a useful `last_url`.
+const UNRECORDED_SCHEMES: &[&str] = &["about:", "chrome:", "chrome-error:", "devtools:", "edge:"];
+
+/// One stored namespace, as listed by [`ContextState::namespaces`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceSummary {
+	pub name: String,
+	pub active: bool,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub last_url: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cdp_endpoint: Option<String>,
+}
+
 /// Runtime context state manager.
 ///
 /// Uses [`LoadedState`] for profile-scoped storage.
@@ -174,6 +206,25 @@ impl ContextState {
 		}
 	}
 
+	/// Sets the default browser engine in config defaults.
+	pub fn set_default_browser(&mut self, browser: Option<BrowserKind>) {
+		if self.no_save || self.no_context {
+			return;
+		}
+		if self.state.config.defaults.browser != browser {
+			self.state.config.defaults.browser = browser;
+			self.dirty = true;
+		}
+	}
+
+	/// Returns the configured default snapshot text length, if any.
+	pub fn max_text_length(&self) -> Option<usize> {
+		if self.no_context {
+			return None;
+		}
+		self.state.config.defaults.max_text_length
+	}
+
 	/// Returns protected URL patterns from config.
 	pub fn protected_urls(&self) -> &[String] {
 		if self.no_context {
@@ -264,18 +315,36 @@ impl ContextState {
 		removed
 	}
 
-	pub fn resolve_output(&self, ctx: &CommandContext, provided: Option<PathBuf>) -> PathBuf {
-		if let Some(output) = provided {
-			return ctx.screenshot_path(&output);
-		}
+	/// Expands `{ts}`, `{host}` and `{n}` tokens in an output path template.
+	///
+	/// `{host}` comes from `target_url`. The `{n}` counter lives in the profile
+	/// cache and only advances when the template uses it.
+	pub fn expand_output(&mut self, template: &Path, target_url: Option<&str>) -> PathBuf {
+		let n = self.state.cache.output_counter.unwrap_or(0) + 1;
+		let tokens = output_template::OutputTokens {
+			ts_ms: std::time::SystemTime::now()
+				.duration_since(std::time::UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_millis(),
+			target_url,
+			n,
+		};
+		let expanded = output_template::expand(&template.to_string_lossy(), &tokens);
 
-		if !self.no_context && !self.refresh {
-			if let Some(last) = &self.state.cache.last_output {
-				return ctx.screenshot_path(Path::new(last));
+		if expanded.used_counter {
+			self.state.cache.output_counter = Some(n);
+			if !self.no_save && !self.no_context {
+				self.dirty = true;
 			}
 		}
 
-		ctx.screenshot_path(Path::new("screenshot.png"))
+		PathBuf::from(expanded.path)
+	}
+
+	/// Expands `template` and places bare filenames in the project's output directory.
+	pub fn resolve_output(&mut self, ctx: &CommandContext, template: &Path, target_url: Option<&str>) -> PathBuf {
+		let output = self.expand_output(template, target_url);
+		ctx.screenshot_path(&output)
 	}
 
 	/// Applies context changes from command execution.
@@ -284,7 +353,7 @@ impl ContextState {
 			return;
 		}
 		let mut changed = false;
-		if let Some(url) = delta.url {
+		if let Some(url) = delta.url.filter(|url| is_recordable_url(url)) {
 			if self.state.cache.last_url.as_deref() != Some(url.as_str()) {
 				self.state.cache.last_url = Some(url);
 				changed = true;
@@ -341,6 +410,21 @@ impl ContextState {
 		self.base_url_override.as_deref().or(self.state.config.defaults.base_url.as_deref())
 	}
 
+	/// Sets the profile default base URL. Returns `true` when the value changed.
+	///
+	/// Fails when context persistence is disabled, since the change would be lost.
+	pub fn set_base_url(&mut self, base_url: Option<String>) -> Result<bool> {
+		if self.no_save || self.no_context {
+			return Err(PwError::Context("cannot set the base URL while context saving is disabled".to_string()));
+		}
+		if self.state.config.defaults.base_url == base_url {
+			return Ok(false);
+		}
+		self.state.config.defaults.base_url = base_url;
+		self.dirty = true;
+		Ok(true)
+	}
+
 	/// Returns the loaded state.
 	pub fn state(&self) -> &LoadedState {
 		&self.state
@@ -356,6 +440,50 @@ impl ContextState {
 	pub(crate) fn is_dirty(&self) -> bool {
 		self.dirty
 	}
+
+	/// Returns the workspace's active namespace: the one `context.use` selected, else `default`.
+	pub fn active_namespace(workspace_root: &Path) -> String {
+		active_profile(workspace_root).unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+	}
+
+	/// Lists the namespaces stored under `workspace_root`, sorted by name.
+	pub fn namespaces(workspace_root: &Path) -> Result<Vec<NamespaceSummary>> {
+		let profiles_dir = storage::StatePaths::new(workspace_root, DEFAULT_PROFILE).state_root.join("profiles");
+		if !profiles_dir.exists() {
+			return Ok(Vec::new());
+		}
+
+		let active = Self::active_namespace(workspace_root);
+		let mut namespaces = Vec::new();
+		for entry in std::fs::read_dir(profiles_dir)? {
+			let entry = entry?;
+			if !entry.file_type()?.is_dir() {
+				continue;
+			}
+			let name = entry.file_name().to_string_lossy().to_string();
+			let state = LoadedState::load(workspace_root, &name)?;
+			namespaces.push(NamespaceSummary {
+				active: name == active,
+				last_url: state.cache.last_url,
+				cdp_endpoint: state.config.defaults.cdp_endpoint,
+				name,
+			});
+		}
+		namespaces.sort_by(|a, b| a.name.cmp(&b.name));
+		Ok(namespaces)
+	}
+}
+
+/// Whether `url` is worth reusing for later `CurrentPage` resolution.
+///
+/// Blank and browser-internal pages, and `data:` URLs over
+/// [`MAX_RECORDED_DATA_URL_LEN`], would only replace a real page.
+pub(crate) fn is_recordable_url(url: &str) -> bool {
+	let lower = url.get(..16).unwrap_or(url).to_ascii_lowercase();
+	if UNRECORDED_SCHEMES.iter().any(|scheme| lower.starts_with(scheme)) {
+		return false;
+	}
+	!(lower.starts_with("data:") && url.len() > MAX_RECORDED_DATA_URL_LEN)
 }
 
 fn now_ts() -> u64 {
@@ -0,0 +1,133 @@
+//! Token expansion for `--output` paths.
+//!
+//! Supported tokens:
+//!
+//! * `{ts}`: Unix epoch milliseconds at expansion time
+//! * `{host}`: host of the target URL, with `:port` rendered as `_port`; `page` when there is no host
+//! * `{n}`: per-profile counter, incremented once for each expansion that uses it
+//!
+//! `{{` and `}}` produce literal braces. Unknown tokens and unmatched braces are kept verbatim.
+
+use url::Url;
+
+/// Values substituted into an output template.
+#[derive(Debug, Clone)]
+pub(crate) struct OutputTokens<'a> {
+	/// Value for `{ts}`.
+	pub ts_ms: u128,
+	/// URL whose host fills `{host}`.
+	pub target_url: Option<&'a str>,
+	/// Value for `{n}`.
+	pub n: u64,
+}
+
+/// Result of expanding an output template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Expanded {
+	pub path: String,
+	/// Whether `{n}` was substituted, so the caller should advance its counter.
+	pub used_counter: bool,
+}
+
+/// Expands the tokens in `template`.
+pub(crate) fn expand(template: &str, tokens: &OutputTokens<'_>) -> Expanded {
+	let mut path = String::with_capacity(template.len());
+	let mut used_counter = false;
+	let mut rest = template;
+
+	while let Some(pos) = rest.find(['{', '}']) {
+		path.push_str(&rest[..pos]);
+		let tail = &rest[pos..];
+
+		if tail.starts_with("{{") || tail.starts_with("}}") {
+			path.push_str(&tail[..1]);
+			rest = &tail[2..];
+			continue;
+		}
+		if let Some(after) = tail.strip_prefix('}') {
+			path.push('}');
+			rest = after;
+			continue;
+		}
+
+		let Some(end) = tail.find('}') else {
+			path.push_str(tail);
+			rest = "";
+			break;
+		};
+		match &tail[1..end] {
+			"ts" => path.push_str(&tokens.ts_ms.to_string()),
+			"host" => path.push_str(&host_token(tokens.target_url)),
+			"n" => {
+				path.push_str(&tokens.n.to_string());
+				used_counter = true;
+			}
+			_ => path.push_str(&tail[..=end]),
+		}
+		rest = &tail[end + 1..];
+	}
+	path.push_str(rest);
+
+	Expanded { path, used_counter }
+}
+
+/// Returns a filename-safe host for `url`, or `page` when it has none.
+fn host_token(url: Option<&str>) -> String {
+	let host = url.and_then(|raw| Url::parse(raw).ok()).and_then(|url| {
+		let host = url.host_str()?.to_string();
+		Some(match url.port() {
+			Some(port) => format!("{host}_{port}"),
+			None => host,
+		})
+	});
+
+	match host {
+		Some(host) if !host.is_empty() => host
+			.chars()
+			.map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+			.collect(),
+		_ => "page".to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn tokens(target_url: Option<&str>) -> OutputTokens<'_> {
+		OutputTokens {
+			ts_ms: 1_760_000_000_000,
+			target_url,
+			n: 7,
+		}
+	}
+
+	#[test]
+	fn expands_all_tokens() {
+		let expanded = expand("shots/{host}-{ts}-{n}.png", &tokens(Some("https://example.com/a?b=c")));
+		assert_eq!(expanded.path, "shots/example.com-1760000000000-7.png");
+		assert!(expanded.used_counter);
+	}
+
+	#[test]
+	fn host_includes_port_and_falls_back_to_page() {
+		assert_eq!(expand("{host}", &tokens(Some("http://localhost:8080/"))).path, "localhost_8080");
+		assert_eq!(expand("{host}", &tokens(Some("http://[::1]:3000/"))).path, "___1__3000");
+		assert_eq!(expand("{host}", &tokens(Some("data:text/html,<p>x</p>"))).path, "page");
+		assert_eq!(expand("{host}", &tokens(None)).path, "page");
+	}
+
+	#[test]
+	fn doubled_braces_escape_and_unknown_tokens_stay_verbatim() {
+		let expanded = expand("{{n}}-{nope}-{ts", &tokens(None));
+		assert_eq!(expanded.path, "{n}-{nope}-{ts");
+		assert!(!expanded.used_counter);
+	}
+
+	#[test]
+	fn plain_paths_are_unchanged() {
+		let expanded = expand("out/screenshot.png", &tokens(None));
+		assert_eq!(expanded.path, "out/screenshot.png");
+		assert!(!expanded.used_counter);
+	}
+}
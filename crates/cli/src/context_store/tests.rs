@@ -1,19 +1,10 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use pw_rs::{HarContentPolicy, HarMode};
 
-use super::ContextState;
 use super::storage::{LoadedState, StatePaths};
 use super::types::{CliCache, CliConfig, HarDefaults, SCHEMA_VERSION};
-
-fn test_state() -> LoadedState {
-	let root = PathBuf::from("/tmp/test-workspace");
-	LoadedState {
-		config: CliConfig::new(),
-		cache: CliCache::new(),
-		paths: StatePaths::new(&root, "default"),
-	}
-}
+use super::{ContextState, MAX_RECORDED_DATA_URL_LEN, is_recordable_url, test_state};
 
 #[test]
 fn cdp_endpoint_reads_from_config_defaults() {
@@ -46,6 +37,30 @@ fn last_url_reads_from_cache() {
 	assert_eq!(ctx_state.last_url(), Some("https://example.com"));
 }
 
+#[test]
+fn expand_output_advances_counter_only_when_used() {
+	let mut ctx_state = ContextState::test_new(test_state(), "ws1".to_string(), "default".to_string());
+
+	let first = ctx_state.expand_output(Path::new("shots/{host}-{n}.png"), Some("https://example.com:8443/a"));
+	let second = ctx_state.expand_output(Path::new("shots/{host}-{n}.png"), Some("https://example.com:8443/a"));
+	let plain = ctx_state.expand_output(Path::new("shots/plain.png"), None);
+
+	assert_eq!(first, PathBuf::from("shots/example.com_8443-1.png"));
+	assert_eq!(second, PathBuf::from("shots/example.com_8443-2.png"));
+	assert_eq!(plain, PathBuf::from("shots/plain.png"));
+	assert_eq!(ctx_state.state().cache.output_counter, Some(2));
+}
+
+#[test]
+fn resolve_output_expands_directory_templates() {
+	let mut ctx_state = ContextState::test_new(test_state(), "ws1".to_string(), "default".to_string());
+	let ctx = crate::context::CommandContext::new(crate::types::BrowserKind::Chromium, true, None, None, false, false);
+
+	let path = ctx_state.resolve_output(&ctx, Path::new("/tmp/shots/{host}-{n}.png"), Some("https://example.com/a"));
+
+	assert_eq!(path, PathBuf::from("/tmp/shots/example.com-1.png"));
+}
+
 #[test]
 fn base_url_prefers_override() {
 	let mut state = test_state();
@@ -67,6 +82,31 @@ fn base_url_falls_back_to_config() {
 	assert_eq!(ctx_state.base_url(), Some("https://config.com"));
 }
 
+#[test]
+fn set_base_url_updates_config_defaults() {
+	let state = test_state();
+	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+
+	assert!(ctx_state.set_base_url(Some("https://app.local/".to_string())).unwrap());
+	assert!(ctx_state.is_dirty());
+	assert!(!ctx_state.set_base_url(Some("https://app.local/".to_string())).unwrap());
+	assert_eq!(ctx_state.base_url(), Some("https://app.local/"));
+	assert_eq!(ctx_state.state().config.defaults.base_url.as_deref(), Some("https://app.local/"));
+
+	assert!(ctx_state.set_base_url(None).unwrap());
+	assert_eq!(ctx_state.base_url(), None);
+}
+
+#[test]
+fn set_base_url_fails_when_context_is_not_saved() {
+	let mut ctx_state = ContextState::test_new(test_state(), "ws1".to_string(), "default".to_string());
+	ctx_state.no_save = true;
+
+	assert!(ctx_state.set_base_url(Some("https://app.local/".to_string())).is_err());
+	assert!(!ctx_state.is_dirty());
+	assert_eq!(ctx_state.base_url(), None);
+}
+
 #[test]
 fn protected_urls_from_config() {
 	let mut state = test_state();
@@ -182,6 +222,38 @@ fn apply_delta_updates_cache() {
 	assert!(ctx_state.is_dirty());
 }
 
+#[test]
+fn apply_delta_skips_blank_and_oversized_data_urls() {
+	let mut state = test_state();
+	state.cache.last_url = Some("https://example.com/start".to_string());
+	let mut ctx_state = ContextState::test_new(state, "ws1".to_string(), "default".to_string());
+	let huge_data_url = format!("data:text/html,{}", "x".repeat(MAX_RECORDED_DATA_URL_LEN));
+
+	for url in ["about:blank", "chrome://newtab/", huge_data_url.as_str()] {
+		ctx_state.apply_delta(crate::commands::def::ContextDelta {
+			url: Some(url.to_string()),
+			..Default::default()
+		});
+		assert_eq!(ctx_state.last_url(), Some("https://example.com/start"), "{url} should not be recorded");
+	}
+	assert!(!ctx_state.is_dirty());
+
+	ctx_state.apply_delta(crate::commands::def::ContextDelta {
+		url: Some("https://example.com/next".to_string()),
+		..Default::default()
+	});
+	assert_eq!(ctx_state.last_url(), Some("https://example.com/next"));
+}
+
+#[test]
+fn recordable_url_keeps_small_data_urls() {
+	assert!(is_recordable_url("https://example.com"));
+	assert!(is_recordable_url("file:///tmp/page.html"));
+	assert!(is_recordable_url("data:text/html,<h1>Hi</h1>"));
+	assert!(!is_recordable_url("ABOUT:BLANK"));
+	assert!(!is_recordable_url("chrome-error://chromewebdata/"));
+}
+
 #[test]
 fn apply_empty_delta_does_not_mark_state_dirty() {
 	let state = test_state();
@@ -285,3 +357,37 @@ fn has_context_url_with_last_url() {
 
 	assert!(ctx_state.has_context_url());
 }
+
+#[test]
+fn namespaces_lists_stored_profiles_with_last_url_and_endpoint() {
+	let tmp = tempfile::TempDir::new().unwrap();
+
+	let mut agent_a = LoadedState::load(tmp.path(), "agent-a").unwrap();
+	agent_a.cache.last_url = Some("https://a.example.com/".to_string());
+	agent_a.save().unwrap();
+	let mut agent_b = LoadedState::load(tmp.path(), "agent-b").unwrap();
+	agent_b.config.defaults.cdp_endpoint = Some("ws://127.0.0.1:9222/devtools/browser/b".to_string());
+	agent_b.save().unwrap();
+	crate::workspace::set_active_profile(tmp.path(), "agent-b").unwrap();
+
+	let namespaces = ContextState::namespaces(tmp.path()).unwrap();
+	let summary: Vec<_> = namespaces
+		.iter()
+		.map(|ns| (ns.name.as_str(), ns.active, ns.last_url.as_deref(), ns.cdp_endpoint.as_deref()))
+		.collect();
+	assert_eq!(
+		summary,
+		[
+			("agent-a", false, Some("https://a.example.com/"), None),
+			("agent-b", true, None, Some("ws://127.0.0.1:9222/devtools/browser/b")),
+		]
+	);
+	assert_eq!(ContextState::active_namespace(tmp.path()), "agent-b");
+}
+
+#[test]
+fn namespaces_is_empty_without_state() {
+	let tmp = tempfile::TempDir::new().unwrap();
+	assert!(ContextState::namespaces(tmp.path()).unwrap().is_empty());
+	assert_eq!(ContextState::active_namespace(tmp.path()), "default");
+}
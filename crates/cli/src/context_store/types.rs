@@ -30,6 +30,9 @@ pub struct Defaults {
 	pub use_daemon: Option<bool>,
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub launch_server: Option<bool>,
+	/// Default `page.snapshot` text cap when the request omits `maxTextLength`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_text_length: Option<usize>,
 }
 
 /// Persisted network defaults scoped to a profile.
@@ -101,6 +104,9 @@ pub struct CliCache {
 	pub last_selector: Option<String>,
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub last_output: Option<String>,
+	/// Last value substituted for `{n}` in output path templates.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub output_counter: Option<u64>,
 	/// Unix epoch seconds.
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub last_used_at: Option<u64>,
@@ -0,0 +1,163 @@
+//! One-off cookie specs for request-scoped injection.
+//!
+//! A cookie spec mirrors a `Set-Cookie` header value:
+//!
+//! ```text
+//! name=value; Domain=example.com; Path=/; Secure; HttpOnly; SameSite=Lax
+//! ```
+//!
+//! Supported attributes (case-insensitive):
+//! * `Domain` (required, Playwright needs a domain or URL to scope the cookie)
+//! * `Path` (defaults to `/`)
+//! * `Expires` as a Unix timestamp in seconds
+//! * `Max-Age` in seconds from now (takes precedence over `Expires`)
+//! * `Secure` and `HttpOnly` flags
+//! * `SameSite` with `Strict`, `Lax`, or `None`
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pw_rs::{Cookie, SameSite};
+
+use crate::error::{PwError, Result};
+
+/// Parses every spec in `specs`, failing on the first malformed entry.
+pub fn parse_cookie_specs(specs: &[String]) -> Result<Vec<Cookie>> {
+	specs.iter().map(|spec| parse_cookie_spec(spec)).collect()
+}
+
+/// Parses a single `name=value; Attr=...` cookie spec into a [`Cookie`].
+pub fn parse_cookie_spec(spec: &str) -> Result<Cookie> {
+	let mut parts = spec.split(';').map(str::trim);
+	let pair = parts.next().unwrap_or_default();
+	let (name, value) = pair.split_once('=').ok_or_else(|| invalid(spec, "expected `name=value` before attributes"))?;
+	let name = name.trim();
+	if name.is_empty() {
+		return Err(invalid(spec, "cookie name is empty"));
+	}
+
+	let mut cookie = Cookie {
+		name: name.to_string(),
+		value: value.trim().to_string(),
+		domain: None,
+		path: None,
+		expires: None,
+		http_only: None,
+		secure: None,
+		same_site: None,
+		url: None,
+	};
+	let mut max_age = None;
+
+	for attr in parts.filter(|part| !part.is_empty()) {
+		let (key, val) = match attr.split_once('=') {
+			Some((key, val)) => (key.trim(), Some(val.trim())),
+			None => (attr, None),
+		};
+
+		match (key.to_ascii_lowercase().as_str(), val) {
+			("domain", Some(domain)) if !domain.is_empty() => cookie.domain = Some(domain.to_string()),
+			("path", Some(path)) if path.starts_with('/') => cookie.path = Some(path.to_string()),
+			("expires", Some(ts)) => {
+				let ts = ts.parse::<f64>().map_err(|_| invalid(spec, "`Expires` must be a Unix timestamp in seconds"))?;
+				cookie.expires = Some(ts);
+			}
+			("max-age", Some(secs)) => {
+				let secs = secs
+					.parse::<i64>()
+					.map_err(|_| invalid(spec, "`Max-Age` must be an integer number of seconds"))?;
+				max_age = Some(secs);
+			}
+			("secure", None) => cookie.secure = Some(true),
+			("httponly", None) => cookie.http_only = Some(true),
+			("samesite", Some(mode)) => cookie.same_site = Some(parse_same_site(mode).ok_or_else(|| invalid(spec, "`SameSite` must be Strict, Lax, or None"))?),
+			_ => return Err(invalid(spec, &format!("unsupported or malformed attribute `{attr}`"))),
+		}
+	}
+
+	if let Some(secs) = max_age {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs_f64()).unwrap_or_default();
+		cookie.expires = Some(now + secs as f64);
+	}
+
+	if cookie.domain.is_none() {
+		return Err(invalid(spec, "`Domain` attribute is required"));
+	}
+	cookie.path.get_or_insert_with(|| "/".to_string());
+
+	Ok(cookie)
+}
+
+fn parse_same_site(mode: &str) -> Option<SameSite> {
+	match mode.to_ascii_lowercase().as_str() {
+		"strict" => Some(SameSite::Strict),
+		"lax" => Some(SameSite::Lax),
+		"none" => Some(SameSite::None),
+		_ => None,
+	}
+}
+
+fn invalid(spec: &str, reason: &str) -> PwError {
+	PwError::Context(format!("invalid cookie `{spec}`: {reason}"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_name_value_and_domain() {
+		let cookie = parse_cookie_spec("session=abc123; Domain=example.com").unwrap();
+		assert_eq!(cookie.name, "session");
+		assert_eq!(cookie.value, "abc123");
+		assert_eq!(cookie.domain.as_deref(), Some("example.com"));
+		assert_eq!(cookie.path.as_deref(), Some("/"));
+	}
+
+	#[test]
+	fn parses_standard_attributes_case_insensitively() {
+		let cookie = parse_cookie_spec("id=1; domain=.example.com; PATH=/app; secure; HttpOnly; samesite=strict; Expires=1700000000").unwrap();
+		assert_eq!(cookie.path.as_deref(), Some("/app"));
+		assert_eq!(cookie.secure, Some(true));
+		assert_eq!(cookie.http_only, Some(true));
+		assert_eq!(cookie.same_site, Some(SameSite::Strict));
+		assert_eq!(cookie.expires, Some(1_700_000_000.0));
+	}
+
+	#[test]
+	fn max_age_overrides_expires() {
+		let cookie = parse_cookie_spec("id=1; Domain=example.com; Expires=1; Max-Age=3600").unwrap();
+		assert!(cookie.expires.unwrap() > 1_000_000_000.0);
+	}
+
+	#[test]
+	fn value_may_contain_equals_signs() {
+		let cookie = parse_cookie_spec("token=a=b==; Domain=example.com").unwrap();
+		assert_eq!(cookie.value, "a=b==");
+	}
+
+	#[test]
+	fn rejects_malformed_specs() {
+		for spec in [
+			"",
+			"novalue; Domain=example.com",
+			"=value; Domain=example.com",
+			"id=1",
+			"id=1; Domain=",
+			"id=1; Domain=example.com; Path=relative",
+			"id=1; Domain=example.com; SameSite=Sometimes",
+			"id=1; Domain=example.com; Expires=Wed, 21 Oct 2015 07:28:00 GMT",
+			"id=1; Domain=example.com; Secure=yes",
+			"id=1; Domain=example.com; Priority=High",
+		] {
+			let err = parse_cookie_spec(spec).unwrap_err();
+			assert!(err.to_string().contains("invalid cookie"), "{spec}: {err}");
+		}
+	}
+
+	#[test]
+	fn parse_specs_fails_on_first_bad_entry() {
+		let specs = vec!["a=1; Domain=example.com".to_string(), "b".to_string()];
+		assert!(parse_cookie_specs(&specs).is_err());
+		assert_eq!(parse_cookie_specs(&specs[..1]).unwrap().len(), 1);
+	}
+}
@@ -6,7 +6,7 @@ use anyhow::{Result, anyhow};
 use jsonrpsee::core::ClientError;
 use jsonrpsee::http_client::HttpClient;
 use rpc::DaemonRpcClient as _;
-pub use rpc::{BrowserInfo, BrowserLease};
+pub use rpc::{BrowserInfo, BrowserLease, DriverState, DriverStatus};
 pub use server::Daemon;
 use tracing::debug;
 
@@ -95,6 +95,15 @@ pub async fn list_browsers() -> Result<Option<Vec<BrowserInfo>>> {
 	}
 }
 
+pub async fn driver_status() -> Result<Option<DriverStatus>> {
+	let client = client::connect_probe_client()?;
+	match client.driver_status().await {
+		Ok(status) => Ok(Some(status)),
+		Err(err) if is_not_running(&err) => Ok(None),
+		Err(err) => Err(anyhow!("daemon RPC driver_status failed: {err}")),
+	}
+}
+
 fn is_not_running(err: &ClientError) -> bool {
 	client::is_not_running_error(err)
 }
@@ -24,6 +24,27 @@ pub struct BrowserInfo {
 	pub last_used_at: u64,
 }
 
+/// Lifecycle of the daemon's Playwright driver process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriverState {
+	Running,
+	/// The driver exited and a relaunch is in progress.
+	Restarting,
+	/// Restart attempts were exhausted; the daemon must be restarted manually.
+	Failed,
+}
+
+/// Driver supervision snapshot reported by `daemon status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriverStatus {
+	pub state: DriverState,
+	/// Number of successful driver restarts since the daemon started.
+	pub restarts: u32,
+	/// Unix timestamp of the last successful restart.
+	pub last_restart_at: Option<u64>,
+}
+
 #[rpc(client, server)]
 pub trait DaemonRpc {
 	#[method(name = "daemon_ping")]
@@ -47,6 +68,9 @@ pub trait DaemonRpc {
 	#[method(name = "daemon_list_browsers")]
 	async fn list_browsers(&self) -> RpcResult<Vec<BrowserInfo>>;
 
+	#[method(name = "daemon_driver_status")]
+	async fn driver_status(&self) -> RpcResult<DriverStatus>;
+
 	#[method(name = "daemon_shutdown")]
 	async fn shutdown(&self) -> RpcResult<()>;
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result, anyhow};
 use jsonrpsee::core::{RpcResult, async_trait};
@@ -12,7 +13,7 @@ use tokio::sync::{Mutex, oneshot, watch};
 use tracing::{debug, info, warn};
 
 use super::DAEMON_TCP_PORT;
-use super::rpc::{BrowserInfo, BrowserLease, DaemonRpcServer};
+use super::rpc::{BrowserInfo, BrowserLease, DaemonRpcServer, DriverState, DriverStatus};
 use crate::types::BrowserKind;
 
 const PORT_RANGE_START: u16 = 9222;
@@ -23,6 +24,71 @@ const RPC_SPAWN_FAILED: i32 = -32051;
 const RPC_KILL_FAILED: i32 = -32052;
 const RPC_SHUTDOWN_FAILED: i32 = -32053;
 
+/// Consecutive relaunch attempts before the driver is marked failed.
+const MAX_DRIVER_RESTART_ATTEMPTS: u32 = 3;
+/// Pause between failed relaunch attempts.
+const DRIVER_RESTART_BACKOFF: Duration = Duration::from_millis(500);
+/// How often the daemon checks whether the driver process is still alive.
+const DRIVER_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Restart bookkeeping for the Playwright driver.
+///
+/// Kept outside [`DaemonState`] so `daemon status` can report it without
+/// waiting on the state lock.
+#[derive(Debug)]
+struct DriverSupervisor {
+	state: DriverState,
+	restarts: u32,
+	last_restart_at: Option<u64>,
+	/// Failed attempts since the driver was last running.
+	failed_attempts: u32,
+	max_attempts: u32,
+}
+
+impl DriverSupervisor {
+	fn new(max_attempts: u32) -> Self {
+		Self {
+			state: DriverState::Running,
+			restarts: 0,
+			last_restart_at: None,
+			failed_attempts: 0,
+			max_attempts,
+		}
+	}
+
+	/// Enters `Restarting`, returning `false` once the attempt budget is spent.
+	fn begin_restart(&mut self) -> bool {
+		if self.state == DriverState::Failed || self.failed_attempts >= self.max_attempts {
+			self.state = DriverState::Failed;
+			return false;
+		}
+		self.state = DriverState::Restarting;
+		true
+	}
+
+	fn restart_succeeded(&mut self, at: u64) {
+		self.state = DriverState::Running;
+		self.restarts += 1;
+		self.last_restart_at = Some(at);
+		self.failed_attempts = 0;
+	}
+
+	fn restart_failed(&mut self) {
+		self.failed_attempts += 1;
+		if self.failed_attempts >= self.max_attempts {
+			self.state = DriverState::Failed;
+		}
+	}
+
+	fn status(&self) -> DriverStatus {
+		DriverStatus {
+			state: self.state,
+			restarts: self.restarts,
+			last_restart_at: self.last_restart_at,
+		}
+	}
+}
+
 struct BrowserInstance {
 	info: BrowserInfo,
 	browser: pw_rs::Browser,
@@ -36,8 +102,18 @@ struct DaemonState {
 	session_index: HashMap<String, u16>,
 }
 
+/// Shared handles for checking and relaunching the driver.
+#[derive(Clone)]
+struct DriverControl {
+	state: Arc<Mutex<DaemonState>>,
+	supervisor: Arc<std::sync::Mutex<DriverSupervisor>>,
+	/// Held for the whole relaunch so concurrent callers wait for one restart.
+	restart_gate: Arc<Mutex<()>>,
+}
+
 struct DaemonRpcHandler {
 	state: Arc<Mutex<DaemonState>>,
+	driver: DriverControl,
 	shutdown_tx: watch::Sender<bool>,
 }
 
@@ -48,6 +124,10 @@ impl DaemonRpcServer for DaemonRpcHandler {
 	}
 
 	async fn acquire_browser(&self, browser: BrowserKind, headless: bool, session_key: String) -> RpcResult<BrowserLease> {
+		self.driver
+			.ensure_running()
+			.await
+			.map_err(|err| rpc_error("acquire_failed", RPC_ACQUIRE_FAILED, err))?;
 		let mut daemon = self.state.lock().await;
 		daemon
 			.acquire_browser(browser, headless, session_key)
@@ -57,6 +137,10 @@ impl DaemonRpcServer for DaemonRpcHandler {
 	}
 
 	async fn spawn_browser(&self, browser: BrowserKind, headless: bool, port: Option<u16>) -> RpcResult<BrowserLease> {
+		self.driver
+			.ensure_running()
+			.await
+			.map_err(|err| rpc_error("spawn_failed", RPC_SPAWN_FAILED, err))?;
 		let mut daemon = self.state.lock().await;
 		let session_key = format!("spawn:{}:{}:{}", browser, headless, now_ts());
 		daemon
@@ -94,6 +178,10 @@ impl DaemonRpcServer for DaemonRpcHandler {
 		Ok(daemon.browsers.values().map(|instance| instance.info.clone()).collect())
 	}
 
+	async fn driver_status(&self) -> RpcResult<DriverStatus> {
+		Ok(lock_supervisor(&self.driver.supervisor).status())
+	}
+
 	async fn shutdown(&self) -> RpcResult<()> {
 		let mut daemon = self.state.lock().await;
 		daemon.shutdown().await.map_err(|err| rpc_error("shutdown_failed", RPC_SHUTDOWN_FAILED, err))?;
@@ -104,6 +192,7 @@ impl DaemonRpcServer for DaemonRpcHandler {
 
 pub struct Daemon {
 	state: Arc<Mutex<DaemonState>>,
+	driver: DriverControl,
 	shutdown_tx: watch::Sender<bool>,
 	shutdown_rx: watch::Receiver<bool>,
 }
@@ -111,14 +200,20 @@ pub struct Daemon {
 impl Daemon {
 	pub async fn start() -> Result<Self> {
 		let playwright = Playwright::launch().await.map_err(|e| anyhow!(e.to_string()))?;
-		let state = DaemonState {
+		let state = Arc::new(Mutex::new(DaemonState {
 			playwright,
 			browsers: HashMap::new(),
 			session_index: HashMap::new(),
+		}));
+		let driver = DriverControl {
+			state: Arc::clone(&state),
+			supervisor: Arc::new(std::sync::Mutex::new(DriverSupervisor::new(MAX_DRIVER_RESTART_ATTEMPTS))),
+			restart_gate: Arc::new(Mutex::new(())),
 		};
 		let (shutdown_tx, shutdown_rx) = watch::channel(false);
 		Ok(Self {
-			state: Arc::new(Mutex::new(state)),
+			state,
+			driver,
 			shutdown_tx,
 			shutdown_rx,
 		})
@@ -137,6 +232,7 @@ impl Daemon {
 
 		let rpc = DaemonRpcHandler {
 			state: Arc::clone(&self.state),
+			driver: self.driver.clone(),
 			shutdown_tx: self.shutdown_tx.clone(),
 		};
 		let handle = server.start(rpc.into_rpc());
@@ -144,6 +240,7 @@ impl Daemon {
 		if let Some(tx) = ready_tx {
 			let _ = tx.send(());
 		}
+		let watcher = tokio::spawn(watch_driver(self.driver.clone(), self.shutdown_rx.clone()));
 
 		#[cfg(unix)]
 		{
@@ -196,6 +293,7 @@ impl Daemon {
 			}
 		}
 
+		watcher.abort();
 		let _ = handle.stop();
 		handle.stopped().await;
 		Ok(())
@@ -321,6 +419,88 @@ impl DaemonState {
 	}
 }
 
+impl DriverControl {
+	fn is_healthy(&self, daemon: &DaemonState) -> bool {
+		daemon.playwright.is_driver_running() && lock_supervisor(&self.supervisor).state == DriverState::Running
+	}
+
+	/// Relaunches the driver if its process has exited.
+	///
+	/// Browsers launched by the old driver die with it, so their entries are
+	/// dropped. The new driver starts without the state lock held, so RPCs
+	/// that don't need the driver keep answering; callers that do need it wait
+	/// on the restart gate for the outcome. Attempts are bounded by the supervisor.
+	async fn ensure_running(&self) -> Result<()> {
+		if self.is_healthy(&*self.state.lock().await) {
+			return Ok(());
+		}
+
+		let _gate = self.restart_gate.lock().await;
+		{
+			let mut daemon = self.state.lock().await;
+			// Another caller may have finished a restart while we waited.
+			if self.is_healthy(&daemon) {
+				return Ok(());
+			}
+			warn!(target = "pw.daemon", "playwright driver exited, restarting");
+			daemon.browsers.clear();
+			daemon.session_index.clear();
+		}
+
+		loop {
+			if !lock_supervisor(&self.supervisor).begin_restart() {
+				return Err(anyhow!(
+					"Playwright driver failed after {MAX_DRIVER_RESTART_ATTEMPTS} restart attempts; restart the daemon"
+				));
+			}
+			match Playwright::launch().await {
+				Ok(playwright) => {
+					self.state.lock().await.playwright = playwright;
+					lock_supervisor(&self.supervisor).restart_succeeded(now_ts());
+					info!(target = "pw.daemon", "playwright driver restarted");
+					return Ok(());
+				}
+				Err(err) => {
+					warn!(target = "pw.daemon", error = %err, "playwright driver restart failed");
+					lock_supervisor(&self.supervisor).restart_failed();
+					tokio::time::sleep(DRIVER_RESTART_BACKOFF).await;
+				}
+			}
+		}
+	}
+}
+
+/// Polls driver liveness so restarts happen before the next session needs one.
+async fn watch_driver(driver: DriverControl, mut shutdown_rx: watch::Receiver<bool>) {
+	let mut interval = tokio::time::interval(DRIVER_WATCH_INTERVAL);
+	loop {
+		tokio::select! {
+			_ = interval.tick() => {}
+			_ = shutdown_rx.changed() => {
+				if *shutdown_rx.borrow() {
+					return;
+				}
+			}
+		}
+		// Busy state means a request is in flight; it checks the driver itself.
+		let Ok(daemon) = driver.state.try_lock() else {
+			continue;
+		};
+		let running = daemon.playwright.is_driver_running();
+		drop(daemon);
+		if running || lock_supervisor(&driver.supervisor).state == DriverState::Failed {
+			continue;
+		}
+		if let Err(err) = driver.ensure_running().await {
+			warn!(target = "pw.daemon", error = %err, "giving up on playwright driver");
+		}
+	}
+}
+
+fn lock_supervisor(supervisor: &std::sync::Mutex<DriverSupervisor>) -> std::sync::MutexGuard<'_, DriverSupervisor> {
+	supervisor.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
 async fn shutdown_daemon_state(state: &Arc<Mutex<DaemonState>>) {
 	let mut daemon = state.lock().await;
 	if let Err(err) = daemon.shutdown().await {
@@ -335,3 +515,53 @@ fn rpc_error(code: &str, rpc_code: i32, err: anyhow::Error) -> ErrorObjectOwned
 fn now_ts() -> u64 {
 	std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn supervisor_counts_successful_restarts() {
+		let mut supervisor = DriverSupervisor::new(3);
+		assert_eq!(supervisor.status().state, DriverState::Running);
+
+		assert!(supervisor.begin_restart());
+		assert_eq!(supervisor.status().state, DriverState::Restarting);
+		supervisor.restart_succeeded(100);
+
+		let status = supervisor.status();
+		assert_eq!(status.state, DriverState::Running);
+		assert_eq!(status.restarts, 1);
+		assert_eq!(status.last_restart_at, Some(100));
+	}
+
+	#[test]
+	fn supervisor_fails_after_bounded_attempts() {
+		let mut supervisor = DriverSupervisor::new(2);
+
+		assert!(supervisor.begin_restart());
+		supervisor.restart_failed();
+		assert_eq!(supervisor.status().state, DriverState::Restarting);
+		assert!(supervisor.begin_restart());
+		supervisor.restart_failed();
+
+		assert_eq!(supervisor.status().state, DriverState::Failed);
+		assert!(!supervisor.begin_restart());
+		assert_eq!(supervisor.status().restarts, 0);
+	}
+
+	#[test]
+	fn supervisor_success_resets_failed_attempts() {
+		let mut supervisor = DriverSupervisor::new(2);
+
+		assert!(supervisor.begin_restart());
+		supervisor.restart_failed();
+		assert!(supervisor.begin_restart());
+		supervisor.restart_succeeded(7);
+
+		assert!(supervisor.begin_restart());
+		supervisor.restart_failed();
+		assert_eq!(supervisor.status().state, DriverState::Restarting);
+		assert_eq!(supervisor.status().restarts, 1);
+	}
+}
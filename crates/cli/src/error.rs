@@ -28,6 +28,10 @@ pub enum PwError {
 		source: anyhow::Error,
 	},
 
+	/// Navigation succeeded but the main response status was outside the expected range.
+	#[error("navigation to {url} returned HTTP {status}, expected {expected}")]
+	UnexpectedStatus { url: String, status: u16, expected: String },
+
 	#[error("element not found: {selector}")]
 	ElementNotFound { selector: String },
 
@@ -47,6 +51,20 @@ pub enum PwError {
 	#[error("context resolution failed: {0}")]
 	Context(String),
 
+	/// Command input that failed to deserialize into the command's arguments.
+	#[error("{message}")]
+	InvalidInput {
+		message: String,
+		/// Input key that caused the failure, when it could be determined.
+		field: Option<String>,
+		/// Field names the command accepts.
+		expected: Vec<&'static str>,
+	},
+
+	/// Console errors logged by the page during an otherwise successful command.
+	#[error("page logged {} console error(s)", messages.len())]
+	ConsoleErrors { messages: Vec<String> },
+
 	#[error("unsupported mode: {0}")]
 	UnsupportedMode(String),
 
@@ -63,6 +81,15 @@ pub enum PwError {
 	Anyhow(#[from] anyhow::Error),
 }
 
+/// Playwright error text that identifies a failed download rather than any message mentioning one.
+const DOWNLOAD_ERROR_MARKERS: &[&str] = &[
+	"download.saveAs:",
+	"download.path:",
+	"Download was canceled",
+	"Download is starting",
+	"acceptDownloads",
+];
+
 /// Classify a Playwright error and clean up verbose messages.
 ///
 /// Playwright's strict mode violations dump every matching element, which is
@@ -70,18 +97,21 @@ pub enum PwError {
 fn classify_and_clean_playwright_error(msg: &str) -> (ErrorCode, String) {
 	// Handle strict mode violations - extremely verbose, need cleanup
 	if msg.contains("strict mode violation") {
-		if let Some(clean) = clean_strict_mode_error(msg) {
-			return (ErrorCode::SelectorNotFound, clean);
-		}
+		let clean = clean_strict_mode_error(msg).unwrap_or_else(|| msg.to_string());
+		return (ErrorCode::SelectorAmbiguous, clean);
 	}
 
 	// Map to appropriate error codes
 	let code = if msg.contains("Timeout") {
 		ErrorCode::Timeout
+	} else if msg.contains("net::ERR_") || msg.contains("NS_ERROR_") {
+		ErrorCode::NetworkError
 	} else if msg.contains("not found") || msg.contains("no element") {
 		ErrorCode::SelectorNotFound
 	} else if msg.contains("navigation") {
 		ErrorCode::NavigationFailed
+	} else if DOWNLOAD_ERROR_MARKERS.iter().any(|marker| msg.contains(marker)) {
+		ErrorCode::DownloadFailed
 	} else {
 		ErrorCode::InternalError
 	};
@@ -89,6 +119,36 @@ fn classify_and_clean_playwright_error(msg: &str) -> (ErrorCode, String) {
 	(code, msg.to_string())
 }
 
+/// Maps structural runtime errors to a code without inspecting the message.
+///
+/// Returns `None` for errors that need message-based classification, such as
+/// most remote errors raised by the Playwright server.
+fn runtime_error_code(err: &pw_rs::Error) -> Option<ErrorCode> {
+	use pw_rs::Error;
+
+	let code = match err {
+		Error::ServerNotFound | Error::LaunchFailed(_) | Error::HandshakeTimeout { .. } => ErrorCode::BrowserLaunchFailed,
+		Error::ConnectionFailed(_) => ErrorCode::NetworkError,
+		Error::TransportError(_)
+		| Error::ProtocolError(_)
+		| Error::Json(_)
+		| Error::ObjectNotFound { .. }
+		| Error::UnknownObjectType(_)
+		| Error::ChannelClosed => ErrorCode::ProtocolError,
+		Error::Timeout(_) | Error::NavigationTimeout { .. } | Error::AssertionTimeout(_) => ErrorCode::Timeout,
+		Error::TargetClosed { .. } => ErrorCode::SessionError,
+		Error::InvalidArgument(_) => ErrorCode::InvalidInput,
+		Error::UnsupportedMode(_) => ErrorCode::UnsupportedMode,
+		Error::ElementNotFound(_) => ErrorCode::SelectorNotFound,
+		Error::Io(_) => ErrorCode::IoError,
+		Error::ServerError(_) => ErrorCode::InternalError,
+		Error::Remote { .. } if err.is_timeout() => ErrorCode::Timeout,
+		Error::Remote { .. } if err.is_target_closed() => ErrorCode::SessionError,
+		Error::Remote { .. } => return None,
+	};
+	Some(code)
+}
+
 /// Clean up verbose strict mode violation errors.
 ///
 /// Input like:
@@ -101,8 +161,10 @@ fn classify_and_clean_playwright_error(msg: &str) -> (ErrorCode, String) {
 ///
 /// Becomes:
 /// ```text
-/// Selector "button" matched 55 elements (strict mode requires exactly 1). Use a more specific selector or `>> nth=0` to select the first match.
+/// Selector "button" matched 55 elements (strict mode requires exactly 1). Use a more specific selector.
 /// ```
+///
+/// Commands that can pick a match get the `--first`/`--nth` hint from [`with_match_picking_hint`].
 fn clean_strict_mode_error(msg: &str) -> Option<String> {
 	// Extract selector and count from the error message
 	// Pattern: locator("...") resolved to N elements
@@ -118,12 +180,23 @@ fn clean_strict_mode_error(msg: &str) -> Option<String> {
 	let count: u32 = msg[count_start..count_start + count_end].parse().ok()?;
 
 	Some(format!(
-		"Selector \"{}\" matched {} elements (strict mode requires exactly 1). \
-         Use a more specific selector or `>> nth=0` to select the first match.",
+		"Selector \"{}\" matched {} elements (strict mode requires exactly 1). Use a more specific selector.",
 		selector, count
 	))
 }
 
+/// Commands that accept `--first` / `--nth` to pick one of several matches.
+const MATCH_PICKING_COMMANDS: &[&str] = &["click", "fill", "page.upload"];
+
+/// Adds the `--first`/`--nth` hint to an ambiguous-selector error from `op`,
+/// when that command accepts those flags.
+pub fn with_match_picking_hint(mut error: CommandError, op: &str) -> CommandError {
+	if error.code == ErrorCode::SelectorAmbiguous && MATCH_PICKING_COMMANDS.contains(&op) {
+		error.message.push_str(" Or pass `--first` or `--nth N` to pick a match.");
+	}
+	error
+}
+
 impl PwError {
 	pub fn failure_with_artifacts(&self) -> Option<&crate::output::FailureWithArtifacts> {
 		match self {
@@ -148,6 +221,11 @@ impl PwError {
 				}
 				(ErrorCode::NavigationFailed, msg, Some(serde_json::json!({ "url": url })))
 			}
+			PwError::UnexpectedStatus { url, status, expected } => (
+				ErrorCode::NavigationFailed,
+				format!("Navigation to {url} returned HTTP {status}, expected {expected}"),
+				Some(serde_json::json!({ "url": url, "status": status, "expected": expected })),
+			),
 			PwError::ElementNotFound { selector } => (
 				ErrorCode::SelectorNotFound,
 				format!("No elements matched selector: {selector}"),
@@ -165,13 +243,23 @@ impl PwError {
 				Some(serde_json::json!({ "timeout_ms": ms, "condition": condition })),
 			),
 			PwError::Context(msg) => (ErrorCode::InvalidInput, msg.clone(), None),
+			PwError::InvalidInput { message, field, expected } => (
+				ErrorCode::InvalidInput,
+				message.clone(),
+				Some(serde_json::json!({ "field": field, "expectedFields": expected })),
+			),
+			PwError::ConsoleErrors { messages } => (
+				ErrorCode::ConsoleError,
+				format!("Page logged console errors: {}", messages.join("; ")),
+				Some(serde_json::json!({ "consoleErrors": messages })),
+			),
 			PwError::UnsupportedMode(msg) => (ErrorCode::UnsupportedMode, msg.clone(), None),
 			PwError::Io(err) => (ErrorCode::IoError, err.to_string(), None),
 			PwError::Json(err) => (ErrorCode::InternalError, format!("JSON error: {err}"), None),
 			PwError::Playwright(err) => {
 				let msg = err.to_string();
 				let (code, clean_msg) = classify_and_clean_playwright_error(&msg);
-				(code, clean_msg, None)
+				(runtime_error_code(err).unwrap_or(code), clean_msg, None)
 			}
 			PwError::Anyhow(err) => (ErrorCode::InternalError, err.to_string(), None),
 		};
@@ -179,3 +267,135 @@ impl PwError {
 		CommandError { code, message, details }
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn strict_mode_violation_is_ambiguous() {
+		let raw = "Error: strict mode violation: locator(\"button\") resolved to 3 elements:\n    1) <button>A</button>";
+		let (code, msg) = classify_and_clean_playwright_error(raw);
+		assert_eq!(code, ErrorCode::SelectorAmbiguous);
+		assert!(msg.contains("matched 3 elements"), "{msg}");
+		assert!(!msg.contains("--first"), "{msg}");
+
+		let error = CommandError {
+			code,
+			message: msg,
+			details: None,
+		};
+		assert!(with_match_picking_hint(error.clone(), "click").message.contains("--nth N"));
+		assert!(!with_match_picking_hint(error, "page.text").message.contains("--first"));
+	}
+
+	fn runtime_code(err: pw_rs::Error) -> ErrorCode {
+		PwError::Playwright(err).to_command_error().code
+	}
+
+	fn remote(name: &str, message: &str) -> pw_rs::Error {
+		pw_rs::Error::Remote {
+			name: name.to_string(),
+			message: message.to_string(),
+			stack: None,
+		}
+	}
+
+	#[test]
+	fn runtime_errors_map_to_failure_classes() {
+		use pw_rs::Error;
+
+		let cases = [
+			(Error::ServerNotFound, ErrorCode::BrowserLaunchFailed),
+			(Error::LaunchFailed("node missing".into()), ErrorCode::BrowserLaunchFailed),
+			(Error::HandshakeTimeout { attempts: 3 }, ErrorCode::BrowserLaunchFailed),
+			(Error::ServerError("boom".into()), ErrorCode::InternalError),
+			(Error::ConnectionFailed("refused".into()), ErrorCode::NetworkError),
+			(Error::TransportError("broken pipe".into()), ErrorCode::ProtocolError),
+			(Error::ProtocolError("bad frame".into()), ErrorCode::ProtocolError),
+			(
+				Error::Json(serde_json::from_str::<serde_json::Value>("{").unwrap_err()),
+				ErrorCode::ProtocolError,
+			),
+			(
+				Error::ObjectNotFound {
+					guid: "page@1".into(),
+					expected: Some("Page"),
+				},
+				ErrorCode::ProtocolError,
+			),
+			(Error::UnknownObjectType("Widget".into()), ErrorCode::ProtocolError),
+			(Error::ChannelClosed, ErrorCode::ProtocolError),
+			(Error::Io(std::io::Error::other("disk")), ErrorCode::IoError),
+			(Error::Timeout("click".into()), ErrorCode::Timeout),
+			(
+				Error::NavigationTimeout {
+					url: "https://example.com".into(),
+					duration_ms: 100,
+				},
+				ErrorCode::Timeout,
+			),
+			(Error::AssertionTimeout("visible".into()), ErrorCode::Timeout),
+			(Error::UnsupportedMode("IME composition".into()), ErrorCode::UnsupportedMode),
+			(
+				Error::TargetClosed {
+					target_type: "page".into(),
+					context: String::new(),
+				},
+				ErrorCode::SessionError,
+			),
+			(Error::InvalidArgument("nope".into()), ErrorCode::InvalidInput),
+			(Error::ElementNotFound("#gone".into()), ErrorCode::SelectorNotFound),
+		];
+
+		for (err, expected) in cases {
+			let label = err.to_string();
+			assert_eq!(runtime_code(err), expected, "{label}");
+		}
+	}
+
+	#[test]
+	fn remote_errors_are_classified_by_name_then_message() {
+		assert_eq!(runtime_code(remote("TimeoutError", "locator.click: exceeded")), ErrorCode::Timeout);
+		assert_eq!(runtime_code(remote("TargetClosedError", "page closed")), ErrorCode::SessionError);
+		assert_eq!(
+			runtime_code(remote("Error", "page.goto: net::ERR_NAME_NOT_RESOLVED at https://nope.invalid/")),
+			ErrorCode::NetworkError
+		);
+		assert_eq!(
+			runtime_code(remote("Error", "download.saveAs: Download was canceled")),
+			ErrorCode::DownloadFailed
+		);
+		assert_eq!(runtime_code(remote("Error", "page.goto: Download is starting")), ErrorCode::DownloadFailed);
+		assert_eq!(
+			runtime_code(remote("Error", "locator.click: element \"#download-link\" not found")),
+			ErrorCode::SelectorNotFound
+		);
+		assert_eq!(
+			runtime_code(remote("Error", "page.goto: navigation to /downloads interrupted")),
+			ErrorCode::NavigationFailed
+		);
+		assert_eq!(runtime_code(remote("Error", "something odd")), ErrorCode::InternalError);
+	}
+
+	#[test]
+	fn error_code_display_is_screaming_snake_case() {
+		assert_eq!(ErrorCode::InternalError.to_string(), "INTERNAL_ERROR");
+		assert_eq!(ErrorCode::NetworkError.to_string(), "NETWORK_ERROR");
+		assert_eq!(ErrorCode::ProtocolError.to_string(), "PROTOCOL_ERROR");
+		assert_eq!(ErrorCode::DownloadFailed.to_string(), "DOWNLOAD_FAILED");
+		assert_eq!(serde_json::to_value(ErrorCode::DownloadFailed).unwrap(), "DOWNLOAD_FAILED");
+	}
+
+	#[test]
+	fn console_errors_list_messages_in_details() {
+		let err = PwError::ConsoleErrors {
+			messages: vec!["boom".to_string(), "bang".to_string()],
+		}
+		.to_command_error();
+		assert_eq!(err.code, ErrorCode::ConsoleError);
+		assert_eq!(err.code.to_string(), "CONSOLE_ERROR");
+		assert!(err.message.contains("boom; bang"), "{}", err.message);
+		assert_eq!(err.details.unwrap()["consoleErrors"], serde_json::json!(["boom", "bang"]));
+	}
+}
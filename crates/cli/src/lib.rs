@@ -5,17 +5,20 @@ pub mod cli;
 pub mod commands;
 pub mod context;
 pub mod context_store;
+pub mod cookies;
 pub mod daemon;
 pub mod error;
 pub mod logging;
 pub mod output;
 pub mod project;
+pub mod project_defaults;
 pub mod protocol;
 pub mod readable;
 pub mod relay;
 pub mod runtime;
 pub mod session;
 pub mod session_helpers;
+pub mod signals;
 pub mod styles;
 pub mod target;
 #[cfg(test)]
@@ -1,18 +1,53 @@
 use clap::Parser;
-use pw_cli::cli::Cli;
+use pw_cli::cli::{Cli, Commands};
 use pw_cli::error::PwError;
+use pw_cli::protocol::{CommandResponse, print_error_envelope};
+use pw_cli::signals::{SHUTDOWN, SHUTDOWN_GRACE, wait_for_shutdown_signal};
 use pw_cli::{commands, logging};
 
 #[tokio::main]
 async fn main() {
 	let cli = Cli::parse();
 	logging::init_logging(cli.verbose);
-	if let Err(err) = commands::dispatch(cli).await {
-		handle_error(err);
-		std::process::exit(1);
+	let strict_exit = cli.strict_exit;
+	let json_errors = cli.json_errors;
+	let op = match &cli.command {
+		Commands::Exec(args) => args.op.clone(),
+		_ => None,
+	};
+
+	// The daemon installs its own signal handlers and tears down its sessions itself.
+	let result = if matches!(cli.command, Commands::Daemon(_)) {
+		commands::dispatch(cli).await
+	} else {
+		tokio::select! {
+			result = commands::dispatch(cli) => result,
+			signal = wait_for_shutdown_signal() => {
+				SHUTDOWN.cleanup(SHUTDOWN_GRACE).await;
+				std::process::exit(signal.exit_code());
+			}
+		}
+	};
+
+	match result {
+		Ok(true) => {}
+		Ok(false) => {
+			if strict_exit {
+				std::process::exit(1);
+			}
+		}
+		Err(err) => {
+			handle_error(err, json_errors, op.as_deref());
+			std::process::exit(1);
+		}
 	}
 }
 
-fn handle_error(err: PwError) {
+/// Reports a CLI-level failure, also as a stdout envelope under `--json-errors`.
+fn handle_error(err: PwError, json_errors: bool, op: Option<&str>) {
 	eprintln!("Error: {err}");
+	if json_errors {
+		let response = CommandResponse::error(None, op.unwrap_or("pw").to_string(), err.to_command_error(), None);
+		print_error_envelope(&response);
+	}
 }
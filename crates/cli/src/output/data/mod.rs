@@ -71,7 +71,31 @@ pub struct FillData {
 #[serde(rename_all = "camelCase")]
 pub struct EvalData {
 	pub result: serde_json::Value,
+	/// JavaScript type of the returned value; distinguishes `undefined` from `null`.
+	pub result_type: EvalResultType,
 	pub expression: String,
+	/// Set when the serialized result exceeded the size cap and `result` holds a truncated JSON string.
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub truncated: bool,
+	/// Result of every pipeline stage, in order, when `keepIntermediates` is set.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub intermediates: Option<Vec<serde_json::Value>>,
+}
+
+/// JavaScript type of a `page.eval` result.
+///
+/// `undefined` (and values `JSON.stringify` drops, such as functions) report
+/// `undefined` with a `null` result; `NaN` and `Infinity` serialize as `null`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvalResultType {
+	String,
+	Number,
+	Boolean,
+	Object,
+	Array,
+	Null,
+	Undefined,
 }
 
 /// Result data for session start command.
@@ -99,7 +123,7 @@ pub struct ElementsData {
 }
 
 /// An interactive element found on the page.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InteractiveElement {
 	pub tag: String,
@@ -112,10 +136,31 @@ pub struct InteractiveElement {
 	pub name: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub id: Option<String>,
+	/// Current value of inputs, selects, and textareas (omitted for passwords).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub value: Option<String>,
+	/// Checked state of checkboxes and radios.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub checked: Option<bool>,
+	/// Viewport-space position from `getBoundingClientRect`.
 	pub x: i32,
 	pub y: i32,
+	/// Document-space position (`x`/`y` plus the window scroll offset), stable across scrolling.
+	#[serde(default)]
+	pub page_x: i32,
+	#[serde(default)]
+	pub page_y: i32,
 	pub width: i32,
 	pub height: i32,
+	/// Whether the element passed the visibility check; reported only with `includeHidden`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub visible: Option<bool>,
+	/// Whether the element or an ancestor has `aria-hidden="true"`; reported only with `includeHidden`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub aria_hidden: Option<bool>,
+	/// Number of elements this entry stands for; reported only with `dedupBy` when above 1.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub count: Option<usize>,
 }
 
 /// Result data for snapshot command.
@@ -126,7 +171,59 @@ pub struct SnapshotData {
 	pub title: String,
 	pub viewport_width: i32,
 	pub viewport_height: i32,
+	/// Language and key meta tags; fields the page does not declare are absent.
+	#[serde(default)]
+	pub meta: SnapshotMeta,
 	pub text: String,
 	pub elements: Vec<InteractiveElement>,
 	pub element_count: usize,
+	/// Changes against `--baseline`, present only when a baseline was compared.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub diff: Option<SnapshotDiff>,
+}
+
+/// Document language and meta tags captured by a snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotMeta {
+	/// `<html lang>`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub lang: Option<String>,
+	/// Declared `<meta charset>` or `http-equiv` Content-Type charset, e.g. `utf-8`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub charset: Option<String>,
+	/// `<meta name="description">`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// `<meta property="og:title">`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub og_title: Option<String>,
+	/// `<meta property="og:image">`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub og_image: Option<String>,
+}
+
+/// Structural difference between a baseline snapshot and the current one.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDiff {
+	/// Interactive elements whose selector is new.
+	pub added: Vec<InteractiveElement>,
+	/// Baseline elements whose selector is gone.
+	pub removed: Vec<InteractiveElement>,
+	/// Elements present in both whose tag, text, or name changed.
+	pub changed: Vec<ChangedElement>,
+	/// Current text length minus baseline text length, in characters.
+	pub text_delta: i64,
+	pub url_changed: bool,
+	pub title_changed: bool,
+}
+
+/// An interactive element present in both snapshots with different content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedElement {
+	pub selector: String,
+	pub before: InteractiveElement,
+	pub after: InteractiveElement,
 }
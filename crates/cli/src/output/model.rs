@@ -71,6 +71,13 @@ pub enum ErrorCode {
 	InvalidInput,
 	UnsupportedMode,
 	AuthError,
+	/// Network-level failure (DNS, refused connection, `net::ERR_*`).
+	NetworkError,
+	/// Malformed or unexpected traffic between the CLI and the Playwright driver.
+	ProtocolError,
+	DownloadFailed,
+	/// The page logged a console error under `--fail-on-console-error`.
+	ConsoleError,
 	InternalError,
 }
 
@@ -89,6 +96,10 @@ impl std::fmt::Display for ErrorCode {
 			ErrorCode::InvalidInput => write!(f, "INVALID_INPUT"),
 			ErrorCode::UnsupportedMode => write!(f, "UNSUPPORTED_MODE"),
 			ErrorCode::AuthError => write!(f, "AUTH_ERROR"),
+			ErrorCode::NetworkError => write!(f, "NETWORK_ERROR"),
+			ErrorCode::ProtocolError => write!(f, "PROTOCOL_ERROR"),
+			ErrorCode::DownloadFailed => write!(f, "DOWNLOAD_FAILED"),
+			ErrorCode::ConsoleError => write!(f, "CONSOLE_ERROR"),
 			ErrorCode::InternalError => write!(f, "INTERNAL_ERROR"),
 		}
 	}
@@ -115,10 +126,14 @@ pub enum ArtifactType {
 	Trace,
 	Video,
 	Download,
+	/// Extracted text or markdown written by `page.read --output`.
+	Text,
+	/// Document written by `page.pdf`.
+	Pdf,
 }
 
 /// Diagnostic message attached to a command result.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Diagnostic {
 	pub level: DiagnosticLevel,
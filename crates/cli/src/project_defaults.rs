@@ -0,0 +1,200 @@
+//! Shared CLI defaults from `pw.toml` and `PW_*` environment variables.
+//!
+//! `pw.toml` lives at the workspace root so a team can commit the same
+//! defaults. Precedence for each field is: CLI flag or request override,
+//! then environment, then profile config, then `pw.toml`, then the built-in
+//! fallback.
+//!
+//! ```toml
+//! browser = "firefox"
+//! timeout_ms = 15000
+//! format = "json"
+//! artifacts_dir = "playwright/results"
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use toml_edit::{DocumentMut, Item};
+
+use crate::error::{PwError, Result};
+use crate::output::OutputFormat;
+use crate::types::BrowserKind;
+
+/// File name looked up at the workspace root.
+pub const PROJECT_DEFAULTS_FILE: &str = "pw.toml";
+
+/// Environment variables mirroring each `pw.toml` key.
+pub const BROWSER_ENV_VAR: &str = "PW_BROWSER";
+pub const TIMEOUT_MS_ENV_VAR: &str = "PW_TIMEOUT_MS";
+pub const FORMAT_ENV_VAR: &str = "PW_FORMAT";
+pub const ARTIFACTS_DIR_ENV_VAR: &str = "PW_ARTIFACTS_DIR";
+
+/// Template written by `init`.
+pub const PW_TOML_TEMPLATE: &str = r#"# Shared defaults for the pw CLI.
+# Precedence: CLI flags > PW_* env vars > profile config > this file > built-ins.
+
+# Browser engine: "chromium", "firefox", or "webkit".
+browser = "chromium"
+
+# Navigation and wait timeout in milliseconds.
+# timeout_ms = 30000
+
+# Output format: "toon", "json", "ndjson", or "text".
+# format = "toon"
+
+# Directory for failure artifacts, relative to this file.
+# artifacts_dir = "playwright/results"
+"#;
+
+/// CLI defaults from one source (`pw.toml` or the environment).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectDefaults {
+	pub browser: Option<BrowserKind>,
+	pub timeout_ms: Option<u64>,
+	pub format: Option<OutputFormat>,
+	pub artifacts_dir: Option<PathBuf>,
+}
+
+impl ProjectDefaults {
+	/// Loads `pw.toml` from `root`, returning empty defaults when it does not exist.
+	pub fn load(root: &Path) -> Result<Self> {
+		let path = root.join(PROJECT_DEFAULTS_FILE);
+		let contents = match std::fs::read_to_string(&path) {
+			Ok(contents) => contents,
+			Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+			Err(err) => return Err(err.into()),
+		};
+		Self::parse(&contents, root).map_err(|msg| PwError::Context(format!("invalid {}: {msg}", path.display())))
+	}
+
+	/// Reads `PW_BROWSER`, `PW_TIMEOUT_MS`, `PW_FORMAT`, and `PW_ARTIFACTS_DIR`.
+	pub fn from_env() -> Result<Self> {
+		Self::from_vars(|key| std::env::var(key).ok().filter(|value| !value.is_empty()))
+	}
+
+	fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+		let invalid = |key: &str, value: &str| PwError::Context(format!("invalid {key}={value:?}"));
+		Ok(Self {
+			browser: var(BROWSER_ENV_VAR)
+				.map(|value| BrowserKind::from_str(&value, true).map_err(|_| invalid(BROWSER_ENV_VAR, &value)))
+				.transpose()?,
+			timeout_ms: var(TIMEOUT_MS_ENV_VAR)
+				.map(|value| value.parse().map_err(|_| invalid(TIMEOUT_MS_ENV_VAR, &value)))
+				.transpose()?,
+			format: var(FORMAT_ENV_VAR)
+				.map(|value| value.parse::<OutputFormat>().map_err(|_| invalid(FORMAT_ENV_VAR, &value)))
+				.transpose()?,
+			artifacts_dir: var(ARTIFACTS_DIR_ENV_VAR).map(PathBuf::from),
+		})
+	}
+
+	fn parse(contents: &str, root: &Path) -> std::result::Result<Self, String> {
+		let doc: DocumentMut = contents.parse().map_err(|err| format!("{err}"))?;
+
+		let string = |key: &str| -> std::result::Result<Option<&str>, String> {
+			match doc.get(key) {
+				None => Ok(None),
+				Some(item) => item.as_str().map(Some).ok_or_else(|| format!("`{key}` must be a string")),
+			}
+		};
+
+		let browser = string("browser")?
+			.map(|value| BrowserKind::from_str(value, true).map_err(|_| format!("unknown browser {value:?}")))
+			.transpose()?;
+		let format = string("format")?
+			.map(|value| value.parse::<OutputFormat>().map_err(|_| format!("unknown format {value:?}")))
+			.transpose()?;
+		let artifacts_dir = string("artifacts_dir")?.map(|value| root.join(value));
+		let timeout_ms = match doc.get("timeout_ms") {
+			None => None,
+			Some(item) => Some(non_negative_integer(item).ok_or("`timeout_ms` must be a non-negative integer")?),
+		};
+
+		Ok(Self {
+			browser,
+			timeout_ms,
+			format,
+			artifacts_dir,
+		})
+	}
+
+	/// Fills unset fields from `fallback`.
+	pub fn or(self, fallback: Self) -> Self {
+		Self {
+			browser: self.browser.or(fallback.browser),
+			timeout_ms: self.timeout_ms.or(fallback.timeout_ms),
+			format: self.format.or(fallback.format),
+			artifacts_dir: self.artifacts_dir.or(fallback.artifacts_dir),
+		}
+	}
+}
+
+fn non_negative_integer(item: &Item) -> Option<u64> {
+	item.as_integer().and_then(|value| u64::try_from(value).ok())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+
+	use super::*;
+
+	#[test]
+	fn missing_file_is_empty() {
+		let dir = tempfile::tempdir().unwrap();
+		assert_eq!(ProjectDefaults::load(dir.path()).unwrap(), ProjectDefaults::default());
+	}
+
+	#[test]
+	fn parses_all_keys() {
+		let dir = tempfile::tempdir().unwrap();
+		std::fs::write(
+			dir.path().join(PROJECT_DEFAULTS_FILE),
+			"browser = \"firefox\"\ntimeout_ms = 15000\nformat = \"json\"\nartifacts_dir = \"out\"\n",
+		)
+		.unwrap();
+
+		let defaults = ProjectDefaults::load(dir.path()).unwrap();
+		assert_eq!(defaults.browser, Some(BrowserKind::Firefox));
+		assert_eq!(defaults.timeout_ms, Some(15000));
+		assert_eq!(defaults.format, Some(OutputFormat::Json));
+		assert_eq!(defaults.artifacts_dir, Some(dir.path().join("out")));
+	}
+
+	#[test]
+	fn rejects_bad_values() {
+		let dir = tempfile::tempdir().unwrap();
+		for bad in ["browser = \"netscape\"", "timeout_ms = -1", "timeout_ms = \"fast\"", "browser = ["] {
+			std::fs::write(dir.path().join(PROJECT_DEFAULTS_FILE), bad).unwrap();
+			let err = ProjectDefaults::load(dir.path()).unwrap_err();
+			assert!(matches!(err, PwError::Context(_)), "{bad}: {err}");
+		}
+	}
+
+	#[test]
+	fn template_parses_to_chromium() {
+		let defaults = ProjectDefaults::parse(PW_TOML_TEMPLATE, Path::new(".")).unwrap();
+		assert_eq!(defaults.browser, Some(BrowserKind::Chromium));
+		assert_eq!(defaults.timeout_ms, None);
+	}
+
+	#[test]
+	fn env_overrides_file() {
+		let vars = HashMap::from([(BROWSER_ENV_VAR, "webkit"), (TIMEOUT_MS_ENV_VAR, "500")]);
+		let env = ProjectDefaults::from_vars(|key| vars.get(key).map(|value| value.to_string())).unwrap();
+		let file = ProjectDefaults {
+			browser: Some(BrowserKind::Firefox),
+			format: Some(OutputFormat::Json),
+			..Default::default()
+		};
+
+		let merged = env.or(file);
+		assert_eq!(merged.browser, Some(BrowserKind::Webkit));
+		assert_eq!(merged.timeout_ms, Some(500));
+		assert_eq!(merged.format, Some(OutputFormat::Json));
+
+		let bad = HashMap::from([(FORMAT_ENV_VAR, "yaml")]);
+		assert!(ProjectDefaults::from_vars(|key| bad.get(key).map(|value| value.to_string())).is_err());
+	}
+}
@@ -1,4 +1,5 @@
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -10,6 +11,19 @@ use crate::runtime::RuntimeOverrides;
 /// Current request/response schema for protocol-first CLI execution.
 pub const SCHEMA_VERSION: u32 = 5;
 
+/// Set from `--json-errors`: failed responses are also written to stdout as a JSON line.
+static JSON_ERRORS: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the extra JSON error envelope for non-JSON formats.
+pub fn set_json_errors(enabled: bool) {
+	JSON_ERRORS.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `--json-errors` is in effect.
+pub fn json_errors() -> bool {
+	JSON_ERRORS.load(Ordering::Relaxed)
+}
+
 /// Runtime selection for a request.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -33,6 +47,9 @@ pub struct CommandRequest {
 	pub input: Value,
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub runtime: Option<RuntimeSpec>,
+	/// Resolve the input and report it without touching the browser.
+	#[serde(default, alias = "dry_run", skip_serializing_if = "std::ops::Not::not")]
+	pub dry_run: bool,
 }
 
 /// Effective runtime returned in responses for observability.
@@ -46,6 +63,16 @@ pub struct EffectiveRuntime {
 	pub cdp_endpoint: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub timeout_ms: Option<u64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_text_length: Option<usize>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub base_url: Option<String>,
+	/// Version of the browser the command ran against, once a session was acquired.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub browser_version: Option<String>,
+	/// `navigator.userAgent` of the session page, once a session was acquired.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub user_agent: Option<String>,
 }
 
 /// Context changes applied as a side effect of command execution.
@@ -95,6 +122,12 @@ pub struct CommandResponse {
 	pub context_delta: Option<ContextDeltaView>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub effective_runtime: Option<EffectiveRuntime>,
+	/// Position of this response within a streamed reply (batch streaming ops only).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub seq: Option<u64>,
+	/// Marks the terminal response of a streamed reply.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub done: Option<bool>,
 }
 
 impl CommandResponse {
@@ -119,6 +152,8 @@ impl CommandResponse {
 			diagnostics: Vec::new(),
 			context_delta: Some(delta.into()),
 			effective_runtime: Some(effective_runtime),
+			seq: None,
+			done: None,
 		}
 	}
 
@@ -136,8 +171,48 @@ impl CommandResponse {
 			diagnostics: Vec::new(),
 			context_delta: None,
 			effective_runtime,
+			seq: None,
+			done: None,
+		}
+	}
+
+	/// Builds an intermediate response carrying one chunk of a streamed reply.
+	pub fn chunk(request_id: Option<String>, op: String, seq: u64, data: Value) -> Self {
+		Self {
+			schema_version: SCHEMA_VERSION,
+			request_id,
+			op,
+			ok: true,
+			inputs: None,
+			data: Some(data),
+			error: None,
+			duration_ms: None,
+			artifacts: Vec::new(),
+			diagnostics: Vec::new(),
+			context_delta: None,
+			effective_runtime: None,
+			seq: Some(seq),
+			done: Some(false),
 		}
 	}
+
+	/// Marks this response as the terminal entry of a streamed reply.
+	pub fn with_stream_end(mut self, seq: u64) -> Self {
+		self.seq = Some(seq);
+		self.done = Some(true);
+		self
+	}
+
+	/// Attaches command diagnostics to this response.
+	pub fn with_diagnostics(mut self, diagnostics: Vec<Diagnostic>) -> Self {
+		self.diagnostics = diagnostics;
+		self
+	}
+
+	pub fn with_artifacts(mut self, artifacts: Vec<Artifact>) -> Self {
+		self.artifacts = artifacts;
+		self
+	}
 }
 
 /// Prints protocol responses according to the selected output format.
@@ -160,6 +235,16 @@ pub fn print_response(response: &CommandResponse, format: OutputFormat) {
 		}
 		OutputFormat::Text => print_response_text(response),
 	}
+	if !response.ok && json_errors() && matches!(format, OutputFormat::Toon | OutputFormat::Text) {
+		print_error_envelope(response);
+	}
+}
+
+/// Writes `response` to stdout as a single JSON line.
+pub fn print_error_envelope(response: &CommandResponse) {
+	if let Ok(json) = serde_json::to_string(response) {
+		println!("{json}");
+	}
 }
 
 fn print_response_text(response: &CommandResponse) {
@@ -5,8 +5,11 @@ use std::time::Duration;
 
 use anyhow::{Context, Result, anyhow};
 use axum::Router;
+use axum::extract::ws::rejection::WebSocketUpgradeRejection;
 use axum::extract::ws::{Message, WebSocket};
-use axum::extract::{Path, State, WebSocketUpgrade};
+use axum::extract::{Path, Query, State, WebSocketUpgrade};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use futures::{SinkExt, StreamExt};
 use serde_json::{Value, json};
@@ -23,6 +26,8 @@ struct ConnectedTarget {
 }
 
 struct RelayState {
+	/// Shared token required from the extension; `None` accepts any extension.
+	token: Option<String>,
 	extension_tx: Option<mpsc::UnboundedSender<Message>>,
 	clients: HashMap<String, mpsc::UnboundedSender<Message>>,
 	connected_targets: HashMap<String, ConnectedTarget>,
@@ -31,8 +36,9 @@ struct RelayState {
 }
 
 impl RelayState {
-	fn new() -> Self {
+	fn new(token: Option<String>) -> Self {
 		Self {
+			token,
 			extension_tx: None,
 			clients: HashMap::new(),
 			connected_targets: HashMap::new(),
@@ -52,15 +58,16 @@ impl RelayState {
 
 type SharedState = Arc<Mutex<RelayState>>;
 
-pub async fn run_relay_server(host: &str, port: u16) -> Result<()> {
-	let state = Arc::new(Mutex::new(RelayState::new()));
+/// Runs the relay until the listener fails.
+///
+/// With `token` set, the extension must connect to `/extension?token=<token>`;
+/// any other `/extension` upgrade is refused with `401 Unauthorized`.
+pub async fn run_relay_server(host: &str, port: u16, token: Option<String>) -> Result<()> {
+	let state = Arc::new(Mutex::new(RelayState::new(token)));
 
 	let app = Router::new()
 		.route("/", get(|| async { "OK" }))
-		.route(
-			"/extension",
-			get(|ws: WebSocketUpgrade, State(state): State<SharedState>| async move { ws.on_upgrade(|socket| handle_extension_socket(socket, state)) }),
-		)
+		.route("/extension", get(extension_upgrade))
 		.route(
 			"/cdp",
 			get(|ws: WebSocketUpgrade, State(state): State<SharedState>| async move {
@@ -90,6 +97,39 @@ pub async fn run_relay_server(host: &str, port: u16) -> Result<()> {
 	axum::serve(listener, app.into_make_service()).await.context("Relay server error")
 }
 
+async fn extension_upgrade(
+	Query(query): Query<HashMap<String, String>>,
+	State(state): State<SharedState>,
+	ws: std::result::Result<WebSocketUpgrade, WebSocketUpgradeRejection>,
+) -> Response {
+	let authorized = {
+		let st = state.lock().await;
+		extension_authorized(st.token.as_deref(), query.get("token").map(String::as_str))
+	};
+	if !authorized {
+		warn!(target = "pw", "Rejected extension connection with missing or invalid token");
+		return (StatusCode::UNAUTHORIZED, "Invalid token").into_response();
+	}
+
+	match ws {
+		Ok(ws) => ws.on_upgrade(|socket| handle_extension_socket(socket, state)),
+		Err(rejection) => rejection.into_response(),
+	}
+}
+
+/// Checks the token an extension presented against the configured one.
+///
+/// Compares in constant time so response timing does not leak a token prefix.
+fn extension_authorized(expected: Option<&str>, presented: Option<&str>) -> bool {
+	let Some(expected) = expected else {
+		return true;
+	};
+	let Some(presented) = presented else {
+		return false;
+	};
+	expected.len() == presented.len() && expected.bytes().zip(presented.bytes()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
 async fn handle_extension_socket(socket: WebSocket, state: SharedState) {
 	info!(target = "pw", "Extension connected");
 
@@ -499,3 +539,57 @@ async fn snapshot_targets(state: &SharedState) -> Vec<ConnectedTarget> {
 	let st = state.lock().await;
 	st.connected_targets.values().cloned().collect()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn extension_token_is_optional_when_unconfigured() {
+		assert!(extension_authorized(None, None));
+		assert!(extension_authorized(None, Some("anything")));
+	}
+
+	#[test]
+	fn extension_token_must_match_when_configured() {
+		assert!(extension_authorized(Some("s3cret"), Some("s3cret")));
+		assert!(!extension_authorized(Some("s3cret"), None));
+		assert!(!extension_authorized(Some("s3cret"), Some("s3cre")));
+		assert!(!extension_authorized(Some("s3cret"), Some("s3creT")));
+		assert!(!extension_authorized(Some("s3cret"), Some("")));
+	}
+
+	#[tokio::test]
+	async fn relay_rejects_extension_upgrade_without_token() {
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+		let port = listener.local_addr().unwrap().port();
+		drop(listener);
+		tokio::spawn(run_relay_server("127.0.0.1", port, Some("s3cret".to_string())));
+
+		let client = reqwest::Client::new();
+		let upgrade = |url: String| {
+			client
+				.get(url)
+				.header("connection", "upgrade")
+				.header("upgrade", "websocket")
+				.header("sec-websocket-version", "13")
+				.header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+				.send()
+		};
+
+		let mut rejected = None;
+		for _ in 0..50 {
+			match upgrade(format!("http://127.0.0.1:{port}/extension?token=wrong")).await {
+				Ok(resp) => {
+					rejected = Some(resp.status());
+					break;
+				}
+				Err(_) => tokio::time::sleep(Duration::from_millis(20)).await,
+			}
+		}
+		assert_eq!(rejected, Some(reqwest::StatusCode::UNAUTHORIZED));
+
+		let accepted = upgrade(format!("http://127.0.0.1:{port}/extension?token=s3cret")).await.unwrap();
+		assert_ne!(accepted.status(), reqwest::StatusCode::UNAUTHORIZED);
+	}
+}
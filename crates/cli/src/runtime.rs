@@ -4,11 +4,14 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
-use crate::context::{BlockConfig, CommandContext, CommandContextConfig, DownloadConfig};
+use crate::cli::{CliColorScheme, CliForcedColors, CliReducedMotion, CliThrottle};
+use crate::context::{BlockConfig, CommandContext, CommandContextConfig, DownloadConfig, LaunchConfig, MediaConfig, NetworkConfig};
 use crate::context_store::ContextState;
-use crate::error::Result;
+use crate::cookies::parse_cookie_specs;
+use crate::error::{PwError, Result};
 use crate::output::CdpEndpointSource;
-use crate::types::BrowserKind;
+use crate::project_defaults::ProjectDefaults;
+use crate::types::{BrowserChannel, BrowserKind};
 use crate::workspace::WorkspaceScope;
 
 /// Request-scoped runtime overrides.
@@ -33,12 +36,55 @@ pub struct RuntimeOverrides {
 	pub block_patterns: Option<Vec<String>>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub downloads_dir: Option<PathBuf>,
+	/// Disables viewport emulation so pages use the OS window size.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub no_viewport: Option<bool>,
+	/// Branded Chromium channel (`chrome`, `msedge`, `chrome-beta`, ...).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub channel: Option<BrowserChannel>,
+	/// Opens DevTools for each tab and forces headful mode.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub devtools: Option<bool>,
+	/// Slows every Playwright operation by this many milliseconds.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub slow_mo_ms: Option<u64>,
+	/// Emulated `prefers-color-scheme` (`light`, `dark`, `no-preference`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color_scheme: Option<CliColorScheme>,
+	/// Emulated `prefers-reduced-motion` (`reduce`, `no-preference`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reduced_motion: Option<CliReducedMotion>,
+	/// Emulated `forced-colors` (`active`, `none`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub forced_colors: Option<CliForcedColors>,
+	/// Emulates the network being offline for the session's context.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub offline: Option<bool>,
+	/// Network throttling preset (`offline`, `slow-3g`, `fast-3g`); Chromium only.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub throttle: Option<CliThrottle>,
+	/// Skips certificate validation for `wss://` browser server endpoints.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub insecure: Option<bool>,
+	/// Attaches a screenshot and the last console errors to timeout failures.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub save_trace_on_timeout: Option<bool>,
+	/// Fails otherwise successful commands when the page logs a console error.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub fail_on_console_error: Option<bool>,
+	/// Navigates to the target even when the page is already on that URL.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub force_navigate: Option<bool>,
+	/// One-off cookie specs (`name=value; Domain=...; Path=/`) injected before navigation.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub cookies: Vec<String>,
 }
 
 /// Configuration for building a runtime.
 #[derive(Debug, Clone)]
 pub struct RuntimeConfig {
-	pub profile: String,
+	/// Requested profile; `None` uses the workspace's active profile.
+	pub profile: Option<String>,
 	pub overrides: RuntimeOverrides,
 }
 
@@ -49,6 +95,10 @@ pub struct RuntimeInfo {
 	pub browser: BrowserKind,
 	pub cdp_endpoint: Option<String>,
 	pub timeout_ms: Option<u64>,
+	/// Profile default for `page.snapshot` text length, when configured.
+	pub max_text_length: Option<usize>,
+	/// Base URL for relative targets: request override, else profile default.
+	pub base_url: Option<String>,
 }
 
 /// Runtime context bundle used for request execution.
@@ -60,7 +110,7 @@ pub struct RuntimeContext {
 
 /// Builds a runtime context from profile state and request overrides.
 pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
-	let scope = WorkspaceScope::resolve(None, Some(config.profile.as_str()), false)?;
+	let scope = WorkspaceScope::resolve(None, config.profile.as_deref(), false)?;
 	let mut ctx_state = ContextState::new(
 		scope.root().to_path_buf(),
 		scope.workspace_id().to_string(),
@@ -75,8 +125,22 @@ pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
 	let network = &ctx_state.state().config.network;
 	let downloads = &ctx_state.state().config.downloads;
 
-	let browser = config.overrides.browser.or(defaults.browser).unwrap_or(BrowserKind::Chromium);
-	let timeout_ms = config.overrides.timeout_ms.or(defaults.timeout_ms);
+	// Request overrides > PW_* env > profile config > pw.toml > built-in.
+	let env_defaults = ProjectDefaults::from_env()?;
+	let project_defaults = ProjectDefaults::load(scope.root())?;
+	let browser = config
+		.overrides
+		.browser
+		.or(env_defaults.browser)
+		.or(defaults.browser)
+		.or(project_defaults.browser)
+		.unwrap_or(BrowserKind::Chromium);
+	let timeout_ms = config
+		.overrides
+		.timeout_ms
+		.or(env_defaults.timeout_ms)
+		.or(defaults.timeout_ms)
+		.or(project_defaults.timeout_ms);
 	let resolved_cdp = config.overrides.cdp_endpoint.clone().or_else(|| ctx_state.cdp_endpoint().map(str::to_string));
 	let cdp_endpoint_source = if config.overrides.cdp_endpoint.is_some() {
 		CdpEndpointSource::CliFlag
@@ -91,6 +155,15 @@ pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
 	let auth_file = config.overrides.auth_file.clone().or_else(|| defaults.auth_file.clone());
 	let block_patterns = config.overrides.block_patterns.clone().unwrap_or_else(|| network.block_patterns.clone());
 	let downloads_dir = config.overrides.downloads_dir.clone().or_else(|| downloads.dir.clone());
+	let cookies = parse_cookie_specs(&config.overrides.cookies)?;
+	let launch_config = LaunchConfig {
+		channel: config.overrides.channel,
+		devtools: config.overrides.devtools.unwrap_or(false),
+		slow_mo_ms: config.overrides.slow_mo_ms,
+	};
+	if let Some(channel) = launch_config.channel.filter(|_| browser != BrowserKind::Chromium) {
+		return Err(PwError::Context(format!("channel {channel} requires the chromium browser, got {browser}")));
+	}
 
 	let ctx = CommandContext::with_config(CommandContextConfig {
 		browser,
@@ -99,11 +172,32 @@ pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
 		cdp_endpoint: resolved_cdp.clone(),
 		cdp_endpoint_source,
 		launch_server,
-		no_daemon: !use_daemon,
+		// Daemon browsers are shared and pre-launched, so they cannot honor per-request launch options.
+		no_daemon: !use_daemon || launch_config.is_enabled(),
 		har_config: ctx_state.effective_har_config(),
-		block_config: BlockConfig { patterns: block_patterns },
+		block_config: BlockConfig {
+			patterns: block_patterns,
+			..Default::default()
+		},
 		download_config: DownloadConfig { dir: downloads_dir },
+		cookies,
+		no_viewport: config.overrides.no_viewport.unwrap_or(false),
+		insecure: config.overrides.insecure.unwrap_or(false),
+		headful: defaults.headless == Some(false),
+		launch_config,
+		media_config: MediaConfig {
+			color_scheme: config.overrides.color_scheme.map(Into::into),
+			reduced_motion: config.overrides.reduced_motion.map(Into::into),
+			forced_colors: config.overrides.forced_colors.map(Into::into),
+		},
+		network_config: NetworkConfig {
+			offline: config.overrides.offline.unwrap_or(false),
+			throttle: config.overrides.throttle.map(Into::into),
+		},
 		timeout_ms,
+		save_trace_on_timeout: config.overrides.save_trace_on_timeout.unwrap_or(false),
+		fail_on_console_error: config.overrides.fail_on_console_error.unwrap_or(false),
+		force_navigate: config.overrides.force_navigate.unwrap_or(false),
 		workspace_root: Some(scope.root().to_path_buf()),
 		workspace_id: Some(scope.workspace_id().to_string()),
 		namespace: Some(scope.profile().to_string()),
@@ -118,6 +212,8 @@ pub fn build_runtime(config: &RuntimeConfig) -> Result<RuntimeContext> {
 		browser,
 		cdp_endpoint: resolved_cdp,
 		timeout_ms,
+		max_text_length: ctx_state.max_text_length(),
+		base_url: ctx_state.base_url().map(str::to_string),
 	};
 
 	Ok(RuntimeContext { ctx, ctx_state, info })
@@ -9,9 +9,37 @@ use super::cdp_probe::{CdpVersionInfo, fetch_cdp_endpoint};
 use super::wsl;
 use crate::error::{PwError, Result};
 
-pub(super) async fn launch_chrome(port: u16, user_data_dir: Option<&Path>) -> Result<CdpVersionInfo> {
+/// Extra Chromium switches added by `connect --launch --stealth`.
+///
+/// These hide the most obvious automation tells of a CDP-attached browser:
+/// `navigator.webdriver` and the "controlled by automated software" infobar.
+/// The user agent is left alone: a headful Chrome already reports a real one,
+/// and a hardcoded string would drift from the installed version and
+/// contradict the `Sec-CH-UA` client hints, which is itself detectable.
+pub(super) const STEALTH_ARGS: &[&str] = &[
+	"--disable-blink-features=AutomationControlled",
+	"--disable-infobars",
+	"--no-service-autorun",
+	"--password-store=basic",
+	"--start-maximized",
+];
+
+/// Builds the switches shared by native and WSL launches, before `--user-data-dir`.
+pub(super) fn launch_args(port: u16, stealth: bool) -> Vec<String> {
+	let mut args = vec![
+		format!("--remote-debugging-port={port}"),
+		"--no-first-run".to_string(),
+		"--no-default-browser-check".to_string(),
+	];
+	if stealth {
+		args.extend(STEALTH_ARGS.iter().map(|arg| arg.to_string()));
+	}
+	args
+}
+
+pub(super) async fn launch_chrome(port: u16, user_data_dir: Option<&Path>, stealth: bool) -> Result<CdpVersionInfo> {
 	if wsl::is_wsl() {
-		return wsl::launch_windows_chrome_from_wsl(port, user_data_dir).await;
+		return wsl::launch_windows_chrome_from_wsl(port, user_data_dir, stealth).await;
 	}
 
 	let chrome_path = find_chrome_executable().ok_or_else(|| {
@@ -22,11 +50,7 @@ pub(super) async fn launch_chrome(port: u16, user_data_dir: Option<&Path>) -> Re
 		)
 	})?;
 
-	let mut args = vec![
-		format!("--remote-debugging-port={}", port),
-		"--no-first-run".to_string(),
-		"--no-default-browser-check".to_string(),
-	];
+	let mut args = launch_args(port, stealth);
 
 	if let Some(dir) = user_data_dir {
 		args.push(format!("--user-data-dir={}", dir.display()));
@@ -75,3 +99,19 @@ pub(super) async fn launch_chrome(port: u16, user_data_dir: Option<&Path>) -> Re
 		port, last_error
 	)))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn stealth_appends_curated_switches() {
+		let plain = launch_args(9222, false);
+		assert_eq!(plain, ["--remote-debugging-port=9222", "--no-first-run", "--no-default-browser-check"]);
+
+		let stealth = launch_args(9222, true);
+		assert_eq!(&stealth[..plain.len()], plain.as_slice());
+		assert_eq!(&stealth[plain.len()..], STEALTH_ARGS);
+		assert!(stealth.iter().any(|arg| arg == "--disable-blink-features=AutomationControlled"));
+	}
+}
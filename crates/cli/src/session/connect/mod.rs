@@ -2,6 +2,8 @@
 //!
 //! This module owns CDP discovery, browser launch/kill orchestration, and
 //! profile-scoped endpoint persistence used by `connect` and related flows.
+//! Firefox and WebKit do not speak CDP, so `connect --browser firefox|webkit`
+//! stores a Playwright server endpoint instead (see [`ConnectTransport`]).
 
 use std::path::{Path, PathBuf};
 
@@ -9,6 +11,7 @@ use serde_json::{Value, json};
 
 use crate::context_store::ContextState;
 use crate::error::Result;
+use crate::types::BrowserKind;
 
 mod auth_injector;
 mod browser_finder;
@@ -21,6 +24,38 @@ pub mod wsl;
 pub use cdp_probe::{CdpVersionInfo, fetch_cdp_endpoint};
 pub use user_data_dir::{resolve_connect_port, resolve_user_data_dir};
 
+/// Wire protocol used to reach a remote browser for a given engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectTransport {
+	/// Chrome DevTools Protocol, discovered through `/json/version`.
+	Cdp,
+	/// Playwright-native protocol served by `launchServer` or `playwright run-server`.
+	Playwright,
+}
+
+impl ConnectTransport {
+	/// Selects the transport for `browser`; only Chromium exposes CDP.
+	pub fn for_browser(browser: BrowserKind) -> Self {
+		match browser {
+			BrowserKind::Chromium => Self::Cdp,
+			BrowserKind::Firefox | BrowserKind::Webkit => Self::Playwright,
+		}
+	}
+
+	/// Returns the protocol label reported in command output.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Cdp => "cdp",
+			Self::Playwright => "playwright",
+		}
+	}
+}
+
+/// Builds the Playwright server endpoint for `port`, preferring an explicit endpoint.
+pub fn playwright_server_endpoint(endpoint: Option<&str>, port: u16) -> String {
+	endpoint.map(str::to_string).unwrap_or_else(|| format!("ws://127.0.0.1:{}/", port))
+}
+
 #[derive(Debug, Clone)]
 struct ConnectAuthPayload {
 	auth_file: PathBuf,
@@ -64,6 +99,10 @@ enum ConnectResult {
 	Set {
 		endpoint: String,
 	},
+	Server {
+		endpoint: String,
+		browser: BrowserKind,
+	},
 	Show {
 		endpoint: Option<String>,
 	},
@@ -148,6 +187,13 @@ impl ConnectResult {
 				"endpoint": endpoint,
 				"message": format!("CDP endpoint set to {}", endpoint)
 			}),
+			ConnectResult::Server { endpoint, browser } => json!({
+				"action": "set",
+				"endpoint": endpoint,
+				"browser": browser,
+				"protocol": ConnectTransport::Playwright.as_str(),
+				"message": format!("Playwright server endpoint for {} set to {}", browser, endpoint)
+			}),
 			ConnectResult::Show { endpoint } => match endpoint {
 				Some(endpoint) => json!({
 					"action": "show",
@@ -168,7 +214,7 @@ impl ConnectResult {
 pub async fn kill_browser_on_port(ctx_state: &mut ContextState, port: u16) -> Result<Value> {
 	let result = match process_killer::kill_chrome(port).await? {
 		Some(pids) => {
-			ctx_state.set_cdp_endpoint(None);
+			store_endpoint(ctx_state, None, None);
 			ConnectResult::Killed { port, pids }
 		}
 		None => ConnectResult::KillNoop { port },
@@ -176,20 +222,28 @@ pub async fn kill_browser_on_port(ctx_state: &mut ContextState, port: u16) -> Re
 	Ok(result.into_json())
 }
 
-/// Clears the stored CDP endpoint from context defaults.
+/// Clears the stored endpoint and its engine from context defaults.
 pub fn clear_cdp_endpoint(ctx_state: &mut ContextState) -> Value {
-	ctx_state.set_cdp_endpoint(None);
+	store_endpoint(ctx_state, None, None);
 	ConnectResult::Cleared.into_json()
 }
 
 /// Launches a browser with remote debugging and stores discovered endpoint.
-pub async fn launch_and_connect(ctx_state: &mut ContextState, port: u16, user_data_dir: Option<&Path>, auth_file: Option<&Path>) -> Result<Value> {
+///
+/// `stealth` adds [`browser_launcher::STEALTH_ARGS`] to the launch switches.
+pub async fn launch_and_connect(
+	ctx_state: &mut ContextState,
+	port: u16,
+	user_data_dir: Option<&Path>,
+	stealth: bool,
+	auth_file: Option<&Path>,
+) -> Result<Value> {
 	let launch_data_dir = resolve_user_data_dir(ctx_state, user_data_dir)?;
-	let info = browser_launcher::launch_chrome(port, Some(launch_data_dir.as_path())).await?;
+	let info = browser_launcher::launch_chrome(port, Some(launch_data_dir.as_path()), stealth).await?;
 	let auth_applied = auth_injector::maybe_apply_auth(&info.web_socket_debugger_url, auth_file)
 		.await?
 		.map(ConnectAuthPayload::from);
-	ctx_state.set_cdp_endpoint(Some(info.web_socket_debugger_url.clone()));
+	store_endpoint(ctx_state, Some(info.web_socket_debugger_url.clone()), Some(BrowserKind::Chromium));
 
 	Ok(ConnectResult::Launched {
 		endpoint: info.web_socket_debugger_url,
@@ -207,7 +261,7 @@ pub async fn discover_and_connect(ctx_state: &mut ContextState, port: u16, auth_
 	let auth_applied = auth_injector::maybe_apply_auth(&info.web_socket_debugger_url, auth_file)
 		.await?
 		.map(ConnectAuthPayload::from);
-	ctx_state.set_cdp_endpoint(Some(info.web_socket_debugger_url.clone()));
+	store_endpoint(ctx_state, Some(info.web_socket_debugger_url.clone()), Some(BrowserKind::Chromium));
 
 	Ok(ConnectResult::Discovered {
 		endpoint: info.web_socket_debugger_url,
@@ -220,13 +274,30 @@ pub async fn discover_and_connect(ctx_state: &mut ContextState, port: u16, auth_
 
 /// Stores an explicit CDP endpoint in context defaults.
 pub fn set_cdp_endpoint(ctx_state: &mut ContextState, endpoint: &str) -> Value {
-	ctx_state.set_cdp_endpoint(Some(endpoint.to_string()));
+	store_endpoint(ctx_state, Some(endpoint.to_string()), Some(BrowserKind::Chromium));
 	ConnectResult::Set {
 		endpoint: endpoint.to_string(),
 	}
 	.into_json()
 }
 
+/// Stores a Playwright server endpoint and makes `browser` the profile default.
+///
+/// No CDP discovery happens here: Firefox and WebKit sessions attach through
+/// `BrowserType::connect` when the stored endpoint is used.
+pub fn set_playwright_server_endpoint(ctx_state: &mut ContextState, browser: BrowserKind, endpoint: Option<&str>, port: u16) -> Value {
+	let endpoint = playwright_server_endpoint(endpoint, port);
+	store_endpoint(ctx_state, Some(endpoint.clone()), Some(browser));
+	ConnectResult::Server { endpoint, browser }.into_json()
+}
+
+/// Saves or clears `endpoint` together with the engine that speaks it, so a
+/// stale Firefox/WebKit default never outlives its Playwright server endpoint.
+fn store_endpoint(ctx_state: &mut ContextState, endpoint: Option<String>, browser: Option<BrowserKind>) {
+	ctx_state.set_cdp_endpoint(endpoint);
+	ctx_state.set_default_browser(browser);
+}
+
 /// Returns current endpoint configuration payload for command output.
 pub fn show_cdp_endpoint(ctx_state: &ContextState) -> Value {
 	ConnectResult::Show {
@@ -234,3 +305,53 @@ pub fn show_cdp_endpoint(ctx_state: &ContextState) -> Value {
 	}
 	.into_json()
 }
+
+#[cfg(test)]
+mod tests {
+	use tempfile::TempDir;
+
+	use super::*;
+
+	#[test]
+	fn transport_follows_browser_engine() {
+		assert_eq!(ConnectTransport::for_browser(BrowserKind::Chromium), ConnectTransport::Cdp);
+		assert_eq!(ConnectTransport::for_browser(BrowserKind::Firefox), ConnectTransport::Playwright);
+		assert_eq!(ConnectTransport::for_browser(BrowserKind::Webkit), ConnectTransport::Playwright);
+	}
+
+	#[test]
+	fn firefox_connect_stores_playwright_endpoint() {
+		let temp = TempDir::new().unwrap();
+		let mut ctx_state = ContextState::new(
+			temp.path().to_path_buf(),
+			"workspace-id".to_string(),
+			"agent-a".to_string(),
+			None,
+			false,
+			false,
+			false,
+		)
+		.unwrap();
+
+		let data = set_playwright_server_endpoint(&mut ctx_state, BrowserKind::Firefox, None, 9333);
+
+		assert_eq!(data["protocol"], "playwright");
+		assert_eq!(data["browser"], "firefox");
+		assert_eq!(data["endpoint"], "ws://127.0.0.1:9333/");
+		assert_eq!(ctx_state.cdp_endpoint(), Some("ws://127.0.0.1:9333/"));
+		assert_eq!(ctx_state.state().config.defaults.browser, Some(BrowserKind::Firefox));
+
+		set_cdp_endpoint(&mut ctx_state, "ws://127.0.0.1:9222/devtools/browser/abc");
+		assert_eq!(ctx_state.state().config.defaults.browser, Some(BrowserKind::Chromium));
+
+		set_playwright_server_endpoint(&mut ctx_state, BrowserKind::Webkit, None, 9333);
+		clear_cdp_endpoint(&mut ctx_state);
+		assert_eq!(ctx_state.cdp_endpoint(), None);
+		assert_eq!(ctx_state.state().config.defaults.browser, None);
+	}
+
+	#[test]
+	fn explicit_endpoint_wins_over_port() {
+		assert_eq!(playwright_server_endpoint(Some("ws://remote:3000/abc"), 9333), "ws://remote:3000/abc");
+	}
+}
@@ -141,7 +141,7 @@ fn ps_single_quote(value: &str) -> String {
 	format!("'{}'", value.replace('\'', "''"))
 }
 
-pub(super) async fn launch_windows_chrome_from_wsl(port: u16, user_data_dir: Option<&Path>) -> Result<CdpVersionInfo> {
+pub(super) async fn launch_windows_chrome_from_wsl(port: u16, user_data_dir: Option<&Path>, stealth: bool) -> Result<CdpVersionInfo> {
 	let chrome_path = find_windows_chrome_executable_wsl().ok_or_else(|| {
 		PwError::Context(
 			"Could not find a Windows Chromium browser from WSL (Chrome/Edge/Brave). \
@@ -158,11 +158,7 @@ pub(super) async fn launch_windows_chrome_from_wsl(port: u16, user_data_dir: Opt
 		)
 	})?;
 
-	let mut args = vec![
-		format!("--remote-debugging-port={port}"),
-		"--no-first-run".to_string(),
-		"--no-default-browser-check".to_string(),
-	];
+	let mut args = super::browser_launcher::launch_args(port, stealth);
 
 	if let Some(dir) = user_data_dir {
 		let win_dir =
@@ -4,9 +4,12 @@ use std::path::Path;
 
 use serde_json::Value;
 
-use super::connect::{clear_cdp_endpoint, discover_and_connect, kill_browser_on_port, launch_and_connect, set_cdp_endpoint, show_cdp_endpoint};
+use super::connect::{
+	clear_cdp_endpoint, discover_and_connect, kill_browser_on_port, launch_and_connect, set_cdp_endpoint, set_playwright_server_endpoint, show_cdp_endpoint,
+};
 use crate::context_store::ContextState;
 use crate::error::Result;
+use crate::types::BrowserKind;
 
 /// Service facade used by protocol/CLI commands for connect flows.
 pub struct ConnectService<'a> {
@@ -31,8 +34,8 @@ impl<'a> ConnectService<'a> {
 	}
 
 	/// Launches browser and stores discovered endpoint.
-	pub async fn launch(&mut self, port: u16, user_data_dir: Option<&Path>) -> Result<Value> {
-		launch_and_connect(self.ctx_state, port, user_data_dir, self.auth_file).await
+	pub async fn launch(&mut self, port: u16, user_data_dir: Option<&Path>, stealth: bool) -> Result<Value> {
+		launch_and_connect(self.ctx_state, port, user_data_dir, stealth, self.auth_file).await
 	}
 
 	/// Discovers an existing debug browser and stores endpoint.
@@ -45,6 +48,11 @@ impl<'a> ConnectService<'a> {
 		set_cdp_endpoint(self.ctx_state, endpoint)
 	}
 
+	/// Stores a Playwright server endpoint for a non-CDP browser engine.
+	pub fn set_server_endpoint(&mut self, browser: BrowserKind, endpoint: Option<&str>, port: u16) -> Value {
+		set_playwright_server_endpoint(self.ctx_state, browser, endpoint, port)
+	}
+
 	/// Shows stored endpoint metadata.
 	pub fn show(&self) -> Value {
 		show_cdp_endpoint(self.ctx_state)
@@ -1,9 +1,14 @@
 //! Session orchestration for browser acquisition and lifecycle.
 
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
 
 use pw_rs::WaitUntil;
+use pw_rs::pw_runtime::channel_owner::ChannelOwner;
 use serde_json::json;
+use tracing::warn;
 
 use super::daemon_lease::acquire_daemon_lease;
 use super::descriptor::SessionDescriptor;
@@ -22,8 +27,24 @@ pub struct SessionManager<'a> {
 	repository: SessionRepository,
 	namespace_id: Option<String>,
 	refresh: bool,
+	browser_info: Option<BrowserInfo>,
+	user_agent_probe: Option<(String, pw_rs::Page)>,
 }
 
+/// Version and user agent of the browser behind the last acquired session.
+#[derive(Debug, Clone)]
+pub struct BrowserInfo {
+	pub version: String,
+	/// `navigator.userAgent` of the session page, including any context override.
+	pub user_agent: Option<String>,
+}
+
+/// Upper bound on the `navigator.userAgent` probe so a stuck page cannot stall a response.
+const USER_AGENT_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// User agents already probed in this process, keyed by browser guid.
+static USER_AGENTS: LazyLock<Mutex<HashMap<String, String>>> = LazyLock::new(Default::default);
+
 impl<'a> SessionManager<'a> {
 	/// Creates a manager for the current command execution scope.
 	pub fn new(ctx: &'a CommandContext, descriptor_path: Option<std::path::PathBuf>, namespace_id: Option<String>, refresh: bool) -> Self {
@@ -32,6 +53,8 @@ impl<'a> SessionManager<'a> {
 			repository: SessionRepository::new(descriptor_path),
 			namespace_id,
 			refresh,
+			browser_info: None,
+			user_agent_probe: None,
 		}
 	}
 
@@ -40,6 +63,21 @@ impl<'a> SessionManager<'a> {
 		self.ctx
 	}
 
+	/// Returns browser details for the last acquired session.
+	///
+	/// The user agent is read from the session page on first call, at most once
+	/// per browser in this process and bounded by a short timeout; a failed probe
+	/// is logged and leaves `user_agent` unset.
+	pub async fn browser_info(&mut self) -> Option<&BrowserInfo> {
+		if let Some((browser_guid, page)) = self.user_agent_probe.take() {
+			let user_agent = probe_user_agent(&browser_guid, &page).await;
+			if let Some(info) = self.browser_info.as_mut() {
+				info.user_agent = user_agent;
+			}
+		}
+		self.browser_info.as_ref()
+	}
+
 	/// Returns descriptor path when persistence is enabled.
 	pub fn descriptor_path(&self) -> Option<&Path> {
 		self.repository.path()
@@ -99,6 +137,7 @@ impl<'a> SessionManager<'a> {
 
 		let session = self.session(request).await?;
 		session.browser().close().await?;
+		self.user_agent_probe = None;
 		let _ = self.clear_descriptor()?;
 
 		Ok(json!({
@@ -108,7 +147,20 @@ impl<'a> SessionManager<'a> {
 	}
 
 	/// Acquires a session using descriptor reuse, daemon leasing, or launch flows.
+	///
+	/// Also records the browser's version for [`browser_info`](Self::browser_info),
+	/// which reads the user agent only when asked.
 	pub async fn session(&mut self, request: SessionRequest<'_>) -> Result<SessionHandle> {
+		let handle = self.acquire(request).await?;
+		self.browser_info = Some(BrowserInfo {
+			version: handle.browser().version().to_string(),
+			user_agent: None,
+		});
+		self.user_agent_probe = Some((handle.browser().guid().to_string(), handle.page().clone()));
+		Ok(handle)
+	}
+
+	async fn acquire(&mut self, request: SessionRequest<'_>) -> Result<SessionHandle> {
 		let storage_state = request.auth_file.map(SessionFactory::load_storage_state).transpose()?;
 		let strategy = resolve_session_strategy(SessionStrategyInput {
 			has_descriptor_path: self.descriptor_path().is_some(),
@@ -126,6 +178,8 @@ impl<'a> SessionManager<'a> {
 			if let Some(descriptor) = self.load_descriptor()? {
 				let factory = SessionFactory::new(self.ctx);
 				if let Some(handle) = factory.acquire_from_descriptor(&descriptor, &request, storage_state.clone()).await? {
+					factory.inject_request_cookies(&request, &handle.session).await?;
+					factory.apply_network_conditions(&request, &handle.session).await?;
 					return Ok(handle);
 				}
 			}
@@ -138,9 +192,15 @@ impl<'a> SessionManager<'a> {
 			.await?;
 
 		factory.auto_inject_auth_if_needed(&request, daemon_lease.as_ref(), &mut session).await?;
+		factory.inject_request_cookies(&request, &session).await?;
+		factory.apply_network_conditions(&request, &session).await?;
 		self.descriptors().persist_for_session(&request, &session, daemon_lease.as_ref());
 
-		Ok(SessionHandle { session, source })
+		Ok(SessionHandle {
+			session,
+			source,
+			reuse_current_url: request.reuse_current_url,
+		})
 	}
 
 	fn descriptors(&self) -> DescriptorLifecycle<'_> {
@@ -148,12 +208,39 @@ impl<'a> SessionManager<'a> {
 	}
 }
 
+/// Reads `navigator.userAgent` from `page`, reusing the value cached for `browser_guid`.
+async fn probe_user_agent(browser_guid: &str, page: &pw_rs::Page) -> Option<String> {
+	if let Some(user_agent) = USER_AGENTS.lock().ok()?.get(browser_guid) {
+		return Some(user_agent.clone());
+	}
+
+	let user_agent = match tokio::time::timeout(USER_AGENT_PROBE_TIMEOUT, page.evaluate_typed::<String>("navigator.userAgent")).await {
+		Ok(Ok(user_agent)) => user_agent,
+		Ok(Err(err)) => {
+			warn!(target = "pw.session", error = %err, "failed to read browser user agent");
+			return None;
+		}
+		Err(_) => {
+			warn!(
+				target = "pw.session",
+				timeout_ms = USER_AGENT_PROBE_TIMEOUT.as_millis() as u64,
+				"timed out reading browser user agent"
+			);
+			return None;
+		}
+	};
+	if let Ok(mut cache) = USER_AGENTS.lock() {
+		cache.insert(browser_guid.to_string(), user_agent.clone());
+	}
+	Some(user_agent)
+}
+
 #[cfg(test)]
 mod tests {
 	use pw_rs::WaitUntil;
 
 	use super::*;
-	use crate::context::{BlockConfig, DownloadConfig, HarConfig};
+	use crate::context::{BlockConfig, DownloadConfig, HarConfig, LaunchConfig, MediaConfig, NetworkConfig};
 	use crate::types::BrowserKind;
 
 	static DEFAULT_HAR_CONFIG: HarConfig = HarConfig {
@@ -164,8 +251,21 @@ mod tests {
 		url_filter: None,
 	};
 
-	static DEFAULT_BLOCK_CONFIG: BlockConfig = BlockConfig { patterns: Vec::new() };
 	static DEFAULT_DOWNLOAD_CONFIG: DownloadConfig = DownloadConfig { dir: None };
+	static DEFAULT_LAUNCH_CONFIG: LaunchConfig = LaunchConfig {
+		channel: None,
+		devtools: false,
+		slow_mo_ms: None,
+	};
+	static DEFAULT_MEDIA_CONFIG: MediaConfig = MediaConfig {
+		color_scheme: None,
+		reduced_motion: None,
+		forced_colors: None,
+	};
+	static DEFAULT_NETWORK_CONFIG: NetworkConfig = NetworkConfig {
+		offline: false,
+		throttle: None,
+	};
 
 	#[test]
 	fn session_request_builders_round_trip() {
@@ -188,6 +288,7 @@ mod tests {
 
 	#[test]
 	fn default_configs_are_accessible() {
+		let block_config = BlockConfig::default();
 		let request = SessionRequest {
 			wait_until: WaitUntil::NetworkIdle,
 			headless: true,
@@ -199,9 +300,16 @@ mod tests {
 			keep_browser_running: false,
 			protected_urls: &[],
 			preferred_url: None,
+			reuse_current_url: true,
 			har_config: &DEFAULT_HAR_CONFIG,
-			block_config: &DEFAULT_BLOCK_CONFIG,
+			block_config: &block_config,
 			download_config: &DEFAULT_DOWNLOAD_CONFIG,
+			cookies: &[],
+			no_viewport: false,
+			insecure: false,
+			launch_config: &DEFAULT_LAUNCH_CONFIG,
+			media_config: &DEFAULT_MEDIA_CONFIG,
+			network_config: &DEFAULT_NETWORK_CONFIG,
 		};
 		assert_eq!(request.block_config.patterns.len(), 0);
 		assert!(request.download_config.dir.is_none());
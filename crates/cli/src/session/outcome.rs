@@ -3,7 +3,8 @@
 use std::path::Path;
 
 use crate::artifact_collector::{CollectedArtifacts, collect_failure_artifacts};
-use crate::browser::{BrowserSession, DownloadInfo, SessionEndpoints, ShutdownMode};
+use crate::browser::{BrowserSession, DownloadInfo, SessionEndpoints, SessionPage, ShutdownMode};
+use crate::context::CommandContext;
 use crate::error::Result;
 use crate::output::SessionSource;
 use crate::target::Target;
@@ -12,6 +13,8 @@ use crate::target::Target;
 pub struct SessionHandle {
 	pub(crate) session: BrowserSession,
 	pub(crate) source: SessionSource,
+	/// Whether [`goto_if_needed`](Self::goto_if_needed) skips navigating to the current URL.
+	pub(crate) reuse_current_url: bool,
 }
 
 impl SessionHandle {
@@ -25,17 +28,26 @@ impl SessionHandle {
 		self.session.goto(url, timeout_ms).await
 	}
 
-	/// Navigates only when current URL differs from `url`.
-	pub async fn goto_if_needed(&self, url: &str, timeout_ms: Option<u64>) -> Result<bool> {
-		let current_url = self.page().evaluate_value("window.location.href").await.unwrap_or_else(|_| self.page().url());
-		let current = current_url.trim_matches('"');
+	/// Navigates to a URL, returning the main resource response when there is one.
+	pub async fn goto_response(&self, url: &str, timeout_ms: Option<u64>) -> Result<Option<pw_rs::Response>> {
+		self.session.goto_response(url, timeout_ms).await
+	}
 
-		if urls_match(current, url) {
-			Ok(false)
+	/// Navigates only when current URL differs from `url`, unless the request forces navigation.
+	///
+	/// Returns whether a `goto` was sent.
+	pub async fn goto_if_needed(&self, url: &str, timeout_ms: Option<u64>) -> Result<bool> {
+		let current_url = if self.reuse_current_url {
+			Some(self.page().evaluate_value("window.location.href").await.unwrap_or_else(|_| self.page().url()))
 		} else {
-			self.session.goto(url, timeout_ms).await?;
-			Ok(true)
+			None
+		};
+		if !needs_navigation(current_url.as_deref().map(|current| current.trim_matches('"')), url) {
+			return Ok(false);
 		}
+
+		self.session.goto(url, timeout_ms).await?;
+		Ok(true)
 	}
 
 	/// Navigates according to typed [`Target`] semantics.
@@ -56,6 +68,11 @@ impl SessionHandle {
 		self.session.context()
 	}
 
+	/// Opens another page on the session's context with the per-page setup `ctx` asks for.
+	pub async fn open_page(&self, ctx: &CommandContext) -> Result<SessionPage> {
+		self.session.open_page(ctx.block_config(), ctx.download_config(), ctx.network_config()).await
+	}
+
 	/// Returns discovered session endpoints.
 	pub fn endpoints(&self) -> SessionEndpoints {
 		self.session.endpoints().clone()
@@ -71,6 +88,11 @@ impl SessionHandle {
 		self.session.downloads()
 	}
 
+	/// Takes console messages buffered since the previous call or session acquisition.
+	pub fn take_console_messages(&self) -> Vec<crate::types::ConsoleMessage> {
+		self.session.take_console_messages()
+	}
+
 	/// Shuts down session resources with an explicit mode.
 	pub async fn shutdown(self, mode: ShutdownMode) -> Result<()> {
 		self.session.shutdown(mode).await
@@ -96,6 +118,11 @@ impl SessionHandle {
 	}
 }
 
+/// Whether a `goto` to `target` is needed; `current` is `None` when reuse is disabled.
+fn needs_navigation(current: Option<&str>, target: &str) -> bool {
+	current.is_none_or(|current| !urls_match(current, target))
+}
+
 fn urls_match(current: &str, target: &str) -> bool {
 	if current == target {
 		return true;
@@ -118,6 +145,13 @@ mod tests {
 		assert!(urls_match("https://example.com/path/", "https://example.com/path"));
 	}
 
+	#[test]
+	fn navigation_skipped_only_when_reusing_a_matching_url() {
+		assert!(!needs_navigation(Some("https://example.com/"), "https://example.com"));
+		assert!(needs_navigation(Some("https://example.com/a"), "https://example.com/b"));
+		assert!(needs_navigation(None, "https://example.com"));
+	}
+
 	#[test]
 	fn urls_match_rejects_different_targets() {
 		assert!(!urls_match("https://example.com", "https://other.example.com"));
@@ -64,6 +64,7 @@ impl<'a> SessionFactory<'a> {
 		Ok(Some(SessionHandle {
 			session,
 			source: SessionSource::CachedDescriptor,
+			reuse_current_url: request.reuse_current_url,
 		}))
 	}
 
@@ -104,7 +105,14 @@ impl<'a> SessionFactory<'a> {
 				Ok((session, SessionSource::PersistentDebug))
 			}
 			PrimarySessionStrategy::LaunchServer => {
-				let session = BrowserSession::launch_server_session(request.wait_until, storage_state, request.headless, request.browser).await?;
+				let session = BrowserSession::launch_server_session(
+					request.wait_until,
+					storage_state,
+					request.headless,
+					request.browser,
+					request.launch_config.clone(),
+				)
+				.await?;
 				Ok((session, SessionSource::BrowserServer))
 			}
 			PrimarySessionStrategy::FreshLaunch => {
@@ -143,6 +151,38 @@ impl<'a> SessionFactory<'a> {
 		Ok(())
 	}
 
+	/// Adds request-scoped one-off cookies to the acquired browser context.
+	pub(super) async fn inject_request_cookies(&self, request: &SessionRequest<'_>, session: &BrowserSession) -> Result<()> {
+		if request.cookies.is_empty() {
+			return Ok(());
+		}
+
+		debug!(target = "pw.session", count = request.cookies.len(), "injecting one-off cookies");
+		session.context().add_cookies(request.cookies.to_vec()).await?;
+		Ok(())
+	}
+
+	/// Sets the context's offline state and the page's throttling preset to what the request asks for.
+	///
+	/// Runs on reused sessions too, so a daemon browser drops conditions left by an earlier request.
+	pub(super) async fn apply_network_conditions(&self, request: &SessionRequest<'_>, session: &BrowserSession) -> Result<()> {
+		let network = request.network_config;
+
+		debug!(target = "pw.session", offline = network.offline, "setting network offline state");
+		session.context().set_offline(network.offline).await?;
+		match network.throttle {
+			Some(preset) => {
+				if request.browser != BrowserKind::Chromium {
+					return Err(PwError::Context(format!("--throttle requires chromium, not {}", request.browser)));
+				}
+				debug!(target = "pw.session", ?preset, "throttling network");
+				session.page().throttle_network(preset).await?;
+			}
+			None => session.page().clear_network_throttle().await?,
+		}
+		Ok(())
+	}
+
 	async fn session_with_config(
 		&self,
 		request: &SessionRequest<'_>,
@@ -161,6 +201,10 @@ impl<'a> SessionFactory<'a> {
 			har: request.har_config.clone(),
 			block: request.block_config.clone(),
 			download: request.download_config.clone(),
+			no_viewport: request.no_viewport,
+			insecure: request.insecure,
+			launch: request.launch_config.clone(),
+			media: request.media_config.clone(),
 		})
 		.await
 	}
@@ -2,9 +2,9 @@
 
 use std::path::Path;
 
-use pw_rs::WaitUntil;
+use pw_rs::{Cookie, WaitUntil};
 
-use crate::context::{BlockConfig, CommandContext, DownloadConfig, HarConfig};
+use crate::context::{BlockConfig, CommandContext, DownloadConfig, HarConfig, LaunchConfig, MediaConfig, NetworkConfig};
 use crate::types::BrowserKind;
 
 /// Fully resolved request for acquiring a browser session.
@@ -29,12 +29,26 @@ pub struct SessionRequest<'a> {
 	pub protected_urls: &'a [String],
 	/// Preferred URL for page-reuse selection.
 	pub preferred_url: Option<&'a str>,
+	/// Whether navigation is skipped when the page is already on the target URL.
+	pub reuse_current_url: bool,
 	/// HAR recording configuration.
 	pub har_config: &'a HarConfig,
 	/// Request-blocking configuration.
 	pub block_config: &'a BlockConfig,
 	/// Download-tracking configuration.
 	pub download_config: &'a DownloadConfig,
+	/// One-off cookies added to the context before any navigation.
+	pub cookies: &'a [Cookie],
+	/// Whether new contexts are created with a `null` viewport.
+	pub no_viewport: bool,
+	/// Whether `wss://` browser server certificates go unvalidated.
+	pub insecure: bool,
+	/// Channel, devtools, and slow-mo options for browsers pw launches.
+	pub launch_config: &'a LaunchConfig,
+	/// Media feature emulation for new contexts.
+	pub media_config: &'a MediaConfig,
+	/// Offline and throttling emulation applied after acquisition.
+	pub network_config: &'a NetworkConfig,
 }
 
 impl<'a> SessionRequest<'a> {
@@ -42,7 +56,7 @@ impl<'a> SessionRequest<'a> {
 	pub fn from_context(wait_until: WaitUntil, ctx: &'a CommandContext) -> Self {
 		Self {
 			wait_until,
-			headless: true,
+			headless: ctx.headless(),
 			auth_file: ctx.auth_file(),
 			browser: ctx.browser,
 			cdp_endpoint: ctx.cdp_endpoint(),
@@ -51,9 +65,16 @@ impl<'a> SessionRequest<'a> {
 			keep_browser_running: false,
 			protected_urls: &[],
 			preferred_url: None,
+			reuse_current_url: !ctx.force_navigate(),
 			har_config: ctx.har_config(),
 			block_config: ctx.block_config(),
 			download_config: ctx.download_config(),
+			cookies: ctx.cookies(),
+			no_viewport: ctx.no_viewport(),
+			insecure: ctx.insecure(),
+			launch_config: ctx.launch_config(),
+			media_config: ctx.media_config(),
+			network_config: ctx.network_config(),
 		}
 	}
 
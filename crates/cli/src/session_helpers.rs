@@ -1,8 +1,11 @@
 //! Session lifecycle helpers for command execution.
 
+use std::path::PathBuf;
+
+use crate::artifact_collector::{ConsoleErrorTail, FAIL_CONSOLE_ERRORS, collect_timeout_trace};
 use crate::commands::def::ExecCtx;
 use crate::error::{PwError, Result};
-use crate::output::FailureWithArtifacts;
+use crate::output::{ErrorCode, FailureWithArtifacts};
 use crate::session::{SessionHandle, SessionRequest};
 
 /// When to collect failure artifacts (screenshots, traces).
@@ -12,7 +15,24 @@ pub enum ArtifactsPolicy {
 	OnError { command: &'static str },
 }
 
+impl ArtifactsPolicy {
+	fn command(self) -> Option<&'static str> {
+		match self {
+			Self::Never => None,
+			Self::OnError { command } => Some(command),
+		}
+	}
+}
+
 /// Execute a callback with a session, collecting artifacts on failure.
+///
+/// With `save_trace_on_timeout` enabled, timeout failures also get a screenshot
+/// and the last console errors in their `details`, regardless of `artifacts`.
+/// The screenshot reuses the failure artifact when one was collected, and is
+/// otherwise placed by [`ContextState::resolve_output`](crate::context_store::ContextState::resolve_output).
+///
+/// With `fail_on_console_error` enabled, a callback that succeeds while the
+/// page logged console errors fails with [`PwError::ConsoleErrors`] instead.
 pub async fn with_session<'exec, 'ctx, T>(
 	exec: &mut ExecCtx<'exec, 'ctx>,
 	req: SessionRequest<'_>,
@@ -23,29 +43,51 @@ where
 	'ctx: 'exec,
 {
 	let session = exec.session.session(req).await?;
+	let console_errors = exec.ctx.save_trace_on_timeout().then(|| ConsoleErrorTail::watch(session.page()));
+	let console_failures = exec
+		.ctx
+		.fail_on_console_error()
+		.then(|| ConsoleErrorTail::watch_first(session.page(), FAIL_CONSOLE_ERRORS));
 
 	let res = f(&session).await;
 
 	match res {
 		Ok(v) => {
+			let logged = console_failures.map(|tail| tail.recent()).unwrap_or_default();
 			session.close().await?;
+			if !logged.is_empty() {
+				return Err(PwError::ConsoleErrors { messages: logged });
+			}
 			Ok(v)
 		}
 		Err(e) => {
-			if let ArtifactsPolicy::OnError { command } = artifacts {
-				let artifacts = session.collect_failure_artifacts(exec.artifacts_dir, command).await;
+			let collected = match artifacts {
+				ArtifactsPolicy::OnError { command } => session.collect_failure_artifacts(exec.artifacts_dir, command).await.artifacts,
+				ArtifactsPolicy::Never => Vec::new(),
+			};
 
-				if !artifacts.is_empty() {
-					let failure = FailureWithArtifacts::new(e.to_command_error()).with_artifacts(artifacts.artifacts);
+			let error = e.to_command_error();
+			if let Some(console_errors) = console_errors.filter(|_| error.code == ErrorCode::Timeout) {
+				let command = artifacts.command().unwrap_or("command");
+				let name = PathBuf::from(format!("{command}-{{ts}}-timeout.png"));
+				let template = exec.artifacts_dir.map(|dir| dir.join(&name)).unwrap_or(name);
+				let screenshot_path = exec.ctx_state.resolve_output(exec.ctx, &template, None);
+				let failure = collect_timeout_trace(session.page(), &screenshot_path, error, console_errors.recent(), collected).await;
 
-					let _ = session.close().await;
+				let _ = session.close().await;
 
-					return Err(PwError::FailureWithArtifacts { command, failure });
-				}
+				return Err(PwError::FailureWithArtifacts { command, failure });
 			}
 
 			let _ = session.close().await;
-			Err(e)
+
+			match artifacts {
+				ArtifactsPolicy::OnError { command } if !collected.is_empty() => {
+					let failure = FailureWithArtifacts::new(error).with_artifacts(collected);
+					Err(PwError::FailureWithArtifacts { command, failure })
+				}
+				_ => Err(e),
+			}
 		}
 	}
 }
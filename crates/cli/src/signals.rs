@@ -0,0 +1,141 @@
+//! Interrupt handling for short-lived CLI invocations.
+//!
+//! A Ctrl+C during `pw exec` or `pw batch` would otherwise drop the in-flight
+//! command future without running async teardown, orphaning the Node driver.
+//! [`wait_for_shutdown_signal`] is raced against dispatch in `main`, and
+//! [`ShutdownCoordinator::cleanup`] terminates every tracked driver before exit.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use pw_runtime::DriverRegistry;
+use tracing::{debug, info};
+
+/// How long drivers get to exit after SIGTERM before they are force-killed.
+pub const SHUTDOWN_GRACE: Duration = Duration::from_millis(1500);
+
+/// Process-wide coordinator used by the CLI entry point.
+pub static SHUTDOWN: ShutdownCoordinator = ShutdownCoordinator::new();
+
+/// Signal that interrupted the CLI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownSignal {
+	Interrupt,
+	Terminate,
+}
+
+impl ShutdownSignal {
+	/// Conventional shell exit code (`128 + signo`).
+	pub fn exit_code(self) -> i32 {
+		match self {
+			Self::Interrupt => 130,
+			Self::Terminate => 143,
+		}
+	}
+}
+
+/// Runs driver cleanup at most once, no matter how many signals arrive.
+#[derive(Debug)]
+pub struct ShutdownCoordinator {
+	started: AtomicBool,
+	drivers: &'static DriverRegistry,
+}
+
+impl ShutdownCoordinator {
+	/// Coordinator for the process-wide driver registry.
+	pub const fn new() -> Self {
+		Self::with_registry(pw_runtime::tracked_driver_registry())
+	}
+
+	/// Coordinator that terminates only the drivers tracked in `drivers`.
+	pub const fn with_registry(drivers: &'static DriverRegistry) -> Self {
+		Self {
+			started: AtomicBool::new(false),
+			drivers,
+		}
+	}
+
+	/// Terminates tracked drivers, waiting up to `grace` before force-killing.
+	///
+	/// Returns `false` without doing anything when cleanup already ran or is
+	/// running elsewhere. The driver registry lock is never held across an
+	/// await, so concurrent callers cannot deadlock.
+	pub async fn cleanup(&self, grace: Duration) -> bool {
+		if self.started.swap(true, Ordering::SeqCst) {
+			debug!(target = "pw", "shutdown cleanup already in progress");
+			return false;
+		}
+
+		let terminated = self.drivers.terminate(grace).await;
+		info!(target = "pw", terminated, "terminated Playwright drivers on shutdown");
+		true
+	}
+
+	/// Returns `true` once [`Self::cleanup`] has been invoked.
+	pub fn is_started(&self) -> bool {
+		self.started.load(Ordering::SeqCst)
+	}
+}
+
+impl Default for ShutdownCoordinator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Resolves when the process receives SIGINT or SIGTERM (Ctrl+C on Windows).
+///
+/// Pends forever if the handlers cannot be installed, so dispatch runs unaffected.
+pub async fn wait_for_shutdown_signal() -> ShutdownSignal {
+	#[cfg(unix)]
+	{
+		use tokio::signal::unix::{SignalKind, signal};
+
+		let (Ok(mut sigint), Ok(mut sigterm)) = (signal(SignalKind::interrupt()), signal(SignalKind::terminate())) else {
+			return std::future::pending().await;
+		};
+
+		tokio::select! {
+			_ = sigint.recv() => ShutdownSignal::Interrupt,
+			_ = sigterm.recv() => ShutdownSignal::Terminate,
+		}
+	}
+
+	#[cfg(not(unix))]
+	{
+		match tokio::signal::ctrl_c().await {
+			Ok(()) => ShutdownSignal::Interrupt,
+			Err(_) => std::future::pending().await,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn cleanup_is_safe_to_call_twice() {
+		static DRIVERS: DriverRegistry = DriverRegistry::new();
+		let coordinator = ShutdownCoordinator::with_registry(&DRIVERS);
+		assert!(!coordinator.is_started());
+
+		assert!(coordinator.cleanup(Duration::from_millis(10)).await);
+		assert!(!coordinator.cleanup(Duration::from_millis(10)).await);
+		assert!(coordinator.is_started());
+	}
+
+	#[tokio::test]
+	async fn concurrent_cleanup_runs_once() {
+		static DRIVERS: DriverRegistry = DriverRegistry::new();
+		let coordinator = ShutdownCoordinator::with_registry(&DRIVERS);
+		let (a, b) = tokio::join!(coordinator.cleanup(Duration::from_millis(10)), coordinator.cleanup(Duration::from_millis(10)));
+		assert!(a ^ b);
+	}
+
+	#[test]
+	fn exit_codes_follow_shell_convention() {
+		assert_eq!(ShutdownSignal::Interrupt.exit_code(), 130);
+		assert_eq!(ShutdownSignal::Terminate.exit_code(), 143);
+	}
+}
@@ -171,6 +171,11 @@ pub fn resolve_target(provided: Option<String>, base_url: Option<&str>, last_url
 }
 
 /// Apply base URL to a potentially relative URL.
+///
+/// Joining follows the URL standard, like Playwright's `baseURL`: `path` and
+/// `./path` replace the last segment of the base path, `../path` climbs one
+/// level, and `/path` replaces the whole path. Give the base a trailing slash
+/// to treat its last segment as a directory.
 fn apply_base_url(url: &str, base: Option<&str>) -> Result<Url> {
 	// Check if URL is already absolute
 	if is_absolute(url) {
@@ -180,7 +185,7 @@ fn apply_base_url(url: &str, base: Option<&str>) -> Result<Url> {
 	// Relative URL needs a base
 	let Some(base_str) = base else {
 		return Err(PwError::Context(format!(
-			"relative URL '{}' requires a base URL (use --base-url or set in context)",
+			"relative URL '{}' requires a base URL; set one with `session.base-url` or pass an absolute URL",
 			url
 		)));
 	};
@@ -193,13 +198,15 @@ fn apply_base_url(url: &str, base: Option<&str>) -> Result<Url> {
 		.map_err(|e| PwError::Context(format!("failed to join '{}' with base '{}': {}", url, base_str, e)))
 }
 
+/// Whether `url` starts with a scheme, either hierarchical (`scheme://`) or one
+/// of the opaque schemes browsers navigate to (`data:`, `about:`, ...).
 fn is_absolute(url: &str) -> bool {
-	url.starts_with("http://")
-		|| url.starts_with("https://")
-		|| url.starts_with("ws://")
-		|| url.starts_with("wss://")
-		|| url.starts_with("file://")
-		|| url.starts_with("data:")
+	let Some((scheme, rest)) = url.split_once(':') else {
+		return false;
+	};
+	let valid_scheme =
+		scheme.starts_with(|c: char| c.is_ascii_alphabetic()) && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+	valid_scheme && (rest.starts_with("//") || matches!(scheme.to_ascii_lowercase().as_str(), "data" | "about" | "blob" | "javascript"))
 }
 
 use crate::context_store::ContextState;
@@ -227,6 +234,11 @@ impl<'a> ResolveEnv<'a> {
 		resolve_target(provided, self.ctx_state.base_url(), self.ctx_state.last_url(), self.has_cdp, policy)
 	}
 
+	/// Resolve a possibly relative URL against the effective base URL.
+	pub fn resolve_url(&self, url: &str) -> Result<Url> {
+		apply_base_url(url, self.ctx_state.base_url())
+	}
+
 	/// Resolve a selector with optional fallback.
 	pub fn resolve_selector(&self, provided: Option<String>, fallback: Option<&str>) -> Result<String> {
 		self.ctx_state.resolve_selector(provided, fallback)
@@ -314,6 +326,41 @@ mod tests {
 		assert!(result.is_err());
 	}
 
+	#[test]
+	fn relative_paths_join_per_url_standard() {
+		let base = Some("https://example.com/app/settings/");
+		assert_eq!(apply_base_url("path", base).unwrap().as_str(), "https://example.com/app/settings/path");
+		assert_eq!(apply_base_url("./path", base).unwrap().as_str(), "https://example.com/app/settings/path");
+		assert_eq!(apply_base_url("../path", base).unwrap().as_str(), "https://example.com/app/path");
+		assert_eq!(
+			apply_base_url("path?q=1", Some("https://example.com/app")).unwrap().as_str(),
+			"https://example.com/path?q=1"
+		);
+	}
+
+	#[test]
+	fn relative_url_without_base_names_the_fix() {
+		let err = apply_base_url("./path", None).unwrap_err();
+		assert!(err.to_string().contains("session.base-url"), "{err}");
+	}
+
+	#[test]
+	fn scheme_detection() {
+		for url in [
+			"https://x.com",
+			"ws://x",
+			"file:///tmp/a.html",
+			"about:blank",
+			"data:text/plain,hi",
+			"chrome://version",
+		] {
+			assert!(is_absolute(url), "{url}");
+		}
+		for url in ["path", "./a", "../a", "/a?next=https://x.com", "a:b"] {
+			assert!(!is_absolute(url), "{url}");
+		}
+	}
+
 	#[test]
 	fn preferred_url_for_navigate() {
 		let result = resolve_target(
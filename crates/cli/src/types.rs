@@ -24,6 +24,40 @@ impl std::fmt::Display for BrowserKind {
 	}
 }
 
+/// Branded Chromium distribution launched instead of the bundled Chromium.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BrowserChannel {
+	Chrome,
+	ChromeBeta,
+	ChromeDev,
+	ChromeCanary,
+	Msedge,
+	MsedgeBeta,
+	MsedgeDev,
+}
+
+impl BrowserChannel {
+	/// Channel name as Playwright expects it in `LaunchOptions.channel`.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Chrome => "chrome",
+			Self::ChromeBeta => "chrome-beta",
+			Self::ChromeDev => "chrome-dev",
+			Self::ChromeCanary => "chrome-canary",
+			Self::Msedge => "msedge",
+			Self::MsedgeBeta => "msedge-beta",
+			Self::MsedgeDev => "msedge-dev",
+		}
+	}
+}
+
+impl std::fmt::Display for BrowserChannel {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct NavigateResult {
@@ -34,7 +68,7 @@ pub struct NavigateResult {
 	pub has_errors: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ConsoleMessage {
 	#[serde(rename = "type")]
@@ -44,6 +78,85 @@ pub struct ConsoleMessage {
 	pub stack: Option<String>,
 }
 
+/// Which point of an element's bounding box `x`/`y` refer to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoordsPoint {
+	/// Center of the bounding box
+	#[default]
+	Center,
+	/// Top-left corner of the bounding box
+	#[value(name = "topleft")]
+	TopLeft,
+}
+
+/// Order in which `page.coords-all` reports matches.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoordsOrder {
+	/// Document order of the matches
+	#[default]
+	Dom,
+	/// Rows top to bottom, each row left to right
+	#[value(name = "reading_order")]
+	ReadingOrder,
+	/// By top edge, ties broken by left edge
+	#[value(name = "top_to_bottom")]
+	TopToBottom,
+	/// By left edge, ties broken by top edge
+	#[value(name = "left_to_right")]
+	LeftToRight,
+}
+
+/// Unrounded element bounding box in CSS pixels, as reported by `getBoundingClientRect`.
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ElementBox {
+	#[serde(default)]
+	pub index: usize,
+	pub x: f64,
+	pub y: f64,
+	pub width: f64,
+	pub height: f64,
+	#[serde(default)]
+	pub text: Option<String>,
+	#[serde(default)]
+	pub href: Option<String>,
+}
+
+impl ElementBox {
+	/// Projects the box to integer coordinates for `point`, multiplying every dimension by `scale`.
+	pub fn project(self, point: CoordsPoint, scale: f64) -> ElementCoords {
+		let (x, y) = match point {
+			CoordsPoint::Center => (self.x + self.width / 2.0, self.y + self.height / 2.0),
+			CoordsPoint::TopLeft => (self.x, self.y),
+		};
+		ElementCoords {
+			x: (x * scale).round() as i32,
+			y: (y * scale).round() as i32,
+			width: (self.width * scale).round() as i32,
+			height: (self.height * scale).round() as i32,
+			text: self.text,
+			href: self.href,
+		}
+	}
+
+	/// Like [`Self::project`], keeping the element's match index.
+	pub fn project_indexed(self, point: CoordsPoint, scale: f64) -> IndexedElementCoords {
+		let index = self.index;
+		let coords = self.project(point, scale);
+		IndexedElementCoords {
+			index,
+			x: coords.x,
+			y: coords.y,
+			width: coords.width,
+			height: coords.height,
+			text: coords.text,
+			href: coords.href,
+		}
+	}
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ElementCoords {
@@ -121,6 +234,37 @@ mod tests {
 		assert_eq!(back.text, Some("Click me".into()));
 	}
 
+	#[test]
+	fn element_box_projects_center_by_default() {
+		let bbox = ElementBox {
+			x: 10.0,
+			y: 20.0,
+			width: 100.0,
+			height: 41.0,
+			..Default::default()
+		};
+		let coords = bbox.clone().project(CoordsPoint::default(), 1.0);
+		assert_eq!((coords.x, coords.y, coords.width, coords.height), (60, 41, 100, 41));
+
+		let top_left = bbox.project(CoordsPoint::TopLeft, 1.0);
+		assert_eq!((top_left.x, top_left.y), (10, 20));
+	}
+
+	#[test]
+	fn element_box_scales_by_device_pixel_ratio() {
+		let bbox = ElementBox {
+			index: 3,
+			x: 10.0,
+			y: 20.0,
+			width: 100.0,
+			height: 40.0,
+			..Default::default()
+		};
+		let coords = bbox.project_indexed(CoordsPoint::Center, 2.0);
+		assert_eq!(coords.index, 3);
+		assert_eq!((coords.x, coords.y, coords.width, coords.height), (120, 80, 200, 80));
+	}
+
 	#[test]
 	fn indexed_element_coords_round_trip() {
 		let coords = IndexedElementCoords {
@@ -17,6 +17,8 @@ pub const DEFAULT_PROFILE: &str = "default";
 pub const WORKSPACE_ROOT_ENV_VAR: &str = "PW_WORKSPACE_ROOT";
 pub const STATE_VERSION_DIR: &str = ".pw-cli-v4";
 pub const STATE_GITIGNORE_CONTENT: &str = "*\n";
+/// File under the state root naming the profile `pw context use` selected.
+pub const ACTIVE_PROFILE_FILE: &str = "active-profile";
 pub const CDP_PORT_RANGE_START: u16 = 9222;
 pub const CDP_PORT_RANGE_SIZE: u16 = 1000;
 
@@ -32,11 +34,15 @@ impl WorkspaceScope {
 	/// Resolve workspace root + profile from CLI values.
 	///
 	/// * `workspace`: explicit workspace path, or `"auto"` for detection.
-	/// * `profile`: optional profile; defaults to [`DEFAULT_PROFILE`].
+	/// * `profile`: optional profile; defaults to the workspace's active profile,
+	///   then [`DEFAULT_PROFILE`].
 	/// * `no_project`: when true, skip playwright project-root detection.
 	pub fn resolve(workspace: Option<&str>, profile: Option<&str>, no_project: bool) -> Result<Self> {
-		let profile = normalize_profile(profile.unwrap_or(DEFAULT_PROFILE));
 		let root = resolve_workspace_root(workspace, no_project)?;
+		let profile = match profile {
+			Some(profile) => normalize_profile(profile),
+			None => active_profile(&root).unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+		};
 		Ok(Self::from_parts(root, profile))
 	}
 
@@ -114,6 +120,29 @@ pub fn normalize_profile(profile: &str) -> String {
 	}
 }
 
+/// Profile persisted by [`set_active_profile`] for `workspace_root`, if any.
+pub fn active_profile(workspace_root: &Path) -> Option<String> {
+	let path = active_profile_path(workspace_root);
+	let content = std::fs::read_to_string(path).ok()?;
+	let name = content.trim();
+	(!name.is_empty()).then(|| normalize_profile(name))
+}
+
+/// Makes `profile` the default for requests in `workspace_root` that name none.
+pub fn set_active_profile(workspace_root: &Path, profile: &str) -> Result<()> {
+	let path = active_profile_path(workspace_root);
+	ensure_state_gitignore_for(&path)?;
+	if let Some(parent) = path.parent() {
+		std::fs::create_dir_all(parent)?;
+	}
+	std::fs::write(path, format!("{}\n", normalize_profile(profile)))?;
+	Ok(())
+}
+
+fn active_profile_path(workspace_root: &Path) -> PathBuf {
+	workspace_root.join(dirs::PLAYWRIGHT).join(STATE_VERSION_DIR).join(ACTIVE_PROFILE_FILE)
+}
+
 /// Backward compatibility alias.
 pub fn normalize_namespace(namespace: &str) -> String {
 	normalize_profile(namespace)
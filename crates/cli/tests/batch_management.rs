@@ -26,12 +26,17 @@ fn clear_context_store() {
 }
 
 fn run_pw_batch(lines: &[&str]) -> (bool, String, String) {
+	run_pw_batch_with_args(&[], lines)
+}
+
+fn run_pw_batch_with_args(extra_args: &[&str], lines: &[&str]) -> (bool, String, String) {
 	let workspace = workspace_root();
 	let _ = std::fs::create_dir_all(&workspace);
 
 	let mut child = Command::new(pw_binary())
 		.current_dir(&workspace)
 		.args(["-f", "ndjson", "batch", "--profile", "default"])
+		.args(extra_args)
 		.stdin(Stdio::piped())
 		.stdout(Stdio::piped())
 		.stderr(Stdio::piped())
@@ -51,6 +56,36 @@ fn run_pw_batch(lines: &[&str]) -> (bool, String, String) {
 	(output.status.success(), stdout, stderr)
 }
 
+fn run_pw_script(script: &[&str], stdin_lines: &[&str]) -> (bool, String, String) {
+	let workspace = workspace_root();
+	let _ = std::fs::create_dir_all(&workspace);
+	let script_path = workspace.join("warmup.ndjson");
+	std::fs::write(&script_path, script.join("\n")).expect("failed to write script");
+
+	let mut child = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "ndjson", "run", "--script"])
+		.arg(&script_path)
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()
+		.expect("failed to start pw run");
+
+	{
+		let stdin = child.stdin.as_mut().expect("stdin unavailable");
+		for line in stdin_lines {
+			// `pw run` may exit without reading stdin, so a broken pipe is expected.
+			let _ = writeln!(stdin, "{line}");
+		}
+	}
+
+	let output = child.wait_with_output().expect("failed waiting for pw run");
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	(output.status.success(), stdout, stderr)
+}
+
 fn parse_ndjson(stdout: &str) -> Vec<serde_json::Value> {
 	stdout
 		.lines()
@@ -80,6 +115,49 @@ fn batch_supports_har_show() {
 	assert_eq!(first["data"]["enabled"], false);
 }
 
+#[test]
+fn batch_dry_run_navigate_reports_resolution_without_navigating() {
+	clear_context_store();
+
+	let (success, stdout, stderr) = run_pw_batch(&[
+		r#"{"schemaVersion":5,"requestId":"1","op":"navigate","input":{"url":"https://example.com/dry"},"dryRun":true}"#,
+		r#"{"schemaVersion":5,"requestId":"2","op":"navigate","input":{},"dry_run":true}"#,
+		r#"{"schemaVersion":5,"requestId":"3","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	assert!(lines.len() >= 2, "expected at least two response lines, got: {stdout}");
+
+	let first = &lines[0];
+	assert_eq!(first["ok"], true, "{first}");
+	assert_eq!(first["data"]["dryRun"], true);
+	assert_eq!(first["data"]["resolved"]["target"]["url"], "https://example.com/dry");
+	assert_eq!(first["data"]["resolved"]["target"]["source"], "explicit");
+
+	// The dry run recorded no last URL, so a target-less navigate has nothing to resolve.
+	let second = &lines[1];
+	assert_eq!(second["requestId"], "2");
+	assert_eq!(second["ok"], false, "{second}");
+}
+
+#[test]
+fn batch_dry_run_click_reports_selector() {
+	clear_context_store();
+
+	let (success, stdout, stderr) = run_pw_batch(&[
+		r##"{"schemaVersion":5,"requestId":"1","op":"click","input":{"url":"https://example.com/form","selector":"#submit"},"dryRun":true}"##,
+		r#"{"schemaVersion":5,"requestId":"2","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	let first = &lines[0];
+	assert_eq!(first["ok"], true, "{first}");
+	assert_eq!(first["data"]["resolved"]["target"]["url"], "https://example.com/form");
+	assert_eq!(first["data"]["resolved"]["selector"], "#submit");
+}
+
 #[test]
 fn batch_rejects_auth_login_as_interactive() {
 	clear_context_store();
@@ -121,3 +199,115 @@ fn batch_alias_is_rejected() {
 	assert_eq!(first["op"], "har-show");
 	assert_eq!(first["error"]["code"], "INVALID_INPUT");
 }
+
+#[test]
+fn batch_log_file_records_one_line_per_command() {
+	clear_context_store();
+	let log_dir = tempfile::tempdir().unwrap();
+	let log_path = log_dir.path().join("run.log");
+
+	let (success, stdout, stderr) = run_pw_batch_with_args(
+		&["--log-file", log_path.to_str().unwrap()],
+		&[
+			r#"{"schemaVersion":5,"requestId":"1","op":"har.show","input":{}}"#,
+			r#"{"schemaVersion":5,"requestId":"2","op":"har-show","input":{}}"#,
+		],
+	);
+
+	assert!(success, "batch run failed: {stderr}");
+	assert_eq!(parse_ndjson(&stdout).len(), 2, "log lines must not leak into stdout: {stdout}");
+
+	let logged = parse_ndjson(&std::fs::read_to_string(&log_path).expect("log file should exist"));
+	assert_eq!(logged.len(), 2);
+	assert_eq!(logged[0]["requestId"], "1");
+	assert_eq!(logged[0]["op"], "har.show");
+	assert_eq!(logged[0]["ok"], true);
+	assert!(logged[0]["durationMs"].is_u64());
+	assert_eq!(logged[1]["requestId"], "2");
+	assert_eq!(logged[1]["ok"], false);
+	assert_eq!(logged[1]["errorCode"], "INVALID_INPUT");
+}
+
+#[test]
+fn batch_config_directive_enables_log_file() {
+	clear_context_store();
+	let log_dir = tempfile::tempdir().unwrap();
+	let log_path = log_dir.path().join("run.log");
+	let config = serde_json::json!({
+		"schemaVersion": 5,
+		"requestId": "cfg",
+		"op": "config",
+		"input": { "logFile": log_path },
+	})
+	.to_string();
+
+	let (success, stdout, stderr) = run_pw_batch(&[
+		config.as_str(),
+		r#"{"schemaVersion":5,"requestId":"1","op":"ping","input":{}}"#,
+		r#"{"schemaVersion":5,"requestId":"2","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	assert_eq!(lines[0]["op"], "config");
+	assert_eq!(lines[0]["ok"], true);
+
+	let logged = parse_ndjson(&std::fs::read_to_string(&log_path).expect("log file should exist"));
+	let ops: Vec<_> = logged.iter().map(|line| line["op"].as_str().unwrap()).collect();
+	assert_eq!(ops, ["config", "ping", "quit"]);
+}
+
+#[test]
+fn batch_responses_report_duration_for_executed_commands() {
+	clear_context_store();
+
+	let (success, stdout, stderr) = run_pw_batch(&[
+		r#"{"schemaVersion":5,"requestId":"1","op":"navigate","input":{"url":"data:text/html,<h1>Timed</h1>"}}"#,
+		r#"{"schemaVersion":5,"requestId":"2","op":"ping","input":{}}"#,
+		r#"{"schemaVersion":5,"requestId":"3","op":"quit","input":{}}"#,
+	]);
+
+	assert!(success, "batch run failed: {stderr}");
+	let lines = parse_ndjson(&stdout);
+	assert_eq!(lines[0]["op"], "navigate");
+	assert_eq!(lines[0]["ok"], true, "navigate failed: {}", lines[0]);
+	assert!(lines[0]["durationMs"].as_u64().expect("navigate should report durationMs") > 0);
+	assert_eq!(lines[1]["op"], "ping");
+	assert!(lines[1].get("durationMs").is_none());
+}
+
+#[test]
+fn run_script_executes_before_stdin() {
+	let (success, stdout, stderr) = run_pw_script(
+		&[r#"{"schemaVersion":5,"requestId":"script-1","op":"ping","input":{}}"#],
+		&[
+			r#"{"schemaVersion":5,"requestId":"stdin-1","op":"ping","input":{}}"#,
+			r#"{"schemaVersion":5,"requestId":"stdin-2","op":"quit","input":{}}"#,
+		],
+	);
+
+	assert!(success, "pw run failed: {stderr}");
+	let ids: Vec<_> = parse_ndjson(&stdout)
+		.iter()
+		.map(|line| line["requestId"].as_str().unwrap_or_default().to_string())
+		.collect();
+	assert_eq!(ids, ["script-1", "stdin-1", "stdin-2"], "unexpected response order: {stdout}");
+}
+
+#[test]
+fn run_script_ending_with_quit_skips_stdin() {
+	let (success, stdout, stderr) = run_pw_script(
+		&[
+			r#"{"schemaVersion":5,"requestId":"script-1","op":"ping","input":{}}"#,
+			r#"{"schemaVersion":5,"requestId":"script-2","op":"quit","input":{}}"#,
+		],
+		&[r#"{"schemaVersion":5,"requestId":"stdin-1","op":"ping","input":{}}"#],
+	);
+
+	assert!(success, "pw run failed: {stderr}");
+	let ids: Vec<_> = parse_ndjson(&stdout)
+		.iter()
+		.map(|line| line["requestId"].as_str().unwrap_or_default().to_string())
+		.collect();
+	assert_eq!(ids, ["script-1", "script-2"], "stdin should not be read after quit: {stdout}");
+}
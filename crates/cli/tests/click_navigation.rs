@@ -1,4 +1,4 @@
-//! Integration tests for click navigation detection in protocol v2.
+//! Integration tests for click navigation detection and `--first`/`--nth` match selection in protocol v2.
 
 use std::path::PathBuf;
 use std::process::Command;
@@ -22,11 +22,17 @@ fn clear_context_store() {
 }
 
 fn run_exec(op: &str, input: serde_json::Value) -> (bool, serde_json::Value, String) {
+	run_exec_with_args(op, input, &[])
+}
+
+fn run_exec_with_args(op: &str, input: serde_json::Value, extra_args: &[&str]) -> (bool, serde_json::Value, String) {
 	let workspace = workspace_root();
 	let _ = std::fs::create_dir_all(&workspace);
 	let output = Command::new(pw_binary())
 		.current_dir(&workspace)
-		.args(["-f", "json", "exec", op, "--input"])
+		.args(["-f", "json", "exec", op])
+		.args(extra_args)
+		.arg("--input")
 		.arg(input.to_string())
 		.output()
 		.expect("failed to execute pw");
@@ -85,3 +91,128 @@ fn click_uses_accurate_url_detection() {
 	let before = json["data"]["beforeUrl"].as_str().unwrap_or_default();
 	assert!(before.contains("data:text/html"), "expected data URL in beforeUrl: {before}");
 }
+
+const BUTTON_LIST: &str = "data:text/html,<html><body>\
+	<button onclick=\"history.pushState({}, '', '?picked=a')\">A</button>\
+	<button onclick=\"history.pushState({}, '', '?picked=b')\">B</button>\
+	<button onclick=\"history.pushState({}, '', '?picked=c')\">C</button>\
+	</body></html>";
+
+#[test]
+fn click_multi_match_is_ambiguous_without_first_or_nth() {
+	clear_context_store();
+
+	let (_success, json, _stderr) = run_exec("click", json!({ "url": BUTTON_LIST, "selector": "button" }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "SELECTOR_AMBIGUOUS");
+	assert!(json["error"]["message"].as_str().unwrap_or_default().contains("--first"), "{json}");
+}
+
+#[test]
+fn click_first_picks_first_match() {
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec("click", json!({ "url": BUTTON_LIST, "selector": "button", "first": true, "waitMs": 100 }));
+	assert!(success, "click failed: {stderr}");
+	let after = json["data"]["afterUrl"].as_str().unwrap_or_default();
+	assert!(after.contains("picked=a"), "expected first button to be clicked: {after}");
+}
+
+#[test]
+fn click_nth_picks_indexed_match() {
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec("click", json!({ "url": BUTTON_LIST, "selector": "button", "nth": 2, "waitMs": 100 }));
+	assert!(success, "click failed: {stderr}");
+	let after = json["data"]["afterUrl"].as_str().unwrap_or_default();
+	assert!(after.contains("picked=c"), "expected third button to be clicked: {after}");
+	assert_eq!(json["inputs"]["nth"], 2);
+}
+
+#[test]
+fn click_rejects_first_with_nth() {
+	clear_context_store();
+
+	let (_success, json, _stderr) = run_exec("click", json!({ "url": BUTTON_LIST, "selector": "button", "first": true, "nth": 1 }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
+
+const INPUT_LIST: &str = "data:text/html,<html><body><input class='f' disabled><input class='f'><input class='f' disabled></body></html>";
+
+#[test]
+fn fill_multi_match_is_ambiguous_without_first_or_nth() {
+	clear_context_store();
+
+	let (_success, json, _stderr) = run_exec("fill", json!({ "url": INPUT_LIST, "selector": ".f", "text": "x" }));
+	assert_eq!(json["ok"], false, "{json}");
+	assert_eq!(json["error"]["code"], "SELECTOR_AMBIGUOUS");
+}
+
+#[test]
+fn fill_nth_fills_indexed_match() {
+	clear_context_store();
+
+	let (success, json, stderr) = run_exec("fill", json!({ "url": INPUT_LIST, "selector": ".f", "text": "picked", "nth": 1 }));
+	assert!(success, "fill failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["inputs"]["nth"], 1);
+}
+
+#[test]
+fn fill_rejects_first_with_nth() {
+	clear_context_store();
+
+	let (_success, json, _stderr) = run_exec("fill", json!({ "url": INPUT_LIST, "selector": ".f", "text": "x", "first": true, "nth": 1 }));
+	assert_eq!(json["ok"], false, "{json}");
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
+
+#[test]
+fn click_retry_on_selector_survives_replaced_element() {
+	clear_context_store();
+	let html = "data:text/html,<html><body><div id='root'></div><script>\
+		const started = Date.now();\
+		const render = () => { const id = Date.now() - started < 900 ? 'pending' : 'churn'; \
+		document.getElementById('root').innerHTML = \
+		`<button id='${id}' onclick=\\\"history.pushState({}, '', '?churned=1')\\\">Go</button>`; };\
+		render(); setInterval(render, 30);\
+		</script></body></html>";
+
+	let (success, json, stderr) = run_exec_with_args(
+		"click",
+		json!({ "url": html, "selector": "#churn", "retryOnSelector": true, "waitMs": 100 }),
+		&["--timeout", "2000"],
+	);
+	assert!(success, "click failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	let after = json["data"]["afterUrl"].as_str().unwrap_or_default();
+	assert!(after.contains("churned=1"), "expected the re-rendered button to be clicked: {json}");
+	let diagnostics = json["diagnostics"].as_array().cloned().unwrap_or_default();
+	assert!(
+		diagnostics.iter().any(|d| d["source"] == "retry-on-selector"),
+		"expected a retry diagnostic: {json}"
+	);
+}
+
+#[test]
+fn click_retry_on_selector_reports_retry_for_late_element() {
+	clear_context_store();
+	let html = "data:text/html,<html><body><div id='root'></div><script>\
+		setTimeout(() => { document.getElementById('root').innerHTML = \
+		\"<button id='late' onclick=\\\"history.pushState({}, '', '?late=1')\\\">Go</button>\"; }, 900);\
+		</script></body></html>";
+
+	let (_success, json, stderr) = run_exec_with_args(
+		"click",
+		json!({ "url": html, "selector": "#late", "retryOnSelector": true, "waitMs": 100 }),
+		&["--timeout", "2000"],
+	);
+	assert_eq!(json["ok"], true, "click failed: {stderr} {json}");
+	assert!(json["data"]["afterUrl"].as_str().unwrap_or_default().contains("late=1"), "{json}");
+	let diagnostics = json["diagnostics"].as_array().cloned().unwrap_or_default();
+	assert!(
+		diagnostics.iter().any(|d| d["source"] == "retry-on-selector"),
+		"expected a retry diagnostic: {json}"
+	);
+}
@@ -99,3 +99,23 @@ fn subsequent_command_uses_context_url() {
 	assert!(success, "page.text failed: {stderr}");
 	assert_eq!(json["data"]["text"], "Title");
 }
+
+#[test]
+fn stored_base_url_resolves_relative_navigate() {
+	let _lock = CONTEXT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+	clear_context_store();
+
+	let site = workspace_root().join("site");
+	std::fs::create_dir_all(site.join("path")).unwrap();
+	std::fs::write(site.join("path").join("to.html"), "<h1>Relative</h1>").unwrap();
+	let base = url::Url::from_directory_path(&site).unwrap().to_string();
+
+	let (success, json, stderr) = run_exec("session.base-url", json!({ "url": base }));
+	assert!(success, "session.base-url failed: {stderr}");
+	assert_eq!(json["data"]["changed"], true);
+
+	let (success, json, stderr) = run_exec("navigate", json!({ "url": "path/to.html" }));
+	assert!(success, "navigate failed: {stderr}");
+	assert_eq!(json["effectiveRuntime"]["baseUrl"], base);
+	assert_eq!(json["data"]["url"], format!("{base}path/to.html"));
+}
@@ -0,0 +1,43 @@
+//! Integration tests for the global `--dump-protocol` flag.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::Value;
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+#[test]
+fn navigate_dumps_sent_and_received_messages() {
+	let workspace = tempfile::tempdir().unwrap();
+	let dump = workspace.path().join("wire.ndjson");
+
+	let output = Command::new(pw_binary())
+		.current_dir(workspace.path())
+		.arg("--dump-protocol")
+		.arg(&dump)
+		.args(["-f", "json", "exec", "navigate", "--input", r#"{"url":"data:text/html,<h1>Dump</h1>"}"#])
+		.output()
+		.expect("failed to execute pw");
+	let json: Value =
+		serde_json::from_slice(&output.stdout).unwrap_or_else(|_| panic!("expected JSON stdout, stderr: {}", String::from_utf8_lossy(&output.stderr)));
+	assert_eq!(json["ok"], true, "{json}");
+
+	let lines: Vec<Value> = std::fs::read_to_string(&dump)
+		.expect("dump file should exist")
+		.lines()
+		.map(|line| serde_json::from_str(line).expect("each dump line should be JSON"))
+		.collect();
+	assert!(lines.iter().all(|line| line["ts"].is_u64()), "{lines:?}");
+	assert!(
+		lines.iter().any(|line| line["dir"] == "send" && line["message"]["method"] == "goto"),
+		"expected a sent goto: {lines:?}"
+	);
+	assert!(lines.iter().any(|line| line["dir"] == "recv"), "expected received messages: {lines:?}");
+}
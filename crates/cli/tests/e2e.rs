@@ -43,6 +43,93 @@ fn run_exec(op: &str, input: serde_json::Value) -> (bool, serde_json::Value, Str
 	(success, parsed, stderr)
 }
 
+#[test]
+fn strict_exit_fails_process_on_error_envelope() {
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "exec", "does.not.exist"]);
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["ok"], false, "{stdout}");
+	assert!(success, "failed envelope should exit 0 by default: {stderr}");
+
+	let (success, stdout, _stderr) = run_pw(&["-f", "json", "--strict-exit", "exec", "does.not.exist"]);
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["error"]["code"], "INVALID_INPUT", "{stdout}");
+	assert!(!success, "--strict-exit should exit non-zero on ok=false");
+}
+
+#[test]
+fn json_errors_emits_envelope_to_stdout_in_text_mode() {
+	let (_success, stdout, _stderr) = run_pw(&["-f", "text", "exec", "does.not.exist"]);
+	assert!(stdout.lines().all(|line| serde_json::from_str::<serde_json::Value>(line).is_err()), "{stdout}");
+
+	let (_success, stdout, stderr) = run_pw(&["-f", "text", "--json-errors", "exec", "does.not.exist"]);
+	let envelope = stdout
+		.lines()
+		.find_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+		.unwrap_or_else(|| panic!("no JSON envelope on stdout: {stdout} {stderr}"));
+	assert_eq!(envelope["ok"], false, "{envelope}");
+	assert_eq!(envelope["op"], "does.not.exist", "{envelope}");
+	assert_eq!(envelope["error"]["code"], "INVALID_INPUT", "{envelope}");
+}
+
+#[test]
+fn wrong_typed_input_reports_offending_field() {
+	let (_success, json, stderr) = run_exec("click", json!({ "selector": "button", "wait_ms": "soon" }));
+	assert_eq!(json["ok"], false, "{json} {stderr}");
+	assert_eq!(json["error"]["code"], "INVALID_INPUT", "{json}");
+	assert!(json["error"]["message"].as_str().unwrap().contains("wait_ms"), "{json}");
+	assert_eq!(json["error"]["details"]["field"], "wait_ms", "{json}");
+}
+
+#[test]
+fn zero_timeout_disables_navigation_deadline() {
+	let (success, stdout, stderr) = run_pw(&[
+		"-f",
+		"json",
+		"exec",
+		"navigate",
+		"--timeout",
+		"0",
+		"--input",
+		&json!({ "url": "data:text/html,<h1>No deadline</h1>" }).to_string(),
+	]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["ok"], true, "{stdout}");
+	assert_eq!(json["effectiveRuntime"]["timeoutMs"], 0, "{stdout}");
+}
+
+#[test]
+fn offline_flag_takes_the_page_offline() {
+	let input = r#"{"url":"data:text/html,<h1>Net</h1>","expression":"navigator.onLine"}"#;
+
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "exec", "page.eval", "--offline", "--input", input]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["ok"], true, "{stdout}");
+	assert_eq!(json["data"]["result"], false, "{stdout}");
+
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "exec", "page.eval", "--input", input]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["data"]["result"], true, "{stdout}");
+}
+
+#[test]
+fn pdf_reports_written_file_as_artifact() {
+	let output_path = std::env::temp_dir().join("pw-test-page.pdf");
+	let _ = std::fs::remove_file(&output_path);
+
+	let (success, json, stderr) = run_exec(
+		"page.pdf",
+		json!({ "url": "data:text/html,<h1>Report</h1>", "output": output_path.to_string_lossy() }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["artifacts"][0]["type"], "pdf", "{json}");
+	assert_eq!(json["artifacts"][0]["path"], output_path.to_string_lossy().as_ref(), "{json}");
+	assert!(output_path.exists(), "pdf file was not created");
+}
+
 #[test]
 fn screenshot_creates_file() {
 	let temp_dir = std::env::temp_dir();
@@ -62,6 +149,59 @@ fn screenshot_creates_file() {
 	let _ = std::fs::remove_file(&output_path);
 }
 
+#[test]
+fn text_inner_text_hides_what_text_content_keeps() {
+	let url = "data:text/html,<p>Shown<span style='display:none'> Hidden</span></p>";
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "p" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"], "Shown", "{json}");
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "p", "innerText": false }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"], "Shown Hidden", "{json}");
+}
+
+#[test]
+fn text_includes_shadow_root_content_unless_light_dom_only() {
+	let url = "data:text/html,<x-greeting><i>slotted</i></x-greeting><script>customElements.define('x-greeting', class extends HTMLElement { \
+	           constructor() { super(); this.attachShadow({ mode: 'open' }).innerHTML = 'Hello from shadow, <slot></slot>'; } });</script>";
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "x-greeting" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"], "Hello from shadow, slotted", "{json}");
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "x-greeting", "lightDomOnly": true }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["text"], "slotted", "{json}");
+}
+
+#[test]
+fn text_waits_for_late_shadow_host() {
+	let url = "data:text/html,<script>customElements.define('x-late', class extends HTMLElement { \
+	           constructor() { super(); this.attachShadow({ mode: 'open' }).innerHTML = 'Late shadow'; } }); \
+	           setTimeout(() => document.body.append(document.createElement('x-late')), 300);</script>";
+
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": url, "selector": "x-late" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["text"], "Late shadow", "{json}");
+}
+
+#[test]
+fn html_inner_excludes_own_tag() {
+	let url = "data:text/html,<div id='box' class='wrap'><p>Inside</p></div>";
+	let (success, json, stderr) = run_exec("page.html", json!({ "url": url, "selector": "#box", "which": "inner" }));
+	assert!(success, "command failed: {stderr}");
+	let inner = json["data"]["html"].as_str().unwrap_or_default();
+	assert_eq!(inner, "<p>Inside</p>");
+
+	let (success, json, stderr) = run_exec("page.html", json!({ "url": url, "selector": "#box" }));
+	assert!(success, "command failed: {stderr}");
+	let outer = json["data"]["html"].as_str().unwrap_or_default();
+	assert!(outer.starts_with("<div id=\"box\""), "expected outer HTML: {outer}");
+}
+
 #[test]
 fn html_with_selector() {
 	let (success, json, stderr) = run_exec(
@@ -127,6 +267,410 @@ fn eval_query_selector() {
 	assert_eq!(json["data"]["result"], "Content");
 }
 
+#[test]
+fn save_trace_on_timeout_attaches_console_errors() {
+	let artifacts_dir = std::env::temp_dir().join(format!("pw-timeout-trace-{}", std::process::id()));
+	let input = json!({
+		"url": "data:text/html,<h1>Stuck</h1><script>console.error('widget failed to load')</script>",
+		"expression": "new Promise(() => {})",
+		"timeoutMs": 500
+	});
+	let (success, stdout, stderr) = run_pw(&[
+		"-f",
+		"json",
+		"exec",
+		"page.eval",
+		"--save-trace-on-timeout",
+		"--artifacts-dir",
+		artifacts_dir.to_str().unwrap(),
+		"--input",
+		&input.to_string(),
+	]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["ok"], false, "{stdout}");
+	assert_eq!(json["error"]["code"], "TIMEOUT", "{stdout}");
+
+	let details = &json["error"]["details"];
+	assert_eq!(details["consoleErrors"], json!(["widget failed to load"]), "{stdout}");
+	let screenshot = details["screenshot"].as_str().unwrap_or_default();
+	assert!(std::path::Path::new(screenshot).exists(), "missing screenshot: {stdout}");
+
+	let _ = std::fs::remove_dir_all(&artifacts_dir);
+}
+
+#[test]
+fn save_trace_on_timeout_keeps_failure_artifacts() {
+	let artifacts_dir = std::env::temp_dir().join(format!("pw-timeout-artifacts-{}", std::process::id()));
+	let input = json!({
+		"url": "data:text/html,<h1>No form</h1><script>console.error('form failed to render')</script>",
+		"selector": "#missing",
+		"text": "hello"
+	});
+	let (success, stdout, stderr) = run_pw(&[
+		"-f",
+		"json",
+		"exec",
+		"fill",
+		"--timeout",
+		"500",
+		"--save-trace-on-timeout",
+		"--artifacts-dir",
+		artifacts_dir.to_str().unwrap(),
+		"--input",
+		&input.to_string(),
+	]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["error"]["code"], "TIMEOUT", "{stdout}");
+	assert_eq!(json["error"]["details"]["consoleErrors"], json!(["form failed to render"]), "{stdout}");
+
+	let artifacts = json["artifacts"].as_array().cloned().unwrap_or_default();
+	let types: Vec<_> = artifacts.iter().map(|artifact| artifact["type"].clone()).collect();
+	assert!(types.contains(&json!("screenshot")) && types.contains(&json!("html")), "{stdout}");
+	let screenshot = artifacts.iter().find(|artifact| artifact["type"] == "screenshot").unwrap();
+	assert_eq!(json["error"]["details"]["screenshot"], screenshot["path"], "{stdout}");
+
+	let _ = std::fs::remove_dir_all(&artifacts_dir);
+}
+
+#[test]
+fn fail_on_console_error_fails_navigate() {
+	let input = json!({ "url": "data:text/html,<h1>Hi</h1><script>console.error('widget failed to load')</script>" });
+	let (success, stdout, stderr) = run_pw(&["-f", "json", "exec", "navigate", "--fail-on-console-error", "--input", &input.to_string()]);
+	assert!(success, "command failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["ok"], false, "{stdout}");
+	assert_eq!(json["error"]["code"], "CONSOLE_ERROR", "{stdout}");
+	assert_eq!(json["error"]["details"]["consoleErrors"], json!(["widget failed to load"]), "{stdout}");
+
+	let (success, _json, stderr) = run_exec("navigate", input);
+	assert!(success, "navigate without the flag should succeed: {stderr}");
+}
+
+#[test]
+fn urls_file_emits_one_envelope_per_url() {
+	let dir = unique_workspace();
+	std::fs::create_dir_all(&dir).unwrap();
+	let urls_file = dir.join("urls.txt");
+	std::fs::write(
+		&urls_file,
+		"data:text/html,<title>One</title>\n\n# skipped\ndata:text/html,<title>Two</title>\n",
+	)
+	.unwrap();
+
+	let (success, stdout, stderr) = run_pw(&["exec", "navigate", "--urls-file", urls_file.to_str().unwrap(), "--concurrency", "2"]);
+	assert!(success, "command failed: {stderr}");
+	let envelopes: Vec<serde_json::Value> = stdout.lines().map(|line| serde_json::from_str(line).expect("ndjson envelope")).collect();
+	assert_eq!(envelopes.len(), 2, "{stdout}");
+	for envelope in &envelopes {
+		assert_eq!(envelope["ok"], true, "{envelope}");
+	}
+	assert_eq!(envelopes[0]["data"]["title"], "One", "{stdout}");
+	assert_eq!(envelopes[1]["data"]["title"], "Two", "{stdout}");
+}
+
+#[test]
+fn eval_reports_result_type() {
+	let cases = [
+		("'hi'", "string"),
+		("1 + 1", "number"),
+		("true", "boolean"),
+		("({ a: 1 })", "object"),
+		("[1, 2]", "array"),
+		("null", "null"),
+		("undefined", "undefined"),
+	];
+	for (expression, expected) in cases {
+		let (success, json, stderr) = run_exec("page.eval", json!({ "url": "data:text/html,<h1>Hi</h1>", "expression": expression }));
+		assert!(success, "command failed: {stderr}");
+		assert_eq!(json["data"]["resultType"], expected, "{expression}: {json}");
+	}
+}
+
+#[test]
+fn console_returns_messages_logged_during_navigation() {
+	let (success, json, stderr) = run_exec(
+		"page.console",
+		json!({
+			"url": "data:text/html,<h1>Hi</h1><script>console.log('booted'); console.error('widget failed')</script>",
+			"timeoutMs": 200
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	let texts: Vec<&str> = json["data"]["messages"]
+		.as_array()
+		.map(|messages| messages.iter().filter_map(|m| m["text"].as_str()).collect())
+		.unwrap_or_default();
+	assert_eq!(texts, ["booted", "widget failed"], "{json}");
+	assert_eq!(json["data"]["errorCount"], 1, "{json}");
+}
+
+#[test]
+fn eval_pipeline_feeds_each_result_to_the_next_stage() {
+	let (success, json, stderr) = run_exec(
+		"page.eval",
+		json!({
+			"url": "data:text/html,<li>a</li><li>b</li><li>c</li>",
+			"expressions": ["Array.from(document.querySelectorAll('li'), li => li.textContent)", "items => items.join('-').toUpperCase()"],
+			"keepIntermediates": true
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["result"], "A-B-C", "{json}");
+	assert_eq!(json["data"]["intermediates"], json!([["a", "b", "c"], "A-B-C"]), "{json}");
+
+	let (_success, json, _stderr) = run_exec(
+		"page.eval",
+		json!({
+			"url": "data:text/html,<h1>Hi</h1>",
+			"expressions": ["1", "n => { throw new Error('boom') }", "n => n + 1"]
+		}),
+	);
+	assert_eq!(json["ok"], false, "{json}");
+	assert!(
+		json["error"]["message"].as_str().unwrap_or_default().contains("pipeline stage 2 failed: boom"),
+		"{json}"
+	);
+}
+
+#[test]
+fn fresh_builtins_eval_ignores_page_overridden_globals() {
+	let page = "data:text/html,<h1>Broken</h1><script>JSON.stringify = () => 'hijacked';</script>";
+	let expression = "Array.from(document.querySelectorAll('h1'), h => h.textContent)";
+
+	let (_success, json, _stderr) = run_exec("page.eval", json!({ "expression": expression, "url": page }));
+	assert_ne!(json["data"]["result"], json!(["Broken"]), "page built-ins should see the page overrides");
+
+	let (success, json, stderr) = run_exec("page.eval", json!({ "expression": expression, "url": page, "builtins": "fresh" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["result"], json!(["Broken"]));
+}
+
+#[test]
+fn eval_times_out_on_infinite_loop() {
+	let (_success, json, _stderr) = run_exec(
+		"page.eval",
+		json!({
+			"expression": "(() => { while (true) {} })()",
+			"url": "data:text/html,<h1>Loop</h1>",
+			"timeoutMs": 500
+		}),
+	);
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "TIMEOUT");
+}
+
+#[test]
+fn pause_is_unsupported_headless() {
+	let (_success, json, _stderr) = run_exec("page.pause", json!({ "url": "data:text/html,<h1>Paused</h1>" }));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "UNSUPPORTED_MODE");
+}
+
+#[test]
+fn eval_truncates_huge_result() {
+	let (success, json, stderr) = run_exec(
+		"page.eval",
+		json!({
+			"expression": "Array.from({ length: 100000 }, (_, i) => i)",
+			"url": "data:text/html,<h1>Big</h1>",
+			"maxResultBytes": 1024
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert_eq!(json["data"]["truncated"], true);
+	assert_eq!(json["data"]["result"].as_str().unwrap_or_default().len(), 1024);
+	assert_eq!(json["diagnostics"][0]["level"], "warning");
+}
+
+#[test]
+fn effective_runtime_reports_browser_version_and_user_agent() {
+	let (success, json, stderr) = run_exec("page.text", json!({ "url": "data:text/html,<p>Version</p>", "selector": "p" }));
+	assert!(success, "command failed: {stderr}");
+	let runtime = &json["effectiveRuntime"];
+	assert!(!runtime["browserVersion"].as_str().unwrap_or_default().is_empty(), "{json}");
+	assert!(runtime["userAgent"].as_str().unwrap_or_default().starts_with("Mozilla/"), "{json}");
+}
+
+#[test]
+fn snapshot_reports_form_field_state() {
+	let (success, json, stderr) = run_exec(
+		"page.snapshot",
+		json!({ "url": "data:text/html,<input name='q' value='prefilled'><input type='checkbox' name='agree' checked><input type='checkbox' name='spam'>" }),
+	);
+	assert!(success, "command failed: {stderr}");
+	let elements = json["data"]["elements"].as_array().cloned().unwrap_or_default();
+	let find = |selector: &str| elements.iter().find(|el| el["selector"] == selector).cloned().unwrap_or_default();
+
+	assert_eq!(find(r#"input[name="q"]"#)["value"], "prefilled", "{json}");
+	assert_eq!(find(r#"input[name="agree"]"#)["checked"], true, "{json}");
+	assert_eq!(find(r#"input[name="spam"]"#)["checked"], false, "{json}");
+	assert!(find(r#"input[name="agree"]"#).get("value").is_none(), "{json}");
+}
+
+#[test]
+fn exists_reports_matches_without_failing_on_absent_selector() {
+	let url = "data:text/html,<ul><li class='item'>A</li><li class='item'>B</li></ul>";
+
+	let (success, json, stderr) = run_exec("page.exists", json!({ "url": url, "selector": ".item" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["exists"], true, "{json}");
+	assert_eq!(json["data"]["count"], 2, "{json}");
+
+	let (success, json, stderr) = run_exec("page.exists", json!({ "url": url, "selector": ".missing" }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["exists"], false, "{json}");
+	assert_eq!(json["data"]["count"], 0, "{json}");
+}
+
+#[test]
+fn snapshot_reports_lang_and_meta_tags() {
+	let html =
+		"data:text/html,<html lang='fr'><head><meta charset='utf-8'><meta name='description' content='Une page de test'></head><body>Bonjour</body></html>";
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html }));
+	assert!(success, "command failed: {stderr}");
+	let meta = &json["data"]["meta"];
+	assert_eq!(meta["lang"], "fr", "{json}");
+	assert_eq!(meta["charset"], "utf-8", "{json}");
+	assert_eq!(meta["description"], "Une page de test", "{json}");
+	assert!(meta.get("ogTitle").is_none(), "{json}");
+}
+
+#[test]
+fn snapshot_charset_is_only_reported_when_declared() {
+	let html = "data:text/html,<head><meta http-equiv='Content-Type' content='text/html; charset=ISO-8859-1'></head><body>Hi</body>";
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["data"]["meta"]["charset"], "ISO-8859-1", "{json}");
+
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": "data:text/html,<body>Hi</body>" }));
+	assert!(success, "command failed: {stderr}");
+	assert!(json["data"]["meta"].get("charset").is_none(), "{json}");
+}
+
+#[test]
+fn snapshot_include_hidden_reports_hidden_controls() {
+	let html = "data:text/html,<button>Shown</button><button style='display:none'>Menu item</button>";
+	let hidden = |elements: &[serde_json::Value]| elements.iter().find(|el| el["text"] == "Menu item").cloned();
+
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html }));
+	assert!(success, "command failed: {stderr}");
+	let elements = json["data"]["elements"].as_array().cloned().unwrap_or_default();
+	assert!(hidden(&elements).is_none(), "hidden button leaked into default snapshot: {json}");
+	assert!(elements.iter().all(|el| el.get("visible").is_none()), "{json}");
+
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html, "includeHidden": true }));
+	assert!(success, "command failed: {stderr}");
+	let elements = json["data"]["elements"].as_array().cloned().unwrap_or_default();
+	let menu = hidden(&elements).unwrap_or_else(|| panic!("expected hidden button with includeHidden: {json}"));
+	assert_eq!(menu["visible"], false, "{json}");
+	assert_eq!(menu["ariaHidden"], false, "{json}");
+	let shown = elements.iter().find(|el| el["text"] == "Shown").cloned().unwrap_or_default();
+	assert_eq!(shown["visible"], true, "{json}");
+}
+
+#[test]
+fn snapshot_dedup_by_name_collapses_repeated_buttons() {
+	let html = format!("data:text/html,{}<a href='/cart'>Cart</a>", "<div><button>Add to cart</button></div>".repeat(5));
+
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html, "dedupBy": "name" }));
+	assert!(success, "command failed: {stderr}");
+	let elements = json["data"]["elements"].as_array().cloned().unwrap_or_default();
+	let buttons: Vec<_> = elements.iter().filter(|el| el["text"] == "Add to cart").collect();
+	assert_eq!(buttons.len(), 1, "{json}");
+	assert_eq!(buttons[0]["count"], 5, "{json}");
+	let cart = elements.iter().find(|el| el["text"] == "Cart").cloned().unwrap_or_default();
+	assert!(cart.get("count").is_none(), "{json}");
+
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html }));
+	assert!(success, "command failed: {stderr}");
+	let elements = json["data"]["elements"].as_array().cloned().unwrap_or_default();
+	assert!(elements.iter().all(|el| el.get("count").is_none()), "{json}");
+}
+
+#[test]
+fn snapshot_pierces_open_shadow_roots() {
+	let html = "data:text/html,<x-card></x-card><x-vault></x-vault><script>\
+		customElements.define('x-card', class extends HTMLElement { constructor() { super(); \
+		this.attachShadow({ mode: 'open' }).innerHTML = '<p>Shadow greeting</p><button>Shadow action</button>'; } });\
+		customElements.define('x-vault', class extends HTMLElement { constructor() { super(); \
+		this.attachShadow({ mode: 'closed' }).innerHTML = '<p>Sealed away</p>'; } });\
+		</script>";
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html }));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+
+	let text = json["data"]["text"].as_str().unwrap_or_default();
+	assert!(text.contains("Shadow greeting"), "{json}");
+	assert!(!text.contains("Sealed away"), "{json}");
+
+	let elements = json["data"]["elements"].as_array().cloned().unwrap_or_default();
+	assert!(
+		elements.iter().any(|el| el["tag"] == "button" && el["text"] == "Shadow action"),
+		"expected shadow button in snapshot: {json}"
+	);
+}
+
+#[test]
+fn snapshot_reports_page_coordinates_on_scrolled_page() {
+	// Tall content on both sides of the button so the 2500px scroll is never clamped.
+	let html = "data:text/html,<div style='height:3000px'></div><button>Far</button><div style='height:3000px'></div>\
+		<script>window.scrollTo(0, 2500)</script>";
+	let (success, json, stderr) = run_exec("page.snapshot", json!({ "url": html }));
+	assert!(success, "command failed: {stderr}");
+	let elements = json["data"]["elements"].as_array().cloned().unwrap_or_default();
+	let button = elements.iter().find(|el| el["text"] == "Far").cloned().unwrap_or_default();
+
+	assert_eq!(button["pageX"].as_i64(), button["x"].as_i64(), "{json}");
+	assert_eq!(button["pageY"].as_i64(), button["y"].as_i64().map(|y| y + 2500), "{json}");
+	assert!(button["pageY"].as_i64().unwrap_or_default() >= 3000, "{json}");
+}
+
+#[test]
+fn frames_lists_nested_iframe_tree() {
+	let (success, json, stderr) = run_exec(
+		"page.frames",
+		json!({ "url": "data:text/html,<iframe name=\"outer\" srcdoc=\"<iframe name='inner' srcdoc='<p>deep</p>'></iframe>\"></iframe>" }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["count"], 3, "{json}");
+	let frames = json["data"]["frames"].as_array().cloned().unwrap_or_default();
+	let tree: Vec<(serde_json::Value, serde_json::Value, serde_json::Value)> = frames
+		.iter()
+		.map(|frame| (frame["name"].clone(), frame["parent"].clone(), frame["depth"].clone()))
+		.collect();
+	assert_eq!(
+		tree,
+		[
+			(json!(""), json!(null), json!(0)),
+			(json!("outer"), json!(0), json!(1)),
+			(json!("inner"), json!(1), json!(2))
+		],
+		"{json}"
+	);
+}
+
+#[test]
+fn accessibility_snapshot_includes_labeled_button() {
+	let (success, json, stderr) = run_exec(
+		"page.accessibility",
+		json!({ "url": "data:text/html,<button aria-label='Save draft'>S</button>" }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert!(json["data"]["nodeCount"].as_u64().unwrap_or_default() >= 2);
+	let children = json["data"]["snapshot"]["children"].as_array().cloned().unwrap_or_default();
+	assert!(
+		children.iter().any(|node| node["role"] == "button" && node["name"] == "Save draft"),
+		"expected labeled button in snapshot: {json}"
+	);
+}
+
 #[test]
 fn coords_finds_element() {
 	let (success, json, stderr) = run_exec(
@@ -143,6 +687,23 @@ fn coords_finds_element() {
 	assert!(json["data"]["coords"]["height"].is_number());
 }
 
+#[test]
+fn coords_top_left_in_device_pixels() {
+	let (success, json, stderr) = run_exec(
+		"page.coords",
+		json!({
+			"url": "data:text/html,<body style='margin:0'><div id='box' style='position:absolute;left:10px;top:20px;width:100px;height:40px'></div></body>",
+			"selector": "#box",
+			"point": "topleft",
+			"devicePixels": true
+		}),
+	);
+	assert!(success, "command failed: {stderr}");
+	let ratio = json["data"]["devicePixelRatio"].as_f64().expect("devicePixelRatio reported");
+	assert_eq!(json["data"]["coords"]["x"].as_f64(), Some((10.0 * ratio).round()));
+	assert_eq!(json["data"]["coords"]["width"].as_f64(), Some((100.0 * ratio).round()));
+}
+
 #[test]
 fn coords_element_not_found() {
 	let (_success, json, _stderr) = run_exec(
@@ -170,6 +731,21 @@ fn coords_all_multiple_elements() {
 	assert_eq!(json["data"]["count"], 3);
 }
 
+#[test]
+fn coords_all_sorts_top_to_bottom() {
+	let html = "data:text/html,<div style='position:relative;height:300px'>\
+		<button id='c' style='position:absolute;top:200px'>C</button>\
+		<button id='a' style='position:absolute;top:0'>A</button>\
+		<button id='b' style='position:absolute;top:100px'>B</button></div>";
+	let (success, json, stderr) = run_exec("page.coords-all", json!({ "url": html, "selector": "button", "sort": "top_to_bottom" }));
+	assert!(success, "command failed: {stderr}");
+	let coords = json["data"]["coords"].as_array().cloned().unwrap_or_default();
+	let texts: Vec<&str> = coords.iter().filter_map(|c| c["text"].as_str()).collect();
+	assert_eq!(texts, ["A", "B", "C"], "{json}");
+	let indices: Vec<u64> = coords.iter().filter_map(|c| c["index"].as_u64()).collect();
+	assert_eq!(indices, [1, 2, 0], "{json}");
+}
+
 #[test]
 fn coords_all_empty_result() {
 	let (success, json, stderr) = run_exec(
@@ -198,7 +774,8 @@ fn wait_timeout() {
 	let (success, json, stderr) = run_exec("wait", json!({ "url": "data:text/html,<div>Test</div>", "condition": "100" }));
 	assert!(success, "command failed: {stderr}");
 	assert_eq!(json["ok"], true);
-	assert_eq!(json["data"]["waitedMs"], 100);
+	assert_eq!(json["data"]["satisfied"], true);
+	assert!(json["data"]["elapsedMs"].as_u64().unwrap() >= 100, "{json}");
 }
 
 #[test]
@@ -207,6 +784,7 @@ fn wait_load_state() {
 	assert!(success, "command failed: {stderr}");
 	assert_eq!(json["ok"], true);
 	assert_eq!(json["data"]["condition"], "loadstate:networkidle");
+	assert_eq!(json["data"]["satisfied"], true, "{json}");
 }
 
 #[test]
@@ -214,7 +792,34 @@ fn wait_selector_found() {
 	let (success, json, stderr) = run_exec("wait", json!({ "url": "data:text/html,<div id='target'>Exists</div>", "condition": "#target" }));
 	assert!(success, "command failed: {stderr}");
 	assert_eq!(json["ok"], true);
-	assert_eq!(json["data"]["selectorFound"], true);
+	assert_eq!(json["data"]["satisfied"], true, "{json}");
+	assert_eq!(json["data"]["matchCount"], 1, "{json}");
+	let elapsed = json["data"]["elapsedMs"].as_u64().expect("elapsedMs");
+	assert!(elapsed < 30_000, "implausible elapsed time: {json}");
+}
+
+#[test]
+fn wait_selector_hidden_state() {
+	let (success, json, stderr) = run_exec(
+		"wait",
+		json!({ "url": "data:text/html,<div class='spinner' style='display:none'>Loading</div>", "condition": ".spinner", "state": "hidden" }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["satisfied"], true, "{json}");
+	assert_eq!(json["data"]["matchCount"], 1, "{json}");
+}
+
+#[test]
+fn wait_selector_with_several_matches_reports_count() {
+	let (success, json, stderr) = run_exec(
+		"wait",
+		json!({ "url": "data:text/html,<li class='row'>A</li><li class='row'>B</li><li class='row'>C</li>", "condition": ".row", "state": "visible" }),
+	);
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["satisfied"], true, "{json}");
+	assert_eq!(json["data"]["matchCount"], 3, "{json}");
 }
 
 #[test]
@@ -0,0 +1,86 @@
+//! Integration tests for `navigate --expect-status`.
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::{Value, json};
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+/// Serves `/` with 200 and every other path with 404; returns the base URL.
+fn serve_with_missing_route() -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let addr = listener.local_addr().unwrap();
+	std::thread::spawn(move || {
+		for stream in listener.incoming() {
+			let Ok(mut stream) = stream else { continue };
+			let mut buf = [0u8; 4096];
+			let read = stream.read(&mut buf).unwrap_or(0);
+			let request = String::from_utf8_lossy(&buf[..read]);
+			let (status, body) = if request.starts_with("GET / ") {
+				("200 OK", "<h1>Home</h1>")
+			} else {
+				("404 Not Found", "<h1>Missing</h1>")
+			};
+			let _ = write!(
+				stream,
+				"HTTP/1.1 {status}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+				body.len()
+			);
+		}
+	});
+	format!("http://{addr}")
+}
+
+fn navigate(input: Value) -> Value {
+	let workspace = tempfile::tempdir().unwrap();
+	let output = Command::new(pw_binary())
+		.current_dir(workspace.path())
+		.args(["-f", "json", "exec", "navigate", "--input", &input.to_string()])
+		.output()
+		.expect("failed to execute pw");
+	serde_json::from_slice(&output.stdout).unwrap_or_else(|_| panic!("expected JSON stdout, stderr: {}", String::from_utf8_lossy(&output.stderr)))
+}
+
+#[test]
+fn expect_status_fails_on_404_with_actual_status() {
+	let base = serve_with_missing_route();
+
+	let json = navigate(json!({ "url": format!("{base}/missing"), "expectStatus": "200-299" }));
+	assert_eq!(json["ok"], false, "{json}");
+	assert_eq!(json["error"]["code"], "NAVIGATION_FAILED");
+	assert_eq!(json["error"]["details"]["status"], 404);
+	assert_eq!(json["error"]["details"]["expected"], "200-299");
+
+	let json = navigate(json!({ "url": format!("{base}/missing"), "expectStatus": "404" }));
+	assert_eq!(json["ok"], true, "{json}");
+
+	let json = navigate(json!({ "url": format!("{base}/"), "expectStatus": "200-299" }));
+	assert_eq!(json["ok"], true, "{json}");
+}
+
+#[test]
+fn expect_status_skips_data_urls_with_diagnostic() {
+	let json = navigate(json!({ "url": "data:text/html,<h1>Inline</h1>", "expectStatus": "200" }));
+	assert_eq!(json["ok"], true, "{json}");
+	let diagnostics = json["diagnostics"].as_array().expect("diagnostics should be present");
+	assert!(
+		diagnostics.iter().any(|d| d["level"] == "warning" && d["source"] == "navigate"),
+		"{diagnostics:?}"
+	);
+}
+
+#[test]
+fn expect_status_rejects_malformed_range() {
+	let json = navigate(json!({ "url": "data:text/html,<h1>Inline</h1>", "expectStatus": "2xx" }));
+	assert_eq!(json["ok"], false, "{json}");
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
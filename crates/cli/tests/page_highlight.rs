@@ -0,0 +1,48 @@
+//! Integration tests for `page.highlight`.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::{Value, json};
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+fn workspace_root() -> PathBuf {
+	std::env::temp_dir().join("pw-cli-page-highlight")
+}
+
+fn run_exec(op: &str, input: Value) -> (bool, Value, String) {
+	let workspace = workspace_root();
+	let _ = std::fs::create_dir_all(&workspace);
+	let output = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "json", "exec", op, "--input"])
+		.arg(input.to_string())
+		.output()
+		.expect("failed to execute pw");
+
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str(&stdout).unwrap_or_else(|err| panic!("expected JSON stdout: {err}\nstdout:\n{stdout}\nstderr:\n{stderr}"));
+	(output.status.success(), parsed, stderr)
+}
+
+#[test]
+fn highlight_is_a_noop_when_headless() {
+	let (success, json, stderr) = run_exec("page.highlight", json!({ "url": "data:text/html,<h1>Hi</h1>", "selector": "h1" }));
+	assert!(success, "page.highlight failed: {stderr}");
+	assert_eq!(json["data"]["highlighted"], false);
+	assert!(json["data"].get("count").is_none());
+
+	let diagnostics = json["diagnostics"].as_array().expect("headless highlight should report a diagnostic");
+	assert!(
+		diagnostics.iter().any(|d| d["message"].as_str().is_some_and(|m| m.contains("headless"))),
+		"diagnostics: {diagnostics:?}"
+	);
+}
@@ -0,0 +1,53 @@
+//! Integration tests for `page.add-style` and `page.add-script`.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::{Value, json};
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+fn workspace_root() -> PathBuf {
+	std::env::temp_dir().join("pw-cli-page-inject")
+}
+
+fn run_exec(op: &str, input: Value) -> (bool, Value, String) {
+	let workspace = workspace_root();
+	let _ = std::fs::create_dir_all(&workspace);
+	let output = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "json", "exec", op, "--input"])
+		.arg(input.to_string())
+		.output()
+		.expect("failed to execute pw");
+
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str(&stdout).unwrap_or_else(|err| panic!("expected JSON stdout: {err}\nstdout:\n{stdout}\nstderr:\n{stderr}"));
+	(output.status.success(), parsed, stderr)
+}
+
+#[test]
+fn add_style_returns_the_injected_element() {
+	let (success, json, stderr) = run_exec(
+		"page.add-style",
+		json!({ "url": "data:text/html,<h1>Hi</h1>", "content": "h1 { color: rgb(255, 0, 0); }" }),
+	);
+	assert!(success, "page.add-style failed: {stderr}");
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["tag"], "style");
+	assert!(json["data"]["elementGuid"].as_str().is_some_and(|guid| !guid.is_empty()), "{json}");
+}
+
+#[test]
+fn add_script_requires_one_source() {
+	let (_success, json, _stderr) = run_exec("page.add-script", json!({ "url": "data:text/html,<h1>Hi</h1>" }));
+	assert_eq!(json["ok"], false, "{json}");
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+}
@@ -138,3 +138,27 @@ fn read_omits_metadata_fields_without_flag() {
 	assert!(!data.contains_key("author"));
 	assert!(!data.contains_key("published"));
 }
+
+#[test]
+fn read_output_writes_file_and_records_artifact() {
+	let _lock = CONTEXT_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+	clear_context_store();
+
+	let output = workspace_root().join("out/article.md");
+	let (success, json, stderr) = run_exec(
+		"page.read",
+		json!({
+			"url": "data:text/html,<article><h1>Saved</h1><p>This article is written to a file instead of being printed inline.</p></article>",
+			"output": output
+		}),
+	);
+
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["ok"], Value::Bool(true));
+	let written = std::fs::read_to_string(&output).expect("output file should exist");
+	assert!(written.contains("# Saved"));
+	assert_eq!(json["data"]["path"], json!(output));
+	assert_eq!(json["artifacts"][0]["type"], "text");
+	assert_eq!(json["artifacts"][0]["path"], json!(output));
+	assert_eq!(json["artifacts"][0]["sizeBytes"], json!(written.len()));
+}
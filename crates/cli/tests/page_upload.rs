@@ -0,0 +1,73 @@
+//! Integration tests for `page.upload`.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::{Value, json};
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+fn workspace_root() -> PathBuf {
+	std::env::temp_dir().join("pw-cli-page-upload")
+}
+
+fn run_exec(op: &str, input: Value) -> (bool, Value, String) {
+	let workspace = workspace_root();
+	let _ = std::fs::create_dir_all(&workspace);
+	let output = Command::new(pw_binary())
+		.current_dir(&workspace)
+		.args(["-f", "json", "exec", op, "--input"])
+		.arg(input.to_string())
+		.output()
+		.expect("failed to execute pw");
+
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str(&stdout).unwrap_or_else(|err| panic!("expected JSON stdout: {err}\nstdout:\n{stdout}\nstderr:\n{stderr}"));
+	(output.status.success(), parsed, stderr)
+}
+
+#[test]
+fn upload_sets_input_files() {
+	let dir = tempfile::tempdir().unwrap();
+	let a = dir.path().join("a.txt");
+	let b = dir.path().join("b.txt");
+	std::fs::write(&a, "first").unwrap();
+	std::fs::write(&b, "second").unwrap();
+
+	let (success, json, stderr) = run_exec(
+		"page.upload",
+		json!({
+			"url": "data:text/html,<input type='file' id='f' multiple>",
+			"selector": "#f",
+			"files": [a, b]
+		}),
+	);
+
+	assert!(success, "upload failed: {stderr}");
+	assert_eq!(json["ok"], true);
+	assert_eq!(json["data"]["fileCount"], 2);
+	assert_eq!(json["data"]["files"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn upload_rejects_missing_file() {
+	let (_success, json, _stderr) = run_exec(
+		"page.upload",
+		json!({
+			"url": "data:text/html,<input type='file' id='f'>",
+			"selector": "#f",
+			"files": ["/definitely/not/here.txt"]
+		}),
+	);
+
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["error"]["code"], "INVALID_INPUT");
+	assert!(json["error"]["message"].as_str().unwrap().contains("here.txt"));
+}
@@ -171,3 +171,43 @@ fn profile_delete_removes_profile_directory() {
 	assert_eq!(json["data"]["removed"], true);
 	assert!(!profile_dir.exists(), "profile directory should be removed after delete");
 }
+
+#[test]
+fn context_list_and_use_switch_the_active_namespace() {
+	let tmp = TempDir::new().expect("temp dir should be created");
+	for (name, config) in [
+		("agent-a", r#"{"schema":4,"defaults":{"baseUrl":"https://a.example"}}"#),
+		("agent-b", r#"{"schema":4,"defaults":{"cdpEndpoint":"ws://127.0.0.1:9333/devtools/browser/b"}}"#),
+	] {
+		let file_path = tmp.path().join(format!("{name}.json"));
+		std::fs::write(&file_path, config).expect("profile file should be written");
+		let (success, _json, stderr) = run_exec_json(tmp.path(), "profile.set", json!({ "name": name, "file": file_path }));
+		assert!(success, "profile.set failed: {stderr}");
+	}
+
+	let (success, stdout, stderr) = run_pw(tmp.path(), &["-f", "json", "context", "list"]);
+	assert!(success, "context list failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).expect("context list should print JSON");
+	assert_eq!(json["data"]["active"], "default");
+	let contexts = json["data"]["contexts"].as_array().expect("contexts should be an array");
+	let names: Vec<&str> = contexts.iter().filter_map(|c| c["name"].as_str()).collect();
+	assert_eq!(names, ["agent-a", "agent-b"], "{json}");
+	assert_eq!(contexts[1]["cdpEndpoint"], "ws://127.0.0.1:9333/devtools/browser/b");
+
+	let (success, stdout, stderr) = run_pw(tmp.path(), &["-f", "json", "context", "use", "agent-b"]);
+	assert!(success, "context use failed: {stderr}");
+	let json: serde_json::Value = serde_json::from_str(&stdout).expect("context use should print JSON");
+	assert_eq!(json["data"]["active"], "agent-b");
+	assert_eq!(json["data"]["previous"], "default");
+
+	// Requests without --profile now run in the active namespace.
+	let (success, json, stderr) = run_exec_json(tmp.path(), "profile.list", json!({}));
+	assert!(success, "profile.list failed: {stderr}");
+	assert_eq!(json["effectiveRuntime"]["profile"], "agent-b", "{json}");
+
+	let (success, stdout, _stderr) = run_pw(tmp.path(), &["-f", "json", "context", "list"]);
+	assert!(success);
+	let json: serde_json::Value = serde_json::from_str(&stdout).expect("context list should print JSON");
+	assert_eq!(json["data"]["active"], "agent-b");
+	assert_eq!(json["data"]["contexts"][1]["active"], true);
+}
@@ -0,0 +1,114 @@
+//! Integration tests for `pw.toml` project defaults.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::{Value, json};
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+fn pw_command(workspace: &Path) -> Command {
+	let mut command = Command::new(pw_binary());
+	command
+		.current_dir(workspace)
+		.env_remove("PW_WORKSPACE_ROOT")
+		.env_remove("PW_BROWSER")
+		.env_remove("PW_TIMEOUT_MS")
+		.env_remove("PW_FORMAT")
+		.env_remove("PW_ARTIFACTS_DIR");
+	command
+}
+
+fn run_exec(workspace: &Path, args: &[&str], input: Value) -> (bool, Value, String) {
+	let output = pw_command(workspace)
+		.args(args)
+		.args(["exec", "har.show", "--input"])
+		.arg(input.to_string())
+		.output()
+		.expect("failed to execute pw");
+
+	let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+	let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+	let parsed = serde_json::from_str(&stdout).unwrap_or_else(|err| panic!("expected JSON stdout: {err}\nstdout:\n{stdout}\nstderr:\n{stderr}"));
+	(output.status.success(), parsed, stderr)
+}
+
+#[test]
+fn pw_toml_browser_changes_effective_default() {
+	let workspace = tempfile::tempdir().unwrap();
+
+	let (success, json, stderr) = run_exec(workspace.path(), &["-f", "json"], json!({}));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["effectiveRuntime"]["browser"], "chromium");
+
+	std::fs::write(workspace.path().join("pw.toml"), "browser = \"firefox\"\ntimeout_ms = 1234\n").unwrap();
+
+	let (success, json, stderr) = run_exec(workspace.path(), &["-f", "json"], json!({}));
+	assert!(success, "command failed: {stderr}");
+	assert_eq!(json["effectiveRuntime"]["browser"], "firefox");
+	assert_eq!(json["effectiveRuntime"]["timeoutMs"], 1234);
+}
+
+#[test]
+fn env_and_request_override_pw_toml() {
+	let workspace = tempfile::tempdir().unwrap();
+	std::fs::write(workspace.path().join("pw.toml"), "browser = \"firefox\"\nformat = \"json\"\n").unwrap();
+
+	// No -f flag: format comes from pw.toml.
+	let output = pw_command(workspace.path())
+		.env("PW_BROWSER", "webkit")
+		.args(["exec", "har.show", "--input", "{}"])
+		.output()
+		.expect("failed to execute pw");
+	let json: Value = serde_json::from_slice(&output.stdout).expect("pw.toml format should select JSON output");
+	assert_eq!(json["effectiveRuntime"]["browser"], "webkit");
+
+	let request = json!({
+		"schemaVersion": 5,
+		"op": "har.show",
+		"input": {},
+		"runtime": { "overrides": { "browser": "chromium" } }
+	});
+	let file = workspace.path().join("request.json");
+	std::fs::write(&file, request.to_string()).unwrap();
+	let output = pw_command(workspace.path())
+		.env("PW_BROWSER", "webkit")
+		.args(["exec", "--file"])
+		.arg(&file)
+		.output()
+		.expect("failed to execute pw");
+	let json: Value = serde_json::from_slice(&output.stdout).expect("expected JSON stdout");
+	assert_eq!(json["effectiveRuntime"]["browser"], "chromium");
+}
+
+#[test]
+fn invalid_pw_toml_is_reported() {
+	let workspace = tempfile::tempdir().unwrap();
+	std::fs::write(workspace.path().join("pw.toml"), "browser = \"netscape\"\n").unwrap();
+
+	let (_, json, _) = run_exec(workspace.path(), &["-f", "json"], json!({}));
+	assert_eq!(json["ok"], false);
+	assert_eq!(json["op"], "har.show");
+	let message = json["error"]["message"].as_str().unwrap();
+	assert!(message.contains("pw.toml") && message.contains("netscape"), "{json}");
+}
+
+#[test]
+fn invalid_env_default_is_reported() {
+	let workspace = tempfile::tempdir().unwrap();
+
+	let output = pw_command(workspace.path())
+		.env("PW_FORMAT", "yaml")
+		.args(["-f", "json", "exec", "har.show", "--input", "{}"])
+		.output()
+		.expect("failed to execute pw");
+	let json: Value = serde_json::from_slice(&output.stdout).expect("expected JSON stdout");
+	assert_eq!(json["ok"], false);
+	assert!(json["error"]["message"].as_str().unwrap().contains("PW_FORMAT"), "{json}");
+}
@@ -68,3 +68,98 @@ fn batch_rejects_alias_operation_name() {
 	assert_eq!(json["op"], "har-show");
 	assert_eq!(json["error"]["code"], "INVALID_INPUT");
 }
+
+#[test]
+fn batch_stream_elements_emits_chunks_with_done_marker() {
+	let tmp = TempDir::new().unwrap();
+	let mut child = Command::new(pw_binary())
+		.current_dir(tmp.path())
+		.args(["-f", "ndjson", "batch", "--profile", "default"])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()
+		.expect("failed to start pw batch");
+
+	let buttons: String = (1..=5).map(|i| format!("<button>B{i}</button>")).collect();
+	let request = serde_json::json!({
+		"schemaVersion": 5,
+		"requestId": "s1",
+		"op": "page.stream_elements",
+		"input": { "url": format!("data:text/html,{buttons}"), "chunkSize": 2 },
+	});
+	{
+		let stdin = child.stdin.as_mut().unwrap();
+		writeln!(stdin, "{request}").unwrap();
+		writeln!(stdin, r#"{{"schemaVersion":5,"requestId":"q","op":"quit","input":{{}}}}"#).unwrap();
+	}
+
+	let output = child.wait_with_output().unwrap();
+	assert!(output.status.success(), "batch process failed: {}", String::from_utf8_lossy(&output.stderr));
+
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	let responses: Vec<serde_json::Value> = stdout
+		.lines()
+		.filter(|line| !line.trim().is_empty())
+		.map(|line| serde_json::from_str(line).unwrap())
+		.filter(|json: &serde_json::Value| json["requestId"] == "s1")
+		.collect();
+
+	assert_eq!(responses.len(), 4, "expected 3 chunks plus a terminal response: {stdout}");
+	for (seq, chunk) in responses[..3].iter().enumerate() {
+		assert_eq!(chunk["ok"], true, "{chunk}");
+		assert_eq!(chunk["seq"], seq, "{chunk}");
+		assert_eq!(chunk["done"], false, "{chunk}");
+		assert_eq!(chunk["data"]["offset"], seq * 2, "{chunk}");
+	}
+	assert_eq!(responses[2]["data"]["elements"].as_array().map(Vec::len), Some(1));
+
+	let last = &responses[3];
+	assert_eq!(last["ok"], true, "{last}");
+	assert_eq!(last["seq"], 3);
+	assert_eq!(last["done"], true);
+	assert_eq!(last["data"]["count"], 5);
+	assert_eq!(last["data"]["chunks"], 3);
+}
+
+#[test]
+fn batch_stream_without_chunks_still_ends_with_done() {
+	let tmp = TempDir::new().unwrap();
+	let mut child = Command::new(pw_binary())
+		.current_dir(tmp.path())
+		.args(["-f", "ndjson", "batch", "--profile", "default"])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()
+		.expect("failed to start pw batch");
+
+	let request = serde_json::json!({
+		"schemaVersion": 5,
+		"requestId": "s0",
+		"op": "page.stream_elements",
+		"input": { "url": "data:text/html,<p>Nothing to click</p>", "chunkSize": 2 },
+	});
+	{
+		let stdin = child.stdin.as_mut().unwrap();
+		writeln!(stdin, "{request}").unwrap();
+		writeln!(stdin, r#"{{"schemaVersion":5,"requestId":"q","op":"quit","input":{{}}}}"#).unwrap();
+	}
+
+	let output = child.wait_with_output().unwrap();
+	assert!(output.status.success(), "batch process failed: {}", String::from_utf8_lossy(&output.stderr));
+
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	let responses: Vec<serde_json::Value> = stdout
+		.lines()
+		.filter(|line| !line.trim().is_empty())
+		.map(|line| serde_json::from_str(line).unwrap())
+		.filter(|json: &serde_json::Value| json["requestId"] == "s0")
+		.collect();
+
+	assert_eq!(responses.len(), 1, "expected only the terminal response: {stdout}");
+	assert_eq!(responses[0]["ok"], true, "{}", responses[0]);
+	assert_eq!(responses[0]["seq"], 0);
+	assert_eq!(responses[0]["done"], true);
+	assert_eq!(responses[0]["data"]["chunks"], 0);
+}
@@ -0,0 +1,103 @@
+//! Integration tests for request blocking diagnostics.
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::{Value, json};
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+/// Serves one HTML page that references an image; returns its URL.
+fn serve_page_with_image() -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let addr = listener.local_addr().unwrap();
+	std::thread::spawn(move || {
+		for stream in listener.incoming() {
+			let Ok(mut stream) = stream else { continue };
+			let mut buf = [0u8; 4096];
+			let _ = stream.read(&mut buf);
+			let body = "<html><body><h1>Gallery</h1><img src=\"/photo.png\"></body></html>";
+			let _ = write!(
+				stream,
+				"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+				body.len()
+			);
+		}
+	});
+	format!("http://{addr}/")
+}
+
+#[test]
+fn blocked_pattern_is_reported_as_diagnostic() {
+	let workspace = tempfile::tempdir().unwrap();
+	let url = serve_page_with_image();
+
+	let request = json!({
+		"schemaVersion": 5,
+		"op": "navigate",
+		"input": { "url": url },
+		"runtime": { "overrides": { "blockPatterns": ["**/*.png", "**/*.woff2"] } }
+	});
+	let file = workspace.path().join("request.json");
+	std::fs::write(&file, request.to_string()).unwrap();
+
+	let output = Command::new(pw_binary())
+		.current_dir(workspace.path())
+		.args(["-f", "json", "exec", "--file"])
+		.arg(&file)
+		.output()
+		.expect("failed to execute pw");
+	let stderr = String::from_utf8_lossy(&output.stderr);
+	assert!(output.status.success(), "navigate failed: {stderr}");
+
+	let json: Value = serde_json::from_slice(&output.stdout).expect("expected JSON stdout");
+	let network: Vec<&Value> = json["diagnostics"]
+		.as_array()
+		.expect("diagnostics should be present")
+		.iter()
+		.filter(|d| d["source"] == "network")
+		.collect();
+	assert_eq!(network.len(), 1, "only the matching pattern is reported: {network:?}");
+	assert_eq!(network[0]["level"], "info");
+	assert!(network[0]["message"].as_str().unwrap().contains("**/*.png"), "{network:?}");
+}
+
+#[test]
+fn blocked_pattern_is_reported_on_failure() {
+	let workspace = tempfile::tempdir().unwrap();
+	let url = serve_page_with_image();
+
+	let request = json!({
+		"schemaVersion": 5,
+		"op": "click",
+		"input": { "url": url, "selector": "#missing" },
+		"runtime": { "overrides": { "blockPatterns": ["**/*.png"], "timeoutMs": 500 } }
+	});
+	let file = workspace.path().join("request.json");
+	std::fs::write(&file, request.to_string()).unwrap();
+
+	let output = Command::new(pw_binary())
+		.current_dir(workspace.path())
+		.args(["-f", "json", "exec", "--file"])
+		.arg(&file)
+		.output()
+		.expect("failed to execute pw");
+
+	let json: Value = serde_json::from_slice(&output.stdout).expect("expected JSON stdout");
+	assert_eq!(json["ok"], false, "{json}");
+	let network = json["diagnostics"].as_array().expect("diagnostics should be present");
+	assert!(
+		network
+			.iter()
+			.any(|d| d["source"] == "network" && d["message"].as_str().unwrap().contains("**/*.png")),
+		"{json}"
+	);
+}
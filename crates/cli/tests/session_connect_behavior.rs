@@ -178,3 +178,57 @@ fn connect_action_precedence_prefers_kill_over_clear_and_endpoint_set() {
 	assert_eq!(json["ok"], true);
 	assert_eq!(json["data"]["endpoint"], original);
 }
+
+#[test]
+fn insecure_is_rejected_for_cdp_endpoints() {
+	let tmp = TempDir::new().expect("temp dir should be created");
+	let (success, _json, stderr) = run_exec_json(tmp.path(), "connect", json!({ "endpoint": "wss://127.0.0.1:9/devtools/browser/x" }));
+	assert!(success, "connect set failed: {stderr}");
+
+	let input = r#"{"url":"data:text/html,<p>Hi</p>","selector":"p"}"#;
+	let (success, stdout, _stderr) = run_pw(tmp.path(), &["-f", "json", "exec", "page.text", "--insecure", "--input", input]);
+	assert!(!success);
+	let json: serde_json::Value = serde_json::from_str(&stdout).unwrap_or_default();
+	assert_eq!(json["error"]["code"], "UNSUPPORTED_MODE", "{stdout}");
+}
+
+#[test]
+fn offline_is_lifted_on_a_reused_session() {
+	let tmp = TempDir::new().expect("tempdir");
+	let (_success, json, stderr) = run_exec_json(tmp.path(), "session.start", json!({}));
+	assert_eq!(json["ok"], true, "session.start failed: {json} {stderr}");
+
+	let input = r#"{"url":"data:text/html,<h1>Net</h1>","expression":"navigator.onLine"}"#;
+	let (_success, offline, _stderr) = run_pw(tmp.path(), &["-f", "json", "exec", "page.eval", "--offline", "--input", input]);
+	let (_success, online, _stderr) = run_pw(
+		tmp.path(),
+		&["-f", "json", "exec", "page.eval", "--input", r#"{"expression":"navigator.onLine"}"#],
+	);
+	let _ = run_exec_json(tmp.path(), "session.stop", json!({}));
+
+	let offline: serde_json::Value = serde_json::from_str(&offline).unwrap_or_default();
+	assert_eq!(offline["data"]["result"], false, "{offline}");
+	let online: serde_json::Value = serde_json::from_str(&online).unwrap_or_default();
+	assert_eq!(online["ok"], true, "{online}");
+	assert_eq!(online["data"]["result"], true, "the reused page should be back online: {online}");
+}
+
+#[test]
+fn same_url_target_is_not_reloaded_on_a_reused_session() {
+	let tmp = TempDir::new().expect("tempdir");
+	let (_success, json, stderr) = run_exec_json(tmp.path(), "session.start", json!({}));
+	assert_eq!(json["ok"], true, "session.start failed: {json} {stderr}");
+
+	let url = "data:text/html,Same";
+	let (_success, marked, _stderr) = run_exec_json(tmp.path(), "page.eval", json!({ "url": url, "expression": "window.marker = 42" }));
+	let (_success, reused, _stderr) = run_exec_json(tmp.path(), "page.eval", json!({ "url": url, "expression": "window.marker ?? null" }));
+	let input = json!({ "url": url, "expression": "window.marker ?? null" }).to_string();
+	let (_success, forced, _stderr) = run_pw(tmp.path(), &["-f", "json", "exec", "page.eval", "--force-navigate", "--input", &input]);
+	let _ = run_exec_json(tmp.path(), "session.stop", json!({}));
+
+	assert_eq!(marked["ok"], true, "{marked}");
+	assert_eq!(reused["data"]["result"], 42, "a goto to the current URL would have reset the page: {reused}");
+	let forced: serde_json::Value = serde_json::from_str(&forced).unwrap_or_default();
+	assert_eq!(forced["ok"], true, "{forced}");
+	assert!(forced["data"]["result"].is_null(), "--force-navigate should reload the page: {forced}");
+}
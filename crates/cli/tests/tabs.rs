@@ -0,0 +1,44 @@
+//! Integration tests for the `tabs.*` commands.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::Value;
+
+fn pw_binary() -> PathBuf {
+	let mut path = std::env::current_exe().expect("current_exe should resolve");
+	path.pop();
+	path.pop();
+	path.push("pw");
+	path
+}
+
+#[test]
+fn switch_brings_selected_tab_to_front() {
+	let workspace = tempfile::tempdir().unwrap();
+	let dump = workspace.path().join("wire.ndjson");
+
+	let output = Command::new(pw_binary())
+		.current_dir(workspace.path())
+		.arg("--dump-protocol")
+		.arg(&dump)
+		.args(["-f", "json", "exec", "tabs.switch", "--input", r#"{"target":"0"}"#])
+		.output()
+		.expect("failed to execute pw");
+	let json: Value =
+		serde_json::from_slice(&output.stdout).unwrap_or_else(|_| panic!("expected JSON stdout, stderr: {}", String::from_utf8_lossy(&output.stderr)));
+	assert_eq!(json["ok"], true, "{json}");
+	assert_eq!(json["data"]["switched"], true, "{json}");
+	assert_eq!(json["data"]["index"], 0, "{json}");
+
+	let sent: Vec<Value> = std::fs::read_to_string(&dump)
+		.expect("dump file should exist")
+		.lines()
+		.map(|line| serde_json::from_str::<Value>(line).expect("each dump line should be JSON"))
+		.filter(|line| line["dir"] == "send")
+		.collect();
+	assert!(
+		sent.iter().any(|line| line["message"]["method"] == "bringToFront"),
+		"expected a sent bringToFront: {sent:?}"
+	);
+}
@@ -142,96 +142,113 @@ impl AccessibilitySnapshotOptionsBuilder {
 /// Represents an element as seen by assistive technologies like screen readers.
 ///
 /// See: <https://playwright.dev/docs/api/class-accessibility#accessibility-snapshot>
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccessibilityNode {
 	/// The ARIA role of the node (e.g., "button", "heading", "link").
 	pub role: String,
 
 	/// The accessible name of the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub name: Option<String>,
 
 	/// The accessible value of the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value: Option<AccessibilityValue>,
 
 	/// The accessible description of the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub description: Option<String>,
 
 	/// Keyboard shortcut associated with the node.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub key_shortcuts: Option<String>,
 
 	/// Role description override.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub role_description: Option<String>,
 
 	/// Value text for range widgets.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value_text: Option<String>,
 
 	/// Whether the node is disabled.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub disabled: bool,
 
 	/// Whether the node is expanded (for expandable elements).
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub expanded: Option<bool>,
 
 	/// Whether the node is focused.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub focused: bool,
 
 	/// Whether the node is modal.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub modal: bool,
 
 	/// Whether the node supports multiple selection.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub multiselectable: bool,
 
 	/// Whether the node is readonly.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub readonly: bool,
 
 	/// Whether the node is required.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
 	pub required: bool,
 
 	/// Whether the node is selected.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub selected: Option<bool>,
 
 	/// The checked state for checkboxes and radio buttons.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub checked: Option<CheckedState>,
 
 	/// The pressed state for toggle buttons.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub pressed: Option<PressedState>,
 
 	/// The heading level (1-6).
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub level: Option<u8>,
 
 	/// Minimum value for range widgets.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value_min: Option<f64>,
 
 	/// Maximum value for range widgets.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value_max: Option<f64>,
 
 	/// The autocomplete behavior.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub autocomplete: Option<String>,
 
 	/// The haspopup behavior.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub haspopup: Option<String>,
 
 	/// Whether the node is invalid.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub invalid: Option<String>,
 
 	/// The orientation for sliders and scrollbars.
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub orientation: Option<String>,
 
 	/// Child nodes.
-	#[serde(default)]
+	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub children: Option<Vec<AccessibilityNode>>,
 }
 
 /// The value of an accessibility node.
 ///
 /// Can be a string or a number depending on the node type.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum AccessibilityValue {
 	/// String value (e.g., text content)
@@ -241,7 +258,7 @@ pub enum AccessibilityValue {
 }
 
 /// The checked state of a checkbox or radio button.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckedState {
 	/// The element is checked
@@ -252,8 +269,14 @@ pub enum CheckedState {
 	Mixed,
 }
 
+impl From<bool> for CheckedState {
+	fn from(checked: bool) -> Self {
+		if checked { Self::True } else { Self::False }
+	}
+}
+
 /// The pressed state of a toggle button.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PressedState {
 	/// The button is pressed
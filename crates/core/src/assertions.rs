@@ -9,7 +9,7 @@ use std::time::Duration;
 
 use pw_runtime::Result;
 
-use crate::Locator;
+use crate::{CheckedState, Locator};
 
 /// Default timeout for assertions (5 seconds, matching Playwright)
 const DEFAULT_ASSERTION_TIMEOUT: Duration = Duration::from_secs(5);
@@ -56,7 +56,7 @@ const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
 ///
 ///     // Test to_be_checked and to_be_unchecked
 ///     page.goto("data:text/html,<input type='checkbox' id='checked' checked><input type='checkbox' id='unchecked'>", None).await?;
-///     expect(page.locator("#checked").await).to_be_checked().await?;
+///     expect(page.locator("#checked").await).to_be_checked(None).await?;
 ///     expect(page.locator("#unchecked").await).to_be_unchecked().await?;
 ///
 ///     // Test to_be_editable
@@ -351,20 +351,23 @@ impl Expectation {
 		}
 	}
 
-	/// Asserts that the input element has the specified value.
+	/// Asserts that the input element's value matches `expected`.
 	///
-	/// This assertion will retry until the input has the exact value or timeout.
+	/// This assertion will retry until the input value matches or timeout.
+	/// `expected` is compared exactly unless it starts with `glob:` or `re:`;
+	/// see [`ValuePattern`].
 	///
 	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-have-value>
 	pub async fn to_have_value(self, expected: &str) -> Result<()> {
 		let start = std::time::Instant::now();
 		let selector = self.locator.selector().to_string();
+		let pattern = ValuePattern::parse(expected)?;
 
 		loop {
 			let actual = self.locator.input_value(None).await?;
 
 			// Check if condition matches (with negation support)
-			let matches = if self.negate { actual != expected } else { actual == expected };
+			let matches = if self.negate { !pattern.is_match(&actual) } else { pattern.is_match(&actual) };
 
 			if matches {
 				return Ok(());
@@ -391,6 +394,56 @@ impl Expectation {
 		}
 	}
 
+	/// Asserts that the element has attribute `name` with a value matching `expected`.
+	///
+	/// This assertion will retry until the attribute matches or timeout. A missing
+	/// attribute never matches. `expected` accepts the same exact, glob, and `re:`
+	/// forms as [`Self::to_have_value`].
+	///
+	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-have-attribute>
+	pub async fn to_have_attribute(self, name: &str, expected: &str) -> Result<()> {
+		let start = std::time::Instant::now();
+		let selector = self.locator.selector().to_string();
+		let pattern = ValuePattern::parse(expected)?;
+
+		loop {
+			let actual = self.locator.get_attribute(name).await?;
+			let is_match = actual.as_deref().is_some_and(|value| pattern.is_match(value));
+
+			// Check if condition matches (with negation support)
+			let matches = if self.negate { !is_match } else { is_match };
+
+			if matches {
+				return Ok(());
+			}
+
+			// Check timeout
+			if start.elapsed() >= self.timeout {
+				let message = if self.negate {
+					format!(
+						"Expected element '{}' NOT to have attribute {}='{}', but it did after {:?}",
+						selector, name, expected, self.timeout
+					)
+				} else {
+					match actual {
+						Some(actual) => format!(
+							"Expected element '{}' to have attribute {}='{}', but had '{}' after {:?}",
+							selector, name, expected, actual, self.timeout
+						),
+						None => format!(
+							"Expected element '{}' to have attribute {}='{}', but the attribute was missing after {:?}",
+							selector, name, expected, self.timeout
+						),
+					}
+				};
+				return Err(pw_runtime::Error::AssertionTimeout(message));
+			}
+
+			// Wait before next poll
+			tokio::time::sleep(self.poll_interval).await;
+		}
+	}
+
 	/// Asserts that the input element's value matches the specified regex pattern.
 	///
 	/// This assertion will retry until the input value matches the pattern or timeout.
@@ -481,20 +534,29 @@ impl Expectation {
 		negated.to_be_enabled().await
 	}
 
-	/// Asserts that the checkbox or radio button is checked.
+	/// Asserts that the checkbox or radio button is in the `expected` state.
+	///
+	/// `None` means [`CheckedState::True`]. [`CheckedState::Mixed`] matches
+	/// tri-state controls left indeterminate (see [`Locator::is_indeterminate`]);
+	/// an indeterminate element never counts as checked or unchecked.
 	///
-	/// This assertion will retry until the element is checked or timeout.
+	/// This assertion will retry until the element reaches the state or timeout.
 	///
 	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-checked>
-	pub async fn to_be_checked(self) -> Result<()> {
+	pub async fn to_be_checked(self, expected: Option<CheckedState>) -> Result<()> {
+		let expected = expected.unwrap_or(CheckedState::True);
 		let start = std::time::Instant::now();
 		let selector = self.locator.selector().to_string();
 
 		loop {
-			let is_checked = self.locator.is_checked().await?;
+			let actual = if self.locator.is_indeterminate().await? {
+				CheckedState::Mixed
+			} else {
+				CheckedState::from(self.locator.is_checked().await?)
+			};
 
 			// Check if condition matches (with negation support)
-			let matches = if self.negate { !is_checked } else { is_checked };
+			let matches = if self.negate { actual != expected } else { actual == expected };
 
 			if matches {
 				return Ok(());
@@ -503,9 +565,20 @@ impl Expectation {
 			// Check timeout
 			if start.elapsed() >= self.timeout {
 				let message = if self.negate {
-					format!("Expected element '{}' NOT to be checked, but it was checked after {:?}", selector, self.timeout)
+					format!(
+						"Expected element '{}' NOT to be {}, but it was after {:?}",
+						selector,
+						describe_checked(expected),
+						self.timeout
+					)
 				} else {
-					format!("Expected element '{}' to be checked, but it was not checked after {:?}", selector, self.timeout)
+					format!(
+						"Expected element '{}' to be {}, but it was {} after {:?}",
+						selector,
+						describe_checked(expected),
+						describe_checked(actual),
+						self.timeout
+					)
 				};
 				return Err(pw_runtime::Error::AssertionTimeout(message));
 			}
@@ -515,9 +588,10 @@ impl Expectation {
 		}
 	}
 
-	/// Asserts that the checkbox or radio button is unchecked.
+	/// Asserts that the checkbox or radio button is not checked.
 	///
-	/// This assertion will retry until the element is unchecked or timeout.
+	/// Shorthand for `.not().to_be_checked(None)`, so indeterminate elements
+	/// pass too. This assertion will retry until the element is unchecked or timeout.
 	///
 	/// See: <https://playwright.dev/docs/test-assertions#locator-assertions-to-be-checked>
 	pub async fn to_be_unchecked(self) -> Result<()> {
@@ -527,7 +601,7 @@ impl Expectation {
 			negate: !self.negate, // Flip negation
 			..self
 		};
-		negated.to_be_checked().await
+		negated.to_be_checked(None).await
 	}
 
 	/// Asserts that the element is editable.
@@ -605,3 +679,91 @@ impl Expectation {
 		}
 	}
 }
+
+/// Wording for a checked state in assertion messages.
+fn describe_checked(state: CheckedState) -> &'static str {
+	match state {
+		CheckedState::True => "checked",
+		CheckedState::False => "unchecked",
+		CheckedState::Mixed => "indeterminate",
+	}
+}
+
+/// Expected value for [`Expectation::to_have_value`] and [`Expectation::to_have_attribute`].
+///
+/// * `re:<regex>` matches when the regex finds a match anywhere in the value
+/// * `glob:<pattern>` is a glob over the whole value
+/// * anything else must match exactly, including values containing `*`, `?`, or `[`
+#[derive(Debug, Clone)]
+pub enum ValuePattern {
+	Exact(String),
+	Glob(glob::Pattern),
+	Regex(regex::Regex),
+}
+
+impl ValuePattern {
+	/// Parses an expected value, rejecting invalid `re:` and `glob:` patterns.
+	pub fn parse(expected: &str) -> Result<Self> {
+		if let Some(pattern) = expected.strip_prefix("re:") {
+			let re = regex::Regex::new(pattern).map_err(|e| pw_runtime::Error::InvalidArgument(format!("Invalid regex: {}", e)))?;
+			return Ok(Self::Regex(re));
+		}
+		if let Some(pattern) = expected.strip_prefix("glob:") {
+			let glob = glob::Pattern::new(pattern).map_err(|e| pw_runtime::Error::InvalidArgument(format!("Invalid glob: {}", e)))?;
+			return Ok(Self::Glob(glob));
+		}
+		Ok(Self::Exact(expected.to_string()))
+	}
+
+	/// Returns `true` if `actual` satisfies this pattern.
+	pub fn is_match(&self, actual: &str) -> bool {
+		match self {
+			Self::Exact(expected) => actual == expected,
+			Self::Glob(glob) => glob.matches(actual),
+			Self::Regex(re) => re.is_match(actual),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn value_pattern_exact_glob_and_regex() {
+		let exact = ValuePattern::parse("hello").unwrap();
+		assert!(exact.is_match("hello"));
+		assert!(!exact.is_match("hello world"));
+
+		let glob = ValuePattern::parse("glob:https://example.com/*").unwrap();
+		assert!(glob.is_match("https://example.com/docs"));
+		assert!(!glob.is_match("https://other.com/docs"));
+
+		let re = ValuePattern::parse(r"re:^\d{3}-\d{4}$").unwrap();
+		assert!(re.is_match("555-1234"));
+		assert!(!re.is_match("5551234"));
+	}
+
+	#[test]
+	fn checked_state_from_bool_and_wording() {
+		assert_eq!(CheckedState::from(true), CheckedState::True);
+		assert_eq!(CheckedState::from(false), CheckedState::False);
+		assert_eq!(describe_checked(CheckedState::Mixed), "indeterminate");
+	}
+
+	#[test]
+	fn value_pattern_rejects_invalid_regex_and_glob() {
+		assert!(ValuePattern::parse("re:(unclosed").is_err());
+		assert!(ValuePattern::parse("glob:[abc").is_err());
+	}
+
+	#[test]
+	fn value_pattern_wildcards_without_prefix_are_exact() {
+		for expected in ["a*b", "why?", "[abc"] {
+			let pattern = ValuePattern::parse(expected).unwrap();
+			assert!(matches!(pattern, ValuePattern::Exact(_)), "{expected}");
+			assert!(pattern.is_match(expected));
+		}
+		assert!(!ValuePattern::parse("a*b").unwrap().is_match("axxb"));
+	}
+}
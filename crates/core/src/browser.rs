@@ -106,6 +106,24 @@ impl Browser {
 		&self.version
 	}
 
+	/// Returns the browser's default user agent.
+	///
+	/// Reads `navigator.userAgent` from a throwaway context, so it reflects the
+	/// engine default rather than any per-context `userAgent` override.
+	///
+	/// # Errors
+	///
+	/// Returns error if the browser has been closed or the probe page fails.
+	pub async fn user_agent(&self) -> Result<String> {
+		let context = self.new_context().await?;
+		let user_agent = match context.new_page().await {
+			Ok(page) => page.evaluate_typed::<String>("navigator.userAgent").await,
+			Err(err) => Err(err),
+		};
+		context.close().await?;
+		user_agent
+	}
+
 	/// Returns the browser name (e.g., "chromium", "firefox", "webkit").
 	///
 	/// See: <https://playwright.dev/docs/api/class-browser#browser-name>
@@ -202,6 +220,8 @@ impl Browser {
 			guid: Arc<str>,
 		}
 
+		let save_storage_state_on_close = options.save_storage_state_on_close.clone();
+
 		// Convert options to JSON
 		let options_json =
 			serde_json::to_value(options).map_err(|e| pw_runtime::Error::ProtocolError(format!("Failed to serialize context options: {}", e)))?;
@@ -217,6 +237,10 @@ impl Browser {
 			.downcast_ref::<BrowserContext>()
 			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected BrowserContext object, got {}", context_arc.type_name())))?;
 
+		if save_storage_state_on_close.is_some() {
+			context.set_save_storage_state_on_close(save_storage_state_on_close);
+		}
+
 		Ok(context.clone())
 	}
 
@@ -338,9 +362,3 @@ impl std::fmt::Debug for Browser {
 			.finish()
 	}
 }
-
-// Note: Browser testing is done via integration tests since it requires:
-// - A real Connection with object registry
-// - Protocol messages from the server
-// - BrowserType.launch() to create Browser objects
-// See: crates/playwright-core/tests/browser_launch_integration.rs (Phase 2 Slice 3)
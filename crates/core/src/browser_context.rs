@@ -7,8 +7,10 @@
 //! and storage state helpers used when creating or managing contexts.
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use parking_lot::Mutex;
 use pw_runtime::Result;
 use pw_runtime::channel::Channel;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
@@ -16,7 +18,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::Page;
+use crate::cdp_session::CdpSession;
 use crate::cookie::{ClearCookiesOptions, Cookie, StorageState, StorageStateOptions};
+use crate::timeout_settings::TimeoutSettings;
 use crate::tracing::Tracing;
 
 /// Options for [`BrowserContext::route_from_har`].
@@ -109,6 +113,10 @@ impl HarNotFound {
 #[derive(Clone)]
 pub struct BrowserContext {
 	base: ChannelOwnerImpl,
+	/// Storage-state file written by [`close`](Self::close), shared across clones.
+	save_storage_state_on_close: Arc<Mutex<Option<PathBuf>>>,
+	/// Default timeouts inherited by this context's pages.
+	timeouts: TimeoutSettings,
 }
 
 impl BrowserContext {
@@ -130,21 +138,27 @@ impl BrowserContext {
 	pub fn new(parent: Arc<dyn ChannelOwner>, type_name: String, guid: Arc<str>, initializer: Value) -> Result<Self> {
 		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent), type_name, guid, initializer);
 
-		let context = Self { base };
+		let context = Self {
+			base,
+			save_storage_state_on_close: Arc::new(Mutex::new(None)),
+			timeouts: TimeoutSettings::default(),
+		};
 
-		// Enable dialog event subscription
-		// Dialog events need to be explicitly subscribed to via updateSubscription command
+		// Enable dialog and console event subscriptions
+		// Both need to be explicitly subscribed to via updateSubscription command
 		let channel = context.channel().clone();
 		tokio::spawn(async move {
-			let _ = channel
-				.send_no_result(
-					"updateSubscription",
-					serde_json::json!({
-						"event": "dialog",
-						"enabled": true
-					}),
-				)
-				.await;
+			for event in ["dialog", "console"] {
+				let _ = channel
+					.send_no_result(
+						"updateSubscription",
+						serde_json::json!({
+							"event": event,
+							"enabled": true
+						}),
+					)
+					.await;
+			}
 		});
 
 		Ok(context)
@@ -157,6 +171,31 @@ impl BrowserContext {
 		self.base.channel()
 	}
 
+	/// Sets the default timeout, in milliseconds, for actions in every page of this context.
+	///
+	/// Also applies to navigations unless
+	/// [`set_default_navigation_timeout`](Self::set_default_navigation_timeout) is set.
+	/// Page-level defaults and per-call timeouts take precedence.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-default-timeout>
+	pub fn set_default_timeout(&self, timeout_ms: f64) {
+		self.timeouts.set_default_timeout(timeout_ms);
+	}
+
+	/// Sets the default timeout, in milliseconds, for navigations and load-state waits
+	/// in every page of this context.
+	///
+	/// Page-level defaults and per-call timeouts take precedence.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-default-navigation-timeout>
+	pub fn set_default_navigation_timeout(&self, timeout_ms: f64) {
+		self.timeouts.set_default_navigation_timeout(timeout_ms);
+	}
+
+	pub(crate) fn timeouts(&self) -> &TimeoutSettings {
+		&self.timeouts
+	}
+
 	/// Returns all pages in this browser context.
 	///
 	/// This returns all currently open pages (tabs) within this context.
@@ -210,21 +249,92 @@ impl BrowserContext {
 		Ok(page.clone())
 	}
 
+	/// Engine name (`chromium`, `firefox`, `webkit`) of the browser that owns this context.
+	///
+	/// Persistent contexts are owned by their browser type rather than a browser.
+	pub(crate) fn engine_name(&self) -> Option<String> {
+		let parent = self.base.parent()?;
+		if let Some(browser) = parent.downcast_ref::<crate::Browser>() {
+			return Some(browser.name().to_string());
+		}
+		parent.downcast_ref::<crate::BrowserType>().map(|browser_type| browser_type.name().to_string())
+	}
+
+	/// Opens a raw Chrome DevTools Protocol session attached to `page`.
+	///
+	/// Chromium only; other engines reject the call.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-new-cdp-session>
+	pub async fn new_cdp_session(&self, page: &Page) -> Result<CdpSession> {
+		#[derive(Deserialize)]
+		struct NewCdpSessionResponse {
+			session: GuidRef,
+		}
+
+		#[derive(Deserialize)]
+		struct GuidRef {
+			#[serde(deserialize_with = "pw_runtime::connection::deserialize_arc_str")]
+			guid: Arc<str>,
+		}
+
+		let response: NewCdpSessionResponse = self
+			.channel()
+			.send("newCDPSession", serde_json::json!({ "page": { "guid": page.guid() } }))
+			.await?;
+
+		let session_arc = self.connection().get_object(&response.session.guid).await?;
+		let session = session_arc
+			.downcast_ref::<CdpSession>()
+			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected CDPSession object, got {}", session_arc.type_name())))?;
+
+		Ok(session.clone())
+	}
+
+	/// Emulates the network being offline for every page in the context.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-offline>
+	pub async fn set_offline(&self, offline: bool) -> Result<()> {
+		self.channel().send_no_result("setOffline", serde_json::json!({ "offline": offline })).await
+	}
+
 	/// Closes the browser context and all its pages.
 	///
 	/// This is a graceful operation that sends a close command to the context
-	/// and waits for it to shut down properly.
+	/// and waits for it to shut down properly. When a save path is set (see
+	/// [`BrowserContextOptions::save_storage_state_on_close`]), the storage state
+	/// is written there first; the context is closed even if that write fails.
 	///
 	/// # Errors
 	///
 	/// Returns error if:
 	/// * Context has already been closed
 	/// * Communication with browser process fails
+	/// * The storage state cannot be written on close
 	///
 	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-close>
 	pub async fn close(&self) -> Result<()> {
+		let save_path = self.save_storage_state_on_close.lock().clone();
+		let saved = match save_path {
+			Some(path) => self.save_storage_state(&path).await,
+			None => Ok(()),
+		};
+
 		// Send close RPC to server
-		self.channel().send_no_result("close", serde_json::json!({})).await
+		self.channel().send_no_result("close", serde_json::json!({})).await?;
+		saved
+	}
+
+	/// Sets (or clears with `None`) the file [`close`](Self::close) writes storage state to.
+	///
+	/// Contexts created with [`BrowserContextOptions::save_storage_state_on_close`]
+	/// start with that path; this lets callers arm or disarm the save later.
+	pub fn set_save_storage_state_on_close(&self, path: Option<PathBuf>) {
+		*self.save_storage_state_on_close.lock() = path;
+	}
+
+	/// Returns the file [`close`](Self::close) writes storage state to, if armed.
+	pub fn save_storage_state_on_close(&self) -> Option<PathBuf> {
+		self.save_storage_state_on_close.lock().clone()
 	}
 
 	/// Adds cookies to the browser context.
@@ -255,11 +365,14 @@ impl BrowserContext {
 	///
 	/// Returns error if:
 	/// * Context has been closed
-	/// * Cookie specification is invalid (missing both domain and url)
+	/// * A cookie fails [`Cookie::validate`]; nothing is sent in that case
 	/// * Communication with browser process fails
 	///
 	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-add-cookies>
 	pub async fn add_cookies(&self, cookies: Vec<Cookie>) -> Result<()> {
+		for cookie in &cookies {
+			cookie.validate()?;
+		}
 		self.channel().send_no_result("addCookies", serde_json::json!({ "cookies": cookies })).await
 	}
 
@@ -304,6 +417,19 @@ impl BrowserContext {
 		Ok(response.cookies)
 	}
 
+	/// Returns every cookie in the context whose name is in `names`, across all domains.
+	///
+	/// Fetches all cookies and filters locally; the protocol only filters by URL.
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// let sessions = context.cookies_named(&["session_id"]).await?;
+	/// ```
+	pub async fn cookies_named(&self, names: &[&str]) -> Result<Vec<Cookie>> {
+		Ok(retain_named(self.cookies(None).await?, names))
+	}
+
 	/// Clears cookies from the browser context.
 	///
 	/// If options are provided, only cookies matching all specified criteria
@@ -401,6 +527,38 @@ impl BrowserContext {
 		Ok(state)
 	}
 
+	/// Returns the storage state, reading localStorage from open pages when the protocol reports none.
+	///
+	/// Contexts attached over CDP often come back from `storageState` with an
+	/// empty `origins` list even though pages hold localStorage. In that case
+	/// each open page is evaluated for its origin and localStorage entries and
+	/// the results are merged via [`StorageState::merge_origins`]. Pages that fail
+	/// to evaluate (closed, crashed, cross-origin restrictions) are skipped.
+	pub async fn storage_state_with_page_fallback(&self) -> Result<StorageState> {
+		let mut state = self.storage_state(None).await?;
+		if state.origins.is_empty() {
+			let captured = self.capture_local_storage().await;
+			let added = state.merge_origins(captured);
+			tracing::debug!(added, "merged localStorage captured from open pages");
+		}
+		Ok(state)
+	}
+
+	/// Evaluates every open page for its origin and localStorage entries.
+	async fn capture_local_storage(&self) -> Vec<crate::OriginState> {
+		const CAPTURE_JS: &str = "(() => { try { return { origin: location.origin, localStorage: Object.entries(localStorage).map(([name, value]) => ({ name, value })) }; } catch (e) { return null; } })()";
+
+		let mut captured = Vec::new();
+		for page in self.pages() {
+			match page.evaluate_typed::<Option<crate::OriginState>>(CAPTURE_JS).await {
+				Ok(Some(origin)) => captured.push(origin),
+				Ok(None) => {}
+				Err(e) => tracing::debug!(url = %page.url(), error = %e, "skipping page for localStorage capture"),
+			}
+		}
+		captured
+	}
+
 	/// Saves the storage state to the specified `path`.
 	///
 	/// This is a convenience method equivalent to calling [`storage_state`] and
@@ -430,7 +588,7 @@ impl BrowserContext {
 	/// [`storage_state`]: Self::storage_state
 	///
 	/// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-storage-state>
-	pub async fn save_storage_state(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+	pub async fn save_storage_state(&self, path: impl AsRef<Path>) -> Result<()> {
 		let state = self.storage_state(None).await?;
 		state.to_file(path.as_ref())?;
 		Ok(())
@@ -721,6 +879,19 @@ impl ChannelOwner for BrowserContext {
 					});
 				}
 			}
+			"console" => {
+				// Console events come to BrowserContext with the message fields
+				// inlined next to a reference to the Page that logged them.
+				// Pages are children of their context, so the lookup stays
+				// synchronous and messages reach the page in arrival order.
+				let Some(page_guid) = params.get("page").and_then(|v| v.get("guid")).and_then(|v| v.as_str()) else {
+					return;
+				};
+				let page = self.base.children().into_iter().find(|child| child.guid() == page_guid);
+				if let Some(page) = page.as_ref().and_then(|child| child.downcast_ref::<Page>()) {
+					page.trigger_console_event(crate::page::ConsoleMessage::from_event(&params));
+				}
+			}
 			_ => {
 				// Other events will be handled in future phases
 			}
@@ -789,6 +960,37 @@ pub enum HarMode {
 	Minimal,
 }
 
+/// Emulated `prefers-color-scheme` media feature.
+///
+/// See: <https://playwright.dev/docs/api/class-browser#browser-new-context-option-color-scheme>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorScheme {
+	Light,
+	Dark,
+	NoPreference,
+}
+
+/// Emulated `prefers-reduced-motion` media feature.
+///
+/// See: <https://playwright.dev/docs/api/class-browser#browser-new-context-option-reduced-motion>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReducedMotion {
+	Reduce,
+	NoPreference,
+}
+
+/// Emulated `forced-colors` media feature.
+///
+/// See: <https://playwright.dev/docs/api/class-browser#browser-new-context-option-forced-colors>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ForcedColors {
+	Active,
+	None,
+}
+
 /// Options for starting HAR recording via [`BrowserContext::har_start`].
 #[derive(Debug, Clone, Default)]
 pub struct HarStartOptions {
@@ -814,8 +1016,10 @@ pub struct BrowserContextOptions {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub viewport: Option<Viewport>,
 
-	/// Disables viewport emulation when set to true.
-	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Disables viewport emulation when set to true (a `null` viewport).
+	///
+	/// Pages then follow the OS window size. Sent as the protocol's `noDefaultViewport`.
+	#[serde(rename = "noDefaultViewport", skip_serializing_if = "Option::is_none")]
 	pub no_viewport: Option<bool>,
 
 	/// Custom user agent string
@@ -838,9 +1042,17 @@ pub struct BrowserContextOptions {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub permissions: Option<Vec<String>>,
 
-	/// Emulates 'prefers-colors-scheme' media feature ("light", "dark", "no-preference")
+	/// Emulates the `prefers-color-scheme` media feature
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub color_scheme: Option<ColorScheme>,
+
+	/// Emulates the `prefers-reduced-motion` media feature
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub color_scheme: Option<String>,
+	pub reduced_motion: Option<ReducedMotion>,
+
+	/// Emulates the `forced-colors` media feature
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub forced_colors: Option<ForcedColors>,
 
 	/// Whether the viewport supports touch events
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -914,6 +1126,12 @@ pub struct BrowserContextOptions {
 	/// URL pattern to filter HAR entries by URL.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub record_har_url_filter: Option<String>,
+
+	/// File that [`BrowserContext::close`] writes the storage state to.
+	///
+	/// Client-side only: never sent to the driver.
+	#[serde(skip)]
+	pub save_storage_state_on_close: Option<PathBuf>,
 }
 
 impl BrowserContextOptions {
@@ -933,7 +1151,9 @@ pub struct BrowserContextOptionsBuilder {
 	timezone_id: Option<String>,
 	geolocation: Option<Geolocation>,
 	permissions: Option<Vec<String>>,
-	color_scheme: Option<String>,
+	color_scheme: Option<ColorScheme>,
+	reduced_motion: Option<ReducedMotion>,
+	forced_colors: Option<ForcedColors>,
 	has_touch: Option<bool>,
 	is_mobile: Option<bool>,
 	javascript_enabled: Option<bool>,
@@ -952,6 +1172,7 @@ pub struct BrowserContextOptionsBuilder {
 	record_har_mode: Option<HarMode>,
 	record_har_omit_content: Option<bool>,
 	record_har_url_filter: Option<String>,
+	save_storage_state_on_close: Option<PathBuf>,
 }
 
 impl BrowserContextOptionsBuilder {
@@ -1002,11 +1223,23 @@ impl BrowserContextOptionsBuilder {
 	}
 
 	/// Sets the color scheme preference
-	pub fn color_scheme(mut self, color_scheme: String) -> Self {
+	pub fn color_scheme(mut self, color_scheme: ColorScheme) -> Self {
 		self.color_scheme = Some(color_scheme);
 		self
 	}
 
+	/// Sets the reduced motion preference
+	pub fn reduced_motion(mut self, reduced_motion: ReducedMotion) -> Self {
+		self.reduced_motion = Some(reduced_motion);
+		self
+	}
+
+	/// Sets the forced colors mode
+	pub fn forced_colors(mut self, forced_colors: ForcedColors) -> Self {
+		self.forced_colors = Some(forced_colors);
+		self
+	}
+
 	/// Sets whether the viewport supports touch events
 	pub fn has_touch(mut self, has_touch: bool) -> Self {
 		self.has_touch = Some(has_touch);
@@ -1150,6 +1383,15 @@ impl BrowserContextOptionsBuilder {
 		self
 	}
 
+	/// Saves the context's storage state to `path` when the context is closed.
+	///
+	/// A safety net for interactive sessions: cookies and localStorage survive
+	/// even if nothing saves them explicitly before shutdown.
+	pub fn save_storage_state_on_close(mut self, path: impl Into<PathBuf>) -> Self {
+		self.save_storage_state_on_close = Some(path.into());
+		self
+	}
+
 	/// Builds the BrowserContextOptions
 	pub fn build(self) -> BrowserContextOptions {
 		BrowserContextOptions {
@@ -1161,6 +1403,8 @@ impl BrowserContextOptionsBuilder {
 			geolocation: self.geolocation,
 			permissions: self.permissions,
 			color_scheme: self.color_scheme,
+			reduced_motion: self.reduced_motion,
+			forced_colors: self.forced_colors,
 			has_touch: self.has_touch,
 			is_mobile: self.is_mobile,
 			javascript_enabled: self.javascript_enabled,
@@ -1179,6 +1423,105 @@ impl BrowserContextOptionsBuilder {
 			record_har_mode: self.record_har_mode,
 			record_har_omit_content: self.record_har_omit_content,
 			record_har_url_filter: self.record_har_url_filter,
+			save_storage_state_on_close: self.save_storage_state_on_close,
 		}
 	}
 }
+
+fn retain_named(mut cookies: Vec<Cookie>, names: &[&str]) -> Vec<Cookie> {
+	cookies.retain(|cookie| names.contains(&cookie.name.as_str()));
+	cookies
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock_driver::MockDriver;
+
+	#[test]
+	fn retain_named_keeps_matching_cookies_across_domains() {
+		let cookies = vec![
+			Cookie::new("session_id", "a", "example.com"),
+			Cookie::new("theme", "dark", "example.com"),
+			Cookie::new("session_id", "b", "other.test"),
+			Cookie::new("csrf", "t", "other.test"),
+		];
+		let named = retain_named(cookies, &["session_id", "csrf"]);
+		let pairs: Vec<_> = named.iter().map(|c| (c.name.as_str(), c.value.as_str())).collect();
+		assert_eq!(pairs, [("session_id", "a"), ("session_id", "b"), ("csrf", "t")]);
+		assert!(retain_named(named, &[]).is_empty());
+	}
+
+	#[test]
+	fn media_emulation_serializes_as_new_context_params() {
+		let options = BrowserContextOptions::builder()
+			.color_scheme(ColorScheme::Dark)
+			.reduced_motion(ReducedMotion::Reduce)
+			.forced_colors(ForcedColors::Active)
+			.build();
+		let json = serde_json::to_value(options).unwrap();
+		assert_eq!(json["colorScheme"], "dark");
+		assert_eq!(json["reducedMotion"], "reduce");
+		assert_eq!(json["forcedColors"], "active");
+
+		let json = serde_json::to_value(BrowserContextOptions::builder().color_scheme(ColorScheme::NoPreference).build()).unwrap();
+		assert_eq!(json["colorScheme"], "no-preference");
+		assert!(json.get("reducedMotion").is_none());
+	}
+
+	#[tokio::test]
+	async fn tracing_start_targets_context_tracing_channel() {
+		let mut driver = MockDriver::start();
+		let context = driver.context("context@1", serde_json::json!({ "tracing": { "guid": "tracing@1" } }));
+		let tracing = Tracing::new(Arc::clone(&context) as _, "Tracing".to_string(), Arc::from("tracing@1"), serde_json::json!({})).unwrap();
+		context.add_child(Arc::from("tracing@1"), Arc::new(tracing));
+
+		let tracing = context.tracing().expect("context exposes tracing");
+		let call = tokio::spawn(async move { tracing.start(crate::TracingStartOptions::default()).await });
+
+		let request = loop {
+			let request = driver.next_request().await;
+			if request["method"] != "updateSubscription" {
+				break request;
+			}
+		};
+		assert_eq!(request["guid"], "tracing@1");
+		assert_eq!(request["method"], "tracingStart");
+
+		driver.respond(&request, serde_json::json!({})).await;
+		call.await.unwrap().unwrap();
+	}
+
+	#[tokio::test]
+	async fn console_events_reach_the_page_in_order() {
+		let driver = MockDriver::start();
+		let context = driver.context("context@1", serde_json::json!({}));
+		let page = Page::new(
+			Arc::clone(&context) as _,
+			"Page".to_string(),
+			Arc::from("page@1"),
+			serde_json::json!({ "mainFrame": { "guid": "frame@1" } }),
+		)
+		.unwrap();
+		let mut messages = page.console_messages();
+		context.add_child(Arc::from("page@1"), Arc::new(page));
+
+		for n in 0..20 {
+			context.on_event(
+				"console",
+				serde_json::json!({ "page": { "guid": "page@1" }, "type": "log", "text": n.to_string() }),
+			);
+		}
+		for n in 0..20 {
+			assert_eq!(messages.try_recv().unwrap().text(), n.to_string());
+		}
+	}
+
+	#[test]
+	fn save_storage_state_on_close_is_not_sent_to_driver() {
+		let options = BrowserContextOptions::builder().save_storage_state_on_close("auth.json").build();
+		assert_eq!(options.save_storage_state_on_close, Some(PathBuf::from("auth.json")));
+		let json = serde_json::to_value(options).unwrap();
+		assert!(json.get("saveStorageStateOnClose").is_none());
+	}
+}
@@ -3,9 +3,11 @@
 //! [`BrowserType`] models Chromium, Firefox, and WebKit factories exposed by
 //! [`crate::Playwright`], including launch and CDP connection workflows.
 //!
-//! The module also defines return types for server and CDP connection paths.
+//! The module also defines return types for server and CDP connection paths,
+//! plus [`ConnectOptions`] for attaching to a Playwright browser server.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use pw_runtime::Result;
 use pw_runtime::channel::Channel;
@@ -278,6 +280,94 @@ impl BrowserType {
 			default_context,
 		})
 	}
+
+	/// Connects to a Playwright browser server over its native protocol.
+	///
+	/// `ws_endpoint` is the endpoint reported by [`LaunchedServer::ws_endpoint`]
+	/// (or `launchServer` in another Playwright client). Unlike
+	/// [`Self::connect_over_cdp`], this opens a separate Playwright connection
+	/// over [`pw_runtime::WebSocketTransport`] instead of reusing this
+	/// `BrowserType`'s driver connection, so one server can be shared across
+	/// processes. The connection's message loop runs until the server closes
+	/// the WebSocket; if connecting fails or times out, it is stopped.
+	///
+	/// See: <https://playwright.dev/docs/api/class-browsertype#browser-type-connect>
+	pub async fn connect(&self, ws_endpoint: &str, options: Option<ConnectOptions>) -> Result<Browser> {
+		let options = options.unwrap_or_default();
+		let timeout = options.timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT);
+		let transport = pw_runtime::WebSocketOptions { insecure: options.insecure };
+		let mut message_loop = None;
+		let result = tokio::time::timeout(timeout, connect_browser_server(ws_endpoint, transport, &mut message_loop))
+			.await
+			.map_err(|_| pw_runtime::Error::Timeout(format!("Connecting to browser server {} timed out after {:?}", ws_endpoint, timeout)))
+			.and_then(|result| result);
+		if let Some(message_loop) = message_loop.filter(|_| result.is_err()) {
+			message_loop.abort();
+		}
+		result
+	}
+}
+
+/// Default timeout for [`BrowserType::connect`].
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Options for [`BrowserType::connect`].
+#[derive(Debug, Clone, Default)]
+pub struct ConnectOptions {
+	/// Maximum time to establish the connection and receive the browser (default: 30s).
+	pub timeout: Option<Duration>,
+	/// Skip certificate validation for `wss://` endpoints.
+	pub insecure: bool,
+}
+
+impl ConnectOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	pub fn insecure(mut self, insecure: bool) -> Self {
+		self.insecure = insecure;
+		self
+	}
+}
+
+/// Opens a Playwright connection to a browser server and returns its pre-launched browser.
+///
+/// The spawned message loop is stored in `message_loop` so the caller can stop it.
+async fn connect_browser_server(
+	ws_endpoint: &str,
+	options: pw_runtime::WebSocketOptions,
+	message_loop: &mut Option<tokio::task::JoinHandle<()>>,
+) -> Result<Browser> {
+	use pw_runtime::WebSocketTransport;
+	use pw_runtime::connection::Connection;
+
+	tracing::debug!(%ws_endpoint, "Connecting to Playwright browser server");
+	let (transport, message_rx) = WebSocketTransport::connect_with_options(ws_endpoint, options).await?;
+	let parts = transport.into_transport_parts(message_rx);
+	let connection: Arc<Connection> = Arc::new(Connection::new(parts));
+
+	let conn_for_loop: Arc<Connection> = Arc::clone(&connection);
+	*message_loop = Some(tokio::spawn(async move {
+		conn_for_loop.run().await;
+	}));
+
+	let playwright = crate::initialize_playwright(&connection).await?;
+	let browser_guid = playwright.initializer()["preLaunchedBrowser"]["guid"]
+		.as_str()
+		.ok_or_else(|| pw_runtime::Error::ProtocolError("Browser server did not expose a pre-launched browser".to_string()))?;
+
+	let browser_arc = connection.get_object(browser_guid).await?;
+	let browser = browser_arc
+		.downcast_ref::<Browser>()
+		.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Browser object, got {}", browser_arc.type_name())))?;
+
+	Ok(browser.clone())
 }
 
 /// Response from BrowserType.launch() protocol call
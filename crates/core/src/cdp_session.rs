@@ -0,0 +1,158 @@
+//! Raw Chrome DevTools Protocol sessions.
+//!
+//! A [`CdpSession`] talks CDP directly to one page target, for capabilities the
+//! high-level API does not cover (CPU throttling, performance metrics, ...).
+//! Only Chromium supports CDP sessions.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let cdp = context.new_cdp_session(&page).await?;
+//! cdp.send("Emulation.setCPUThrottlingRate", serde_json::json!({ "rate": 4 })).await?;
+//!
+//! let mut events = cdp.events();
+//! cdp.send("Network.enable", serde_json::json!({})).await?;
+//! while let Some(event) = events.recv().await {
+//!     println!("{}", event.method);
+//! }
+//! ```
+//!
+//! See: <https://playwright.dev/docs/api/class-cdpsession>
+
+use std::sync::Arc;
+
+use pw_runtime::Result;
+use pw_runtime::channel::Channel;
+use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::sync::broadcast;
+
+use crate::events::EventStream;
+
+/// A CDP event received on a [`CdpSession`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CdpEvent {
+	/// CDP event name, e.g. `Network.requestWillBeSent`.
+	pub method: String,
+	/// Event payload; `Null` when the event has none.
+	pub params: Value,
+}
+
+/// Chrome DevTools Protocol session bound to a page.
+///
+/// Obtain one via [`BrowserContext::new_cdp_session`](crate::BrowserContext::new_cdp_session).
+#[derive(Clone)]
+pub struct CdpSession {
+	base: ChannelOwnerImpl,
+	events_tx: broadcast::Sender<CdpEvent>,
+}
+
+impl CdpSession {
+	/// Creates a new CdpSession from protocol initialization.
+	pub fn new(parent: Arc<dyn ChannelOwner>, type_name: String, guid: Arc<str>, initializer: Value) -> Result<Self> {
+		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent), type_name, guid, initializer);
+		let (events_tx, _) = broadcast::channel(256);
+		Ok(Self { base, events_tx })
+	}
+
+	fn channel(&self) -> &Channel {
+		self.base.channel()
+	}
+
+	/// Sends a CDP command and returns its result object.
+	///
+	/// See: <https://playwright.dev/docs/api/class-cdpsession#cdp-session-send>
+	pub async fn send(&self, method: &str, params: Value) -> Result<Value> {
+		#[derive(Deserialize)]
+		struct SendResponse {
+			#[serde(default)]
+			result: Value,
+		}
+
+		let response: SendResponse = self.channel().send("send", json!({ "method": method, "params": params })).await?;
+		Ok(response.result)
+	}
+
+	/// Streams CDP events received after this call.
+	///
+	/// Events are only emitted for domains enabled through [`send`](Self::send)
+	/// (e.g. `Network.enable`).
+	pub fn events(&self) -> EventStream<CdpEvent> {
+		EventStream::new(self.events_tx.subscribe())
+	}
+
+	/// Detaches the session from its target; later sends fail.
+	///
+	/// See: <https://playwright.dev/docs/api/class-cdpsession#cdp-session-detach>
+	pub async fn detach(&self) -> Result<()> {
+		self.channel().send_no_result("detach", json!({})).await
+	}
+}
+
+impl pw_runtime::channel_owner::private::Sealed for CdpSession {}
+
+impl ChannelOwner for CdpSession {
+	fn guid(&self) -> &str {
+		self.base.guid()
+	}
+
+	fn type_name(&self) -> &str {
+		self.base.type_name()
+	}
+
+	fn parent(&self) -> Option<Arc<dyn ChannelOwner>> {
+		self.base.parent()
+	}
+
+	fn connection(&self) -> Arc<dyn pw_runtime::connection::ConnectionLike> {
+		self.base.connection()
+	}
+
+	fn initializer(&self) -> &Value {
+		self.base.initializer()
+	}
+
+	fn channel(&self) -> &Channel {
+		self.base.channel()
+	}
+
+	fn dispose(&self, reason: pw_runtime::channel_owner::DisposeReason) {
+		self.base.dispose(reason)
+	}
+
+	fn adopt(&self, child: Arc<dyn ChannelOwner>) {
+		self.base.adopt(child)
+	}
+
+	fn add_child(&self, guid: Arc<str>, child: Arc<dyn ChannelOwner>) {
+		self.base.add_child(guid, child)
+	}
+
+	fn remove_child(&self, guid: &str) {
+		self.base.remove_child(guid)
+	}
+
+	fn on_event(&self, method: &str, params: Value) {
+		if method != "event" {
+			return;
+		}
+		let Some(cdp_method) = params.get("method").and_then(Value::as_str) else {
+			return;
+		};
+		let _ = self.events_tx.send(CdpEvent {
+			method: cdp_method.to_string(),
+			params: params.get("params").cloned().unwrap_or(Value::Null),
+		});
+	}
+
+	fn was_collected(&self) -> bool {
+		self.base.was_collected()
+	}
+}
+
+impl std::fmt::Debug for CdpSession {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("CdpSession").field("guid", &self.guid()).finish()
+	}
+}
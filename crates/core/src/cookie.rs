@@ -5,6 +5,7 @@
 //!
 //! Serialization is aligned with Playwright's camelCase wire format.
 
+use pw_runtime::{Error, Result};
 use serde::{Deserialize, Serialize};
 
 /// SameSite cookie attribute.
@@ -150,6 +151,52 @@ impl Cookie {
 		self.same_site = Some(same_site);
 		self
 	}
+
+	/// Checks the cookie against the rules Playwright and browsers enforce.
+	///
+	/// A cookie needs a non-empty name and either a `url` or a `domain` and
+	/// `path` pair. A `url` cookie cannot also set `path`, and cannot come from
+	/// `about:blank` or a `data:` URL. `expires` must be `-1` or a positive
+	/// timestamp.
+	///
+	/// `SameSite=None` without `secure` is accepted: browsers drop such cookies
+	/// on their own, and saved storage states may legitimately contain them.
+	///
+	/// [`BrowserContext::add_cookies`](crate::BrowserContext::add_cookies) runs this
+	/// before contacting the driver.
+	pub fn validate(&self) -> Result<()> {
+		let invalid = |reason: &str| Error::InvalidArgument(format!("cookie \"{}\": {reason}", self.name));
+
+		if self.name.is_empty() {
+			return Err(Error::InvalidArgument("cookie name must not be empty".to_string()));
+		}
+		match (&self.domain, &self.url) {
+			(None, None) => return Err(invalid("requires either a domain or a url")),
+			(Some(_), Some(_)) => return Err(invalid("should have either a domain or a url, not both")),
+			(None, Some(url)) => {
+				if self.path.is_some() {
+					return Err(invalid("should have either a url or a path, not both"));
+				}
+				if url == "about:blank" || url.starts_with("data:") {
+					return Err(invalid(&format!("cannot be set for url {url}")));
+				}
+			}
+			(Some(domain), None) => {
+				if domain.is_empty() {
+					return Err(invalid("domain must not be empty"));
+				}
+				if self.path.is_none() {
+					return Err(invalid("a domain cookie also requires a path"));
+				}
+			}
+		}
+		if let Some(expires) = self.expires {
+			if expires != -1.0 && expires <= 0.0 {
+				return Err(invalid("expires must be -1 or a positive Unix timestamp in seconds"));
+			}
+		}
+		Ok(())
+	}
 }
 
 /// Options for clearing cookies.
@@ -221,6 +268,41 @@ pub struct OriginState {
 	pub local_storage: Vec<LocalStorageEntry>,
 }
 
+impl OriginState {
+	/// Creates an origin with no localStorage entries.
+	///
+	/// A trailing `/` is dropped so `https://example.com/` and
+	/// `https://example.com` name the same origin.
+	pub fn new(origin: impl Into<String>) -> Self {
+		let origin = origin.into();
+		Self {
+			origin: normalize_origin(&origin).to_string(),
+			local_storage: Vec::new(),
+		}
+	}
+
+	/// Adds a localStorage entry, replacing any entry with the same key.
+	pub fn entry(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.set_item(name, value);
+		self
+	}
+
+	/// Sets a localStorage entry in place, replacing any entry with the same key.
+	pub fn set_item(&mut self, name: impl Into<String>, value: impl Into<String>) {
+		let name = name.into();
+		let value = value.into();
+		match self.local_storage.iter_mut().find(|entry| entry.name == name) {
+			Some(entry) => entry.value = value,
+			None => self.local_storage.push(LocalStorageEntry { name, value }),
+		}
+	}
+}
+
+/// Origin string with any trailing `/` removed.
+fn normalize_origin(origin: &str) -> &str {
+	origin.trim_end_matches('/')
+}
+
 /// Complete browser storage state.
 ///
 /// Contains all cookies and localStorage data that can be saved and restored
@@ -275,6 +357,52 @@ impl StorageState {
 		let content = serde_json::to_string_pretty(self).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 		std::fs::write(path, content)
 	}
+
+	/// Sets localStorage entries for `origin`, creating the origin if needed.
+	///
+	/// Origins are matched by string (ignoring a trailing `/`), so repeated calls
+	/// for the same origin extend one [`OriginState`]; existing keys are overwritten.
+	pub fn set_local_storage(&mut self, origin: impl Into<String>, entries: &[(&str, &str)]) -> &mut Self {
+		let origin = OriginState::new(origin);
+		let index = match self.origins.iter().position(|existing| normalize_origin(&existing.origin) == origin.origin) {
+			Some(index) => index,
+			None => {
+				self.origins.push(origin);
+				self.origins.len() - 1
+			}
+		};
+		for (name, value) in entries {
+			self.origins[index].set_item(*name, *value);
+		}
+		self
+	}
+
+	/// Adds an origin, folding its entries into an existing origin with the same string.
+	pub fn with_origin(mut self, origin: OriginState) -> Self {
+		let entries: Vec<(&str, &str)> = origin.local_storage.iter().map(|entry| (entry.name.as_str(), entry.value.as_str())).collect();
+		self.set_local_storage(origin.origin.as_str(), &entries);
+		self
+	}
+
+	/// Merges localStorage captured outside the protocol into this state.
+	///
+	/// Origins already present keep their entries. Opaque (`"null"`) origins and
+	/// origins without entries are skipped, and duplicate captures of the same
+	/// origin (e.g. two tabs) are folded into one. Returns the number of origins added.
+	pub fn merge_origins(&mut self, captured: impl IntoIterator<Item = OriginState>) -> usize {
+		let mut added = 0;
+		for origin in captured {
+			if origin.origin.is_empty() || origin.origin == "null" || origin.local_storage.is_empty() {
+				continue;
+			}
+			if self.origins.iter().any(|existing| existing.origin == origin.origin) {
+				continue;
+			}
+			self.origins.push(origin);
+			added += 1;
+		}
+		added
+	}
 }
 
 /// Options for the storage_state() method.
@@ -303,6 +431,63 @@ impl StorageStateOptions {
 mod tests {
 	use super::*;
 
+	fn origin(origin: &str, entries: &[(&str, &str)]) -> OriginState {
+		OriginState {
+			origin: origin.to_string(),
+			local_storage: entries
+				.iter()
+				.map(|(name, value)| LocalStorageEntry {
+					name: name.to_string(),
+					value: value.to_string(),
+				})
+				.collect(),
+		}
+	}
+
+	#[test]
+	fn set_local_storage_deduplicates_origins() {
+		let mut state = StorageState::new();
+		state
+			.set_local_storage("https://app.example.com", &[("token", "abc"), ("theme", "dark")])
+			.set_local_storage("https://app.example.com/", &[("token", "xyz")]);
+		let state = state.with_origin(OriginState::new("https://other.example.com").entry("lang", "en").entry("lang", "fr"));
+
+		assert_eq!(state.origins.len(), 2);
+		let app = &state.origins[0];
+		assert_eq!(app.origin, "https://app.example.com");
+		let entries: Vec<(&str, &str)> = app.local_storage.iter().map(|e| (e.name.as_str(), e.value.as_str())).collect();
+		assert_eq!(entries, [("token", "xyz"), ("theme", "dark")]);
+		assert_eq!(state.origins[1].local_storage.len(), 1);
+		assert_eq!(state.origins[1].local_storage[0].value, "fr");
+	}
+
+	#[test]
+	fn merge_origins_populates_empty_state_from_captures() {
+		let mut state = StorageState::new();
+		let added = state.merge_origins([
+			origin("https://app.example.com", &[("token", "abc")]),
+			origin("https://app.example.com", &[("token", "stale")]),
+			origin("null", &[("x", "1")]),
+			origin("https://empty.example.com", &[]),
+		]);
+		assert_eq!(added, 1);
+		assert_eq!(state.origins.len(), 1);
+		assert_eq!(state.origins[0].local_storage[0].value, "abc");
+	}
+
+	#[test]
+	fn merge_origins_keeps_protocol_entries() {
+		let mut state = StorageState::new();
+		state.origins.push(origin("https://app.example.com", &[("token", "protocol")]));
+		let added = state.merge_origins([
+			origin("https://app.example.com", &[("token", "captured")]),
+			origin("https://other.example.com", &[("k", "v")]),
+		]);
+		assert_eq!(added, 1);
+		assert_eq!(state.origins[0].local_storage[0].value, "protocol");
+		assert_eq!(state.origins[1].origin, "https://other.example.com");
+	}
+
 	#[test]
 	fn test_cookie_new() {
 		let cookie = Cookie::new("session", "abc123", ".example.com");
@@ -327,6 +512,43 @@ mod tests {
 		assert_eq!(cookie.same_site, Some(SameSite::Strict));
 	}
 
+	#[test]
+	fn validate_accepts_well_formed_cookies() {
+		Cookie::new("session", "abc", "example.com").path("/").validate().unwrap();
+		Cookie::from_url("token", "xyz", "https://example.com/")
+			.same_site(SameSite::None)
+			.validate()
+			.unwrap();
+		Cookie::new("cross", "1", "example.com").path("/").same_site(SameSite::None).validate().unwrap();
+	}
+
+	#[test]
+	fn validate_rejects_missing_domain_and_url() {
+		let mut cookie = Cookie::new("session", "abc", "example.com");
+		cookie.domain = None;
+		let err = cookie.validate().unwrap_err().to_string();
+		assert!(err.contains("\"session\""), "{err}");
+		assert!(err.contains("domain or a url"), "{err}");
+	}
+
+	#[test]
+	fn validate_rejects_conflicting_domain_and_url() {
+		let mut cookie = Cookie::new("session", "abc", "example.com");
+		cookie.url = Some("https://example.com/".to_string());
+		let err = cookie.validate().unwrap_err().to_string();
+		assert!(err.contains("not both"), "{err}");
+	}
+
+	#[test]
+	fn validate_rejects_bad_name_path_and_expiry() {
+		assert!(Cookie::new("", "v", "example.com").path("/").validate().is_err());
+		assert!(Cookie::from_url("a", "v", "https://example.com/").path("/x").validate().is_err());
+		assert!(Cookie::from_url("a", "v", "data:text/html,x").validate().is_err());
+		assert!(Cookie::new("a", "v", "example.com").path("/").expires(-5.0).validate().is_err());
+		let err = Cookie::new("a", "v", "example.com").validate().unwrap_err().to_string();
+		assert!(err.contains("requires a path"), "{err}");
+	}
+
 	#[test]
 	fn test_cookie_from_url() {
 		let cookie = Cookie::from_url("token", "xyz", "https://example.com/login");
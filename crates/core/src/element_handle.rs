@@ -3,16 +3,28 @@
 //! [`ElementHandle`] represents a concrete DOM node reference returned from
 //! selector queries and supports element-scoped operations.
 //!
-//! This module currently focuses on screenshot capture and protocol plumbing.
+//! This module currently focuses on screenshots, geometry, frame traversal,
+//! and protocol plumbing.
 
 use std::sync::Arc;
 
 use base64::Engine;
 use pw_runtime::Result;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Element position and size in CSS pixels, relative to the main frame viewport.
+///
+/// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-bounding-box>
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+	pub x: f64,
+	pub y: f64,
+	pub width: f64,
+	pub height: f64,
+}
+
 /// ElementHandle represents a DOM element in the page.
 ///
 /// ElementHandles are created via `page.query_selector()` or `frame.query_selector()`.
@@ -82,6 +94,46 @@ impl ElementHandle {
 
 		Ok(bytes)
 	}
+
+	/// Returns the element's bounding box, or `None` when it is not rendered.
+	///
+	/// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-bounding-box>
+	pub async fn bounding_box(&self) -> Result<Option<BoundingBox>> {
+		#[derive(Deserialize)]
+		struct BoundingBoxResponse {
+			value: Option<BoundingBox>,
+		}
+
+		let response: BoundingBoxResponse = self.base.channel().send("boundingBox", serde_json::json!({})).await?;
+		Ok(response.value)
+	}
+
+	/// Returns the content frame for `<iframe>` and `<frame>` elements, `None` otherwise.
+	///
+	/// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-content-frame>
+	pub async fn content_frame(&self) -> Result<Option<crate::Frame>> {
+		#[derive(Deserialize)]
+		struct FrameRef {
+			guid: String,
+		}
+
+		#[derive(Deserialize)]
+		struct ContentFrameResponse {
+			frame: Option<FrameRef>,
+		}
+
+		let response: ContentFrameResponse = self.base.channel().send("contentFrame", serde_json::json!({})).await?;
+		let Some(frame_ref) = response.frame else {
+			return Ok(None);
+		};
+
+		let frame_arc = self.base.connection().get_object(&frame_ref.guid).await?;
+		let frame = frame_arc
+			.downcast_ref::<crate::Frame>()
+			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Frame object, got {}", frame_arc.type_name())))?;
+
+		Ok(Some(frame.clone()))
+	}
 }
 
 impl pw_runtime::channel_owner::private::Sealed for ElementHandle {}
@@ -141,3 +193,22 @@ impl std::fmt::Debug for ElementHandle {
 		f.debug_struct("ElementHandle").field("guid", &self.guid()).finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bounding_box_deserializes_protocol_rect() {
+		let rect: BoundingBox = serde_json::from_value(serde_json::json!({ "x": 8.0, "y": 16.5, "width": 300, "height": 150 })).unwrap();
+		assert_eq!(
+			rect,
+			BoundingBox {
+				x: 8.0,
+				y: 16.5,
+				width: 300.0,
+				height: 150.0
+			}
+		);
+	}
+}
@@ -6,7 +6,7 @@
 //! [`crate::Page`] owns the main frame and forwards many high-level methods to
 //! this module.
 
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock, RwLock};
 
 use pw_runtime::channel::Channel;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
@@ -16,6 +16,7 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 use crate::page::{GotoOptions, Response};
+use crate::timeout_settings::TimeoutSettings;
 
 /// Frame represents a frame within a page.
 ///
@@ -28,6 +29,16 @@ use crate::page::{GotoOptions, Response};
 #[derive(Clone)]
 pub struct Frame {
 	base: ChannelOwnerImpl,
+	/// Load states reached by the current document, kept in sync by `loadstate` events.
+	load_states: Arc<tokio::sync::watch::Sender<Vec<String>>>,
+	/// Current URL, kept in sync by `navigated` events.
+	url: Arc<RwLock<String>>,
+	/// Frame name (the iframe's `name` or `id` attribute), kept in sync by `navigated` events.
+	name: Arc<RwLock<String>>,
+	/// GUID of the parent frame; `None` for a main frame.
+	parent_frame_guid: Option<Arc<str>>,
+	/// Default timeouts of the owning page, bound when the page hands the frame out.
+	page_timeouts: Arc<OnceLock<TimeoutSettings>>,
 }
 
 impl Frame {
@@ -36,9 +47,108 @@ impl Frame {
 	/// This is called by the object factory when the server sends a `__create__` message
 	/// for a Frame object.
 	pub fn new(parent: Arc<dyn ChannelOwner>, type_name: String, guid: Arc<str>, initializer: Value) -> Result<Self> {
+		let states = initializer["loadStates"]
+			.as_array()
+			.map(|states| states.iter().filter_map(|s| s.as_str().map(str::to_string)).collect())
+			.unwrap_or_default();
+		let url = initializer["url"].as_str().unwrap_or("about:blank").to_string();
+		let name = initializer["name"].as_str().unwrap_or_default().to_string();
+		let parent_frame_guid = initializer["parentFrame"]["guid"].as_str().map(Arc::from);
 		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent), type_name, guid, initializer);
 
-		Ok(Self { base })
+		Ok(Self {
+			base,
+			load_states: Arc::new(tokio::sync::watch::channel(states).0),
+			url: Arc::new(RwLock::new(url)),
+			name: Arc::new(RwLock::new(name)),
+			parent_frame_guid,
+			page_timeouts: Arc::default(),
+		})
+	}
+
+	/// Returns the frame's current URL.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-url>
+	pub fn url(&self) -> String {
+		self.url.read().unwrap_or_else(|e| e.into_inner()).clone()
+	}
+
+	/// Returns the frame's name, taken from the iframe's `name` or `id` attribute.
+	///
+	/// Empty for the main frame and for unnamed iframes.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-name>
+	pub fn name(&self) -> String {
+		self.name.read().unwrap_or_else(|e| e.into_inner()).clone()
+	}
+
+	/// Returns the parent frame, or `None` for a page's main frame.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-parent-frame>
+	pub async fn parent_frame(&self) -> Result<Option<Frame>> {
+		let Some(guid) = &self.parent_frame_guid else {
+			return Ok(None);
+		};
+		let parent = self.connection().get_object(guid).await?;
+		let frame = parent
+			.downcast_ref::<Frame>()
+			.ok_or_else(|| Error::ProtocolError(format!("Expected Frame object, got {}", parent.type_name())))?;
+		Ok(Some(frame.clone()))
+	}
+
+	/// Waits until the frame's document reaches `state`.
+	///
+	/// Returns immediately when the state was already reached. Only `Load`,
+	/// `DomContentLoaded`, and `NetworkIdle` are valid; `Commit` is rejected.
+	/// `None` uses Playwright's default timeout; `Duration::ZERO` waits indefinitely.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-wait-for-load-state>
+	pub async fn wait_for_load_state(&self, state: crate::WaitUntil, timeout: Option<std::time::Duration>) -> Result<()> {
+		if state == crate::WaitUntil::Commit {
+			return Err(Error::InvalidArgument(
+				"wait_for_load_state accepts load, domcontentloaded, or networkidle".to_string(),
+			));
+		}
+		let name = state.as_str();
+		let mut rx = self.load_states.subscribe();
+		let reached = async move {
+			rx.wait_for(|states| states.iter().any(|s| s == name))
+				.await
+				.map(|_| ())
+				.map_err(|_| Error::ChannelClosed)
+		};
+
+		let timeout = timeout.unwrap_or(std::time::Duration::from_millis(self.default_navigation_timeout() as u64));
+		if timeout.is_zero() {
+			return reached.await;
+		}
+		tokio::time::timeout(timeout, reached)
+			.await
+			.map_err(|_| Error::Timeout(format!("Waiting for load state \"{name}\" timed out after {timeout:?}")))?
+	}
+
+	/// Makes this frame use `timeouts` (its page's defaults) for calls without a timeout.
+	pub(crate) fn bind_page_timeouts(&self, timeouts: &TimeoutSettings) {
+		let _ = self.page_timeouts.set(timeouts.clone());
+	}
+
+	/// Default timeouts: the page's once bound, else the owning context's.
+	fn timeouts(&self) -> TimeoutSettings {
+		if let Some(timeouts) = self.page_timeouts.get() {
+			return timeouts.clone();
+		}
+		self.base
+			.parent()
+			.and_then(|parent| parent.downcast_ref::<crate::BrowserContext>().map(|context| context.timeouts().clone()))
+			.unwrap_or_default()
+	}
+
+	fn default_timeout(&self) -> f64 {
+		self.timeouts().timeout()
+	}
+
+	fn default_navigation_timeout(&self) -> f64 {
+		self.timeouts().navigation_timeout()
 	}
 
 	/// Returns the channel for sending protocol messages
@@ -52,7 +162,7 @@ impl Frame {
 	///
 	/// Returns `None` when navigating to URLs that don't produce responses (e.g., data URLs,
 	/// about:blank). This matches Playwright's behavior across all language bindings.
-	/// When no timeout is provided, this method sends Playwright's default timeout value
+	/// When no timeout is provided, this method sends the page's default navigation timeout
 	/// because recent server versions require an explicit timeout field.
 	/// Response objects may be created asynchronously after the RPC resolves, so this
 	/// method waits briefly for the referenced response guid to materialize.
@@ -72,13 +182,13 @@ impl Frame {
 			if let Some(timeout) = opts.timeout {
 				params["timeout"] = serde_json::json!(timeout.as_millis() as u64);
 			} else {
-				params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
+				params["timeout"] = serde_json::json!(self.default_navigation_timeout());
 			}
 			if let Some(wait_until) = opts.wait_until {
 				params["waitUntil"] = serde_json::json!(wait_until.as_str());
 			}
 		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
+			params["timeout"] = serde_json::json!(self.default_navigation_timeout());
 		}
 
 		#[derive(Deserialize)]
@@ -122,6 +232,7 @@ impl Frame {
 				status_text: initializer["statusText"].as_str().unwrap_or("").to_string(),
 				ok: (200..300).contains(&status),
 				headers,
+				object: response_arc.downcast_ref::<crate::ResponseObject>().cloned(),
 			}))
 		} else {
 			Ok(None)
@@ -141,6 +252,30 @@ impl Frame {
 		Ok(response.value)
 	}
 
+	/// Clicks the element matching `selector` in this frame.
+	///
+	/// Selectors resolve against this frame's document, so actions inside
+	/// (nested) iframes need no per-call [`Locator`](crate::Locator).
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-click>
+	pub async fn click(&self, selector: &str, options: Option<crate::ClickOptions>) -> Result<()> {
+		self.locator_click(selector, options).await
+	}
+
+	/// Fills the input matching `selector` in this frame with `value`.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-fill>
+	pub async fn fill(&self, selector: &str, value: &str, options: Option<crate::FillOptions>) -> Result<()> {
+		self.locator_fill(selector, value, options).await
+	}
+
+	/// Returns the `textContent` of the element matching `selector` in this frame.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-text-content>
+	pub async fn text_content(&self, selector: &str) -> Result<Option<String>> {
+		self.locator_text_content(selector).await
+	}
+
 	/// Returns the first element matching the selector, or None if not found.
 	/// Playwright may encode the handle in either `element`, `handle`, or directly as
 	/// the response object depending on transport shape.
@@ -229,6 +364,46 @@ impl Frame {
 		Ok(handles)
 	}
 
+	/// Adds a `<style>` (inline content) or `<link rel="stylesheet">` (URL) to the frame.
+	///
+	/// A [`TagSource::Path`] is read locally and injected as inline content with a
+	/// `sourceURL` comment. Resolves once the stylesheet has loaded.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-add-style-tag>
+	pub async fn add_style_tag(&self, source: TagSource) -> Result<Arc<crate::ElementHandle>> {
+		let params = source.into_params(|path| format!("\n/*# sourceURL={}*/", path.display()))?;
+		let response: Value = self.channel().send("addStyleTag", params).await?;
+		self.element_from_response(&response).await
+	}
+
+	/// Adds a `<script>` tag with inline content or a `src` URL to the frame.
+	///
+	/// `script_type` sets the tag's `type` attribute, e.g. `module`. A
+	/// [`TagSource::Path`] is read locally and injected as inline content with a
+	/// `sourceURL` comment. Resolves once the script has loaded.
+	///
+	/// See: <https://playwright.dev/docs/api/class-frame#frame-add-script-tag>
+	pub async fn add_script_tag(&self, source: TagSource, script_type: Option<&str>) -> Result<Arc<crate::ElementHandle>> {
+		let mut params = source.into_params(|path| format!("\n//# sourceURL={}", path.display()))?;
+		if let Some(script_type) = script_type {
+			params["type"] = serde_json::json!(script_type);
+		}
+		let response: Value = self.channel().send("addScriptTag", params).await?;
+		self.element_from_response(&response).await
+	}
+
+	/// Resolves the `{ "element": { "guid": ... } }` result of a tag injection.
+	async fn element_from_response(&self, response: &Value) -> Result<Arc<crate::ElementHandle>> {
+		let guid = response["element"]["guid"]
+			.as_str()
+			.ok_or_else(|| Error::ProtocolError("Element GUID missing".to_string()))?;
+		let element = self.base.connection().get_object(guid).await?;
+		element
+			.downcast_ref::<crate::ElementHandle>()
+			.map(|e| Arc::new(e.clone()))
+			.ok_or_else(|| Error::ProtocolError(format!("Object {} is not an ElementHandle", guid)))
+	}
+
 	// Locator delegate methods
 	// These are called by Locator to perform actual queries
 
@@ -267,7 +442,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -289,7 +464,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -311,7 +486,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -334,7 +509,7 @@ impl Frame {
 					"selector": selector,
 					"name": name,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -356,7 +531,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -378,7 +553,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -400,7 +575,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -422,7 +597,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
+					"timeout": self.default_timeout()
 				}),
 			)
 			.await?;
@@ -480,6 +655,7 @@ impl Frame {
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -487,9 +663,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("click", params).await.map_err(|e| match e {
 			Error::Timeout(msg) => Error::Timeout(format!("{} (selector: '{}')", msg, selector)),
@@ -504,6 +679,7 @@ impl Frame {
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -511,9 +687,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("dblclick", params).await
 	}
@@ -526,6 +701,7 @@ impl Frame {
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -533,9 +709,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("fill", params).await
 	}
@@ -548,6 +723,7 @@ impl Frame {
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -555,9 +731,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("fill", params).await
 	}
@@ -570,6 +745,7 @@ impl Frame {
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -577,19 +753,51 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("press", params).await
 	}
 
+	/// Waits until the element matching `selector` reaches `state`.
+	pub(crate) async fn locator_wait_for(&self, selector: &str, state: crate::WaitForSelectorState, timeout: Option<f64>) -> Result<()> {
+		self.channel()
+			.send_no_result(
+				"waitForSelector",
+				serde_json::json!({
+					"selector": selector,
+					"strict": true,
+					"state": state.as_str(),
+					"timeout": timeout.unwrap_or_else(|| self.default_timeout())
+				}),
+			)
+			.await
+	}
+
+	/// Focuses the element and types `text` one key at a time.
+	pub(crate) async fn locator_type(&self, selector: &str, text: &str, options: Option<crate::KeyboardOptions>) -> Result<()> {
+		let mut params = serde_json::json!({
+			"selector": selector,
+			"text": text,
+			"strict": true,
+			"timeout": self.default_timeout()
+		});
+
+		let opts = options.map(|opts| opts.to_json()).unwrap_or_default();
+		if let (Some(obj), Some(opts)) = (params.as_object_mut(), opts.as_object()) {
+			obj.extend(opts.clone());
+		}
+
+		self.channel().send_no_result("type", params).await
+	}
+
 	pub(crate) async fn locator_check(&self, selector: &str, options: Option<crate::CheckOptions>) -> Result<()> {
 		let mut params = serde_json::json!({
 			"selector": selector,
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -597,9 +805,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("check", params).await
 	}
@@ -610,6 +817,7 @@ impl Frame {
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -617,9 +825,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("uncheck", params).await
 	}
@@ -630,6 +837,7 @@ impl Frame {
 			"strict": true
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -637,13 +845,17 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		self.channel().send_no_result("hover", params).await
 	}
 
+	/// Draws Playwright's debug overlay over the elements matching `selector`.
+	pub(crate) async fn locator_highlight(&self, selector: &str) -> Result<()> {
+		self.channel().send_no_result("highlight", serde_json::json!({ "selector": selector })).await
+	}
+
 	pub(crate) async fn locator_input_value(&self, selector: &str) -> Result<String> {
 		#[derive(Deserialize)]
 		struct InputValueResponse {
@@ -657,7 +869,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS  // Required in Playwright 1.56.1+
+					"timeout": self.default_timeout()  // Required in Playwright 1.56.1+
 				}),
 			)
 			.await?;
@@ -677,6 +889,7 @@ impl Frame {
 			"options": [value.to_json()]
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -684,10 +897,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			// No options provided, add default timeout (required in Playwright 1.56.1+)
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		let response: SelectOptionResponse = self.channel().send("selectOption", params).await?;
 
@@ -713,6 +924,7 @@ impl Frame {
 			"options": values_array
 		});
 
+		let timeout = options.as_ref().and_then(|opts| opts.timeout);
 		if let Some(opts) = options {
 			let opts_json = opts.to_json();
 			if let Some(obj) = params.as_object_mut() {
@@ -720,10 +932,8 @@ impl Frame {
 					obj.extend(opts_obj.clone());
 				}
 			}
-		} else {
-			// No options provided, add default timeout (required in Playwright 1.56.1+)
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
 		}
+		params["timeout"] = serde_json::json!(timeout.unwrap_or_else(|| self.default_timeout()));
 
 		let response: SelectOptionResponse = self.channel().send("selectOption", params).await?;
 
@@ -731,81 +941,12 @@ impl Frame {
 	}
 
 	pub(crate) async fn locator_set_input_files(&self, selector: &str, file: &std::path::PathBuf) -> Result<()> {
-		use std::io::Read;
-
-		use base64::Engine as _;
-		use base64::engine::general_purpose;
-
-		// Read file contents
-		let mut file_handle = std::fs::File::open(file)?;
-		let mut buffer = Vec::new();
-		file_handle.read_to_end(&mut buffer)?;
-
-		// Base64 encode the file contents
-		let base64_content = general_purpose::STANDARD.encode(&buffer);
-
-		// Get file name
-		let file_name = file
-			.file_name()
-			.and_then(|n| n.to_str())
-			.ok_or_else(|| pw_runtime::Error::InvalidArgument("Invalid file path".to_string()))?;
-
-		self.channel()
-			.send_no_result(
-				"setInputFiles",
-				serde_json::json!({
-					"selector": selector,
-					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS,  // Required in Playwright 1.56.1+
-					"payloads": [{
-						"name": file_name,
-						"buffer": base64_content
-					}]
-				}),
-			)
-			.await
+		self.locator_set_input_files_multiple(selector, &[file]).await
 	}
 
 	pub(crate) async fn locator_set_input_files_multiple(&self, selector: &str, files: &[&std::path::PathBuf]) -> Result<()> {
-		use std::io::Read;
-
-		use base64::Engine as _;
-		use base64::engine::general_purpose;
-
-		// If empty array, clear the files
-		if files.is_empty() {
-			return self
-				.channel()
-				.send_no_result(
-					"setInputFiles",
-					serde_json::json!({
-						"selector": selector,
-						"strict": true,
-						"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS,  // Required in Playwright 1.56.1+
-						"payloads": []
-					}),
-				)
-				.await;
-		}
-
-		// Read and encode each file
-		let mut file_objects = Vec::new();
-		for file_path in files {
-			let mut file_handle = std::fs::File::open(file_path)?;
-			let mut buffer = Vec::new();
-			file_handle.read_to_end(&mut buffer)?;
-
-			let base64_content = general_purpose::STANDARD.encode(&buffer);
-			let file_name = file_path
-				.file_name()
-				.and_then(|n| n.to_str())
-				.ok_or_else(|| pw_runtime::Error::InvalidArgument("Invalid file path".to_string()))?;
-
-			file_objects.push(serde_json::json!({
-				"name": file_name,
-				"buffer": base64_content
-			}));
-		}
+		// An empty list clears the input.
+		let payloads = files.iter().map(|path| file_upload_payload(path)).collect::<Result<Vec<_>>>()?;
 
 		self.channel()
 			.send_no_result(
@@ -813,8 +954,8 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS,  // Required in Playwright 1.56.1+
-					"payloads": file_objects
+					"timeout": self.default_timeout(),  // Required in Playwright 1.56.1+
+					"payloads": payloads
 				}),
 			)
 			.await
@@ -833,7 +974,7 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS,
+					"timeout": self.default_timeout(),
 					"payloads": [{
 						"name": file.name,
 						"mimeType": file.mime_type,
@@ -857,7 +998,7 @@ impl Frame {
 					serde_json::json!({
 						"selector": selector,
 						"strict": true,
-						"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS,
+						"timeout": self.default_timeout(),
 						"payloads": []
 					}),
 				)
@@ -883,13 +1024,56 @@ impl Frame {
 				serde_json::json!({
 					"selector": selector,
 					"strict": true,
-					"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS,
+					"timeout": self.default_timeout(),
 					"payloads": file_objects
 				}),
 			)
 			.await
 	}
 
+	/// Evaluates `expression` with the single element matching `selector` as its first argument.
+	///
+	/// Uses strict mode, so the call fails when the selector matches multiple elements.
+	pub(crate) async fn locator_evaluate(&self, selector: &str, expression: &str, arg: Option<&serde_json::Value>) -> Result<serde_json::Value> {
+		#[derive(Deserialize)]
+		struct EvaluateResult {
+			value: serde_json::Value,
+		}
+
+		let params = serde_json::json!({
+			"selector": selector,
+			"strict": true,
+			"expression": expression,
+			"arg": {
+				"value": Self::json_to_protocol_value(arg.unwrap_or(&Value::Null)),
+				"handles": []
+			}
+		});
+
+		let result: EvaluateResult = self.channel().send("evalOnSelector", params).await?;
+		Self::protocol_value_to_json(&result.value)
+	}
+
+	/// Evaluates `expression` with an array of all elements matching `selector` as its first argument.
+	pub(crate) async fn locator_evaluate_all(&self, selector: &str, expression: &str) -> Result<serde_json::Value> {
+		#[derive(Deserialize)]
+		struct EvaluateResult {
+			value: serde_json::Value,
+		}
+
+		let params = serde_json::json!({
+			"selector": selector,
+			"expression": expression,
+			"arg": {
+				"value": {"v": "undefined"},
+				"handles": []
+			}
+		});
+
+		let result: EvaluateResult = self.channel().send("evalOnSelectorAll", params).await?;
+		Self::protocol_value_to_json(&result.value)
+	}
+
 	/// Evaluates JavaScript expression in the frame context (without return value).
 	///
 	/// This is used internally by Page.evaluate().
@@ -1084,6 +1268,25 @@ impl Frame {
 			_ => Ok(value.clone()),
 		}
 	}
+
+	/// Converts standard JSON into the Playwright protocol value format.
+	///
+	/// This is the inverse of [`Self::protocol_value_to_json`] for plain JSON values.
+	fn json_to_protocol_value(value: &serde_json::Value) -> serde_json::Value {
+		match value {
+			Value::Null => serde_json::json!({"v": "null"}),
+			Value::Bool(b) => serde_json::json!({"b": b}),
+			Value::Number(n) => serde_json::json!({"n": n}),
+			Value::String(s) => serde_json::json!({"s": s}),
+			Value::Array(items) => serde_json::json!({"a": items.iter().map(Self::json_to_protocol_value).collect::<Vec<_>>()}),
+			Value::Object(map) => serde_json::json!({
+				"o": map
+					.iter()
+					.map(|(k, v)| serde_json::json!({"k": k, "v": Self::json_to_protocol_value(v)}))
+					.collect::<Vec<_>>()
+			}),
+		}
+	}
 }
 
 impl pw_runtime::channel_owner::private::Sealed for Frame {}
@@ -1129,9 +1332,31 @@ impl ChannelOwner for Frame {
 		self.base.remove_child(guid)
 	}
 
-	fn on_event(&self, _method: &str, _params: Value) {
-		// Frame-specific event handling is intentionally deferred until dedicated
-		// subscriptions are exposed on the public API.
+	fn on_event(&self, method: &str, params: Value) {
+		// Only URL, name and load-state bookkeeping is handled here; other frame
+		// events are observed through raw connection subscriptions.
+		if method == "navigated" {
+			if let Some(url) = params["url"].as_str() {
+				*self.url.write().unwrap_or_else(|e| e.into_inner()) = url.to_string();
+			}
+			if let Some(name) = params["name"].as_str() {
+				*self.name.write().unwrap_or_else(|e| e.into_inner()) = name.to_string();
+			}
+			return;
+		}
+		if method != "loadstate" {
+			return;
+		}
+		if let Some(added) = params["add"].as_str() {
+			self.load_states.send_modify(|states| {
+				if !states.iter().any(|s| s == added) {
+					states.push(added.to_string());
+				}
+			});
+		}
+		if let Some(removed) = params["remove"].as_str() {
+			self.load_states.send_modify(|states| states.retain(|s| s != removed));
+		}
 	}
 
 	fn was_collected(&self) -> bool {
@@ -1139,8 +1364,140 @@ impl ChannelOwner for Frame {
 	}
 }
 
+/// Where the content of an injected `<style>` or `<script>` tag comes from.
+///
+/// See [`Frame::add_style_tag`] and [`Frame::add_script_tag`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagSource {
+	/// URL the page loads the stylesheet or script from.
+	Url(String),
+	/// Local file whose contents are injected inline.
+	Path(std::path::PathBuf),
+	/// Inline CSS or JavaScript.
+	Content(String),
+}
+
+impl TagSource {
+	/// Builds the `url`/`content` protocol params, reading `Path` sources and
+	/// appending the `sourceURL` comment produced by `source_url`.
+	fn into_params(self, source_url: impl FnOnce(&std::path::Path) -> String) -> Result<Value> {
+		Ok(match self {
+			TagSource::Url(url) => serde_json::json!({ "url": url }),
+			TagSource::Content(content) => serde_json::json!({ "content": content }),
+			TagSource::Path(path) => {
+				let mut content = std::fs::read_to_string(&path).map_err(|err| Error::InvalidArgument(format!("cannot read {}: {err}", path.display())))?;
+				content.push_str(&source_url(&path));
+				serde_json::json!({ "content": content })
+			}
+		})
+	}
+}
+
+/// Reads `path` into a `setInputFiles` payload, rejecting missing or non-file paths.
+fn file_upload_payload(path: &std::path::Path) -> Result<Value> {
+	use base64::Engine as _;
+	use base64::engine::general_purpose;
+
+	if !path.is_file() {
+		return Err(Error::InvalidArgument(format!("upload file not found: {}", path.display())));
+	}
+	let name = path
+		.file_name()
+		.and_then(|n| n.to_str())
+		.ok_or_else(|| Error::InvalidArgument("Invalid file path".to_string()))?;
+	let buffer = std::fs::read(path)?;
+
+	Ok(serde_json::json!({
+		"name": name,
+		"buffer": general_purpose::STANDARD.encode(&buffer)
+	}))
+}
+
 impl std::fmt::Debug for Frame {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("Frame").field("guid", &self.guid()).finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock_driver::MockDriver;
+
+	#[test]
+	fn json_round_trips_through_protocol_format() {
+		let value = serde_json::json!({"id": "b2", "n": 3, "ok": true, "tags": ["x", null], "nested": {"k": 1.5}});
+		let encoded = Frame::json_to_protocol_value(&value);
+		assert_eq!(encoded["o"][0]["k"], "id");
+		assert_eq!(Frame::protocol_value_to_json(&encoded).unwrap(), value);
+	}
+
+	#[test]
+	fn upload_payload_requires_existing_file() {
+		let dir = std::env::temp_dir().join(format!("pw-rs-upload-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let file = dir.join("notes.txt");
+		std::fs::write(&file, "hi").unwrap();
+
+		let payload = file_upload_payload(&file).unwrap();
+		assert_eq!(payload["name"], "notes.txt");
+		assert_eq!(payload["buffer"], "aGk=");
+
+		let err = file_upload_payload(&dir.join("missing.txt")).unwrap_err();
+		assert!(matches!(err, Error::InvalidArgument(_)), "{err}");
+		assert!(matches!(file_upload_payload(&dir), Err(Error::InvalidArgument(_))));
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[test]
+	fn tag_source_inlines_path_with_source_url() {
+		let dir = std::env::temp_dir().join(format!("pw-rs-tag-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let file = dir.join("helper.css");
+		std::fs::write(&file, "h1 { color: red; }").unwrap();
+
+		let params = TagSource::Path(file.clone()).into_params(|path| format!("/*{}*/", path.display())).unwrap();
+		assert_eq!(params["content"], format!("h1 {{ color: red; }}/*{}*/", file.display()));
+		assert!(params.get("url").is_none());
+
+		let params = TagSource::Url("https://cdn.example/x.css".into()).into_params(|_| unreachable!()).unwrap();
+		assert_eq!(params, serde_json::json!({ "url": "https://cdn.example/x.css" }));
+
+		let err = TagSource::Path(dir.join("missing.css")).into_params(|_| String::new()).unwrap_err();
+		assert!(matches!(err, Error::InvalidArgument(_)), "{err}");
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[tokio::test]
+	async fn goto_with_zero_timeout_sends_zero() {
+		let mut driver = MockDriver::start();
+		let frame = driver.frame("frame@main");
+		let options = GotoOptions::new().timeout(std::time::Duration::ZERO);
+		let call = tokio::spawn(async move { frame.goto("about:blank", Some(options)).await });
+
+		let request = driver.next_request().await;
+		assert_eq!(request["method"], "goto");
+		assert_eq!(request["params"]["timeout"], 0, "zero must reach the driver as 'no timeout'");
+
+		driver.respond(&request, serde_json::json!({})).await;
+		assert!(call.await.unwrap().unwrap().is_none());
+	}
+
+	#[tokio::test]
+	async fn fill_sends_frame_scoped_fill_rpc() {
+		let mut driver = MockDriver::start();
+		let frame = driver.frame("frame@child");
+		let call = tokio::spawn(async move { frame.fill("input[name=q]", "hello", None).await });
+
+		let request = driver.next_request().await;
+		assert_eq!(request["guid"], "frame@child");
+		assert_eq!(request["method"], "fill");
+		assert_eq!(request["params"]["selector"], "input[name=q]");
+		assert_eq!(request["params"]["value"], "hello");
+
+		driver.respond(&request, serde_json::json!({})).await;
+		call.await.unwrap().unwrap();
+	}
+}
@@ -4,9 +4,13 @@
 //! `type`) backed by page protocol calls.
 //!
 //! It mirrors Playwright keyboard semantics while keeping call sites strongly
-//! typed in Rust.
+//! typed in Rust. IME composition (`ime_set_composition`, `ime_commit`) has no
+//! Playwright equivalent and is driven over a Chromium CDP session instead.
 
-use pw_runtime::Result;
+use std::ops::Range;
+
+use pw_runtime::{Error, Result};
+use serde_json::{Value, json};
 
 use crate::page::Page;
 
@@ -58,4 +62,42 @@ impl Keyboard {
 	pub async fn insert_text(&self, text: &str) -> Result<()> {
 		self.page.keyboard_insert_text(text).await
 	}
+
+	/// Starts or updates an IME composition with `text` as the pending string.
+	///
+	/// `selection` is the caret/selection inside the composition, in UTF-16
+	/// code units. The focused element receives `compositionstart` (first
+	/// call) and `compositionupdate` events. Chromium only; other engines
+	/// return [`Error::UnsupportedMode`].
+	pub async fn ime_set_composition(&self, text: &str, selection: Range<usize>) -> Result<()> {
+		self.send_ime(
+			"Input.imeSetComposition",
+			json!({
+				"text": text,
+				"selectionStart": selection.start,
+				"selectionEnd": selection.end,
+			}),
+		)
+		.await
+	}
+
+	/// Commits `text`, ending any pending IME composition with `compositionend`.
+	///
+	/// Chromium only; other engines return [`Error::UnsupportedMode`].
+	pub async fn ime_commit(&self, text: &str) -> Result<()> {
+		self.send_ime("Input.insertText", json!({ "text": text })).await
+	}
+
+	async fn send_ime(&self, method: &str, params: Value) -> Result<()> {
+		let context = self.page.context()?;
+		if let Some(engine) = context.engine_name().filter(|name| name != "chromium") {
+			return Err(Error::UnsupportedMode(format!("IME composition requires Chromium, not {engine}")));
+		}
+
+		let cdp = context.new_cdp_session(&self.page).await?;
+		let sent = cdp.send(method, params).await;
+		let detached = cdp.detach().await;
+		sent?;
+		detached
+	}
 }
@@ -143,7 +143,7 @@
 //!
 //!     // Assert checkbox state
 //!     let checkbox = page.locator("#checked").await;
-//!     expect(checkbox).to_be_checked().await?;
+//!     expect(checkbox).to_be_checked(None).await?;
 //!
 //!     browser.close().await?;
 //!     Ok(())
@@ -152,6 +152,8 @@
 
 mod assertions;
 mod init;
+#[cfg(test)]
+mod mock_driver;
 mod object_factory;
 
 pub mod accessibility;
@@ -160,6 +162,7 @@ pub mod artifact;
 pub mod browser;
 pub mod browser_context;
 pub mod browser_type;
+pub mod cdp_session;
 pub mod click;
 pub mod cookie;
 pub mod dialog;
@@ -174,6 +177,7 @@ pub mod launch_options;
 pub mod locator;
 pub mod mouse;
 pub mod page;
+pub mod pdf;
 pub mod playwright;
 pub mod request;
 pub mod response;
@@ -181,6 +185,7 @@ pub mod root;
 pub mod route;
 pub mod screenshot;
 pub mod select_option;
+mod timeout_settings;
 pub mod tracing;
 pub mod video;
 
@@ -189,33 +194,36 @@ pub use accessibility::{
 };
 pub use action_options::{CheckOptions, FillOptions, HoverOptions, KeyboardOptions, MouseOptions, PressOptions, SelectOptions};
 // Re-export assertions
-pub use assertions::{Expectation, expect};
+pub use assertions::{Expectation, ValuePattern, expect};
 pub use browser::Browser;
 pub use browser_context::{
-	BrowserContext, BrowserContextOptions, BrowserContextOptionsBuilder, Geolocation, HarContentPolicy, HarMode, HarNotFound, HarStartOptions,
-	RouteFromHarOptions, Viewport,
+	BrowserContext, BrowserContextOptions, BrowserContextOptionsBuilder, ColorScheme, ForcedColors, Geolocation, HarContentPolicy, HarMode, HarNotFound,
+	HarStartOptions, ReducedMotion, RouteFromHarOptions, Viewport,
 };
-pub use browser_type::{BrowserType, ConnectOverCDPResult, LaunchedServer};
+pub use browser_type::{BrowserType, ConnectOptions, ConnectOverCDPResult, LaunchedServer};
+pub use cdp_session::{CdpEvent, CdpSession};
 pub use click::{ClickOptions, KeyboardModifier, MouseButton, Position};
 pub use cookie::{ClearCookiesOptions, Cookie, LocalStorageEntry, OriginState, SameSite, StorageState, StorageStateOptions};
 pub use dialog::Dialog;
 pub use download::Download;
-pub use element_handle::ElementHandle;
+pub use element_handle::{BoundingBox, ElementHandle};
 pub use events::{ConsoleSubscription, EventStream, EventWaiter};
 pub use file_payload::{FilePayload, FilePayloadBuilder};
-pub use frame::Frame;
+pub use frame::{Frame, TagSource};
 // Re-export initialization function
 pub use init::initialize_playwright;
 pub use keyboard::Keyboard;
 pub use launch_options::{IgnoreDefaultArgs, LaunchOptions, ProxySettings};
 pub use locator::Locator;
 pub use mouse::Mouse;
-pub use page::{ConsoleLocation, ConsoleMessage, ConsoleMessageKind, GotoOptions, Page, Response, Subscription, WaitUntil};
+pub use page::{ConsoleLocation, ConsoleMessage, ConsoleMessageKind, GotoOptions, NetworkPreset, Page, Response, Subscription, WaitUntil};
+pub use pdf::{PdfMargin, PdfOptions, PdfOptionsBuilder};
 pub use playwright::Playwright;
+pub use pw_protocol::options::WaitForSelectorState;
 pub use request::Request;
-pub use response::ResponseObject;
+pub use response::{RemoteAddr, ResponseObject, SecurityDetails};
 pub use root::Root;
-pub use route::{ContinueOptions, ContinueOptionsBuilder, FulfillOptions, FulfillOptionsBuilder, Route};
+pub use route::{AbortReason, ContinueOptions, ContinueOptionsBuilder, FulfillOptions, FulfillOptionsBuilder, Route};
 pub use screenshot::{ScreenshotClip, ScreenshotOptions, ScreenshotType};
 pub use select_option::SelectOption;
 pub use tracing::{Tracing, TracingStartChunkOptions, TracingStartOptions, TracingStartOptionsBuilder, TracingStopOptions};
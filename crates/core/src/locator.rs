@@ -11,6 +11,45 @@ use pw_runtime::Result;
 
 use crate::Frame;
 
+/// Collects an element's text through the composed tree: open shadow roots
+/// replace their host's light children and slots yield their assigned nodes.
+///
+/// Subtrees without shadow hosts or slots use the native `innerText` /
+/// `textContent`, so plain DOM text is unchanged. Each tree is scanned once to
+/// mark the shadow hosts, slots and their ancestors.
+const COMPOSED_TEXT_JS: &str = r#"(root, rendered) => {
+	const marked = new Set();
+	const mark = (scope) => {
+		for (const el of scope.querySelectorAll('*')) {
+			if (!el.shadowRoot && el.localName !== 'slot') continue;
+			for (let node = el; node && node !== scope && !marked.has(node); node = node.parentNode) marked.add(node);
+		}
+	};
+	mark(root);
+	if (marked.size || root.shadowRoot || root.localName === 'slot') marked.add(root);
+	const composed = (el) => marked.has(el);
+	const flat = (text) => (rendered ? text.replace(/\s+/g, ' ') : text);
+	const visit = (node) => {
+		if (node.nodeType === Node.TEXT_NODE) return flat(node.data);
+		if (node.nodeType !== Node.ELEMENT_NODE) return '';
+		const display = rendered ? getComputedStyle(node).display : 'inline';
+		if (display === 'none' || (rendered && ['script', 'style', 'template', 'noscript'].includes(node.localName))) return '';
+		if (!composed(node)) return rendered ? node.innerText : node.textContent;
+		let children = node.childNodes;
+		if (node.shadowRoot) {
+			mark(node.shadowRoot);
+			children = node.shadowRoot.childNodes;
+		} else if (node.localName === 'slot') {
+			const assigned = node.assignedNodes({ flatten: true });
+			if (assigned.length) children = assigned;
+		}
+		const text = [...children].map(visit).join('');
+		const block = !display.startsWith('inline') && display !== 'contents';
+		return block ? `\n${text}\n` : text;
+	};
+	return visit(root);
+}"#;
+
 /// Locator represents a way to find element(s) on the page at any given moment.
 ///
 /// Locators are lazy - they don't execute queries until an action is performed.
@@ -133,6 +172,31 @@ impl Locator {
 		Locator::new(Arc::clone(&self.frame), format!("{} >> {}", self.selector, selector))
 	}
 
+	/// Creates a locator matching elements that match this locator, `other`, or both.
+	///
+	/// Useful for fallback selectors. Actions on the result stay strict, so when
+	/// both sides can match, narrow it with [`first`](Self::first). `other` must
+	/// belong to the same frame; only its selector is used.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-or>
+	pub fn or_(&self, other: &Locator) -> Locator {
+		self.combine("internal:or", other)
+	}
+
+	/// Creates a locator matching elements that match both this locator and `other`.
+	///
+	/// `other` must belong to the same frame; only its selector is used.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-and>
+	pub fn and_(&self, other: &Locator) -> Locator {
+		self.combine("internal:and", other)
+	}
+
+	/// Chains `other` through one of Playwright's combinator engines.
+	fn combine(&self, engine: &str, other: &Locator) -> Locator {
+		Locator::new(Arc::clone(&self.frame), combined_selector(&self.selector, engine, &other.selector))
+	}
+
 	/// Returns the number of elements matching this locator.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-count>
@@ -140,10 +204,21 @@ impl Locator {
 		self.frame.locator_count(&self.selector).await
 	}
 
-	/// Returns the text content of the element.
+	/// Returns the text content of the element, including text from open
+	/// shadow roots and slotted content as the component renders it.
+	///
+	/// Waits for the element to be attached; uses strict mode.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-text-content>
 	pub async fn text_content(&self) -> Result<Option<String>> {
+		let value = self.composed_text(false).await?;
+		Ok(value.as_str().map(String::from))
+	}
+
+	/// Returns the element's own `textContent`, ignoring shadow roots.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-text-content>
+	pub async fn light_text_content(&self) -> Result<Option<String>> {
 		self.frame.locator_text_content(&self.selector).await
 	}
 
@@ -154,6 +229,20 @@ impl Locator {
 		self.frame.locator_inner_text(&self.selector).await
 	}
 
+	/// Like [`inner_text`](Self::inner_text), but includes visible text from
+	/// open shadow roots and slotted content.
+	///
+	/// Waits for the element to be attached; uses strict mode.
+	pub async fn composed_inner_text(&self) -> Result<String> {
+		let value = self.composed_text(true).await?;
+		Ok(value.as_str().unwrap_or_default().to_string())
+	}
+
+	async fn composed_text(&self, rendered: bool) -> Result<serde_json::Value> {
+		self.wait_for(crate::WaitForSelectorState::Attached, None).await?;
+		self.evaluate(COMPOSED_TEXT_JS, Some(serde_json::Value::Bool(rendered))).await
+	}
+
 	/// Returns the inner HTML of the element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-inner-html>
@@ -161,13 +250,33 @@ impl Locator {
 		self.frame.locator_inner_html(&self.selector).await
 	}
 
-	/// Returns the value of the specified attribute.
+	/// Returns the value of the specified attribute, or `None` when the element lacks it.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-get-attribute>
 	pub async fn get_attribute(&self, name: &str) -> Result<Option<String>> {
 		self.frame.locator_get_attribute(&self.selector, name).await
 	}
 
+	/// Evaluates a JavaScript function with the matched element as its first argument.
+	///
+	/// `arg` is passed as the second argument and must be plain JSON. The return
+	/// value is converted to JSON. Uses strict mode, so the locator must resolve
+	/// to exactly one element.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate>
+	pub async fn evaluate(&self, expression: &str, arg: Option<serde_json::Value>) -> Result<serde_json::Value> {
+		self.frame.locator_evaluate(&self.selector, expression, arg.as_ref()).await
+	}
+
+	/// Evaluates a JavaScript function with an array of all matched elements as its first argument.
+	///
+	/// Resolves to an empty array (not an error) when nothing matches.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate-all>
+	pub async fn evaluate_all(&self, expression: &str) -> Result<serde_json::Value> {
+		self.frame.locator_evaluate_all(&self.selector, expression).await
+	}
+
 	/// Returns whether the element is visible.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-is-visible>
@@ -189,6 +298,19 @@ impl Locator {
 		self.frame.locator_is_checked(&self.selector).await
 	}
 
+	/// Returns whether the checkbox is in the mixed (tri-state) state.
+	///
+	/// True when the element's `indeterminate` property is set or it has
+	/// `aria-checked="mixed"`. Uses strict mode and waits for the element to be
+	/// attached, like [`is_checked`](Self::is_checked).
+	pub async fn is_indeterminate(&self) -> Result<bool> {
+		self.wait_for(crate::WaitForSelectorState::Attached, None).await?;
+		let value = self
+			.evaluate("el => el.indeterminate === true || el.getAttribute('aria-checked') === 'mixed'", None)
+			.await?;
+		Ok(value.as_bool().unwrap_or(false))
+	}
+
 	/// Returns whether the element is editable.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-is-editable>
@@ -240,6 +362,24 @@ impl Locator {
 		self.frame.locator_press(&self.selector, key, options).await
 	}
 
+	/// Waits until the element reaches `state`, or `timeout` (ms, default 30s) elapses.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-wait-for>
+	pub async fn wait_for(&self, state: crate::WaitForSelectorState, timeout: Option<f64>) -> Result<()> {
+		self.frame.locator_wait_for(&self.selector, state, timeout).await
+	}
+
+	/// Focuses the element and types `text` key by key, as if on a keyboard.
+	///
+	/// Unlike [`fill`](Self::fill), this dispatches `keydown`/`keypress`/`keyup`
+	/// for every character; use [`KeyboardOptions::delay`](crate::KeyboardOptions)
+	/// to slow typing down.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-press-sequentially>
+	pub async fn type_text(&self, text: &str, options: Option<crate::KeyboardOptions>) -> Result<()> {
+		self.frame.locator_type(&self.selector, text, options).await
+	}
+
 	/// Ensures the checkbox or radio button is checked.
 	///
 	/// This method is idempotent - if already checked, does nothing.
@@ -275,6 +415,16 @@ impl Locator {
 		self.frame.locator_hover(&self.selector, options).await
 	}
 
+	/// Highlights the matched elements with an on-page overlay, for debugging selectors.
+	///
+	/// The overlay is only visible in headful browsers and stays until the page
+	/// navigates or is closed.
+	///
+	/// See: <https://playwright.dev/docs/api/class-locator#locator-highlight>
+	pub async fn highlight(&self) -> Result<()> {
+		self.frame.locator_highlight(&self.selector).await
+	}
+
 	/// Returns the value of the input, textarea, or select element.
 	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-input-value>
@@ -307,6 +457,8 @@ impl Locator {
 
 	/// Sets the file path(s) to upload to a file input element.
 	///
+	/// Fails with [`Error::InvalidArgument`](pw_runtime::Error::InvalidArgument) when the path is not an existing file.
+	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
 	pub async fn set_input_files(&self, file: &std::path::PathBuf, _options: Option<()>) -> Result<()> {
 		self.frame.locator_set_input_files(&self.selector, file).await
@@ -314,6 +466,8 @@ impl Locator {
 
 	/// Sets multiple file paths to upload to a file input element.
 	///
+	/// An empty slice clears the input. Every path must be an existing file.
+	///
 	/// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
 	pub async fn set_input_files_multiple(&self, files: &[&std::path::PathBuf], _options: Option<()>) -> Result<()> {
 		self.frame.locator_set_input_files_multiple(&self.selector, files).await
@@ -350,6 +504,30 @@ impl Locator {
 		// Delegate to ElementHandle.screenshot()
 		element.screenshot(options).await
 	}
+
+	/// Takes a screenshot of the element and returns the image bytes with their
+	/// pixel width and height, read from the PNG or JPEG header.
+	///
+	/// Dimensions are in device pixels, so they scale with the context's
+	/// `device_scale_factor`.
+	///
+	/// # Errors
+	///
+	/// Same as [`screenshot`](Self::screenshot), plus
+	/// [`Error::ProtocolError`](pw_runtime::Error::ProtocolError) if the image
+	/// header cannot be decoded.
+	pub async fn screenshot_with_size(&self, options: Option<crate::ScreenshotOptions>) -> Result<(Vec<u8>, u32, u32)> {
+		let bytes = self.screenshot(options).await?;
+		let (width, height) = crate::screenshot::image_dimensions(&bytes)
+			.ok_or_else(|| pw_runtime::Error::ProtocolError("screenshot is not a decodable PNG or JPEG".to_string()))?;
+		Ok((bytes, width, height))
+	}
+}
+
+/// Joins `selector` and `other` with a combinator engine, which takes a JSON-quoted selector.
+fn combined_selector(selector: &str, engine: &str, other: &str) -> String {
+	let quoted = serde_json::Value::String(other.to_string()).to_string();
+	format!("{selector} >> {engine}={quoted}")
 }
 
 impl std::fmt::Debug for Locator {
@@ -357,3 +535,94 @@ impl std::fmt::Debug for Locator {
 		f.debug_struct("Locator").field("selector", &self.selector).finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::{Locator, combined_selector};
+	use crate::mock_driver::MockDriver;
+
+	#[tokio::test]
+	async fn highlight_sends_highlight_rpc() {
+		let mut driver = MockDriver::start();
+		let locator = Locator::new(driver.frame("frame@1"), "#target".to_string());
+		let call = tokio::spawn(async move { locator.highlight().await });
+
+		let request = driver.next_request().await;
+		assert_eq!(request["guid"], "frame@1");
+		assert_eq!(request["method"], "highlight");
+		assert_eq!(request["params"], json!({ "selector": "#target" }));
+
+		driver.respond(&request, json!({})).await;
+		call.await.unwrap().unwrap();
+	}
+
+	#[tokio::test]
+	async fn get_attribute_returns_value_or_none_when_absent() {
+		let mut driver = MockDriver::start();
+		let locator = Locator::new(driver.frame("frame@1"), "a.docs".to_string());
+		let call = tokio::spawn(async move { (locator.get_attribute("href").await, locator.get_attribute("data-x").await) });
+
+		for (name, result) in [("href", json!({ "value": "/docs" })), ("data-x", json!({}))] {
+			let request = driver.next_request().await;
+			assert_eq!(request["method"], "getAttribute");
+			assert_eq!(request["params"]["selector"], "a.docs");
+			assert_eq!(request["params"]["name"], name);
+
+			driver.respond(&request, result).await;
+		}
+
+		let (href, missing) = call.await.unwrap();
+		assert_eq!(href.unwrap().as_deref(), Some("/docs"));
+		assert_eq!(missing.unwrap(), None);
+	}
+
+	#[test]
+	fn or_and_and_quote_the_other_selector() {
+		assert_eq!(
+			combined_selector("#submit", "internal:or", r#"text="Send""#),
+			r#"#submit >> internal:or="text=\"Send\"""#
+		);
+		assert_eq!(
+			combined_selector("#submit", "internal:and", r#"text="Send""#),
+			r#"#submit >> internal:and="text=\"Send\"""#
+		);
+	}
+
+	#[tokio::test]
+	async fn type_text_sends_type_rpc_with_delay() {
+		let mut driver = MockDriver::start();
+		let locator = Locator::new(driver.frame("frame@1"), "input[name=q]".to_string());
+		let options = crate::KeyboardOptions::builder().delay(20.0).build();
+		let call = tokio::spawn(async move { locator.type_text("rust", Some(options)).await });
+
+		let request = driver.next_request().await;
+		assert_eq!(request["guid"], "frame@1");
+		assert_eq!(request["method"], "type");
+		assert_eq!(request["params"]["selector"], "input[name=q]");
+		assert_eq!(request["params"]["text"], "rust");
+		assert_eq!(request["params"]["delay"], 20.0);
+		assert_eq!(request["params"]["strict"], true);
+
+		driver.respond(&request, json!({})).await;
+		call.await.unwrap().unwrap();
+	}
+
+	#[tokio::test]
+	async fn wait_for_sends_state_to_wait_for_selector() {
+		let mut driver = MockDriver::start();
+		let locator = Locator::new(driver.frame("frame@1"), ".spinner".to_string());
+		let call = tokio::spawn(async move { locator.wait_for(crate::WaitForSelectorState::Hidden, Some(500.0)).await });
+
+		let request = driver.next_request().await;
+		assert_eq!(request["method"], "waitForSelector");
+		assert_eq!(
+			request["params"],
+			json!({ "selector": ".spinner", "strict": true, "state": "hidden", "timeout": 500.0 })
+		);
+
+		driver.respond(&request, json!({})).await;
+		call.await.unwrap().unwrap();
+	}
+}
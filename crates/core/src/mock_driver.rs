@@ -0,0 +1,68 @@
+//! In-process stand-in for the Playwright driver, for unit tests that assert
+//! on the RPCs a channel owner sends.
+//!
+//! The connection talks to the driver over in-memory pipes using the same
+//! length-prefixed framing as [`PipeTransport`], so tests read each request
+//! as JSON and answer it by id.
+
+use std::sync::Arc;
+
+use pw_runtime::PipeTransport;
+use pw_runtime::connection::Connection;
+use serde_json::{Value, json};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+use crate::browser_context::BrowserContext;
+use crate::frame::Frame;
+use crate::root::Root;
+
+/// A connection whose message loop is running, wired to a scripted driver.
+pub(crate) struct MockDriver {
+	root: Arc<Root>,
+	requests: DuplexStream,
+	responses: DuplexStream,
+}
+
+impl MockDriver {
+	/// Starts a connection piped to this driver and spawns its message loop.
+	pub(crate) fn start() -> Self {
+		let (requests, stdin) = tokio::io::duplex(4096);
+		let (stdout, responses) = tokio::io::duplex(4096);
+		let (transport, message_rx) = PipeTransport::new(stdin, stdout);
+		let connection = Arc::new(Connection::new(transport.into_transport_parts(message_rx)));
+		let conn_for_loop = Arc::clone(&connection);
+		tokio::spawn(async move { conn_for_loop.run().await });
+
+		Self {
+			root: Arc::new(Root::new(connection)),
+			requests,
+			responses,
+		}
+	}
+
+	/// Creates a frame with `guid` on this connection.
+	pub(crate) fn frame(&self, guid: &str) -> Arc<Frame> {
+		Arc::new(Frame::new(Arc::clone(&self.root) as _, "Frame".to_string(), Arc::from(guid), json!({})).unwrap())
+	}
+
+	/// Creates a browser context with `guid` and `initializer` on this connection.
+	pub(crate) fn context(&self, guid: &str, initializer: Value) -> Arc<BrowserContext> {
+		Arc::new(BrowserContext::new(Arc::clone(&self.root) as _, "BrowserContext".to_string(), Arc::from(guid), initializer).unwrap())
+	}
+
+	/// Reads the next request the connection sent.
+	pub(crate) async fn next_request(&mut self) -> Value {
+		let mut len = [0u8; 4];
+		self.requests.read_exact(&mut len).await.unwrap();
+		let mut body = vec![0u8; u32::from_le_bytes(len) as usize];
+		self.requests.read_exact(&mut body).await.unwrap();
+		serde_json::from_slice(&body).unwrap()
+	}
+
+	/// Answers `request` with `result`.
+	pub(crate) async fn respond(&mut self, request: &Value, result: Value) {
+		let response = serde_json::to_vec(&json!({ "id": request["id"], "result": result })).unwrap();
+		self.responses.write_all(&(response.len() as u32).to_le_bytes()).await.unwrap();
+		self.responses.write_all(&response).await.unwrap();
+	}
+}
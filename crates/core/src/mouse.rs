@@ -4,6 +4,18 @@
 //! `click`, `dblclick`, `wheel`) through page protocol commands.
 //!
 //! Coordinates are CSS pixels relative to the viewport origin.
+//!
+//! `down`, `move_to`, and `up` compose into gestures that a single drag call
+//! cannot express, such as a drag with intermediate waypoints:
+//!
+//! ```ignore
+//! let mouse = page.mouse();
+//! mouse.move_to(10, 10, None).await?;
+//! mouse.down(None).await?;
+//! mouse.move_to(100, 40, Some(MouseOptions::builder().steps(5).build())).await?;
+//! mouse.move_to(150, 120, Some(MouseOptions::builder().steps(5).build())).await?;
+//! mouse.up(None).await?;
+//! ```
 
 use pw_runtime::Result;
 
@@ -27,6 +39,9 @@ impl Mouse {
 
 	/// Dispatches a `mousemove` event.
 	///
+	/// `steps` in `options` interpolates that many intermediate moves from the
+	/// current position; other options are ignored.
+	///
 	/// See: <https://playwright.dev/docs/api/class-mouse#mouse-move>
 	pub async fn move_to(&self, x: i32, y: i32, options: Option<crate::MouseOptions>) -> Result<()> {
 		self.page.mouse_move(x, y, options).await
@@ -46,14 +61,18 @@ impl Mouse {
 		self.page.mouse_dblclick(x, y, options).await
 	}
 
-	/// Dispatches a `mousedown` event.
+	/// Dispatches a `mousedown` event at the current position.
+	///
+	/// Only `button` and `click_count` from `options` apply.
 	///
 	/// See: <https://playwright.dev/docs/api/class-mouse#mouse-down>
 	pub async fn down(&self, options: Option<crate::MouseOptions>) -> Result<()> {
 		self.page.mouse_down(options).await
 	}
 
-	/// Dispatches a `mouseup` event.
+	/// Dispatches a `mouseup` event at the current position.
+	///
+	/// Only `button` and `click_count` from `options` apply.
 	///
 	/// See: <https://playwright.dev/docs/api/class-mouse#mouse-up>
 	pub async fn up(&self, options: Option<crate::MouseOptions>) -> Result<()> {
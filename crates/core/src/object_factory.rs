@@ -217,6 +217,18 @@ pub async fn create_object(parent: ParentOrConnection, type_name: String, guid:
 			Arc::new(Tracing::new(parent_owner, type_name, guid, initializer)?)
 		}
 
+		"CDPSession" => {
+			// CDPSession has BrowserContext as parent
+			let parent_owner = match parent {
+				ParentOrConnection::Parent(p) => p,
+				ParentOrConnection::Connection(_) => {
+					return Err(Error::ProtocolError("CDPSession must have BrowserContext as parent".to_string()));
+				}
+			};
+
+			Arc::new(crate::CdpSession::new(parent_owner, type_name, guid, initializer)?)
+		}
+
 		"Video" => {
 			// Video has Page as parent (created when video recording is enabled)
 			let parent_owner = match parent {
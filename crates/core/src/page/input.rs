@@ -10,6 +10,28 @@ fn merge_options(params: &mut serde_json::Value, opts_json: serde_json::Value) {
 	}
 }
 
+/// `mouseMove` only accepts `steps`; button and delay options are dropped.
+fn mouse_move_params(x: i32, y: i32, options: Option<&crate::MouseOptions>) -> serde_json::Value {
+	let mut params = serde_json::json!({ "x": x, "y": y });
+	if let Some(steps) = options.and_then(|opts| opts.steps) {
+		params["steps"] = serde_json::json!(steps);
+	}
+	params
+}
+
+/// `mouseDown`/`mouseUp` only accept `button` and `clickCount`.
+fn mouse_button_params(options: Option<&crate::MouseOptions>) -> serde_json::Value {
+	let mut params = serde_json::json!({});
+	if let Some(opts) = options {
+		let mut json = opts.to_json();
+		if let Some(obj) = json.as_object_mut() {
+			obj.retain(|key, _| key == "button" || key == "clickCount");
+		}
+		merge_options(&mut params, json);
+	}
+	params
+}
+
 impl Page {
 	pub(crate) async fn keyboard_down(&self, key: &str) -> Result<()> {
 		self.channel().send_no_result("keyboardDown", serde_json::json!({ "key": key })).await
@@ -40,11 +62,7 @@ impl Page {
 	}
 
 	pub(crate) async fn mouse_move(&self, x: i32, y: i32, options: Option<crate::MouseOptions>) -> Result<()> {
-		let mut params = serde_json::json!({ "x": x, "y": y });
-		if let Some(opts) = options {
-			merge_options(&mut params, opts.to_json());
-		}
-		self.channel().send_no_result("mouseMove", params).await
+		self.channel().send_no_result("mouseMove", mouse_move_params(x, y, options.as_ref())).await
 	}
 
 	pub(crate) async fn mouse_click(&self, x: i32, y: i32, options: Option<crate::MouseOptions>) -> Result<()> {
@@ -64,19 +82,11 @@ impl Page {
 	}
 
 	pub(crate) async fn mouse_down(&self, options: Option<crate::MouseOptions>) -> Result<()> {
-		let mut params = serde_json::json!({});
-		if let Some(opts) = options {
-			merge_options(&mut params, opts.to_json());
-		}
-		self.channel().send_no_result("mouseDown", params).await
+		self.channel().send_no_result("mouseDown", mouse_button_params(options.as_ref())).await
 	}
 
 	pub(crate) async fn mouse_up(&self, options: Option<crate::MouseOptions>) -> Result<()> {
-		let mut params = serde_json::json!({});
-		if let Some(opts) = options {
-			merge_options(&mut params, opts.to_json());
-		}
-		self.channel().send_no_result("mouseUp", params).await
+		self.channel().send_no_result("mouseUp", mouse_button_params(options.as_ref())).await
 	}
 
 	pub(crate) async fn mouse_wheel(&self, delta_x: i32, delta_y: i32) -> Result<()> {
@@ -85,3 +95,19 @@ impl Page {
 			.await
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{MouseButton, MouseOptions};
+
+	#[test]
+	fn mouse_params_keep_only_accepted_fields() {
+		let options = MouseOptions::builder().button(MouseButton::Right).click_count(2).delay(50.0).steps(8).build();
+
+		assert_eq!(mouse_move_params(10, 20, Some(&options)), serde_json::json!({ "x": 10, "y": 20, "steps": 8 }));
+		assert_eq!(mouse_move_params(1, 2, None), serde_json::json!({ "x": 1, "y": 2 }));
+		assert_eq!(mouse_button_params(Some(&options)), serde_json::json!({ "button": "right", "clickCount": 2 }));
+		assert_eq!(mouse_button_params(None), serde_json::json!({}));
+	}
+}
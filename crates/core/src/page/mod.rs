@@ -2,7 +2,9 @@
 
 mod eval;
 mod input;
+mod network;
 mod page_events;
+mod pdf;
 mod routing;
 mod screenshot;
 
@@ -17,8 +19,10 @@ use serde::Deserialize;
 use serde_json::Value;
 use tokio::sync::broadcast;
 
+pub use self::network::NetworkPreset;
 pub use crate::handlers::Subscription;
 use crate::handlers::{HandlerMap, RouteMeta};
+use crate::timeout_settings::TimeoutSettings;
 use crate::{Dialog, Download, Route};
 
 /// A browser tab or window within a [`BrowserContext`](crate::BrowserContext).
@@ -31,6 +35,10 @@ pub struct Page {
 	url: Arc<RwLock<String>>,
 	/// GUID of the main frame.
 	main_frame_guid: Arc<str>,
+	/// GUIDs of attached frames in attach order, main frame first.
+	frame_guids: Arc<RwLock<Vec<Arc<str>>>>,
+	/// Default timeouts, falling back to the owning context's.
+	timeouts: TimeoutSettings,
 	/// Route handlers for network interception (with compiled matchers).
 	route_handlers: HandlerMap<Route, RouteMeta>,
 	/// Download event handlers.
@@ -39,6 +47,8 @@ pub struct Page {
 	dialog_handlers: HandlerMap<Dialog>,
 	/// Console message broadcast channel.
 	console_tx: broadcast::Sender<ConsoleMessage>,
+	/// CDP session used for network throttling, created on first use.
+	network_cdp: Arc<tokio::sync::Mutex<Option<crate::CdpSession>>>,
 }
 
 /// Console message from JavaScript `console.*` calls.
@@ -69,6 +79,27 @@ impl ConsoleMessage {
 	pub fn location(&self) -> Option<&ConsoleLocation> {
 		self.location.as_ref()
 	}
+
+	/// Parses the `type`/`text`/`location` fields of a console event payload.
+	pub(crate) fn from_event(message: &Value) -> Self {
+		let kind = message
+			.get("type")
+			.and_then(|v| v.as_str())
+			.map(ConsoleMessageKind::from_str)
+			.unwrap_or(ConsoleMessageKind::Log);
+
+		let text = message.get("text").and_then(|v| v.as_str()).unwrap_or("").to_string();
+
+		let location = message.get("location").and_then(|loc| {
+			Some(ConsoleLocation {
+				url: loc.get("url")?.as_str()?.to_string(),
+				line_number: loc.get("lineNumber")?.as_u64()? as u32,
+				column_number: loc.get("columnNumber")?.as_u64()? as u32,
+			})
+		});
+
+		Self { kind, text, location }
+	}
 }
 
 /// The type of console message.
@@ -174,9 +205,14 @@ impl Page {
 				.ok_or_else(|| pw_runtime::Error::ProtocolError("Page initializer missing 'mainFrame.guid' field".to_string()))?,
 		);
 
+		let timeouts = match parent.downcast_ref::<crate::BrowserContext>() {
+			Some(context) => TimeoutSettings::with_parent(context.timeouts()),
+			None => TimeoutSettings::default(),
+		};
 		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent), type_name, guid, initializer);
 
 		let url = Arc::new(RwLock::new("about:blank".to_string()));
+		let frame_guids = Arc::new(RwLock::new(vec![Arc::clone(&main_frame_guid)]));
 		let route_handlers = Arc::new(Mutex::new(IndexMap::new()));
 		let download_handlers = Arc::new(Mutex::new(IndexMap::new()));
 		let dialog_handlers = Arc::new(Mutex::new(IndexMap::new()));
@@ -186,10 +222,13 @@ impl Page {
 			base,
 			url,
 			main_frame_guid,
+			frame_guids,
+			timeouts,
 			route_handlers,
 			download_handlers,
 			dialog_handlers,
 			console_tx,
+			network_cdp: Arc::default(),
 		})
 	}
 
@@ -203,10 +242,54 @@ impl Page {
 		let frame = frame_arc
 			.downcast_ref::<crate::Frame>()
 			.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Frame object, got {}", frame_arc.type_name())))?;
+		frame.bind_page_timeouts(&self.timeouts);
 
 		Ok(frame.clone())
 	}
 
+	/// Sets the default timeout, in milliseconds, for actions on this page.
+	///
+	/// Subsequent calls without their own timeout (clicks, fills, locator queries,
+	/// ...) use it instead of the 30s default. Also applies to navigations unless
+	/// [`set_default_navigation_timeout`](Self::set_default_navigation_timeout) is set.
+	/// `0.0` disables the timeout.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-set-default-timeout>
+	pub fn set_default_timeout(&self, timeout_ms: f64) {
+		self.timeouts.set_default_timeout(timeout_ms);
+	}
+
+	/// Sets the default timeout, in milliseconds, for navigations and load-state waits on this page.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-set-default-navigation-timeout>
+	pub fn set_default_navigation_timeout(&self, timeout_ms: f64) {
+		self.timeouts.set_default_navigation_timeout(timeout_ms);
+	}
+
+	pub(crate) fn timeouts(&self) -> &TimeoutSettings {
+		&self.timeouts
+	}
+
+	/// Returns every frame attached to the page, main frame first.
+	///
+	/// Frames are listed in attach order; use [`Frame::parent_frame`](crate::Frame::parent_frame)
+	/// to rebuild the tree. Detached frames are dropped.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-frames>
+	pub async fn frames(&self) -> Result<Vec<crate::Frame>> {
+		let guids = self.frame_guids.read().unwrap_or_else(|e| e.into_inner()).clone();
+		let mut frames = Vec::with_capacity(guids.len());
+		for guid in guids {
+			let frame_arc = self.connection().get_object(&guid).await?;
+			let frame = frame_arc
+				.downcast_ref::<crate::Frame>()
+				.ok_or_else(|| pw_runtime::Error::ProtocolError(format!("Expected Frame object, got {}", frame_arc.type_name())))?;
+			frame.bind_page_timeouts(&self.timeouts);
+			frames.push(frame.clone());
+		}
+		Ok(frames)
+	}
+
 	/// Returns the current URL (initially "about:blank").
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-url>
@@ -259,6 +342,17 @@ impl Page {
 		Ok(response)
 	}
 
+	/// Waits until the main frame reaches `state` without navigating.
+	///
+	/// Useful after interactions that trigger client-side loading, e.g. waiting
+	/// for [`WaitUntil::NetworkIdle`] after a click starts fetches. Returns
+	/// immediately if the state was already reached; [`WaitUntil::Commit`] is rejected.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-wait-for-load-state>
+	pub async fn wait_for_load_state(&self, state: WaitUntil, timeout: Option<std::time::Duration>) -> Result<()> {
+		self.main_frame().await?.wait_for_load_state(state, timeout).await
+	}
+
 	/// Returns the page's title.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-title>
@@ -322,13 +416,13 @@ impl Page {
 			if let Some(timeout) = opts.timeout {
 				params["timeout"] = serde_json::json!(timeout.as_millis() as u64);
 			} else {
-				params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
+				params["timeout"] = serde_json::json!(self.timeouts.navigation_timeout());
 			}
 			if let Some(wait_until) = opts.wait_until {
 				params["waitUntil"] = serde_json::json!(wait_until.as_str());
 			}
 		} else {
-			params["timeout"] = serde_json::json!(pw_protocol::options::DEFAULT_TIMEOUT_MS);
+			params["timeout"] = serde_json::json!(self.timeouts.navigation_timeout());
 		}
 
 		// Send reload RPC directly to Page (not Frame!)
@@ -375,6 +469,7 @@ impl Page {
 				status_text: initializer["statusText"].as_str().unwrap_or("").to_string(),
 				ok: (200..300).contains(&status),
 				headers,
+				object: response_arc.downcast_ref::<crate::ResponseObject>().cloned(),
 			};
 
 			// Update the page's URL
@@ -405,6 +500,20 @@ impl Page {
 		let frame = self.main_frame().await?;
 		frame.query_selector_all(selector).await
 	}
+
+	/// Adds a stylesheet to the main frame and returns the injected element.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-add-style-tag>
+	pub async fn add_style_tag(&self, source: crate::TagSource) -> Result<Arc<crate::ElementHandle>> {
+		self.main_frame().await?.add_style_tag(source).await
+	}
+
+	/// Adds a script to the main frame and returns the injected element.
+	///
+	/// See <https://playwright.dev/docs/api/class-page#page-add-script-tag>
+	pub async fn add_script_tag(&self, source: crate::TagSource, script_type: Option<&str>) -> Result<Arc<crate::ElementHandle>> {
+		self.main_frame().await?.add_script_tag(source, script_type).await
+	}
 }
 
 impl pw_runtime::channel_owner::private::Sealed for Page {}
@@ -505,29 +614,22 @@ impl ChannelOwner for Page {
 					self_clone.on_download_event(download).await;
 				});
 			}
+			"frameAttached" => {
+				if let Some(guid) = params["frame"]["guid"].as_str() {
+					self.frame_guids.write().unwrap_or_else(|e| e.into_inner()).push(Arc::from(guid));
+				}
+			}
+			"frameDetached" => {
+				if let Some(guid) = params["frame"]["guid"].as_str() {
+					self.frame_guids.write().unwrap_or_else(|e| e.into_inner()).retain(|g| &**g != guid);
+				}
+			}
 			"dialog" => {}
 			"console" => {
 				let Some(message_obj) = params.get("message") else {
 					return;
 				};
-
-				let kind = message_obj
-					.get("type")
-					.and_then(|v| v.as_str())
-					.map(ConsoleMessageKind::from_str)
-					.unwrap_or(ConsoleMessageKind::Log);
-
-				let text = message_obj.get("text").and_then(|v| v.as_str()).unwrap_or("").to_string();
-
-				let location = message_obj.get("location").and_then(|loc| {
-					Some(ConsoleLocation {
-						url: loc.get("url")?.as_str()?.to_string(),
-						line_number: loc.get("lineNumber")?.as_u64()? as u32,
-						column_number: loc.get("columnNumber")?.as_u64()? as u32,
-					})
-				});
-
-				let _ = self.console_tx.send(ConsoleMessage { kind, text, location });
+				let _ = self.console_tx.send(ConsoleMessage::from_event(message_obj));
 			}
 			_ => {}
 		}
@@ -547,7 +649,8 @@ impl std::fmt::Debug for Page {
 /// Options for [`Page::goto`] and [`Page::reload`].
 #[derive(Debug, Clone, Default)]
 pub struct GotoOptions {
-	/// Maximum operation time.
+	/// Maximum operation time. `None` uses Playwright's default; `Duration::ZERO`
+	/// disables the timeout (Playwright convention).
 	pub timeout: Option<std::time::Duration>,
 	/// When to consider the operation succeeded.
 	pub wait_until: Option<WaitUntil>,
@@ -559,7 +662,7 @@ impl GotoOptions {
 		Self::default()
 	}
 
-	/// Sets the timeout.
+	/// Sets the timeout; `Duration::ZERO` waits indefinitely.
 	pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
 		self.timeout = Some(timeout);
 		self
@@ -609,6 +712,8 @@ pub struct Response {
 	pub ok: bool,
 	/// Response headers.
 	pub headers: std::collections::HashMap<String, String>,
+	/// Underlying protocol object, used for lazily fetched metadata.
+	pub(crate) object: Option<crate::ResponseObject>,
 }
 
 impl Response {
@@ -636,6 +741,31 @@ impl Response {
 	pub fn headers(&self) -> &std::collections::HashMap<String, String> {
 		&self.headers
 	}
+
+	/// Returns the underlying protocol object, when still registered.
+	pub fn object(&self) -> Option<&crate::ResponseObject> {
+		self.object.as_ref()
+	}
+
+	/// Returns TLS details for the connection, or `None` when unavailable.
+	///
+	/// See [`crate::ResponseObject::security_details`].
+	pub async fn security_details(&self) -> Result<Option<crate::SecurityDetails>> {
+		match &self.object {
+			Some(object) => object.security_details().await,
+			None => Ok(None),
+		}
+	}
+
+	/// Returns the server IP address and port, or `None` when unavailable.
+	///
+	/// See [`crate::ResponseObject::server_addr`].
+	pub async fn server_addr(&self) -> Result<Option<crate::RemoteAddr>> {
+		match &self.object {
+			Some(object) => object.server_addr().await,
+			None => Ok(None),
+		}
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,110 @@
+//! Network condition emulation for [`Page`].
+
+use pw_runtime::{Error, Result};
+use serde_json::json;
+
+use super::Page;
+
+/// Throttling profiles matching the Chrome DevTools presets.
+///
+/// Throughput is in bytes per second and latency in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkPreset {
+	/// No connectivity.
+	Offline,
+	/// 500 kbit/s each way with 2s round-trip latency.
+	Slow3g,
+	/// 1.6 Mbit/s down, 750 kbit/s up, with ~560ms latency.
+	Fast3g,
+	/// Unthrottled; clears an earlier preset.
+	NoThrottling,
+}
+
+impl NetworkPreset {
+	/// Parameters for CDP `Network.emulateNetworkConditions`.
+	fn conditions(self) -> serde_json::Value {
+		let (offline, latency, download, upload) = match self {
+			Self::Offline => (true, 0.0, -1.0, -1.0),
+			Self::Slow3g => (false, 400.0 * 5.0, 500.0 * 1000.0 / 8.0 * 0.8, 500.0 * 1000.0 / 8.0 * 0.8),
+			Self::Fast3g => (false, 150.0 * 3.75, 1.6 * 1000.0 * 1000.0 / 8.0 * 0.9, 750.0 * 1000.0 / 8.0 * 0.9),
+			Self::NoThrottling => (false, 0.0, -1.0, -1.0),
+		};
+		json!({
+			"offline": offline,
+			"latency": latency,
+			"downloadThroughput": download,
+			"uploadThroughput": upload,
+		})
+	}
+}
+
+impl Page {
+	/// Owning browser context, for context-scoped RPCs.
+	pub(crate) fn context(&self) -> Result<crate::BrowserContext> {
+		self.base
+			.parent()
+			.and_then(|parent| parent.downcast_ref::<crate::BrowserContext>().cloned())
+			.ok_or_else(|| Error::ProtocolError("page has no owning browser context".to_string()))
+	}
+
+	/// Emulates the network being offline.
+	///
+	/// Offline mode is a context setting in Playwright, so this also affects the
+	/// other pages of the same context.
+	///
+	/// See <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-offline>
+	pub async fn set_offline(&self, offline: bool) -> Result<()> {
+		self.context()?.set_offline(offline).await
+	}
+
+	/// Throttles this page's network to `preset` through a CDP session.
+	///
+	/// Chromium only. The page keeps one CDP session for throttling, so repeated
+	/// calls replace the conditions. They last until another preset is applied,
+	/// [`clear_network_throttle`](Self::clear_network_throttle) is called, or the
+	/// page closes.
+	pub async fn throttle_network(&self, preset: NetworkPreset) -> Result<()> {
+		let mut slot = self.network_cdp.lock().await;
+		let cdp = match slot.as_ref() {
+			Some(cdp) => cdp.clone(),
+			None => {
+				let cdp = self.context()?.new_cdp_session(self).await?;
+				cdp.send("Network.enable", json!({})).await?;
+				slot.insert(cdp).clone()
+			}
+		};
+		cdp.send("Network.emulateNetworkConditions", preset.conditions()).await?;
+		Ok(())
+	}
+
+	/// Lifts throttling applied by [`throttle_network`](Self::throttle_network).
+	///
+	/// Does nothing, and works on every engine, when the page was never throttled.
+	pub async fn clear_network_throttle(&self) -> Result<()> {
+		let cdp = self.network_cdp.lock().await.clone();
+		match cdp {
+			Some(cdp) => cdp
+				.send("Network.emulateNetworkConditions", NetworkPreset::NoThrottling.conditions())
+				.await
+				.map(drop),
+			None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn presets_match_devtools_values() {
+		let slow = NetworkPreset::Slow3g.conditions();
+		assert_eq!((slow["latency"].as_f64(), slow["downloadThroughput"].as_f64()), (Some(2000.0), Some(50000.0)));
+		let fast = NetworkPreset::Fast3g.conditions();
+		assert_eq!(
+			(fast["latency"].as_f64(), fast["downloadThroughput"].as_f64(), fast["uploadThroughput"].as_f64()),
+			(Some(562.5), Some(180000.0), Some(84375.0))
+		);
+		assert_eq!(NetworkPreset::Offline.conditions()["offline"], true);
+	}
+}
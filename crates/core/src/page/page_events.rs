@@ -154,4 +154,9 @@ impl Page {
 	pub async fn trigger_dialog_event(&self, dialog: Dialog) {
 		self.on_dialog_event(dialog).await;
 	}
+
+	/// Publishes a console message (called by [`BrowserContext`](crate::BrowserContext)).
+	pub(crate) fn trigger_console_event(&self, message: ConsoleMessage) {
+		let _ = self.console_tx.send(message);
+	}
 }
@@ -14,6 +14,9 @@ impl Page {
 	///
 	/// When a request URL matches `pattern` (supports glob patterns like `**/*.png`),
 	/// the handler receives a [`Route`] that can abort, continue, or fulfill the request.
+	/// Matching handlers are tried newest first, so a later registration overrides
+	/// an earlier one; a handler that calls [`Route::fallback`] passes the request
+	/// to the handler registered before it.
 	/// Returns a [`Subscription`] that unregisters the handler when dropped.
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-route>
@@ -22,7 +25,7 @@ impl Page {
 	///
 	/// ```ignore
 	/// let _sub = page.route("**/*.png", |route| async move {
-	///     route.abort(None).await
+	///     route.abort(Some(AbortReason::BlockedByClient)).await
 	/// }).await?;
 	/// ```
 	pub async fn route<F, Fut>(&self, pattern: &str, handler: F) -> Result<Subscription>
@@ -61,23 +64,38 @@ impl Page {
 			.await
 	}
 
-	/// Dispatches a route event to the matching handler (last-registered wins).
+	/// Dispatches a route event to the matching handlers in reverse registration order.
+	///
+	/// Each handler either settles the route or calls [`Route::fallback`] to pass
+	/// it on; a route every handler fell back from continues unchanged.
 	pub(super) async fn on_route_event(&self, route: Route) {
 		let url = route.request().url().to_string();
 
-		let handler = {
+		let handlers: Vec<HandlerFn<Route>> = {
 			let handlers = self.route_handlers.lock();
 			handlers
 				.values()
 				.rev()
-				.find(|entry| entry.meta.matcher.is_match(&url))
+				.filter(|entry| entry.meta.matcher.is_match(&url))
 				.map(|entry| entry.handler.clone())
+				.collect()
 		};
+		if handlers.is_empty() {
+			return;
+		}
 
-		if let Some(handler) = handler {
-			if let Err(e) = handler(route).await {
+		for handler in handlers {
+			if let Err(e) = handler(route.clone()).await {
 				tracing::error!(error = %e, "Route handler error");
+				return;
+			}
+			if !route.take_fallback() {
+				return;
 			}
 		}
+
+		if let Err(e) = route.continue_after_fallback().await {
+			tracing::error!(error = %e, "Route fallback continue failed");
+		}
 	}
 }
@@ -16,12 +16,9 @@ impl Page {
 	///
 	/// See <https://playwright.dev/docs/api/class-page#page-screenshot>
 	pub async fn screenshot(&self, options: Option<crate::ScreenshotOptions>) -> Result<Vec<u8>> {
-		let params = options.map(|o| o.to_json()).unwrap_or_else(|| {
-			serde_json::json!({
-				"type": "png",
-				"timeout": pw_protocol::options::DEFAULT_TIMEOUT_MS
-			})
-		});
+		let timeout = options.as_ref().and_then(|o| o.timeout).unwrap_or_else(|| self.timeouts().timeout());
+		let mut params = options.map(|o| o.to_json()).unwrap_or_else(|| serde_json::json!({ "type": "png" }));
+		params["timeout"] = serde_json::json!(timeout);
 
 		let response: ScreenshotResponse = self.channel().send("screenshot", params).await?;
 
@@ -0,0 +1,210 @@
+//! PDF export option types.
+//!
+//! [`PdfOptions`] maps onto the parameters of Playwright's `pdf` RPC. PDF
+//! generation is only supported by headless Chromium.
+
+use serde::Serialize;
+
+/// Page margins, as CSS lengths (`"1cm"`, `"0.5in"`, `"40px"`).
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-pdf-option-margin>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PdfMargin {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub top: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub right: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub bottom: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub left: Option<String>,
+}
+
+impl PdfMargin {
+	/// The same margin on every side.
+	pub fn all(margin: impl Into<String>) -> Self {
+		let margin = margin.into();
+		Self {
+			top: Some(margin.clone()),
+			right: Some(margin.clone()),
+			bottom: Some(margin.clone()),
+			left: Some(margin),
+		}
+	}
+}
+
+/// PDF export options
+///
+/// # Examples
+///
+/// ```ignore
+/// use pw_rs::{PdfMargin, PdfOptions};
+///
+/// let options = PdfOptions::builder()
+///     .format("A4")
+///     .footer_template(r#"<div style="font-size:8px"><span class="pageNumber"></span></div>"#)
+///     .margin(PdfMargin::all("1cm"))
+///     .build();
+/// ```
+///
+/// See: <https://playwright.dev/docs/api/class-page#page-pdf>
+#[derive(Debug, Clone, Default)]
+pub struct PdfOptions {
+	/// Paper format such as `Letter` or `A4`
+	pub format: Option<String>,
+	/// Landscape orientation
+	pub landscape: Option<bool>,
+	/// Print background graphics
+	pub print_background: Option<bool>,
+	/// Rendering scale (0.1 to 2)
+	pub scale: Option<f64>,
+	/// Show header and footer; implied when either template is set
+	pub display_header_footer: Option<bool>,
+	/// HTML template for the print header
+	pub header_template: Option<String>,
+	/// HTML template for the print footer
+	pub footer_template: Option<String>,
+	/// Page margins
+	pub margin: Option<PdfMargin>,
+}
+
+impl PdfOptions {
+	/// Create a new builder for PdfOptions
+	pub fn builder() -> PdfOptionsBuilder {
+		PdfOptionsBuilder::default()
+	}
+
+	/// Convert options to JSON value for protocol
+	pub(crate) fn to_json(&self) -> serde_json::Value {
+		let mut json = serde_json::json!({});
+
+		if let Some(format) = &self.format {
+			json["format"] = serde_json::json!(format);
+		}
+
+		if let Some(landscape) = self.landscape {
+			json["landscape"] = serde_json::json!(landscape);
+		}
+
+		if let Some(print_background) = self.print_background {
+			json["printBackground"] = serde_json::json!(print_background);
+		}
+
+		if let Some(scale) = self.scale {
+			json["scale"] = serde_json::json!(scale);
+		}
+
+		// Templates are ignored by Chromium unless header/footer display is on.
+		let has_template = self.header_template.is_some() || self.footer_template.is_some();
+		if let Some(display) = self.display_header_footer.or(has_template.then_some(true)) {
+			json["displayHeaderFooter"] = serde_json::json!(display);
+		}
+
+		if let Some(header_template) = &self.header_template {
+			json["headerTemplate"] = serde_json::json!(header_template);
+		}
+
+		if let Some(footer_template) = &self.footer_template {
+			json["footerTemplate"] = serde_json::json!(footer_template);
+		}
+
+		if let Some(margin) = &self.margin {
+			json["margin"] = serde_json::to_value(margin).unwrap();
+		}
+
+		json
+	}
+}
+
+/// Builder for PdfOptions
+#[derive(Debug, Clone, Default)]
+pub struct PdfOptionsBuilder {
+	options: PdfOptions,
+}
+
+impl PdfOptionsBuilder {
+	/// Set the paper format (`Letter`, `A4`, ...)
+	pub fn format(mut self, format: impl Into<String>) -> Self {
+		self.options.format = Some(format.into());
+		self
+	}
+
+	/// Use landscape orientation
+	pub fn landscape(mut self, landscape: bool) -> Self {
+		self.options.landscape = Some(landscape);
+		self
+	}
+
+	/// Print background graphics
+	pub fn print_background(mut self, print_background: bool) -> Self {
+		self.options.print_background = Some(print_background);
+		self
+	}
+
+	/// Set the rendering scale
+	pub fn scale(mut self, scale: f64) -> Self {
+		self.options.scale = Some(scale);
+		self
+	}
+
+	/// Show or hide the header and footer explicitly
+	pub fn display_header_footer(mut self, display: bool) -> Self {
+		self.options.display_header_footer = Some(display);
+		self
+	}
+
+	/// Set the header HTML template
+	///
+	/// Elements with the classes `date`, `title`, `url`, `pageNumber` and
+	/// `totalPages` receive the corresponding print values.
+	pub fn header_template(mut self, template: impl Into<String>) -> Self {
+		self.options.header_template = Some(template.into());
+		self
+	}
+
+	/// Set the footer HTML template (same classes as the header)
+	pub fn footer_template(mut self, template: impl Into<String>) -> Self {
+		self.options.footer_template = Some(template.into());
+		self
+	}
+
+	/// Set the page margins
+	pub fn margin(mut self, margin: PdfMargin) -> Self {
+		self.options.margin = Some(margin);
+		self
+	}
+
+	/// Build the PdfOptions
+	pub fn build(self) -> PdfOptions {
+		self.options
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn templates_imply_display_header_footer_and_carry_margins() {
+		let options = PdfOptions::builder()
+			.footer_template("<span class=\"pageNumber\"></span>")
+			.margin(PdfMargin {
+				top: Some("1cm".into()),
+				bottom: Some("2cm".into()),
+				..Default::default()
+			})
+			.build();
+		assert_eq!(
+			options.to_json(),
+			serde_json::json!({
+				"displayHeaderFooter": true,
+				"footerTemplate": "<span class=\"pageNumber\"></span>",
+				"margin": { "top": "1cm", "bottom": "2cm" },
+			})
+		);
+
+		let explicit = PdfOptions::builder().header_template("<b>Brand</b>").display_header_footer(false).build();
+		assert_eq!(explicit.to_json()["displayHeaderFooter"], false);
+		assert!(PdfOptions::default().to_json().get("displayHeaderFooter").is_none());
+	}
+}
@@ -91,7 +91,21 @@ impl Playwright {
 	/// * Connection to server fails
 	/// * Protocol initialization fails
 	/// * Server doesn't respond within timeout (30s)
+	///
+	/// Spawn failures are retried with exponential backoff (see
+	/// [`LaunchBackoff`](pw_runtime::LaunchBackoff)); a missing driver is reported
+	/// at once, and a driver that never answers surfaces as
+	/// [`HandshakeTimeout`](pw_runtime::Error::HandshakeTimeout) without another attempt.
 	pub async fn launch() -> Result<Self> {
+		pw_runtime::retry_launch(&pw_runtime::LaunchBackoff::default(), |attempt| {
+			tracing::debug!(attempt, "Launching Playwright driver");
+			Self::launch_once()
+		})
+		.await
+	}
+
+	/// One driver spawn plus `initialize` handshake; the driver is killed if the handshake fails.
+	async fn launch_once() -> Result<Self> {
 		use pw_runtime::connection::Connection;
 		use pw_runtime::{PipeTransport, PlaywrightServer};
 
@@ -118,6 +132,16 @@ impl Playwright {
 		let parts = transport.into_transport_parts(message_rx);
 		let connection: Arc<Connection> = Arc::new(Connection::new(parts));
 
+		// Report the driver's exit status when the pipe closes under us
+		let server = Arc::new(Mutex::new(Some(server)));
+		let probe_server = Arc::downgrade(&server);
+		connection.set_exit_status_probe(Box::new(move || {
+			let server = probe_server.upgrade()?;
+			let mut guard = server.lock();
+			let status = guard.as_mut()?.process.try_wait().ok().flatten()?;
+			Some(format!("driver exited with {status}"))
+		}))?;
+
 		// 4. Spawn connection message loop in background
 		let conn_for_loop: Arc<Connection> = Arc::clone(&connection);
 		tokio::spawn(async move {
@@ -126,7 +150,16 @@ impl Playwright {
 
 		// 5. Initialize Playwright (sends initialize message, waits for Playwright object)
 		tracing::debug!("Initializing Playwright protocol");
-		let playwright_obj = crate::initialize_playwright(&connection).await?;
+		let playwright_obj = match crate::initialize_playwright(&connection).await {
+			Ok(obj) => obj,
+			Err(err) => {
+				let server = server.lock().take();
+				if let Some(server) = server {
+					let _ = server.kill().await;
+				}
+				return Err(err);
+			}
+		};
 
 		// 6. Downcast to Playwright type
 		let playwright = playwright_obj
@@ -141,7 +174,7 @@ impl Playwright {
 			chromium: Arc::clone(&playwright.chromium),
 			firefox: Arc::clone(&playwright.firefox),
 			webkit: Arc::clone(&playwright.webkit),
-			server: Arc::new(Mutex::new(Some(server))),
+			server,
 			keep_server_running: false,
 			owns_server: true,
 		})
@@ -255,13 +288,42 @@ impl Playwright {
 	}
 
 	/// Allow the launched Playwright server to keep running after this handle is dropped.
+	///
+	/// The driver is also removed from the signal-cleanup registry, so
+	/// [`pw_runtime::playwright_server::terminate_tracked_drivers`] leaves it alone.
 	pub fn keep_server_running(&mut self) {
 		self.keep_server_running = true;
+		if let Some(pid) = self.server_pid() {
+			pw_runtime::playwright_server::untrack_driver(pid);
+		}
 	}
 
 	/// Re-enable automatic server shutdown on drop (default behavior).
 	pub fn enable_server_shutdown(&mut self) {
 		self.keep_server_running = false;
+		if let Some(pid) = self.server_pid() {
+			pw_runtime::playwright_server::track_driver(pid);
+		}
+	}
+
+	/// Returns `true` while the launched driver process is still alive.
+	///
+	/// Instances attached to an external server (see [`Playwright::connect_ws`])
+	/// have no process to watch and always report `true`. After
+	/// [`Playwright::shutdown`] this returns `false`.
+	pub fn is_driver_running(&self) -> bool {
+		if !self.owns_server {
+			return true;
+		}
+		let mut guard = self.server.lock();
+		match guard.as_mut() {
+			Some(server) => matches!(server.process.try_wait(), Ok(None)),
+			None => false,
+		}
+	}
+
+	fn server_pid(&self) -> Option<u32> {
+		self.server.lock().as_ref().and_then(PlaywrightServer::pid)
 	}
 
 	/// Shuts down the Playwright server gracefully.
@@ -356,6 +418,9 @@ impl Drop for Playwright {
 
 		if let Some(mut server) = self.server.lock().take() {
 			tracing::debug!("Drop: Force-killing Playwright server");
+			if let Some(pid) = server.pid() {
+				pw_runtime::playwright_server::untrack_driver(pid);
+			}
 
 			// We can't call async shutdown in Drop, so use blocking kill
 			// This is less graceful but ensures the process terminates
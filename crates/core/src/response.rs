@@ -3,14 +3,61 @@
 //! [`ResponseObject`] represents network responses linked to requests and
 //! returned by navigation/reload flows.
 //!
-//! The struct is primarily a channel-owner wrapper for protocol identity.
+//! Beyond protocol identity, it exposes lazily fetched connection metadata
+//! ([`SecurityDetails`], [`RemoteAddr`]) useful for TLS and CDN debugging.
 
 use std::sync::Arc;
 
 use pw_runtime::Result;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// TLS details of the connection that served a response.
+///
+/// See: <https://playwright.dev/docs/api/class-response#response-security-details>
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityDetails {
+	/// Common name of the certificate issuer.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub issuer: Option<String>,
+	/// Negotiated security protocol, e.g. `TLS 1.3`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub protocol: Option<String>,
+	/// Common name of the certificate subject.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub subject_name: Option<String>,
+	/// Certificate validity start as a Unix timestamp in seconds.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub valid_from: Option<f64>,
+	/// Certificate validity end as a Unix timestamp in seconds.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub valid_to: Option<f64>,
+}
+
+/// Remote server address a response was received from.
+///
+/// See: <https://playwright.dev/docs/api/class-response#response-server-addr>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteAddr {
+	/// IPv4 or IPv6 address of the server.
+	pub ip_address: String,
+	/// Server port.
+	pub port: u16,
+}
+
+impl std::fmt::Display for RemoteAddr {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.ip_address.contains(':') {
+			write!(f, "[{}]:{}", self.ip_address, self.port)
+		} else {
+			write!(f, "{}:{}", self.ip_address, self.port)
+		}
+	}
+}
+
 /// Response represents an HTTP response from a navigation operation.
 ///
 /// Response objects are not created directly - they are returned from
@@ -32,6 +79,35 @@ impl ResponseObject {
 
 		Ok(Self { base })
 	}
+
+	/// Returns TLS details for the connection, or `None` for non-TLS responses
+	/// (plain HTTP, `data:` URLs, cached responses).
+	///
+	/// See: <https://playwright.dev/docs/api/class-response#response-security-details>
+	pub async fn security_details(&self) -> Result<Option<SecurityDetails>> {
+		#[derive(Deserialize)]
+		struct SecurityDetailsResponse {
+			#[serde(default)]
+			value: Option<SecurityDetails>,
+		}
+
+		let response: SecurityDetailsResponse = self.channel().send("securityDetails", serde_json::json!({})).await?;
+		Ok(response.value)
+	}
+
+	/// Returns the server IP address and port, or `None` when unavailable.
+	///
+	/// See: <https://playwright.dev/docs/api/class-response#response-server-addr>
+	pub async fn server_addr(&self) -> Result<Option<RemoteAddr>> {
+		#[derive(Deserialize)]
+		struct ServerAddrResponse {
+			#[serde(default)]
+			value: Option<RemoteAddr>,
+		}
+
+		let response: ServerAddrResponse = self.channel().send("serverAddr", serde_json::json!({})).await?;
+		Ok(response.value)
+	}
 }
 
 impl pw_runtime::channel_owner::private::Sealed for ResponseObject {}
@@ -91,3 +167,36 @@ impl std::fmt::Debug for ResponseObject {
 		f.debug_struct("ResponseObject").field("guid", &self.guid()).finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn security_details_deserializes_partial_payload() {
+		let details: SecurityDetails = serde_json::from_value(serde_json::json!({
+			"protocol": "TLS 1.3",
+			"subjectName": "example.com",
+			"validTo": 1767225600
+		}))
+		.unwrap();
+		assert_eq!(details.protocol.as_deref(), Some("TLS 1.3"));
+		assert_eq!(details.subject_name.as_deref(), Some("example.com"));
+		assert!(details.issuer.is_none());
+		assert_eq!(details.valid_to, Some(1_767_225_600.0));
+	}
+
+	#[test]
+	fn remote_addr_formats_ipv4_and_ipv6() {
+		let v4 = RemoteAddr {
+			ip_address: "93.184.216.34".into(),
+			port: 443,
+		};
+		let v6 = RemoteAddr {
+			ip_address: "2606:2800:220:1::".into(),
+			port: 443,
+		};
+		assert_eq!(v4.to_string(), "93.184.216.34:443");
+		assert_eq!(v6.to_string(), "[2606:2800:220:1::]:443");
+	}
+}
@@ -3,13 +3,21 @@
 //! [`Route`] exposes abort/continue/fulfill control over intercepted requests
 //! and ties each route back to its originating [`crate::Request`].
 //!
+//! When several page route handlers match a request they are tried in reverse
+//! registration order, as in Playwright: the most recently registered matching
+//! handler runs, and only a call to [`Route::fallback`] passes the request on to
+//! the one registered before it. A request that falls through every handler
+//! continues to the network unchanged.
+//!
 //! The module also defines typed option builders for continuation and fulfill
-//! payloads.
+//! payloads, and [`AbortReason`] for the network error reported on abort.
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use pw_runtime::Result;
 use pw_runtime::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
+use serde::Serialize;
 use serde_json::{Value, json};
 
 use crate::Request;
@@ -22,6 +30,8 @@ use crate::Request;
 #[derive(Clone)]
 pub struct Route {
 	base: ChannelOwnerImpl,
+	/// Set by [`Route::fallback`]; shared by every clone handed to handlers.
+	fell_back: Arc<AtomicBool>,
 }
 
 impl Route {
@@ -32,7 +42,10 @@ impl Route {
 	pub fn new(parent: Arc<dyn ChannelOwner>, type_name: String, guid: Arc<str>, initializer: Value) -> Result<Self> {
 		let base = ChannelOwnerImpl::new(ParentOrConnection::Parent(parent.clone()), type_name, guid, initializer);
 
-		Ok(Self { base })
+		Ok(Self {
+			base,
+			fell_back: Arc::new(AtomicBool::new(false)),
+		})
 	}
 
 	/// Returns the request that is being routed.
@@ -64,27 +77,14 @@ impl Route {
 			.expect("Route fallback: Request::new should not fail for stub construction")
 	}
 
-	/// Aborts the route's request.
-	///
-	/// # Arguments
-	///
-	/// * `error_code` - Optional error code (default: "failed")
+	/// Aborts the route's request with the network error for `error_reason`.
 	///
-	/// Available error codes:
-	/// * "aborted" - User-initiated cancellation
-	/// * "accessdenied" - Permission denied
-	/// * "addressunreachable" - Host unreachable
-	/// * "blockedbyclient" - Client blocked request
-	/// * "connectionaborted", "connectionclosed", "connectionfailed", "connectionrefused", "connectionreset"
-	/// * "internetdisconnected"
-	/// * "namenotresolved"
-	/// * "timedout"
-	/// * "failed" - Generic error (default)
+	/// Defaults to [`AbortReason::Failed`] when `None`.
 	///
 	/// See: <https://playwright.dev/docs/api/class-route#route-abort>
-	pub async fn abort(&self, error_code: Option<&str>) -> Result<()> {
+	pub async fn abort(&self, error_reason: Option<AbortReason>) -> Result<()> {
 		let params = json!({
-			"errorCode": error_code.unwrap_or("failed")
+			"errorCode": error_reason.unwrap_or_default()
 		});
 
 		self.channel().send::<_, serde_json::Value>("abort", params).await.map(|_| ())
@@ -133,6 +133,32 @@ impl Route {
 		self.channel().send::<_, serde_json::Value>("continue", params).await.map(|_| ())
 	}
 
+	/// Passes the request to the previously registered matching route handler.
+	///
+	/// Handlers are tried newest first; when none is left the request
+	/// continues to the network as if it had not been intercepted. Call this
+	/// instead of [`abort`](Self::abort), [`continue_`](Self::continue_) or
+	/// [`fulfill`](Self::fulfill), not in addition to them.
+	///
+	/// See: <https://playwright.dev/docs/api/class-route#route-fallback>
+	pub async fn fallback(&self) -> Result<()> {
+		self.fell_back.store(true, Ordering::SeqCst);
+		Ok(())
+	}
+
+	/// Clears and returns whether the last handler called [`fallback`](Self::fallback).
+	pub(crate) fn take_fallback(&self) -> bool {
+		self.fell_back.swap(false, Ordering::SeqCst)
+	}
+
+	/// Continues the request after every matching handler fell back.
+	pub(crate) async fn continue_after_fallback(&self) -> Result<()> {
+		self.channel()
+			.send::<_, serde_json::Value>("continue", json!({ "isFallback": true }))
+			.await
+			.map(|_| ())
+	}
+
 	/// Fulfills the route's request with a custom response.
 	///
 	/// # Arguments
@@ -210,6 +236,73 @@ impl Route {
 	}
 }
 
+/// Network error reported to the page when a route is aborted.
+///
+/// Serialized as the `errorCode` of the `abort` RPC.
+///
+/// See: <https://playwright.dev/docs/api/class-route#route-abort>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AbortReason {
+	/// User-initiated cancellation
+	Aborted,
+	/// Permission to access a resource was denied
+	AccessDenied,
+	/// The IP address is unreachable
+	AddressUnreachable,
+	/// The client chose to block the request
+	BlockedByClient,
+	/// The response was blocked (e.g. valid response body mismatch)
+	BlockedByResponse,
+	/// A connection timed out as a result of not receiving an ACK
+	ConnectionAborted,
+	/// A connection was closed (TCP FIN)
+	ConnectionClosed,
+	/// A connection attempt failed
+	ConnectionFailed,
+	/// A connection attempt was refused
+	ConnectionRefused,
+	/// A connection was reset (TCP RST)
+	ConnectionReset,
+	/// The Internet connection has been lost
+	InternetDisconnected,
+	/// The host name could not be resolved
+	NameNotResolved,
+	/// An operation timed out
+	TimedOut,
+	/// A generic failure occurred
+	#[default]
+	Failed,
+}
+
+impl AbortReason {
+	/// Returns the protocol error code string.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Aborted => "aborted",
+			Self::AccessDenied => "accessdenied",
+			Self::AddressUnreachable => "addressunreachable",
+			Self::BlockedByClient => "blockedbyclient",
+			Self::BlockedByResponse => "blockedbyresponse",
+			Self::ConnectionAborted => "connectionaborted",
+			Self::ConnectionClosed => "connectionclosed",
+			Self::ConnectionFailed => "connectionfailed",
+			Self::ConnectionRefused => "connectionrefused",
+			Self::ConnectionReset => "connectionreset",
+			Self::InternetDisconnected => "internetdisconnected",
+			Self::NameNotResolved => "namenotresolved",
+			Self::TimedOut => "timedout",
+			Self::Failed => "failed",
+		}
+	}
+}
+
+impl std::fmt::Display for AbortReason {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
 /// Options for continuing a request with modifications.
 ///
 /// Allows modifying headers, method, post data, and URL when continuing a route.
@@ -432,3 +525,16 @@ impl std::fmt::Debug for Route {
 			.finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn abort_reason_serializes_to_protocol_error_code() {
+		assert_eq!(json!({ "errorCode": AbortReason::BlockedByClient }), json!({ "errorCode": "blockedbyclient" }));
+		assert_eq!(serde_json::to_value(AbortReason::ConnectionRefused).unwrap(), "connectionrefused");
+		assert_eq!(serde_json::to_value(AbortReason::TimedOut).unwrap(), AbortReason::TimedOut.as_str());
+		assert_eq!(AbortReason::default(), AbortReason::Failed);
+	}
+}
@@ -210,10 +210,64 @@ impl ScreenshotOptionsBuilder {
 	}
 }
 
+/// Reads the pixel dimensions from a PNG or JPEG header.
+///
+/// Returns `None` when `bytes` is neither format or the header is truncated.
+pub(crate) fn image_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+	const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+	if bytes.starts_with(PNG_SIGNATURE) {
+		// The IHDR chunk always comes first: length (4), type (4), width (4), height (4).
+		if bytes.get(12..16)? != b"IHDR" {
+			return None;
+		}
+		let width = u32::from_be_bytes(bytes.get(16..20)?.try_into().ok()?);
+		let height = u32::from_be_bytes(bytes.get(20..24)?.try_into().ok()?);
+		return Some((width, height));
+	}
+
+	if bytes.starts_with(&[0xFF, 0xD8]) {
+		// Walk the marker segments until a start-of-frame (SOF0..SOF15, minus DHT/JPG/DAC).
+		let mut pos = 2;
+		while pos + 4 <= bytes.len() {
+			if bytes[pos] != 0xFF {
+				return None;
+			}
+			let marker = bytes[pos + 1];
+			let len = u16::from_be_bytes([bytes[pos + 2], bytes[pos + 3]]) as usize;
+			if matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+				let height = u16::from_be_bytes(bytes.get(pos + 5..pos + 7)?.try_into().ok()?);
+				let width = u16::from_be_bytes(bytes.get(pos + 7..pos + 9)?.try_into().ok()?);
+				return Some((width.into(), height.into()));
+			}
+			pos += 2 + len;
+		}
+	}
+
+	None
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn reads_png_and_jpeg_dimensions() {
+		let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+		png.extend_from_slice(&300u32.to_be_bytes());
+		png.extend_from_slice(&150u32.to_be_bytes());
+		assert_eq!(image_dimensions(&png), Some((300, 150)));
+
+		// SOI, an APP0 segment, then SOF0 with precision 8, height 40, width 120.
+		let jpeg = [
+			0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x28, 0x00, 0x78,
+		];
+		assert_eq!(image_dimensions(&jpeg), Some((120, 40)));
+
+		assert_eq!(image_dimensions(&png[..20]), None);
+		assert_eq!(image_dimensions(b"GIF89a"), None);
+	}
+
 	#[test]
 	fn test_screenshot_type_serialization() {
 		assert_eq!(serde_json::to_string(&ScreenshotType::Png).unwrap(), "\"png\"");
@@ -0,0 +1,86 @@
+//! Client-side default timeouts for pages and contexts.
+//!
+//! Playwright 1.5x servers require an explicit `timeout` on every call and no
+//! longer accept `setDefaultTimeoutNoReply`, so defaults set through
+//! [`Page::set_default_timeout`](crate::Page::set_default_timeout) and friends are
+//! resolved here and sent with each request, as the official clients do.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+/// Default timeouts of one page or context, falling back to its parent's.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TimeoutSettings {
+	defaults: Arc<Mutex<Defaults>>,
+	parent: Option<Arc<TimeoutSettings>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Defaults {
+	timeout: Option<f64>,
+	navigation_timeout: Option<f64>,
+}
+
+impl TimeoutSettings {
+	/// Settings that fall back to `parent` for anything not set locally.
+	pub(crate) fn with_parent(parent: &TimeoutSettings) -> Self {
+		Self {
+			defaults: Arc::default(),
+			parent: Some(Arc::new(parent.clone())),
+		}
+	}
+
+	pub(crate) fn set_default_timeout(&self, timeout_ms: f64) {
+		self.defaults.lock().timeout = Some(timeout_ms);
+	}
+
+	pub(crate) fn set_default_navigation_timeout(&self, timeout_ms: f64) {
+		self.defaults.lock().navigation_timeout = Some(timeout_ms);
+	}
+
+	/// Timeout for actions and queries, in milliseconds.
+	pub(crate) fn timeout(&self) -> f64 {
+		let local = self.defaults.lock().timeout;
+		local
+			.or_else(|| self.parent.as_ref().map(|parent| parent.timeout()))
+			.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS)
+	}
+
+	/// Timeout for navigations and load-state waits, in milliseconds.
+	///
+	/// A local navigation default wins over a local general default, which wins
+	/// over anything inherited.
+	pub(crate) fn navigation_timeout(&self) -> f64 {
+		let local = *self.defaults.lock();
+		local
+			.navigation_timeout
+			.or(local.timeout)
+			.or_else(|| self.parent.as_ref().map(|parent| parent.navigation_timeout()))
+			.unwrap_or(pw_protocol::options::DEFAULT_TIMEOUT_MS)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn page_settings_inherit_and_override_context_defaults() {
+		let context = TimeoutSettings::default();
+		let page = TimeoutSettings::with_parent(&context);
+		assert_eq!(page.timeout(), pw_protocol::options::DEFAULT_TIMEOUT_MS);
+
+		context.set_default_navigation_timeout(5_000.0);
+		assert_eq!(page.navigation_timeout(), 5_000.0);
+		assert_eq!(page.timeout(), pw_protocol::options::DEFAULT_TIMEOUT_MS);
+
+		page.set_default_timeout(200.0);
+		assert_eq!(page.timeout(), 200.0);
+		assert_eq!(page.navigation_timeout(), 200.0);
+		assert_eq!(context.timeout(), pw_protocol::options::DEFAULT_TIMEOUT_MS);
+
+		page.set_default_navigation_timeout(1_000.0);
+		assert_eq!(page.navigation_timeout(), 1_000.0);
+	}
+}
@@ -65,8 +65,10 @@ impl Tracing {
 
 	/// Starts recording a trace.
 	///
-	/// Only one trace can be recording at a time per context. Call [`stop`](Self::stop)
-	/// to finish recording and save the trace.
+	/// Recording is scoped to the owning context: every page in it is captured,
+	/// including popups and pages opened after this call. Only one trace can be
+	/// recording at a time per context. Call [`stop`](Self::stop) to finish
+	/// recording and save the trace.
 	///
 	/// # Arguments
 	///
@@ -5,6 +5,7 @@
 //!
 //! 1. Extension connects and sends [`ExtensionMessage::Hello`] with a token
 //! 2. Server responds with [`ServerMessage::Welcome`] or [`ServerMessage::Rejected`]
+//!    (bad token or incompatible [`AUTH_PROTOCOL_VERSION`])
 //! 3. Extension sends [`ExtensionMessage::PushCookies`] with domain-grouped cookies
 //! 4. Server responds with [`ServerMessage::Received`] or [`ServerMessage::Error`]
 //!
@@ -30,6 +31,10 @@ pub enum ExtensionMessage {
 	Hello {
 		/// Token displayed by `pw exec auth.listen --input '{}'`, proves the user authorized this connection.
 		token: String,
+		/// Exchange protocol version the extension speaks; `0` when sent by an extension
+		/// predating version negotiation.
+		#[serde(default)]
+		protocol_version: u32,
 	},
 	/// Push cookies for one or more domains to be saved as auth files.
 	PushCookies {
@@ -48,6 +53,9 @@ pub enum ServerMessage {
 	Welcome {
 		/// Server version for client compatibility checks.
 		version: String,
+		/// Exchange protocol version the server speaks.
+		#[serde(default)]
+		protocol_version: u32,
 	},
 	/// Authentication failed due to invalid or expired token.
 	Rejected {
@@ -156,6 +164,17 @@ impl ExtensionCookie {
 	}
 }
 
+/// Version of the cookie exchange protocol spoken by this build.
+///
+/// Bump whenever message shapes change incompatibly; peers on a different
+/// version are rejected during the [`ExtensionMessage::Hello`] handshake.
+pub const AUTH_PROTOCOL_VERSION: u32 = 1;
+
+/// Returns `true` when a peer speaking `version` can talk to this build.
+pub fn is_compatible_protocol_version(version: u32) -> bool {
+	version == AUTH_PROTOCOL_VERSION
+}
+
 /// Default WebSocket port for the auth listener server.
 pub const AUTH_LISTEN_PORT: u16 = 9271;
 
@@ -168,15 +187,32 @@ mod tests {
 
 	#[test]
 	fn extension_message_hello_serializes_with_type_tag() {
-		let msg = ExtensionMessage::Hello { token: "abc123".into() };
+		let msg = ExtensionMessage::Hello {
+			token: "abc123".into(),
+			protocol_version: AUTH_PROTOCOL_VERSION,
+		};
 		let json = serde_json::to_string(&msg).unwrap();
 		assert!(json.contains(r#""type":"hello""#));
 		assert!(json.contains(r#""token":"abc123""#));
+		assert!(json.contains(r#""protocol_version":1"#));
+	}
+
+	#[test]
+	fn legacy_hello_without_protocol_version_decodes_as_zero() {
+		let msg: ExtensionMessage = serde_json::from_str(r#"{"type":"hello","token":"abc"}"#).unwrap();
+		let ExtensionMessage::Hello { protocol_version, .. } = msg else {
+			panic!("expected hello");
+		};
+		assert_eq!(protocol_version, 0);
+		assert!(!is_compatible_protocol_version(protocol_version));
 	}
 
 	#[test]
 	fn server_message_welcome_serializes_with_type_tag() {
-		let msg = ServerMessage::Welcome { version: "0.12.0".into() };
+		let msg = ServerMessage::Welcome {
+			version: "0.12.0".into(),
+			protocol_version: AUTH_PROTOCOL_VERSION,
+		};
 		let json = serde_json::to_string(&msg).unwrap();
 		assert!(json.contains(r#""type":"welcome""#));
 	}
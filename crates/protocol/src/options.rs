@@ -1108,3 +1108,77 @@ impl AccessibilitySnapshotOptionsBuilder {
 		self.inner
 	}
 }
+
+/// Element state to wait for in `waitForSelector` and `Locator::wait_for`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WaitForSelectorState {
+	/// Element is present in the DOM
+	Attached,
+	/// Element is absent from the DOM
+	Detached,
+	/// Element is present and has a non-empty bounding box without `visibility: hidden`
+	#[default]
+	Visible,
+	/// Element is detached, empty, or `visibility: hidden`
+	Hidden,
+}
+
+impl WaitForSelectorState {
+	/// Returns the protocol value (`attached`, `detached`, `visible`, `hidden`).
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Attached => "attached",
+			Self::Detached => "detached",
+			Self::Visible => "visible",
+			Self::Hidden => "hidden",
+		}
+	}
+}
+
+impl std::fmt::Display for WaitForSelectorState {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl std::str::FromStr for WaitForSelectorState {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"attached" => Ok(Self::Attached),
+			"detached" => Ok(Self::Detached),
+			"visible" => Ok(Self::Visible),
+			"hidden" => Ok(Self::Hidden),
+			other => Err(format!("unknown selector state '{other}' (expected attached, detached, visible, or hidden)")),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const STATES: [WaitForSelectorState; 4] = [
+		WaitForSelectorState::Attached,
+		WaitForSelectorState::Detached,
+		WaitForSelectorState::Visible,
+		WaitForSelectorState::Hidden,
+	];
+
+	#[test]
+	fn wait_for_selector_state_matches_protocol_values() {
+		let values: Vec<&str> = STATES.iter().map(|state| state.as_str()).collect();
+		assert_eq!(values, ["attached", "detached", "visible", "hidden"]);
+		for state in STATES {
+			assert_eq!(serde_json::to_value(state).unwrap(), serde_json::json!(state.as_str()));
+			assert_eq!(state.as_str().parse::<WaitForSelectorState>(), Ok(state));
+		}
+	}
+
+	#[test]
+	fn wait_for_selector_state_rejects_unknown_values() {
+		assert!("present".parse::<WaitForSelectorState>().is_err());
+	}
+}
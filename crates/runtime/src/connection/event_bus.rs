@@ -0,0 +1,49 @@
+//! Raw protocol event fan-out for [`Connection::subscribe_events`](super::Connection::subscribe_events).
+//!
+//! One [`broadcast`] channel exists per GUID filter (plus one for "all
+//! events"). Channels are created on first subscription and dropped lazily
+//! once their last receiver goes away.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use tokio::sync::broadcast;
+
+use super::Event;
+
+/// Buffered events per subscription before slow receivers observe `Lagged`.
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Broadcast senders keyed by GUID filter (`None` receives every event).
+#[derive(Default)]
+pub(super) struct EventBus {
+	senders: DashMap<Option<Arc<str>>, broadcast::Sender<Event>>,
+}
+
+impl EventBus {
+	pub(super) fn subscribe(&self, guid_filter: Option<&str>) -> broadcast::Receiver<Event> {
+		self.senders
+			.entry(guid_filter.map(Arc::from))
+			.or_insert_with(|| broadcast::channel(EVENT_CHANNEL_CAPACITY).0)
+			.subscribe()
+	}
+
+	/// Delivers `event` to unfiltered subscribers and to those watching its GUID.
+	pub(super) fn publish(&self, event: &Event) {
+		if self.senders.is_empty() {
+			return;
+		}
+		self.send(None, event);
+		self.send(Some(Arc::clone(&event.guid)), event);
+	}
+
+	fn send(&self, key: Option<Arc<str>>, event: &Event) {
+		let stale = match self.senders.get(&key) {
+			Some(sender) => sender.send(event.clone()).is_err(),
+			None => return,
+		};
+		if stale {
+			self.senders.remove_if(&key, |_, sender| sender.receiver_count() == 0);
+		}
+	}
+}
@@ -6,6 +6,8 @@
 //! * Correlating responses with pending requests
 //! * Distinguishing events from responses
 //! * Dispatching events to protocol objects
+//! * Fanning raw events out to [`Connection::subscribe_events`] receivers
+//! * Optionally dumping raw traffic to a file (see [`install_protocol_dump`])
 //!
 //! # Message Flow
 //!
@@ -17,7 +19,9 @@
 //! 6. Response is correlated by ID and sent via oneshot channel
 //! 7. Client receives result
 
+mod event_bus;
 mod object_store;
+mod protocol_dump;
 #[cfg(test)]
 mod tests;
 
@@ -30,10 +34,12 @@ use std::time::Duration;
 
 use dashmap::DashMap;
 pub use object_store::ObjectStore;
+pub use protocol_dump::{Direction, ProtocolDump, install_protocol_dump, redact};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio::sync::{Mutex as TokioMutex, mpsc, oneshot};
+use tokio::sync::{Mutex as TokioMutex, broadcast, mpsc, oneshot};
 
+use self::event_bus::EventBus;
 use crate::channel_owner::{ChannelOwner, DisposeReason, ParentOrConnection};
 use crate::error::{Error, Result};
 use crate::transport::{Transport, TransportParts, TransportReceiver};
@@ -66,6 +72,14 @@ pub trait ConnectionLike: Send + Sync {
 	///
 	/// Uses notification-based waiting rather than polling for efficiency.
 	fn wait_for_object(&self, guid: &str, timeout: Duration) -> AsyncChannelOwnerResult<'_>;
+
+	/// Subscribe to raw protocol events, optionally limited to one object GUID.
+	///
+	/// See [`Connection::subscribe_events`]. The default implementation returns
+	/// an already-closed receiver, for connections that do not fan out raw events.
+	fn subscribe_events(&self, _guid_filter: Option<&str>) -> broadcast::Receiver<Event> {
+		broadcast::channel(1).1
+	}
 }
 
 /// Type alias for complex async return type
@@ -287,8 +301,15 @@ pub struct Connection {
 	outbound_rx: Arc<TokioMutex<Option<mpsc::UnboundedReceiver<Value>>>>,
 	objects: Arc<ObjectStore>,
 	factory: OnceLock<Arc<dyn ObjectFactory>>,
+	closed: OnceLock<String>,
+	exit_status_probe: OnceLock<ExitStatusProbe>,
+	events: EventBus,
+	protocol_dump: Option<Arc<ProtocolDump>>,
 }
 
+/// Reports how the process behind a connection exited, if it has.
+pub type ExitStatusProbe = Box<dyn Fn() -> Option<String> + Send + Sync>;
+
 impl Connection {
 	pub fn new(parts: TransportParts) -> Self {
 		let (outbound_tx, outbound_rx) = mpsc::unbounded_channel();
@@ -302,6 +323,10 @@ impl Connection {
 			outbound_rx: Arc::new(TokioMutex::new(Some(outbound_rx))),
 			objects: Arc::new(ObjectStore::new()),
 			factory: OnceLock::new(),
+			closed: OnceLock::new(),
+			exit_status_probe: OnceLock::new(),
+			events: EventBus::default(),
+			protocol_dump: protocol_dump::installed(),
 		}
 	}
 
@@ -314,8 +339,71 @@ impl Connection {
 			.map_err(|_| Error::ProtocolError("set_factory can only be called once".into()))
 	}
 
+	/// Registers a probe used to enrich close diagnostics with the driver's exit status.
+	///
+	/// Returns an error if called more than once.
+	pub fn set_exit_status_probe(&self, probe: ExitStatusProbe) -> Result<()> {
+		self.exit_status_probe
+			.set(probe)
+			.map_err(|_| Error::ProtocolError("set_exit_status_probe can only be called once".into()))
+	}
+
+	/// Subscribes to raw protocol events as they arrive from the driver.
+	///
+	/// This is a low-level escape hatch for events the typed handlers do not
+	/// cover. With `Some(guid)` only events emitted by that object are
+	/// delivered; with `None` every event is, including `__create__`,
+	/// `__dispose__`, and `__adopt__`. Events are published before typed
+	/// dispatch, and receivers that fall behind by more than 256 events get
+	/// [`broadcast::error::RecvError::Lagged`].
+	///
+	/// Method names and params follow the Playwright wire protocol, which
+	/// may change between driver versions.
+	pub fn subscribe_events(&self, guid_filter: Option<&str>) -> broadcast::Receiver<Event> {
+		self.events.subscribe(guid_filter)
+	}
+
+	/// Returns `true` once the connection has been closed.
+	pub fn is_closed(&self) -> bool {
+		self.closed.get().is_some()
+	}
+
+	/// Marks the connection closed and fails every pending request.
+	///
+	/// The first reason wins; later calls are no-ops. Requests sent after
+	/// closing fail immediately with the same [`Error::TargetClosed`].
+	pub fn close(&self, reason: impl Into<String>) {
+		let mut reason = reason.into();
+		if let Some(status) = self.exit_status_probe.get().and_then(|probe| probe()) {
+			reason = format!("{reason} ({status})");
+		}
+		if self.closed.set(reason).is_err() {
+			return;
+		}
+
+		tracing::warn!("Connection closed: {}", self.closed.get().map(String::as_str).unwrap_or_default());
+
+		let ids: Vec<u32> = self.callbacks.iter().map(|entry| *entry.key()).collect();
+		for id in ids {
+			if let Some((_, callback)) = self.callbacks.remove(&id) {
+				let _ = callback.send(Err(self.closed_error().unwrap_or(Error::ChannelClosed)));
+			}
+		}
+	}
+
+	fn closed_error(&self) -> Option<Error> {
+		self.closed.get().map(|reason| Error::TargetClosed {
+			target_type: "Playwright driver connection".to_string(),
+			context: reason.clone(),
+		})
+	}
+
 	/// Sends a message to the Playwright server and awaits the response.
 	pub async fn send_message(&self, guid: &str, method: &str, params: Value) -> Result<Value> {
+		if let Some(err) = self.closed_error() {
+			return Err(err);
+		}
+
 		let id = self.last_id.fetch_add(1, Ordering::SeqCst);
 
 		tracing::debug!("Sending message: id={}, guid='{}', method='{}'", id, guid, method);
@@ -325,6 +413,11 @@ impl Connection {
 
 		let guard = CancelGuard::new(id, Arc::clone(&self.callbacks));
 
+		// `close` may have drained callbacks between the check above and the insert.
+		if let Some(err) = self.closed_error() {
+			return Err(err);
+		}
+
 		let request = Request {
 			id,
 			guid: Arc::from(guid),
@@ -370,13 +463,19 @@ impl Connection {
 			.expect("run() can only be called once - outbound receiver already taken");
 
 		let reader_handle = tokio::spawn(async move {
-			if let Err(e) = transport_receiver.run().await {
+			let result = transport_receiver.run().await;
+			if let Err(e) = &result {
 				tracing::error!("Transport read error: {}", e);
 			}
+			result
 		});
 
+		let dump = self.protocol_dump.clone();
 		let writer_handle = tokio::spawn(async move {
 			while let Some(message) = outbound_rx.recv().await {
+				if let Some(dump) = &dump {
+					dump.record(Direction::Send, &message);
+				}
 				if let Err(e) = transport_sender.send(message).await {
 					tracing::error!("Transport write error: {}", e);
 					break;
@@ -392,6 +491,9 @@ impl Connection {
 			.expect("run() can only be called once - message receiver already taken");
 
 		while let Some(message_value) = message_rx.recv().await {
+			if let Some(dump) = &self.protocol_dump {
+				dump.record(Direction::Recv, &message_value);
+			}
 			match serde_json::from_value::<Message>(message_value) {
 				Ok(message) => {
 					if let Err(e) = self.dispatch_internal(message).await {
@@ -404,7 +506,17 @@ impl Connection {
 			}
 		}
 
-		let _ = reader_handle.await;
+		// The message stream only ends once the reader is done: EOF, a read
+		// error, or the driver exiting. Nothing will answer pending requests now.
+		let reason = match reader_handle.await {
+			Ok(Err(e)) => format!("driver pipe closed: {e}"),
+			Ok(Ok(())) => "driver pipe closed".to_string(),
+			Err(e) => format!("transport reader task failed: {e}"),
+		};
+		self.close(reason);
+
+		// The writer would otherwise wait forever on `outbound_tx`, which this connection owns.
+		writer_handle.abort();
 		let _ = writer_handle.await;
 	}
 
@@ -433,21 +545,10 @@ impl Connection {
 				let _ = callback.send(result);
 				Ok(())
 			}
-			Message::Event(event) => match event.method.as_str() {
-				"__create__" => self.handle_create(&event).await,
-				"__dispose__" => self.handle_dispose(&event).await,
-				"__adopt__" => self.handle_adopt(&event).await,
-				_ => match self.objects.try_get(&event.guid) {
-					Some(object) => {
-						object.on_event(&event.method, event.params);
-						Ok(())
-					}
-					None => {
-						tracing::debug!("Event for unknown object (ignored): guid={}, method={}", event.guid, event.method);
-						Ok(())
-					}
-				},
-			},
+			Message::Event(event) => {
+				self.events.publish(&event);
+				self.route_event(event).await
+			}
 			Message::Unknown(value) => {
 				tracing::debug!(
 					"Unknown message type (forward-compatible, ignored): {}",
@@ -458,6 +559,25 @@ impl Connection {
 		}
 	}
 
+	/// Routes an event to lifecycle handlers or the owning protocol object.
+	async fn route_event(self: &Arc<Self>, event: Event) -> Result<()> {
+		match event.method.as_str() {
+			"__create__" => self.handle_create(&event).await,
+			"__dispose__" => self.handle_dispose(&event).await,
+			"__adopt__" => self.handle_adopt(&event).await,
+			_ => match self.objects.try_get(&event.guid) {
+				Some(object) => {
+					object.on_event(&event.method, event.params);
+					Ok(())
+				}
+				None => {
+					tracing::debug!("Event for unknown object (ignored): guid={}, method={}", event.guid, event.method);
+					Ok(())
+				}
+			},
+		}
+	}
+
 	/// Handle `__create__` protocol message
 	async fn handle_create(self: &Arc<Self>, event: &Event) -> Result<()> {
 		let type_name = event.params["type"]
@@ -600,4 +720,8 @@ impl ConnectionLike for Connection {
 		let guid_owned = guid.to_string();
 		Box::pin(async move { self.objects.wait_for(&guid_owned, timeout).await })
 	}
+
+	fn subscribe_events(&self, guid_filter: Option<&str>) -> broadcast::Receiver<Event> {
+		Connection::subscribe_events(self, guid_filter)
+	}
 }
@@ -0,0 +1,145 @@
+//! Raw JSON-RPC message dump for debugging protocol issues.
+//!
+//! Once [`install_protocol_dump`] has been called, every [`Connection`](super::Connection)
+//! created afterwards appends one JSON line per message it sends or receives:
+//!
+//! ```text
+//! {"ts":1718000000123,"dir":"send","message":{"id":3,"guid":"page@…","method":"goto",…}}
+//! ```
+//!
+//! Values that commonly carry credentials (cookies, passwords, HTTP
+//! credentials, auth headers, storage state, localStorage entry values) are
+//! replaced with `"<redacted>"` before they are written.
+
+use std::fs::{File, OpenOptions};
+use std::io::{LineWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::Mutex;
+use serde_json::{Value, json};
+
+use crate::error::{Error, Result};
+
+/// Placeholder written in place of sensitive values.
+const REDACTED: &str = "<redacted>";
+
+/// Object keys whose values are always redacted (compared case-insensitively).
+const SENSITIVE_KEYS: &[&str] = &[
+	"cookies",
+	"cookie",
+	"password",
+	"httpcredentials",
+	"authorization",
+	"proxy-authorization",
+	"storagestate",
+];
+
+/// Header names whose `value` is redacted in `[{name, value}]` header arrays.
+const SENSITIVE_HEADERS: &[&str] = &["cookie", "set-cookie", "authorization", "proxy-authorization"];
+
+static PROTOCOL_DUMP: OnceLock<Arc<ProtocolDump>> = OnceLock::new();
+
+/// Direction of a dumped message relative to this process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+	Send,
+	Recv,
+}
+
+impl Direction {
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::Send => "send",
+			Self::Recv => "recv",
+		}
+	}
+}
+
+/// Line-oriented sink shared by every connection in the process.
+pub struct ProtocolDump {
+	file: Mutex<LineWriter<File>>,
+}
+
+impl ProtocolDump {
+	/// Appends one redacted message line; write failures are logged and otherwise ignored.
+	pub fn record(&self, direction: Direction, message: &Value) {
+		let ts = std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_millis() as u64;
+		let line = json!({
+			"ts": ts,
+			"dir": direction.as_str(),
+			"message": redact(message),
+		});
+		if let Err(e) = writeln!(self.file.lock(), "{line}") {
+			tracing::warn!("Failed to write protocol dump: {}", e);
+		}
+	}
+}
+
+/// Starts dumping protocol traffic of connections created from now on to `path`.
+///
+/// The file is truncated. Returns an error if it cannot be created or a dump
+/// is already installed.
+pub fn install_protocol_dump(path: &Path) -> Result<()> {
+	let file = OpenOptions::new().create(true).write(true).truncate(true).open(path)?;
+	PROTOCOL_DUMP
+		.set(Arc::new(ProtocolDump {
+			file: Mutex::new(LineWriter::new(file)),
+		}))
+		.map_err(|_| Error::ProtocolError("protocol dump is already installed".into()))
+}
+
+/// The installed dump, if any.
+pub(super) fn installed() -> Option<Arc<ProtocolDump>> {
+	PROTOCOL_DUMP.get().cloned()
+}
+
+/// Returns a copy of `value` with sensitive fields replaced by a placeholder.
+pub fn redact(value: &Value) -> Value {
+	match value {
+		Value::Object(map) => {
+			let sensitive_header = map
+				.get("name")
+				.and_then(Value::as_str)
+				.is_some_and(|name| SENSITIVE_HEADERS.iter().any(|header| name.eq_ignore_ascii_case(header)));
+			map.iter()
+				.map(|(key, value)| {
+					let hidden = SENSITIVE_KEYS.iter().any(|sensitive| key.eq_ignore_ascii_case(sensitive)) || (sensitive_header && key == "value");
+					let value = if hidden {
+						Value::String(REDACTED.into())
+					} else if key.eq_ignore_ascii_case("localstorage") {
+						redact_entry_values(value)
+					} else {
+						redact(value)
+					};
+					(key.clone(), value)
+				})
+				.collect()
+		}
+		Value::Array(items) => items.iter().map(redact).collect(),
+		other => other.clone(),
+	}
+}
+
+/// Redacts the `value` of each `{name, value}` entry, keeping the names.
+fn redact_entry_values(entries: &Value) -> Value {
+	match entries {
+		Value::Array(items) => items
+			.iter()
+			.map(|item| match item {
+				Value::Object(entry) => entry
+					.iter()
+					.map(|(key, value)| {
+						let value = if key == "value" { Value::String(REDACTED.into()) } else { value.clone() };
+						(key.clone(), value)
+					})
+					.collect(),
+				_ => Value::String(REDACTED.into()),
+			})
+			.collect(),
+		_ => Value::String(REDACTED.into()),
+	}
+}
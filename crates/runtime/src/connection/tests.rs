@@ -143,3 +143,122 @@ fn test_error_type_parsing() {
 		_ => panic!("Expected Remote error"),
 	}
 }
+
+#[tokio::test]
+async fn test_pipe_eof_fails_pending_requests() {
+	let (connection, _stdin_read, stdout_write) = create_test_connection();
+	let connection = Arc::new(connection);
+	connection
+		.set_exit_status_probe(Box::new(|| Some("driver exited with exit status: 1".to_string())))
+		.unwrap();
+
+	let runner = Arc::clone(&connection);
+	let run_handle = tokio::spawn(async move { runner.run().await });
+
+	let sender = Arc::clone(&connection);
+	let pending = tokio::spawn(async move { sender.send_message("page@1", "goto", serde_json::json!({})).await });
+
+	while connection.callbacks.is_empty() {
+		tokio::task::yield_now().await;
+	}
+	drop(stdout_write);
+
+	let err = tokio::time::timeout(Duration::from_secs(5), pending)
+		.await
+		.expect("pending request should fail promptly")
+		.unwrap()
+		.unwrap_err();
+	assert!(err.is_target_closed(), "{err}");
+	assert!(err.to_string().contains("exit status: 1"), "{err}");
+
+	tokio::time::timeout(Duration::from_secs(5), run_handle)
+		.await
+		.expect("run loop should exit after EOF")
+		.unwrap();
+
+	assert!(connection.is_closed());
+	let err = connection.send_message("page@1", "reload", serde_json::json!({})).await.unwrap_err();
+	assert!(err.is_target_closed(), "{err}");
+	assert!(connection.callbacks.is_empty());
+}
+
+#[tokio::test]
+async fn test_subscribe_events_filters_by_guid() {
+	let (connection, _, _) = create_test_connection();
+	let connection = Arc::new(connection);
+
+	let mut frame_events = connection.subscribe_events(Some("frame@main"));
+	let mut all_events = connection.subscribe_events(None);
+
+	let event = |guid: &str, method: &str| {
+		Message::Event(Event {
+			guid: Arc::from(guid),
+			method: method.to_string(),
+			params: serde_json::json!({ "url": "https://example.com/" }),
+		})
+	};
+	connection.dispatch(event("page@other", "console")).await.unwrap();
+	connection.dispatch(event("frame@main", "navigated")).await.unwrap();
+
+	let received = frame_events.recv().await.unwrap();
+	assert_eq!(received.guid.as_ref(), "frame@main");
+	assert_eq!(received.method, "navigated");
+	assert_eq!(received.params["url"], "https://example.com/");
+	assert!(frame_events.try_recv().is_err(), "filtered receiver must skip other objects");
+
+	assert_eq!(all_events.recv().await.unwrap().method, "console");
+	assert_eq!(all_events.recv().await.unwrap().method, "navigated");
+}
+
+#[tokio::test]
+async fn test_dropped_subscribers_do_not_block_dispatch() {
+	let (connection, _, _) = create_test_connection();
+	let connection = Arc::new(connection);
+
+	drop(connection.subscribe_events(Some("frame@main")));
+	let message = Message::Event(Event {
+		guid: Arc::from("frame@main"),
+		method: "navigated".to_string(),
+		params: serde_json::json!({}),
+	});
+	connection.dispatch(message).await.unwrap();
+
+	let mut late = connection.subscribe_events(Some("frame@main"));
+	assert!(late.try_recv().is_err());
+}
+
+#[test]
+fn test_protocol_dump_redacts_credentials() {
+	let params = serde_json::json!({
+		"url": "https://example.com",
+		"cookies": [{"name": "sid", "value": "secret"}],
+		"httpCredentials": {"username": "u", "password": "p"},
+		"extraHTTPHeaders": [
+			{"name": "Authorization", "value": "Bearer token"},
+			{"name": "Accept", "value": "text/html"}
+		]
+	});
+
+	let redacted = redact(&params);
+	assert_eq!(redacted["url"], "https://example.com");
+	assert_eq!(redacted["cookies"], "<redacted>");
+	assert_eq!(redacted["httpCredentials"], "<redacted>");
+	assert_eq!(redacted["extraHTTPHeaders"][0]["name"], "Authorization");
+	assert_eq!(redacted["extraHTTPHeaders"][0]["value"], "<redacted>");
+	assert_eq!(redacted["extraHTTPHeaders"][1]["value"], "text/html");
+}
+
+#[test]
+fn test_protocol_dump_redacts_local_storage_values() {
+	let result = serde_json::json!({
+		"origins": [{
+			"origin": "https://app.example.com",
+			"localStorage": [{"name": "token", "value": "secret"}]
+		}]
+	});
+
+	let redacted = redact(&result);
+	assert_eq!(redacted["origins"][0]["origin"], "https://app.example.com");
+	assert_eq!(redacted["origins"][0]["localStorage"][0]["name"], "token");
+	assert_eq!(redacted["origins"][0]["localStorage"][0]["value"], "<redacted>");
+}
@@ -16,6 +16,10 @@ pub enum Error {
 	#[error("Failed to launch Playwright server: {0}. Check that Node.js is installed.")]
 	LaunchFailed(String),
 
+	/// The driver started but never answered `initialize` within the handshake timeout.
+	#[error("Playwright driver did not complete its handshake (launch attempt {attempts})")]
+	HandshakeTimeout { attempts: u32 },
+
 	/// Server error (runtime issue with Playwright server).
 	#[error("Server error: {0}")]
 	ServerError(String),
@@ -86,6 +90,10 @@ pub enum Error {
 	/// Assertion timeout (expect API).
 	#[error("Assertion timeout: {0}")]
 	AssertionTimeout(String),
+
+	/// Operation not available for this browser engine (e.g. CDP-only APIs on Firefox).
+	#[error("Unsupported: {0}")]
+	UnsupportedMode(String),
 }
 
 impl Error {
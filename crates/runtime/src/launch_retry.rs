@@ -0,0 +1,170 @@
+//! Bounded exponential backoff for driver startup.
+//!
+//! The first launch after install can race the driver still being extracted,
+//! so spawning the process may fail transiently. [`retry_launch`] reruns the
+//! spawn a few times; a missing driver or a handshake that already waited out
+//! its timeout is reported straight away.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+/// Retry schedule for driver launch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaunchBackoff {
+	/// Total attempts, including the first one.
+	pub max_attempts: u32,
+	/// Delay after the first failed attempt; doubled after each further failure.
+	pub initial_delay: Duration,
+	/// Upper bound for a single delay.
+	pub max_delay: Duration,
+}
+
+impl Default for LaunchBackoff {
+	fn default() -> Self {
+		Self {
+			max_attempts: 4,
+			initial_delay: Duration::from_millis(250),
+			max_delay: Duration::from_secs(2),
+		}
+	}
+}
+
+impl LaunchBackoff {
+	/// Delay to wait after failed attempt number `attempt` (1-based).
+	pub fn delay_after(&self, attempt: u32) -> Duration {
+		let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+		self.initial_delay.saturating_mul(factor).min(self.max_delay)
+	}
+}
+
+/// Runs `launch` until it succeeds, a non-transient error occurs, or attempts run out.
+///
+/// `launch` receives the 1-based attempt number. Only a failed spawn
+/// ([`Error::LaunchFailed`]) is retried. A handshake [`Error::Timeout`] is
+/// returned at once as [`Error::HandshakeTimeout`], since another attempt would
+/// wait out the same timeout again; other errors, including a missing driver
+/// ([`Error::ServerNotFound`]), are returned unchanged.
+pub async fn retry_launch<T, F, Fut>(backoff: &LaunchBackoff, mut launch: F) -> Result<T>
+where
+	F: FnMut(u32) -> Fut,
+	Fut: Future<Output = Result<T>>,
+{
+	let max_attempts = backoff.max_attempts.max(1);
+	let mut attempt = 1;
+	loop {
+		let err = match launch(attempt).await {
+			Ok(value) => return Ok(value),
+			Err(Error::Timeout(_)) => return Err(Error::HandshakeTimeout { attempts: attempt }),
+			Err(err) if !is_transient(&err) => return Err(err),
+			Err(err) => err,
+		};
+
+		if attempt >= max_attempts {
+			return Err(err);
+		}
+
+		let delay = backoff.delay_after(attempt);
+		tracing::debug!(attempt, ?delay, error = %err, "driver launch failed, retrying");
+		tokio::time::sleep(delay).await;
+		attempt += 1;
+	}
+}
+
+fn is_transient(err: &Error) -> bool {
+	matches!(err, Error::LaunchFailed(_))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::{AtomicU32, Ordering};
+
+	use super::*;
+
+	fn fast() -> LaunchBackoff {
+		LaunchBackoff {
+			max_attempts: 4,
+			initial_delay: Duration::from_millis(1),
+			max_delay: Duration::from_millis(4),
+		}
+	}
+
+	#[test]
+	fn delays_double_up_to_the_cap() {
+		let backoff = LaunchBackoff::default();
+		let delays: Vec<_> = (1..=5).map(|attempt| backoff.delay_after(attempt).as_millis()).collect();
+		assert_eq!(delays, [250, 500, 1000, 2000, 2000]);
+		assert_eq!(backoff.delay_after(u32::MAX), backoff.max_delay);
+	}
+
+	#[tokio::test]
+	async fn retries_until_spawner_succeeds() {
+		let calls = AtomicU32::new(0);
+		let result = retry_launch(&fast(), |attempt| {
+			calls.fetch_add(1, Ordering::SeqCst);
+			async move {
+				match attempt {
+					1 | 2 => Err(Error::LaunchFailed("exited immediately".to_string())),
+					_ => Ok(attempt),
+				}
+			}
+		})
+		.await;
+
+		assert_eq!(result.unwrap(), 3);
+		assert_eq!(calls.load(Ordering::SeqCst), 3);
+	}
+
+	#[tokio::test]
+	async fn exhausted_spawn_failures_are_returned_unchanged() {
+		let calls = AtomicU32::new(0);
+		let err = retry_launch(&fast(), |_| {
+			calls.fetch_add(1, Ordering::SeqCst);
+			async { Err::<(), _>(Error::LaunchFailed("exited immediately".to_string())) }
+		})
+		.await
+		.unwrap_err();
+
+		assert!(matches!(err, Error::LaunchFailed(_)), "{err}");
+		assert_eq!(calls.load(Ordering::SeqCst), 4);
+	}
+
+	#[tokio::test]
+	async fn handshake_timeouts_are_reported_without_retrying() {
+		let calls = AtomicU32::new(0);
+		let err = retry_launch(&fast(), |attempt| {
+			calls.fetch_add(1, Ordering::SeqCst);
+			async move {
+				match attempt {
+					1 => Err::<(), _>(Error::LaunchFailed("exited immediately".to_string())),
+					_ => Err(Error::Timeout("initialize".to_string())),
+				}
+			}
+		})
+		.await
+		.unwrap_err();
+
+		assert!(matches!(err, Error::HandshakeTimeout { attempts: 2 }), "{err}");
+		assert_eq!(calls.load(Ordering::SeqCst), 2);
+	}
+
+	#[tokio::test]
+	async fn non_transient_errors_are_not_retried() {
+		for error in [Error::ProtocolError("bad initialize response".to_string()), Error::ServerNotFound] {
+			let calls = AtomicU32::new(0);
+			let expected = error.to_string();
+			let mut error = Some(error);
+			let err = retry_launch(&fast(), |_| {
+				calls.fetch_add(1, Ordering::SeqCst);
+				let error = error.take().expect("launched once");
+				async move { Err::<(), _>(error) }
+			})
+			.await
+			.unwrap_err();
+
+			assert_eq!(err.to_string(), expected);
+			assert_eq!(calls.load(Ordering::SeqCst), 1);
+		}
+	}
+}
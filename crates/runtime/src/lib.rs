@@ -40,6 +40,7 @@ pub mod channel_owner;
 pub mod connection;
 pub mod driver;
 pub mod error;
+pub mod launch_retry;
 pub mod playwright_server;
 pub mod process;
 pub mod transport;
@@ -47,12 +48,16 @@ pub mod transport;
 // Re-export key types at crate root
 pub use channel::Channel;
 pub use channel_owner::{ChannelOwner, ChannelOwnerImpl, DisposeReason, ParentOrConnection};
-pub use connection::{AsyncChannelOwnerResult, Connection, ConnectionLike, Event, Message, Metadata, ObjectFactory, ObjectStore, Request, Response};
+pub use connection::{
+	AsyncChannelOwnerResult, Connection, ConnectionLike, Event, ExitStatusProbe, Message, Metadata, ObjectFactory, ObjectStore, Request, Response,
+	install_protocol_dump,
+};
 pub use driver::{TestRunnerPaths, get_driver_executable, get_test_runner_paths};
 pub use error::{Error, Result};
-pub use playwright_server::PlaywrightServer;
-pub use process::{pid_is_alive, port_available};
+pub use launch_retry::{LaunchBackoff, retry_launch};
+pub use playwright_server::{DriverRegistry, PlaywrightServer, terminate_tracked_drivers, tracked_driver_registry};
+pub use process::{pid_is_alive, port_available, signal_pid};
 pub use transport::{
-	PipeTransport, PipeTransportReceiver, PipeTransportSender, Transport, TransportParts, TransportReceiver, WebSocketTransport, WebSocketTransportReceiver,
-	WebSocketTransportSender,
+	PipeTransport, PipeTransportReceiver, PipeTransportSender, Transport, TransportParts, TransportReceiver, WebSocketOptions, WebSocketTransport,
+	WebSocketTransportReceiver, WebSocketTransportSender, websocket_uses_tls,
 };
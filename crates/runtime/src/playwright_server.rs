@@ -2,11 +2,115 @@
 //!
 //! Handles downloading, launching, and managing the lifecycle of the Playwright
 //! Node.js server process.
+//!
+//! Every launched driver is tracked in a process-wide registry so that signal
+//! handlers can terminate orphan-prone drivers via [`terminate_tracked_drivers`]
+//! even when the owning handles are buried inside in-flight command futures.
+
+use std::time::Duration;
 
+use parking_lot::Mutex;
 use tokio::process::{Child, Command};
 
 use crate::driver::get_driver_executable;
 use crate::error::{Error, Result};
+use crate::process::{pid_is_alive, signal_pid};
+
+/// PIDs of driver processes launched by this process that are still expected to be running.
+static TRACKED_DRIVERS: DriverRegistry = DriverRegistry::new();
+
+/// Adds `pid` to the driver registry used by [`terminate_tracked_drivers`].
+pub fn track_driver(pid: u32) {
+	TRACKED_DRIVERS.track(pid);
+}
+
+/// Removes `pid` from the driver registry (e.g. when it is intentionally kept alive).
+pub fn untrack_driver(pid: u32) {
+	TRACKED_DRIVERS.untrack(pid);
+}
+
+/// Returns the PIDs of currently tracked driver processes.
+pub fn tracked_drivers() -> Vec<u32> {
+	TRACKED_DRIVERS.pids()
+}
+
+/// The process-wide registry behind [`track_driver`] and [`terminate_tracked_drivers`].
+pub const fn tracked_driver_registry() -> &'static DriverRegistry {
+	&TRACKED_DRIVERS
+}
+
+/// Terminates every tracked driver, waiting up to `grace` before force-killing.
+///
+/// The registry is drained up front, so concurrent or repeated calls never
+/// signal the same driver twice. Returns the number of drivers that were signalled.
+pub async fn terminate_tracked_drivers(grace: Duration) -> usize {
+	TRACKED_DRIVERS.terminate(grace).await
+}
+
+/// Set of driver PIDs to terminate on shutdown.
+///
+/// Launched drivers go into the process-wide registry; separate instances let
+/// callers such as tests terminate only the drivers they tracked.
+#[derive(Debug)]
+pub struct DriverRegistry {
+	pids: Mutex<Vec<u32>>,
+}
+
+impl DriverRegistry {
+	/// Creates an empty registry.
+	pub const fn new() -> Self {
+		Self { pids: Mutex::new(Vec::new()) }
+	}
+
+	/// Adds `pid`, ignoring duplicates.
+	pub fn track(&self, pid: u32) {
+		let mut tracked = self.pids.lock();
+		if !tracked.contains(&pid) {
+			tracked.push(pid);
+		}
+	}
+
+	/// Removes `pid`.
+	pub fn untrack(&self, pid: u32) {
+		self.pids.lock().retain(|tracked| *tracked != pid);
+	}
+
+	/// Returns the tracked PIDs.
+	pub fn pids(&self) -> Vec<u32> {
+		self.pids.lock().clone()
+	}
+
+	/// Drains the registry, then signals each driver and force-kills any still alive after `grace`.
+	pub async fn terminate(&self, grace: Duration) -> usize {
+		let pids = std::mem::take(&mut *self.pids.lock());
+		if pids.is_empty() {
+			return 0;
+		}
+
+		for &pid in &pids {
+			tracing::debug!(pid, "terminating Playwright driver");
+			signal_pid(pid, false);
+		}
+
+		let deadline = tokio::time::Instant::now() + grace;
+		while pids.iter().any(|&pid| pid_is_alive(pid)) && tokio::time::Instant::now() < deadline {
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+
+		for &pid in pids.iter().filter(|&&pid| pid_is_alive(pid)) {
+			tracing::debug!(pid, "force-killing Playwright driver after grace period");
+			signal_pid(pid, true);
+		}
+
+		pids.len()
+	}
+}
+
+impl Default for DriverRegistry {
+	fn default() -> Self {
+		Self::new()
+	}
+}
 
 /// Manages the Playwright server process lifecycle
 ///
@@ -74,9 +178,18 @@ impl PlaywrightServer {
 			}
 		}
 
+		if let Some(pid) = child.id() {
+			track_driver(pid);
+		}
+
 		Ok(Self { process: child })
 	}
 
+	/// Returns the driver process id, if the process is still running.
+	pub fn pid(&self) -> Option<u32> {
+		self.process.id()
+	}
+
 	/// Shut down the server gracefully
 	///
 	/// Sends a shutdown signal to the server and waits for it to exit.
@@ -90,6 +203,10 @@ impl PlaywrightServer {
 	///
 	/// Unix: Uses standard process termination with graceful wait.
 	pub async fn shutdown(mut self) -> Result<()> {
+		if let Some(pid) = self.pid() {
+			untrack_driver(pid);
+		}
+
 		#[cfg(windows)]
 		{
 			drop(self.process.stdin.take());
@@ -128,6 +245,10 @@ impl PlaywrightServer {
 	///
 	/// This should only be used if graceful shutdown fails.
 	pub async fn kill(mut self) -> Result<()> {
+		if let Some(pid) = self.pid() {
+			untrack_driver(pid);
+		}
+
 		#[cfg(windows)]
 		{
 			drop(self.process.stdin.take());
@@ -178,6 +299,28 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn terminate_tracked_drivers_is_idempotent() {
+		#[cfg(unix)]
+		let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+		#[cfg(windows)]
+		let mut child = std::process::Command::new("ping").args(["-n", "30", "127.0.0.1"]).spawn().unwrap();
+
+		let registry = DriverRegistry::new();
+		registry.track(child.id());
+		registry.track(child.id());
+		assert_eq!(registry.pids(), [child.id()]);
+
+		let first = registry.terminate(Duration::from_secs(2)).await;
+		let second = registry.terminate(Duration::from_secs(2)).await;
+		assert_eq!(first, 1);
+		assert_eq!(second, 0);
+		assert!(registry.pids().is_empty());
+
+		let status = child.wait().unwrap();
+		assert!(!status.success());
+	}
+
 	#[tokio::test]
 	async fn test_server_can_be_killed() {
 		let result = PlaywrightServer::launch().await;
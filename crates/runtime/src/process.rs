@@ -41,6 +41,43 @@ pub fn pid_is_alive(pid: u32) -> bool {
 	}
 }
 
+/// Sends a termination request to `pid`, escalating to a hard kill when `force` is set.
+///
+/// Returns `true` when the platform tool reported success.
+pub fn signal_pid(pid: u32, force: bool) -> bool {
+	if pid == 0 || pid == std::process::id() {
+		return false;
+	}
+
+	#[cfg(unix)]
+	{
+		let signal = if force { "-KILL" } else { "-TERM" };
+		std::process::Command::new("kill")
+			.arg(signal)
+			.arg(pid.to_string())
+			.status()
+			.map(|status| status.success())
+			.unwrap_or(false)
+	}
+
+	#[cfg(windows)]
+	{
+		let pid = pid.to_string();
+		let mut cmd = std::process::Command::new("taskkill");
+		cmd.args(["/PID", pid.as_str(), "/T"]);
+		if force {
+			cmd.arg("/F");
+		}
+		cmd.status().map(|status| status.success()).unwrap_or(false)
+	}
+
+	#[cfg(not(any(unix, windows)))]
+	{
+		let _ = force;
+		false
+	}
+}
+
 /// Returns `true` when `port` can be bound on localhost.
 pub fn port_available(port: u16) -> bool {
 	std::net::TcpListener::bind(("127.0.0.1", port)).is_ok()
@@ -87,6 +124,12 @@ mod tests {
 		assert!(pid_is_alive(std::process::id()));
 	}
 
+	#[test]
+	fn signal_pid_refuses_self_and_zero() {
+		assert!(!signal_pid(0, false));
+		assert!(!signal_pid(std::process::id(), true));
+	}
+
 	#[cfg(unix)]
 	#[test]
 	fn pid_zero_is_never_alive() {
@@ -222,3 +222,18 @@ async fn test_graceful_shutdown() {
 	// Should succeed - channel closed is expected shutdown
 	assert!(result.is_ok() || result.unwrap_err().to_string().contains("Failed to read"));
 }
+
+#[test]
+fn websocket_tls_follows_url_scheme() {
+	assert!(!websocket_uses_tls("ws://127.0.0.1:9222/devtools/browser/abc").unwrap());
+	assert!(websocket_uses_tls("wss://grid.example.com/playwright").unwrap());
+	assert!(websocket_uses_tls("WSS://grid.example.com/").unwrap());
+	assert!(websocket_uses_tls("https://grid.example.com/").is_err());
+	assert!(websocket_uses_tls("grid.example.com:443").is_err());
+}
+
+#[test]
+fn insecure_tls_config_builds() {
+	let config = insecure_tls::client_config().unwrap();
+	assert!(config.alpn_protocols.is_empty());
+}
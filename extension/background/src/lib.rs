@@ -70,17 +70,31 @@ enum BackgroundResponse {
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum ServerMessage {
-	Welcome { version: String },
-	Rejected { reason: String },
-	Received { domains_saved: usize, paths: Vec<String> },
-	Error { message: String },
+	Welcome {
+		version: String,
+		#[serde(default)]
+		protocol_version: u32,
+	},
+	Rejected {
+		reason: String,
+	},
+	Received {
+		domains_saved: usize,
+		paths: Vec<String>,
+	},
+	Error {
+		message: String,
+	},
 }
 
+/// Cookie exchange protocol version (mirrors `pw_protocol::AUTH_PROTOCOL_VERSION`).
+const AUTH_PROTOCOL_VERSION: u32 = 1;
+
 /// Messages sent to the server (mirrors `pw_protocol::ExtensionMessage`).
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum ExtensionMessage {
-	Hello { token: String },
+	Hello { token: String, protocol_version: u32 },
 	PushCookies { domains: Vec<DomainCookies> },
 }
 
@@ -276,7 +290,10 @@ async fn connect_to_server(server: &str, token: &str) -> BackgroundResponse {
 	let token_clone = token.clone();
 	let onopen = Closure::<dyn FnMut()>::new(move || {
 		log("Connected, sending hello");
-		let hello = ExtensionMessage::Hello { token: token_clone.clone() };
+		let hello = ExtensionMessage::Hello {
+			token: token_clone.clone(),
+			protocol_version: AUTH_PROTOCOL_VERSION,
+		};
 		if let Ok(json) = serde_json::to_string(&hello) {
 			let _ = ws_clone.send_with_str(&json);
 		}
@@ -321,8 +338,8 @@ fn handle_server_message(text: &str) {
 	};
 
 	match msg {
-		ServerMessage::Welcome { version } => {
-			log(&format!("Authenticated with server v{version}"));
+		ServerMessage::Welcome { version, protocol_version } => {
+			log(&format!("Authenticated with server v{version} (protocol {protocol_version})"));
 			STATE.with(|state| state.borrow_mut().authenticated = true);
 			notify_popup(&BackgroundResponse::Status {
 				connected: true,